@@ -0,0 +1,560 @@
+//! Runtime options shared by the TUI and the k8s client layer.
+//!
+//! `main` builds an [`AppConfig`] from the command line and hands it to
+//! `tui::ui_loop::run`, which installs it so the client and the background
+//! polling tasks can read it without threading it through every app.
+use crate::error::{Error, Result};
+use crate::k8s::custom::parse_kind;
+use kube::config::Kubeconfig;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+use std::time::Duration;
+use tracing::warn;
+
+const DEFAULT_POLL_SECS: u64 = 5;
+pub const DEFAULT_PAGE_SIZE: u32 = 500;
+/// Annotation that, set to `true`, keeps a pod from being deleted in the TUI
+pub const DEFAULT_PROTECTION_ANNOTATION: &str = "k8p.navicore.tech/protected";
+
+static APP_CONFIG: OnceLock<RwLock<AppConfig>> = OnceLock::new();
+
+/// File format used when a view is written out with `w`
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, clap::ValueEnum)]
+pub enum ExportFormat {
+    #[default]
+    Json,
+    Yaml,
+}
+
+impl ExportFormat {
+    #[must_use]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Yaml => "yaml",
+        }
+    }
+}
+
+/// The view the TUI opens on, `--view` or `view` in the config file
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, clap::ValueEnum)]
+pub enum StartView {
+    /// the ReplicaSets with the Deployments that own them, also accepted as `deployments`
+    #[default]
+    #[value(alias = "deployments")]
+    Replicasets,
+    /// every pod in the namespace
+    Pods,
+    Nodes,
+    Problems,
+}
+
+impl StartView {
+    /// The view named `name` as it would be given to `--view`
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if no view goes by that name
+    pub fn parse(name: &str) -> Result<Self> {
+        <Self as clap::ValueEnum>::from_str(name, true).map_err(|_| {
+            Error::InvalidConfig(format!(
+                "unknown view {name}, expected replicasets, pods, nodes or problems"
+            ))
+        })
+    }
+}
+
+/// The views that poll the cluster, each on an interval of its own
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, clap::ValueEnum)]
+pub enum PollView {
+    Replicasets,
+    Pods,
+    Logs,
+    Events,
+    Nodes,
+    Namespaces,
+    /// the ConfigMap and the Secret views
+    Configmaps,
+    Jobs,
+    Daemonsets,
+    Services,
+    Pvcs,
+    Problems,
+    /// the objects of a custom resource kind
+    Custom,
+    /// the api resource kinds found by discovery
+    Resources,
+}
+
+impl PollView {
+    /// The view named `name` as it is written under `poll_intervals` in the config file
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if no polling view goes by that name
+    pub fn parse(name: &str) -> Result<Self> {
+        <Self as clap::ValueEnum>::from_str(name, true).map_err(|_| {
+            let known: Vec<String> = <Self as clap::ValueEnum>::value_variants()
+                .iter()
+                .filter_map(clap::ValueEnum::to_possible_value)
+                .map(|value| value.get_name().to_string())
+                .collect();
+            Error::InvalidConfig(format!(
+                "unknown view {name} in poll_intervals, expected one of {}",
+                known.join(", ")
+            ))
+        })
+    }
+
+    /// How many base poll intervals pass between lists when the config file does not
+    /// say. Nodes and discovery change rarely, and discovery walks every api group.
+    const fn default_factor(self) -> u32 {
+        match self {
+            Self::Nodes | Self::Resources => 6,
+            Self::Namespaces | Self::Pvcs => 3,
+            _ => 1,
+        }
+    }
+}
+
+/// Windows the `s` key steps through in the log and event views
+pub const SINCE_STEPS: [Duration; 4] = [
+    Duration::from_secs(60),
+    Duration::from_secs(5 * 60),
+    Duration::from_secs(15 * 60),
+    Duration::from_secs(60 * 60),
+];
+
+/// A time window such as `30s`, `5m`, `1h` or `2d`
+///
+/// # Errors
+///
+/// Will return `Err` if `text` is not a positive number followed by s, m, h or d
+pub fn parse_since(text: &str) -> Result<Duration> {
+    let invalid = || {
+        Error::InvalidConfig(format!(
+            "--since {text} is not a duration such as 30s, 5m, 1h or 2d"
+        ))
+    };
+    let text = text.trim();
+    let unit_at = text.char_indices().last().ok_or_else(invalid)?.0;
+    let (count, unit) = text.split_at(unit_at);
+    let count: u64 = count.parse().map_err(|_| invalid())?;
+    let scale = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 60 * 60 * 24,
+        _ => return Err(invalid()),
+    };
+    match count.checked_mul(scale) {
+        Some(seconds) if seconds > 0 => Ok(Duration::from_secs(seconds)),
+        _ => Err(invalid()),
+    }
+}
+
+/// `window` in its largest whole unit, e.g. `5m`
+#[must_use]
+pub fn since_label(window: Duration) -> String {
+    let seconds = window.as_secs();
+    [(86_400, "d"), (3_600, "h"), (60, "m")]
+        .iter()
+        .find(|(unit, _)| seconds % unit == 0)
+        .map_or_else(
+            || format!("{seconds}s"),
+            |(unit, suffix)| format!("{}{suffix}", seconds / unit),
+        )
+}
+
+/// The window after `since` in [`SINCE_STEPS`], `None` (everything) after the last
+#[must_use]
+pub fn next_since(since: Option<Duration>) -> Option<Duration> {
+    match since {
+        None => SINCE_STEPS.first().copied(),
+        Some(window) => SINCE_STEPS.iter().copied().find(|step| *step > window),
+    }
+}
+
+/// How the `/` filter text is matched against rows
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum FilterMode {
+    /// characters in order but not necessarily adjacent, best matches first
+    #[default]
+    Fuzzy,
+    /// the filter is a regular expression, rows keep their order
+    Regex,
+}
+
+/// How table columns share the terminal width, cycled with `a`
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ColumnFit {
+    /// each column is as wide as its longest cell
+    #[default]
+    Content,
+    /// every column gets an equal share of the width
+    Balanced,
+    /// content widths, but no column wider than [`COLUMN_CAP_PERCENT`] of the table;
+    /// longer cells are cut with an ellipsis
+    Truncate,
+}
+
+/// The widest a column may grow in [`ColumnFit::Truncate`], as a percentage of the table
+pub const COLUMN_CAP_PERCENT: u16 = 40;
+
+impl ColumnFit {
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Content => Self::Balanced,
+            Self::Balanced => Self::Truncate,
+            Self::Truncate => Self::Content,
+        }
+    }
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Content => "content",
+            Self::Balanced => "balanced",
+            Self::Truncate => "truncate",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AppConfig {
+    pub namespace: Option<String>,
+    pub context: Option<String>,
+    pub kubeconfig: Option<PathBuf>,
+    pub poll_interval: Duration,
+    /// intervals set per view in the config file, in place of the scaled `poll_interval`
+    pub poll_overrides: BTreeMap<PollView, Duration>,
+    /// objects asked for per list request, the rest follow with continue tokens
+    pub page_size: u32,
+    pub export_format: ExportFormat,
+    pub filter_mode: FilterMode,
+    pub column_fit: ColumnFit,
+    /// no colors, for `--no-color` or a set `NO_COLOR`
+    pub monochrome: bool,
+    /// start in the default context and namespace instead of the last session's, `--fresh`
+    pub fresh: bool,
+    /// open the TUI on the objects of this `group/version/Kind`, `--resource`
+    pub resource: Option<String>,
+    /// `j` on the last row moves to the first and `k` on the first to the last
+    pub wrap_navigation: bool,
+    /// keys that would change the cluster only flash a message, `--read-only`
+    pub read_only: bool,
+    /// deletes and edits are sent as a server side dry run first and its outcome shown
+    /// in the confirmation, `--dry-run-first`
+    pub dry_run_first: bool,
+    /// pods that completed or failed are listed when the pod view opens
+    pub show_finished: bool,
+    /// table rows take one line instead of three, toggled with Ctrl-T
+    pub compact_rows: bool,
+    /// pods with this annotation set to `true` are refused when deleted
+    pub protection_annotation: String,
+    /// labels the api narrows the ReplicaSet and pod lists to, typed after `L` in the TUI
+    pub api_selector: BTreeMap<String, String>,
+    /// the view at the bottom of the navigation stack, `--view`
+    pub view: Option<StartView>,
+    /// write the names of the checked pods to stdout on exit, `--print-selection`
+    pub print_selection: bool,
+    /// only logs and events this recent, `--since`, stepped with `s` in those views
+    pub since: Option<Duration>,
+    /// the columns a view's table shows in order, by view, chosen with `|` in the TUI
+    pub columns: BTreeMap<String, Vec<String>>,
+    /// pod rows younger than this are tinted as likely part of a rollout, `new_pod_age`
+    pub new_pod_age: Option<Duration>,
+    /// pod rows older than this are tinted as possibly forgotten, `old_pod_age`
+    pub old_pod_age: Option<Duration>,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            namespace: None,
+            context: None,
+            kubeconfig: None,
+            poll_interval: Duration::from_secs(DEFAULT_POLL_SECS),
+            poll_overrides: BTreeMap::new(),
+            page_size: DEFAULT_PAGE_SIZE,
+            export_format: ExportFormat::default(),
+            filter_mode: FilterMode::default(),
+            column_fit: ColumnFit::default(),
+            monochrome: false,
+            fresh: false,
+            resource: None,
+            wrap_navigation: false,
+            read_only: false,
+            dry_run_first: false,
+            show_finished: false,
+            compact_rows: false,
+            protection_annotation: DEFAULT_PROTECTION_ANNOTATION.to_string(),
+            api_selector: BTreeMap::new(),
+            view: None,
+            print_selection: false,
+            since: None,
+            columns: BTreeMap::new(),
+            new_pod_age: None,
+            old_pod_age: None,
+        }
+    }
+}
+
+impl AppConfig {
+    /// How often `view` lists its objects: its own interval from the config file, else
+    /// `poll_interval` scaled by how fast that kind of object tends to change
+    #[must_use]
+    pub fn poll_interval_for(&self, view: PollView) -> Duration {
+        self.poll_overrides
+            .get(&view)
+            .copied()
+            .unwrap_or_else(|| self.poll_interval * view.default_factor())
+    }
+
+    /// Check option combinations that can not work together.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the options are inconsistent or name a kubeconfig or
+    /// context that does not exist
+    pub fn validate(&self) -> Result<()> {
+        if self.poll_interval.is_zero() {
+            return Err(Error::InvalidConfig(
+                "--poll-interval must be greater than zero".to_string(),
+            ));
+        }
+
+        if self.page_size == 0 {
+            return Err(Error::InvalidConfig(
+                "--page-size must be greater than zero".to_string(),
+            ));
+        }
+
+        if let Some(resource) = &self.resource {
+            parse_kind(resource)?;
+        }
+
+        if let Some(path) = &self.kubeconfig {
+            if !path.is_file() {
+                return Err(Error::InvalidConfig(format!(
+                    "--kubeconfig {} is not a readable file",
+                    path.display()
+                )));
+            }
+        }
+
+        if let Some(context) = &self.context {
+            let kubeconfig = read_kubeconfig(self.kubeconfig.as_deref())?;
+            if !kubeconfig.contexts.iter().any(|c| &c.name == context) {
+                return Err(Error::InvalidConfig(format!(
+                    "--context {context} not found in kubeconfig"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Contexts of `next` that `merged` already has, and so are ignored when it is merged in
+fn shadowed_contexts(merged: &Kubeconfig, next: &Kubeconfig) -> Vec<String> {
+    next.contexts
+        .iter()
+        .filter(|context| merged.contexts.iter().any(|c| c.name == context.name))
+        .map(|context| context.name.clone())
+        .collect()
+}
+
+/// The kubeconfig at `path`, or else every file listed in `KUBECONFIG` merged into one.
+/// Like kubectl the first file naming a context wins; the later ones that name it again
+/// are reported. Listed files that do not exist are skipped.
+///
+/// # Errors
+///
+/// Will return `Err` if a kubeconfig can not be read or parsed
+pub fn read_kubeconfig(path: Option<&Path>) -> Result<Kubeconfig> {
+    if let Some(path) = path {
+        return Ok(Kubeconfig::read_from(path)?);
+    }
+    let Some(paths) = std::env::var_os("KUBECONFIG").filter(|paths| !paths.is_empty()) else {
+        return Ok(Kubeconfig::read()?);
+    };
+    let mut merged: Option<Kubeconfig> = None;
+    for path in std::env::split_paths(&paths).filter(|path| path.is_file()) {
+        let next = Kubeconfig::read_from(&path)?;
+        merged = Some(match merged {
+            None => next,
+            Some(merged) => {
+                for name in shadowed_contexts(&merged, &next) {
+                    warn!(
+                        "context {name} in {} is ignored, an earlier KUBECONFIG file defines it",
+                        path.display()
+                    );
+                }
+                merged.merge(next)?
+            }
+        });
+    }
+    match merged {
+        Some(merged) => Ok(merged),
+        None => Ok(Kubeconfig::read()?),
+    }
+}
+
+fn cell() -> &'static RwLock<AppConfig> {
+    APP_CONFIG.get_or_init(|| RwLock::new(AppConfig::default()))
+}
+
+/// true when the `NO_COLOR` environment variable is set to anything but an empty string,
+/// see <https://no-color.org>
+#[must_use]
+pub fn no_color_env() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+/// Make `config` the active configuration for the rest of the process.
+pub fn install(config: AppConfig) {
+    if let Ok(mut active) = cell().write() {
+        *active = config;
+    }
+}
+
+/// Change the active configuration in place, for settings toggled from the TUI.
+pub fn update(change: impl FnOnce(&mut AppConfig)) {
+    if let Ok(mut active) = cell().write() {
+        change(&mut active);
+    }
+}
+
+/// A snapshot of the active configuration.
+#[must_use]
+pub fn current() -> AppConfig {
+    cell()
+        .read()
+        .map_or_else(|_| AppConfig::default(), |active| active.clone())
+}
+
+/// The active [`FilterMode`], read without the copy of the whole configuration
+/// [`current`] makes, for code that runs once per table row
+#[must_use]
+pub fn filter_mode() -> FilterMode {
+    cell()
+        .read()
+        .map_or_else(|_| FilterMode::default(), |active| active.filter_mode)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{
+        next_since, parse_since, shadowed_contexts, since_label, AppConfig, PollView, StartView,
+    };
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    #[test]
+    fn test_validate_rejects_zero_poll_interval() {
+        let config = AppConfig {
+            poll_interval: Duration::from_secs(0),
+            ..AppConfig::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_poll_interval_for_prefers_the_override() {
+        let config = AppConfig {
+            poll_overrides: [(PollView::Pods, Duration::from_secs(2))].into(),
+            ..AppConfig::default()
+        };
+        assert_eq!(
+            config.poll_interval_for(PollView::Pods),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            config.poll_interval_for(PollView::Nodes),
+            config.poll_interval * 6
+        );
+        assert_eq!(
+            config.poll_interval_for(PollView::Events),
+            config.poll_interval
+        );
+        assert!(PollView::parse("deployments").is_err());
+    }
+
+    #[test]
+    fn test_deployments_view_is_the_replicaset_view() {
+        assert_eq!(
+            StartView::parse("deployments").ok(),
+            Some(StartView::Replicasets)
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_page_size() {
+        let config = AppConfig {
+            page_size: 0,
+            ..AppConfig::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_missing_kubeconfig() {
+        let config = AppConfig {
+            kubeconfig: Some(PathBuf::from("/nonexistent/navipod/kubeconfig")),
+            ..AppConfig::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_accepts_defaults() {
+        assert!(AppConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_parse_since_reads_units() {
+        assert_eq!(parse_since("5m").ok(), Some(Duration::from_secs(300)));
+        assert_eq!(parse_since("1h").ok(), Some(Duration::from_secs(3600)));
+        assert!(parse_since("0s").is_err());
+        assert!(parse_since("5").is_err());
+        assert!(parse_since("").is_err());
+        assert_eq!(since_label(Duration::from_secs(7200)), "2h");
+        assert_eq!(since_label(Duration::from_secs(90)), "90s");
+    }
+
+    #[test]
+    fn test_next_since_wraps_to_everything() {
+        assert_eq!(next_since(None), Some(Duration::from_secs(60)));
+        assert_eq!(
+            next_since(Some(Duration::from_secs(120))),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(next_since(Some(Duration::from_secs(3600))), None);
+    }
+
+    #[test]
+    fn test_shadowed_contexts_are_the_repeated_names() {
+        use kube::config::{Kubeconfig, NamedContext};
+
+        let kubeconfig = |names: &[&str]| Kubeconfig {
+            contexts: names
+                .iter()
+                .map(|name| NamedContext {
+                    name: (*name).to_string(),
+                    context: None,
+                })
+                .collect(),
+            ..Kubeconfig::default()
+        };
+        assert_eq!(
+            shadowed_contexts(
+                &kubeconfig(&["dev", "prod"]),
+                &kubeconfig(&["prod", "stage"])
+            ),
+            vec!["prod".to_string()]
+        );
+    }
+}
@@ -0,0 +1,416 @@
+//! Preferences that outlive a single run, kept as YAML in
+//! `$XDG_CONFIG_HOME/navipod/config.yaml` (or `~/.config/navipod/config.yaml`).
+use crate::config::{parse_since, AppConfig, PollView, StartView};
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+use tracing::warn;
+
+const DEFAULT_DETAILS_PERCENT: u16 = 40;
+const MIN_DETAILS_PERCENT: u16 = 10;
+const MAX_DETAILS_PERCENT: u16 = 80;
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfigFile {
+    /// namespaces listed first in the namespace view
+    pub pinned_namespaces: Vec<String>,
+    /// height of the details pane under the ReplicaSet table, in percent
+    pub details_percent: u16,
+    /// context in use when the TUI last exited, `None` for the kubeconfig's current context
+    pub last_context: Option<String>,
+    /// namespace in use when the TUI last exited, `None` for the context's default
+    pub last_namespace: Option<String>,
+    /// whether row navigation wraps around at the ends of a table
+    pub wrap_navigation: bool,
+    /// whether the pod view lists completed and failed pods from the start
+    pub show_finished: bool,
+    /// whether table rows take one line instead of three
+    pub compact_rows: bool,
+    /// annotation marking pods that must not be deleted, when a team already has its own
+    pub protection_annotation: Option<String>,
+    /// the view the TUI opens on when `--view` is not given, e.g. `pods`
+    pub view: Option<String>,
+    /// how far back logs and events go when `--since` is not given, e.g. `15m`
+    pub since: Option<String>,
+    /// namespaces Ctrl-O steps through in turn, e.g. `[app, app-canary]`
+    pub namespace_cycle: Vec<String>,
+    /// how often a view lists its objects, by view, e.g. `{nodes: 30s, pods: 5s}`
+    pub poll_intervals: BTreeMap<String, String>,
+    /// the columns a view shows in order, by view, e.g. `{pods: [name, status, node]}`
+    pub columns: BTreeMap<String, Vec<String>>,
+    /// deletes and edits are sent as a server side dry run first, like `--dry-run-first`
+    pub dry_run_first: bool,
+    /// pods younger than this are tinted in the pod view, e.g. `2m`
+    pub new_pod_age: Option<String>,
+    /// pods older than this are tinted in the pod view, e.g. `30d`
+    pub old_pod_age: Option<String>,
+    /// resources marked with `"` to open again from the `'` list
+    pub bookmarks: Vec<Bookmark>,
+}
+
+/// A resource kept in the config file's `bookmarks`, e.g. a `Pod` in `app`
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Bookmark {
+    /// `Pod` or `ReplicaSet`
+    pub kind: String,
+    pub namespace: String,
+    pub name: String,
+}
+
+impl Bookmark {
+    #[must_use]
+    pub fn new(kind: &str, namespace: &str, name: &str) -> Self {
+        Self {
+            kind: kind.to_string(),
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+        }
+    }
+}
+
+impl Default for ConfigFile {
+    fn default() -> Self {
+        Self {
+            pinned_namespaces: Vec::new(),
+            details_percent: DEFAULT_DETAILS_PERCENT,
+            last_context: None,
+            last_namespace: None,
+            wrap_navigation: false,
+            show_finished: false,
+            compact_rows: false,
+            protection_annotation: None,
+            view: None,
+            since: None,
+            namespace_cycle: Vec::new(),
+            poll_intervals: BTreeMap::new(),
+            columns: BTreeMap::new(),
+            dry_run_first: false,
+            new_pod_age: None,
+            old_pod_age: None,
+            bookmarks: Vec::new(),
+        }
+    }
+}
+
+/// Grow (positive `step`) or shrink the details pane, keeping some of both panes visible
+#[must_use]
+pub fn resize_details(percent: u16, step: i16) -> u16 {
+    percent
+        .saturating_add_signed(step)
+        .clamp(MIN_DETAILS_PERCENT, MAX_DETAILS_PERCENT)
+}
+
+/// One `poll_intervals` entry, e.g. `nodes: 30s`
+fn parse_poll_interval(view: &str, every: &str) -> Result<(PollView, Duration)> {
+    let interval = parse_since(every).map_err(|_| {
+        Error::InvalidConfig(format!(
+            "poll_intervals {view}: {every} is not a duration such as 30s or 2m"
+        ))
+    })?;
+    Ok((PollView::parse(view)?, interval))
+}
+
+/// A `new_pod_age` or `old_pod_age` setting, e.g. `2m`
+fn parse_pod_age(key: &str, age: Option<&str>) -> Result<Option<Duration>> {
+    age.map(|age| {
+        parse_since(age).map_err(|_| {
+            Error::InvalidConfig(format!(
+                "{key}: {age} is not a duration such as 2m, 1h or 30d"
+            ))
+        })
+    })
+    .transpose()
+}
+
+/// Where the config file lives, `None` when neither `XDG_CONFIG_HOME` nor `HOME` is set
+#[must_use]
+pub fn path() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("navipod").join("config.yaml"))
+}
+
+impl ConfigFile {
+    /// The saved preferences, or the defaults when there is no usable file.
+    #[must_use]
+    pub fn load() -> Self {
+        let Some(path) = path() else {
+            return Self::default();
+        };
+        let Ok(text) = fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        serde_yaml::from_str(&text).unwrap_or_else(|e| {
+            warn!("ignoring unreadable config file {}: {e}", path.display());
+            Self::default()
+        })
+    }
+
+    /// # Errors
+    ///
+    /// Will return `Err` if the config directory or file can not be written
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = path() else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_yaml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// `app_config` with the context and namespace of the last session filled in. Only
+    /// applied when none of `--context`, `--namespace` or `--kubeconfig` were given, since
+    /// the saved pair belongs to the default kubeconfig. A saved context that is gone
+    /// from the kubeconfig is dropped with a warning.
+    #[must_use]
+    pub fn restore_session(&self, app_config: AppConfig) -> AppConfig {
+        if app_config.context.is_some()
+            || app_config.namespace.is_some()
+            || app_config.kubeconfig.is_some()
+        {
+            return app_config;
+        }
+        let restored = AppConfig {
+            context: self.last_context.clone(),
+            namespace: self.last_namespace.clone(),
+            ..app_config.clone()
+        };
+        match restored.validate() {
+            Ok(()) => restored,
+            Err(e) => {
+                warn!("not restoring the last session: {e}");
+                app_config
+            }
+        }
+    }
+
+    /// `app_config` with the preferences that are only set in the file, and the saved
+    /// start view and time window unless `--view` or `--since` chose them
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the saved view or window is not one the flags accept, or a
+    /// poll interval names an unknown view or is not a duration
+    pub fn apply_preferences(&self, app_config: AppConfig) -> Result<AppConfig> {
+        let view = match (app_config.view, self.view.as_deref()) {
+            (None, Some(name)) => Some(StartView::parse(name)?),
+            (view, _) => view,
+        };
+        let since = match (app_config.since, self.since.as_deref()) {
+            (None, Some(window)) => Some(parse_since(window)?),
+            (since, _) => since,
+        };
+        let protection_annotation = self
+            .protection_annotation
+            .clone()
+            .unwrap_or_else(|| app_config.protection_annotation.clone());
+        let poll_overrides = self
+            .poll_intervals
+            .iter()
+            .map(|(view, every)| parse_poll_interval(view, every))
+            .collect::<Result<_>>()?;
+        let new_pod_age = parse_pod_age("new_pod_age", self.new_pod_age.as_deref())?;
+        let old_pod_age = parse_pod_age("old_pod_age", self.old_pod_age.as_deref())?;
+        Ok(AppConfig {
+            wrap_navigation: self.wrap_navigation,
+            show_finished: self.show_finished,
+            compact_rows: self.compact_rows,
+            protection_annotation,
+            view,
+            since,
+            poll_overrides,
+            columns: self.columns.clone(),
+            new_pod_age,
+            old_pod_age,
+            dry_run_first: app_config.dry_run_first || self.dry_run_first,
+            ..app_config
+        })
+    }
+
+    /// Remember the context and namespace of `app_config` for the next run
+    pub fn record_session(&mut self, app_config: &AppConfig) {
+        self.last_context.clone_from(&app_config.context);
+        self.last_namespace.clone_from(&app_config.namespace);
+    }
+
+    #[must_use]
+    pub fn is_pinned(&self, namespace: &str) -> bool {
+        self.pinned_namespaces
+            .iter()
+            .any(|pinned| pinned == namespace)
+    }
+
+    /// The namespace of the cycle after `current`, wrapping around. A namespace outside
+    /// the cycle steps to its first one.
+    #[must_use]
+    pub fn next_in_cycle(&self, current: Option<&str>) -> Option<&str> {
+        let next = current
+            .and_then(|current| {
+                self.namespace_cycle
+                    .iter()
+                    .position(|namespace| namespace == current)
+            })
+            .map_or(0, |index| (index + 1) % self.namespace_cycle.len());
+        self.namespace_cycle.get(next).map(String::as_str)
+    }
+
+    /// Bookmark `bookmark` or drop it if it already is, returning whether it is now kept.
+    pub fn toggle_bookmark(&mut self, bookmark: &Bookmark) -> bool {
+        if self.bookmarks.contains(bookmark) {
+            self.bookmarks.retain(|kept| kept != bookmark);
+            false
+        } else {
+            self.bookmarks.push(bookmark.clone());
+            true
+        }
+    }
+
+    /// Pin `namespace` or unpin it if it already is, returning whether it is now pinned.
+    pub fn toggle_pin(&mut self, namespace: &str) -> bool {
+        if self.is_pinned(namespace) {
+            self.pinned_namespaces.retain(|pinned| pinned != namespace);
+            false
+        } else {
+            self.pinned_namespaces.push(namespace.to_string());
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resize_details, Bookmark, ConfigFile};
+    use crate::config::AppConfig;
+
+    #[test]
+    fn test_toggle_pin() {
+        let mut config = ConfigFile::default();
+        assert!(config.toggle_pin("app"));
+        assert!(config.is_pinned("app"));
+        assert!(!config.toggle_pin("app"));
+        assert!(config.pinned_namespaces.is_empty());
+    }
+
+    #[test]
+    fn test_toggle_bookmark() {
+        let mut config = ConfigFile::default();
+        let web = Bookmark::new("Pod", "app", "web-0");
+        assert!(config.toggle_bookmark(&web));
+        assert!(config.toggle_bookmark(&Bookmark::new("Pod", "app-canary", "web-0")));
+        assert!(!config.toggle_bookmark(&web));
+        assert_eq!(
+            config.bookmarks,
+            vec![Bookmark::new("Pod", "app-canary", "web-0")]
+        );
+    }
+
+    #[test]
+    fn test_next_in_cycle_wraps_around() {
+        let config = ConfigFile {
+            namespace_cycle: vec!["app".to_string(), "app-canary".to_string()],
+            ..ConfigFile::default()
+        };
+        assert_eq!(config.next_in_cycle(Some("app")), Some("app-canary"));
+        assert_eq!(config.next_in_cycle(Some("app-canary")), Some("app"));
+        assert_eq!(config.next_in_cycle(Some("default")), Some("app"));
+        assert_eq!(config.next_in_cycle(None), Some("app"));
+        assert_eq!(ConfigFile::default().next_in_cycle(Some("app")), None);
+    }
+
+    #[test]
+    fn test_missing_fields_default() {
+        let config: ConfigFile = serde_yaml::from_str("{}").unwrap_or_default();
+        assert_eq!(config, ConfigFile::default());
+    }
+
+    #[test]
+    fn test_restore_session_respects_explicit_options() {
+        let saved = ConfigFile {
+            last_namespace: Some("payments".to_string()),
+            ..ConfigFile::default()
+        };
+        let explicit = AppConfig {
+            namespace: Some("default".to_string()),
+            ..AppConfig::default()
+        };
+        assert_eq!(
+            saved.restore_session(explicit).namespace.as_deref(),
+            Some("default")
+        );
+        assert_eq!(
+            saved
+                .restore_session(AppConfig::default())
+                .namespace
+                .as_deref(),
+            Some("payments")
+        );
+    }
+
+    #[test]
+    fn test_apply_preferences_prefers_the_view_flag() {
+        use crate::config::StartView;
+
+        let saved = ConfigFile {
+            view: Some("Pods".to_string()),
+            ..ConfigFile::default()
+        };
+        let flagged = AppConfig {
+            view: Some(StartView::Nodes),
+            ..AppConfig::default()
+        };
+        assert_eq!(
+            saved.apply_preferences(flagged).map(|c| c.view).ok(),
+            Some(Some(StartView::Nodes))
+        );
+        assert_eq!(
+            saved
+                .apply_preferences(AppConfig::default())
+                .map(|c| c.view)
+                .ok(),
+            Some(Some(StartView::Pods))
+        );
+        let unknown = ConfigFile {
+            view: Some("deploys".to_string()),
+            ..ConfigFile::default()
+        };
+        assert!(unknown.apply_preferences(AppConfig::default()).is_err());
+    }
+
+    #[test]
+    fn test_apply_preferences_reads_poll_intervals() {
+        use crate::config::PollView;
+        use std::time::Duration;
+
+        let saved = ConfigFile {
+            poll_intervals: [("nodes".to_string(), "30s".to_string())].into(),
+            ..ConfigFile::default()
+        };
+        assert_eq!(
+            saved
+                .apply_preferences(AppConfig::default())
+                .map(|c| c.poll_interval_for(PollView::Nodes))
+                .ok(),
+            Some(Duration::from_secs(30))
+        );
+        let unknown = ConfigFile {
+            poll_intervals: [("deployments".to_string(), "30s".to_string())].into(),
+            ..ConfigFile::default()
+        };
+        assert!(unknown.apply_preferences(AppConfig::default()).is_err());
+    }
+
+    #[test]
+    fn test_resize_details_clamps() {
+        assert_eq!(resize_details(40, 5), 45);
+        assert_eq!(resize_details(80, 5), 80);
+        assert_eq!(resize_details(10, -5), 10);
+    }
+}
@@ -18,13 +18,35 @@ pub enum Error {
     #[from]
     Infer(kube::config::InferConfigError),
 
+    #[from]
+    Kubeconfig(kube::config::KubeconfigError),
+
     #[from]
     HttpHeader(hyper::http::Error),
+
+    #[from]
+    Io(std::io::Error),
+
+    #[from]
+    Yaml(serde_yaml::Error),
+
+    InvalidConfig(String),
+
+    /// neither a kubeconfig nor an in-cluster service account is available
+    NoCluster(String),
+
+    /// an edited manifest that can not be applied to the resource it came from
+    InvalidManifest(String),
 }
 
 impl core::fmt::Display for Error {
     fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
-        write!(fmt, "{self:?}")
+        match self {
+            Self::NoCluster(message) | Self::InvalidManifest(message) => {
+                write!(fmt, "{message}")
+            }
+            _ => write!(fmt, "{self:?}"),
+        }
     }
 }
 
@@ -0,0 +1,47 @@
+use crate::config_file::Bookmark;
+use crate::error::Result;
+use crate::tui::data::FinderTarget;
+use k8s_openapi::api::apps::v1::ReplicaSet;
+use k8s_openapi::api::core::v1::Pod;
+use kube::{Api, Client};
+
+use super::client::shared;
+
+/// Where `bookmark` leads as it is now, `None` when the resource is gone or of a kind
+/// k8p can not bookmark
+async fn resolve(client: &Client, bookmark: &Bookmark) -> Result<Option<FinderTarget>> {
+    let namespace = bookmark.namespace.as_str();
+    let name = bookmark.name.clone();
+    Ok(match bookmark.kind.as_str() {
+        "Pod" => Api::<Pod>::namespaced(Client::clone(client), namespace)
+            .get_opt(&name)
+            .await?
+            .map(|pod| FinderTarget::Pod {
+                selector: pod.metadata.labels.unwrap_or_default(),
+                name,
+            }),
+        "ReplicaSet" => Api::<ReplicaSet>::namespaced(Client::clone(client), namespace)
+            .get_opt(&name)
+            .await?
+            .and_then(|rs| rs.spec?.selector.match_labels)
+            .map(FinderTarget::Pods),
+        _ => None,
+    })
+}
+
+/// Each of `bookmarks` with where it leads, fetched fresh, `None` for those not found
+///
+/// # Errors
+///
+/// Will return `Err` if data can not be retrieved from k8s cluster api
+pub async fn resolve_bookmarks(
+    bookmarks: Vec<Bookmark>,
+) -> Result<Vec<(Bookmark, Option<FinderTarget>)>> {
+    let client = shared().await?;
+    let mut resolved = Vec::with_capacity(bookmarks.len());
+    for bookmark in bookmarks {
+        let target = resolve(&client, &bookmark).await?;
+        resolved.push((bookmark, target));
+    }
+    Ok(resolved)
+}
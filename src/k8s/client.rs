@@ -1,18 +1,133 @@
 // A hundred lines of code just to add a correct User-Agent header.
-use crate::error::Result as NvResult;
+use crate::config;
+use crate::error::{Error, Result as NvResult};
 use hyper::Request;
 use hyper_util::rt::TokioExecutor;
+use kube::config::{KubeConfigOptions, Kubeconfig};
 use kube::{client::ConfigExt, Client, Config};
 use pin_project::pin_project;
 use std::fmt;
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
 use std::task::{Context, Poll};
+use tokio::sync::Mutex;
 use tower::{Layer, Service};
+use tracing::{info, warn};
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 const MODULE: &str = env!("CARGO_PKG_NAME");
 
+/// The client every k8s call reuses, with the generation it was built in. Held while a
+/// client is built, so callers arriving meanwhile wait for that one instead of building
+/// their own.
+static SHARED_CLIENT: Mutex<Option<(u64, Arc<Client>)>> = Mutex::const_new(None);
+/// Bumped by `reset`, a cached client from an older generation is built again
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+/// Default namespace of the cached client's context, readable without waiting on a build
+static NAMESPACE: RwLock<Option<String>> = RwLock::new(None);
+static CONNECTION_MODE: RwLock<Option<ConnectionMode>> = RwLock::new(None);
+static CONTEXT_NAME: RwLock<Option<String>> = RwLock::new(None);
+static SERVER_VERSION: RwLock<Option<String>> = RwLock::new(None);
+
+/// Shown for the server version until `/version` answers, and for good when it does not
+pub const UNKNOWN_VERSION: &str = "unknown";
+
+/// Where the credentials of the current client came from
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConnectionMode {
+    Kubeconfig,
+    /// the service account token mounted into the pod k8p runs in
+    InCluster,
+}
+
+impl ConnectionMode {
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Kubeconfig => "kubeconfig",
+            Self::InCluster => "in-cluster",
+        }
+    }
+}
+
+/// How the last client built connected to the cluster, `None` before the first one
+#[must_use]
+pub fn connection_mode() -> Option<ConnectionMode> {
+    CONNECTION_MODE.read().ok().and_then(|mode| *mode)
+}
+
+fn set_connection_mode(mode: ConnectionMode) {
+    info!("connected via {}", mode.label());
+    if let Ok(mut active) = CONNECTION_MODE.write() {
+        *active = Some(mode);
+    }
+}
+
+/// The kubeconfig context the last client was built from, `None` in-cluster
+#[must_use]
+pub fn context_name() -> Option<String> {
+    CONTEXT_NAME.read().ok().and_then(|name| name.clone())
+}
+
+fn set_context_name(kubeconfig: &Kubeconfig, options: &KubeConfigOptions) {
+    let name = options
+        .context
+        .clone()
+        .or_else(|| kubeconfig.current_context.clone());
+    if let Ok(mut active) = CONTEXT_NAME.write() {
+        *active = name;
+    }
+}
+
+/// The namespace the views list: the one picked with `--namespace` or switched to
+/// since, otherwise the default of the cached client's context. Every namespaced api is
+/// built for it, so switching namespace keeps the client.
+#[must_use]
+pub fn current_namespace() -> String {
+    config::current()
+        .namespace
+        .or_else(|| {
+            NAMESPACE
+                .read()
+                .ok()
+                .and_then(|namespace| namespace.clone())
+        })
+        .unwrap_or_else(|| "default".to_string())
+}
+
+/// The git version of the API server, e.g. `v1.29.3`, or `UNKNOWN_VERSION`
+#[must_use]
+pub fn server_version() -> String {
+    SERVER_VERSION
+        .read()
+        .ok()
+        .and_then(|version| version.clone())
+        .unwrap_or_else(|| UNKNOWN_VERSION.to_string())
+}
+
+/// Ask the API server for its version and cache it for `server_version`. Meant to be
+/// spawned, an unreachable `/version` only leaves the version unknown.
+pub async fn fetch_server_version() {
+    let client = match shared().await {
+        Ok(client) => client,
+        Err(e) => {
+            warn!("no client to ask the server version: {e}");
+            return;
+        }
+    };
+    match client.apiserver_version().await {
+        Ok(info) => {
+            info!("server version {}", info.git_version);
+            if let Ok(mut version) = SERVER_VERSION.write() {
+                *version = Some(info.git_version);
+            }
+        }
+        Err(e) => warn!("server version unavailable: {e}"),
+    }
+}
+
 #[derive(Debug)]
 pub struct UserAgentError {
     message: String,
@@ -89,13 +204,65 @@ where
     }
 }
 
+/// The kubeconfig files of `KUBECONFIG` merged into one
+async fn merged_config(options: &KubeConfigOptions) -> NvResult<Config> {
+    let kubeconfig = config::read_kubeconfig(None)?;
+    set_context_name(&kubeconfig, options);
+    Ok(Config::from_custom_kubeconfig(kubeconfig, options).await?)
+}
+
+/// The kubeconfig when there is one, otherwise the service account of the pod we run in
+async fn infer_config(options: &KubeConfigOptions) -> NvResult<(Config, ConnectionMode)> {
+    match merged_config(options).await {
+        Ok(config) => Ok((config, ConnectionMode::Kubeconfig)),
+        Err(kubeconfig_err) => match Config::incluster() {
+            Ok(config) => {
+                if let Ok(mut active) = CONTEXT_NAME.write() {
+                    *active = None;
+                }
+                Ok((config, ConnectionMode::InCluster))
+            }
+            Err(incluster_err) => Err(Error::NoCluster(format!(
+                "no usable kubeconfig ({kubeconfig_err}) and not running in a cluster ({incluster_err})"
+            ))),
+        },
+    }
+}
+
+/// Build the client `Config` from the `--kubeconfig` and `--context` options, falling back
+/// to the usual inference when none are given. `--namespace` is left to
+/// `current_namespace`.
+async fn load_config() -> NvResult<Config> {
+    let app_config = config::current();
+    let options = KubeConfigOptions {
+        context: app_config.context.clone(),
+        ..KubeConfigOptions::default()
+    };
+
+    let (config, mode) = match (&app_config.kubeconfig, &app_config.context) {
+        (Some(path), _) => {
+            let kubeconfig = config::read_kubeconfig(Some(path.as_path()))?;
+            set_context_name(&kubeconfig, &options);
+            (
+                Config::from_custom_kubeconfig(kubeconfig, &options).await?,
+                ConnectionMode::Kubeconfig,
+            )
+        }
+        (None, Some(_)) => (merged_config(&options).await?, ConnectionMode::Kubeconfig),
+        (None, None) => infer_config(&options).await?,
+    };
+    set_connection_mode(mode);
+
+    Ok(config)
+}
+
 /// Create a new k8s client to interact with k8s cluster api that includes User-Agent header
 ///
 /// # Errors
 ///
 /// Will return `Err` if data can not be retrieved from k8s cluster api
 pub async fn new(custom_user_agent: Option<&str>) -> NvResult<Client> {
-    let config = Config::infer().await?;
+    let config = load_config().await?;
 
     let https = config.rustls_https_connector()?;
 
@@ -112,3 +279,39 @@ pub async fn new(custom_user_agent: Option<&str>) -> NvResult<Client> {
 
     Ok(client)
 }
+
+fn set_namespace(namespace: Option<String>) {
+    if let Ok(mut active) = NAMESPACE.write() {
+        *active = namespace;
+    }
+}
+
+/// Forget the cached client so the next call builds one from the current
+/// config, e.g. after switching context. Requests already under way finish
+/// with the client they started with. Switching namespace needs no reset.
+pub fn reset() {
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+    set_namespace(None);
+}
+
+/// The cached client, built and cached on first use and after a `reset`.
+///
+/// # Errors
+///
+/// Will return `Err` if a new client has to be built and that fails
+pub async fn shared() -> NvResult<Arc<Client>> {
+    let mut shared = SHARED_CLIENT.lock().await;
+    let generation = GENERATION.load(Ordering::SeqCst);
+    if let Some((built, client)) = shared.as_ref() {
+        if *built == generation {
+            return Ok(Arc::clone(client));
+        }
+    }
+    let client = Arc::new(new(None).await?);
+    // a reset while building leaves this client stale, the next call builds again
+    if GENERATION.load(Ordering::SeqCst) == generation {
+        set_namespace(Some(client.default_namespace().to_string()));
+    }
+    *shared = Some((generation, Arc::clone(&client)));
+    Ok(client)
+}
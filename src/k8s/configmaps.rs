@@ -0,0 +1,105 @@
+use crate::error::Result;
+use crate::k8s::events::age_of;
+use crate::tui::data::{ConfigData, ConfigEntry};
+use k8s_openapi::api::core::v1::{ConfigMap, Secret};
+use kube::api::{ListParams, ObjectList};
+use kube::{Api, Client};
+
+use super::client::{current_namespace, shared};
+
+fn configmap_entries(cm: &ConfigMap) -> Vec<ConfigEntry> {
+    let mut entries: Vec<ConfigEntry> = cm
+        .data
+        .iter()
+        .flatten()
+        .map(|(name, value)| ConfigEntry {
+            name: name.clone(),
+            value: value.clone(),
+            secret: false,
+        })
+        .collect();
+
+    entries.extend(
+        cm.binary_data
+            .iter()
+            .flatten()
+            .map(|(name, value)| ConfigEntry {
+                name: name.clone(),
+                value: format!("<{} bytes of binary data>", value.0.len()),
+                secret: false,
+            }),
+    );
+
+    entries
+}
+
+fn secret_entries(secret: &Secret) -> Vec<ConfigEntry> {
+    // the api returns base64 which k8s-openapi has already decoded into bytes
+    secret
+        .data
+        .iter()
+        .flatten()
+        .map(|(name, value)| ConfigEntry {
+            name: name.clone(),
+            value: String::from_utf8_lossy(&value.0).to_string(),
+            secret: true,
+        })
+        .collect()
+}
+
+/// # Errors
+///
+/// Will return `Err` if data can not be retrieved from k8s cluster api
+pub async fn list_configmaps() -> Result<Vec<ConfigData>> {
+    let client = shared().await?;
+
+    let cm_list: ObjectList<ConfigMap> =
+        Api::namespaced(Client::clone(&client), &current_namespace())
+            .list(&ListParams::default())
+            .await?;
+
+    Ok(cm_list
+        .items
+        .iter()
+        .map(|cm| {
+            let entries = configmap_entries(cm);
+            ConfigData {
+                name: cm.metadata.name.clone().unwrap_or_default(),
+                type_: "ConfigMap".to_string(),
+                keys: entries.len().to_string(),
+                age: age_of(&cm.metadata),
+                entries,
+            }
+        })
+        .collect())
+}
+
+/// Secret values are carried for the reveal action but are masked by the UI and redacted
+/// from `Debug` output so they never reach the logs.
+///
+/// # Errors
+///
+/// Will return `Err` if data can not be retrieved from k8s cluster api
+pub async fn list_secrets() -> Result<Vec<ConfigData>> {
+    let client = shared().await?;
+
+    let secret_list: ObjectList<Secret> =
+        Api::namespaced(Client::clone(&client), &current_namespace())
+            .list(&ListParams::default())
+            .await?;
+
+    Ok(secret_list
+        .items
+        .iter()
+        .map(|secret| {
+            let entries = secret_entries(secret);
+            ConfigData {
+                name: secret.metadata.name.clone().unwrap_or_default(),
+                type_: secret.type_.clone().unwrap_or_else(|| "Opaque".to_string()),
+                keys: entries.len().to_string(),
+                age: age_of(&secret.metadata),
+                entries,
+            }
+        })
+        .collect())
+}
@@ -1,14 +1,25 @@
 use crate::error::Result;
-use crate::k8s::client::new;
-use crate::k8s::utils::format_label_selector;
-use crate::tui::data::{Container, ContainerEnvVar, ContainerMount, LogRec};
-use k8s_openapi::api::core::v1::ContainerPort;
-use k8s_openapi::api::core::v1::Pod;
+use crate::k8s::client::{current_namespace, shared};
+use crate::k8s::events::{format_duration, list_k8sevents};
+use crate::k8s::reasons::{memory_limit, oom_killed, state_reason};
+use crate::labels::format_label_selector;
+use crate::tui::data::{
+    Container, ContainerEnvVar, ContainerMount, ContainerProbe, ContainerReadiness,
+    ContainerRestart, LogRec,
+};
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::core::v1::{
+    ConfigMap, Container as SpecContainer, ContainerPort, EnvVarSource, Secret,
+};
+use k8s_openapi::api::core::v1::{ContainerStateTerminated, ContainerStatus, Event, Pod, Probe};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
 use kube::{
     api::{Api, ListParams, LogParams, ObjectList},
     Client, ResourceExt,
 };
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
+use tracing::debug;
 
 fn format_ports(ports: Option<Vec<ContainerPort>>) -> String {
     ports.map_or_else(
@@ -26,42 +37,504 @@ fn format_ports(ports: Option<Vec<ContainerPort>>) -> String {
     )
 }
 
+fn format_port(port: &IntOrString) -> String {
+    match port {
+        IntOrString::Int(number) => number.to_string(),
+        IntOrString::String(name) => name.clone(),
+    }
+}
+
+/// What the probe runs: "httpGet /healthz :8080", "exec cat /tmp/ready", ...
+fn probe_action(probe: &Probe) -> String {
+    if let Some(http) = &probe.http_get {
+        format!(
+            "httpGet {} :{}",
+            http.path.as_deref().unwrap_or("/"),
+            format_port(&http.port)
+        )
+    } else if let Some(exec) = &probe.exec {
+        format!(
+            "exec {}",
+            exec.command.clone().unwrap_or_default().join(" ")
+        )
+    } else if let Some(tcp) = &probe.tcp_socket {
+        format!("tcpSocket :{}", format_port(&tcp.port))
+    } else if let Some(grpc) = &probe.grpc {
+        format!("grpc :{}", grpc.port)
+    } else {
+        "unknown probe type".to_string()
+    }
+}
+
+/// Timing with the kubernetes defaults filled in for unset fields
+fn probe_timing(probe: &Probe) -> String {
+    format!(
+        "delay {}s, period {}s, timeout {}s, success {}, failure {}",
+        probe.initial_delay_seconds.unwrap_or(0),
+        probe.period_seconds.unwrap_or(10),
+        probe.timeout_seconds.unwrap_or(1),
+        probe.success_threshold.unwrap_or(1),
+        probe.failure_threshold.unwrap_or(3)
+    )
+}
+
+fn container_probe(kind: &str, probe: Option<&Probe>, state: Option<&str>) -> ContainerProbe {
+    probe.map_or_else(
+        || ContainerProbe {
+            name: kind.to_string(),
+            value: "no probe configured".to_string(),
+        },
+        |probe| {
+            let mut value = format!("{} ({})", probe_action(probe), probe_timing(probe));
+            if let Some(state) = state {
+                value.push_str(" - ");
+                value.push_str(state);
+            }
+            ContainerProbe {
+                name: kind.to_string(),
+                value,
+            }
+        },
+    )
+}
+
+/// Readiness is reported directly by the kubelet, a failing liveness probe
+/// shows up as a container that is no longer running.
+fn container_probes(
+    liveness: Option<&Probe>,
+    readiness: Option<&Probe>,
+    status: Option<&ContainerStatus>,
+) -> Vec<ContainerProbe> {
+    let ready = status.map(|cs| if cs.ready { "passing" } else { "failing" });
+    let running = status.map(|cs| {
+        if cs.state.as_ref().is_some_and(|s| s.running.is_some()) {
+            "passing"
+        } else {
+            "failing"
+        }
+    });
+    vec![
+        container_probe("liveness", liveness, running),
+        container_probe("readiness", readiness, ready),
+    ]
+}
+
+/// `started` is only reported by newer kubelets, a running container counts as started
+/// without it. An init container that exited cleanly has done its job and counts as ready.
+fn container_readiness(status: Option<&ContainerStatus>) -> ContainerReadiness {
+    let Some(status) = status else {
+        return ContainerReadiness::Waiting;
+    };
+    let state = status.state.as_ref();
+    let completed = state
+        .and_then(|state| state.terminated.as_ref())
+        .is_some_and(|terminated| terminated.exit_code == 0);
+    let started = status
+        .started
+        .unwrap_or_else(|| state.is_some_and(|state| state.running.is_some()));
+    if completed || (started && status.ready) {
+        ContainerReadiness::Ready
+    } else if started {
+        ContainerReadiness::Started
+    } else {
+        ContainerReadiness::Waiting
+    }
+}
+
+const MAX_RESTART_HISTORY: usize = 10;
+
+/// event reasons the kubelet records around a container restart
+const RESTART_REASONS: [&str; 3] = ["BackOff", "Killing", "Unhealthy"];
+
+fn describe_termination(terminated: &ContainerStateTerminated) -> String {
+    format!(
+        "{} (exit {})",
+        terminated.reason.as_deref().unwrap_or("Terminated"),
+        terminated.exit_code
+    )
+}
+
+/// Description of an init container in the container list
+const INIT_BADGE: &str = "init";
+
+/// What runs when the spec leaves `command` to the image
+const IMAGE_ENTRYPOINT: &str = "image default entrypoint";
+
+/// `word` as a shell would need it typed, quoted when it is empty or holds blanks or quotes
+fn shell_word(word: &str) -> String {
+    if !word.is_empty()
+        && !word
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '\'' | '"' | '\\' | '$' | ';'))
+    {
+        return word.to_string();
+    }
+    format!("'{}'", word.replace('\'', r"'\''"))
+}
+
+/// The command line `container` runs: its `command` followed by its `args`, with the
+/// image's entrypoint standing in when the spec sets no `command`
+fn command_line(container: &SpecContainer) -> String {
+    let words = |words: Option<&Vec<String>>| {
+        words.filter(|words| !words.is_empty()).map(|words| {
+            words
+                .iter()
+                .map(|word| shell_word(word))
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+    };
+    let args = words(container.args.as_ref());
+    match (words(container.command.as_ref()), args) {
+        (Some(command), Some(args)) => format!("{command} {args}"),
+        (Some(command), None) => command,
+        (None, Some(args)) => format!("<{IMAGE_ENTRYPOINT}> {args}"),
+        (None, None) => IMAGE_ENTRYPOINT.to_string(),
+    }
+}
+
+/// How events refer to the container, e.g. `spec.initContainers{migrate}`
+fn field_path(container_name: &str, init: bool) -> String {
+    let list = if init { "initContainers" } else { "containers" };
+    format!("spec.{list}{{{container_name}}}")
+}
+
+/// Shown for a reference whose object or key is missing or can not be read
+const UNRESOLVED: &str = "<not found>";
+
+/// The data of ConfigMaps or Secrets by name, `None` for one missing or unreadable
+type SourceData = BTreeMap<String, Option<BTreeMap<String, String>>>;
+
+/// The ConfigMaps and Secrets a pod's environment refers to
+#[derive(Default)]
+struct EnvSources {
+    config_maps: SourceData,
+    secrets: SourceData,
+}
+
+/// Names of the ConfigMaps and Secrets `containers` read env vars from, as
+/// `(configmaps, secrets)`
+fn env_source_names<'a>(
+    containers: impl IntoIterator<Item = &'a SpecContainer>,
+) -> (BTreeSet<String>, BTreeSet<String>) {
+    let mut config_maps = BTreeSet::new();
+    let mut secrets = BTreeSet::new();
+    for container in containers {
+        for env_from in container.env_from.iter().flatten() {
+            if let Some(config_map) = &env_from.config_map_ref {
+                config_maps.insert(config_map.name.clone());
+            }
+            if let Some(secret) = &env_from.secret_ref {
+                secrets.insert(secret.name.clone());
+            }
+        }
+        let references = container
+            .env
+            .iter()
+            .flatten()
+            .filter_map(|env| env.value_from.as_ref());
+        for from in references {
+            if let Some(key_ref) = &from.config_map_key_ref {
+                config_maps.insert(key_ref.name.clone());
+            }
+            if let Some(key_ref) = &from.secret_key_ref {
+                secrets.insert(key_ref.name.clone());
+            }
+        }
+    }
+    (config_maps, secrets)
+}
+
+/// Read the named ConfigMaps and Secrets. One that can not be read, e.g. a Secret the
+/// user may not get, shows as unresolved rather than failing the container list.
+async fn fetch_env_sources(
+    client: &Client,
+    config_map_names: BTreeSet<String>,
+    secret_names: BTreeSet<String>,
+) -> EnvSources {
+    let namespace = current_namespace();
+    let config_maps: Api<ConfigMap> = Api::namespaced(Client::clone(client), &namespace);
+    let secrets: Api<Secret> = Api::namespaced(Client::clone(client), &namespace);
+    let mut sources = EnvSources::default();
+    for name in config_map_names {
+        let data = match config_maps.get_opt(&name).await {
+            Ok(config_map) => config_map.map(|config_map| config_map.data.unwrap_or_default()),
+            Err(e) => {
+                debug!("can not read configmap {name}: {e}");
+                None
+            }
+        };
+        sources.config_maps.insert(name, data);
+    }
+    for name in secret_names {
+        let data = match secrets.get_opt(&name).await {
+            // the api returns base64 which k8s-openapi has already decoded into bytes
+            Ok(secret) => secret.map(|secret| {
+                secret
+                    .data
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(key, value)| (key, String::from_utf8_lossy(&value.0).to_string()))
+                    .collect()
+            }),
+            Err(e) => {
+                debug!("can not read secret {name}: {e}");
+                None
+            }
+        };
+        sources.secrets.insert(name, data);
+    }
+    sources
+}
+
+fn lookup(objects: &SourceData, name: &str, key: &str) -> Option<String> {
+    objects.get(name)?.as_ref()?.get(key).cloned()
+}
+
+/// An env var whose value comes from somewhere else than the pod spec
+fn referenced_var(name: &str, from: &EnvVarSource, sources: &EnvSources) -> ContainerEnvVar {
+    let var = |value: String, source: String, secret_value: Option<String>| ContainerEnvVar {
+        name: name.to_string(),
+        value,
+        source: Some(source),
+        secret_value,
+    };
+    if let Some(key_ref) = &from.config_map_key_ref {
+        let value = lookup(&sources.config_maps, &key_ref.name, &key_ref.key);
+        var(
+            value.unwrap_or_else(|| UNRESOLVED.to_string()),
+            format!("configmap/{}", key_ref.name),
+            None,
+        )
+    } else if let Some(key_ref) = &from.secret_key_ref {
+        let value = lookup(&sources.secrets, &key_ref.name, &key_ref.key);
+        let shown = if value.is_some() { "" } else { UNRESOLVED };
+        var(shown.to_string(), format!("secret/{}", key_ref.name), value)
+    } else if let Some(field_ref) = &from.field_ref {
+        var(
+            String::new(),
+            format!("field {}", field_ref.field_path),
+            None,
+        )
+    } else if let Some(resource_ref) = &from.resource_field_ref {
+        var(
+            String::new(),
+            format!("resource {}", resource_ref.resource),
+            None,
+        )
+    } else {
+        var(String::new(), "an unknown source".to_string(), None)
+    }
+}
+
+/// Add `var`, replacing an earlier var of the same name
+fn set_var(vars: &mut Vec<ContainerEnvVar>, var: ContainerEnvVar) {
+    vars.retain(|existing| existing.name != var.name);
+    vars.push(var);
+}
+
+/// The environment `container` runs with: the vars of its `envFrom` sources in order,
+/// then its own `env`, a later var replacing an earlier one of the same name as the
+/// kubelet does
+fn resolve_env(container: &SpecContainer, sources: &EnvSources) -> Vec<ContainerEnvVar> {
+    let mut vars = Vec::new();
+    for env_from in container.env_from.iter().flatten() {
+        let prefix = env_from.prefix.as_deref().unwrap_or_default();
+        if let Some(config_map) = &env_from.config_map_ref {
+            let data = sources
+                .config_maps
+                .get(&config_map.name)
+                .and_then(Option::as_ref);
+            for (key, value) in data.into_iter().flatten() {
+                set_var(
+                    &mut vars,
+                    ContainerEnvVar {
+                        name: format!("{prefix}{key}"),
+                        value: value.clone(),
+                        source: Some(format!("configmap/{}", config_map.name)),
+                        secret_value: None,
+                    },
+                );
+            }
+        }
+        if let Some(secret) = &env_from.secret_ref {
+            let data = sources.secrets.get(&secret.name).and_then(Option::as_ref);
+            for (key, value) in data.into_iter().flatten() {
+                set_var(
+                    &mut vars,
+                    ContainerEnvVar {
+                        name: format!("{prefix}{key}"),
+                        value: String::new(),
+                        source: Some(format!("secret/{}", secret.name)),
+                        secret_value: Some(value.clone()),
+                    },
+                );
+            }
+        }
+    }
+    for env in container.env.iter().flatten() {
+        let var = match &env.value_from {
+            Some(from) => referenced_var(&env.name, from, sources),
+            None => ContainerEnvVar {
+                name: env.name.clone(),
+                value: env.value.clone().unwrap_or_default(),
+                source: None,
+                secret_value: None,
+            },
+        };
+        set_var(&mut vars, var);
+    }
+    vars
+}
+
+/// Newest first: the current state, the last termination and the restart related
+/// events recorded against the container at `field_path`, at most
+/// `MAX_RESTART_HISTORY` of them.
+fn restart_timeline(
+    status: Option<&ContainerStatus>,
+    events: &[Event],
+    pod_name: &str,
+    field_path: &str,
+    now: DateTime<Utc>,
+) -> Vec<ContainerRestart> {
+    let mut entries: Vec<(DateTime<Utc>, String)> = Vec::new();
+
+    if let Some(status) = status {
+        if let Some(state) = &status.state {
+            if let Some(waiting) = &state.waiting {
+                entries.push((now, waiting.reason.clone().unwrap_or_default()));
+            }
+            if let Some(running) = &state.running {
+                if let Some(started) = &running.started_at {
+                    entries.push((started.0, "Started".to_string()));
+                }
+            }
+            if let Some(terminated) = &state.terminated {
+                if let Some(finished) = &terminated.finished_at {
+                    entries.push((finished.0, describe_termination(terminated)));
+                }
+            }
+        }
+        if let Some(terminated) = status
+            .last_state
+            .as_ref()
+            .and_then(|s| s.terminated.as_ref())
+        {
+            if let Some(finished) = &terminated.finished_at {
+                entries.push((finished.0, describe_termination(terminated)));
+            }
+        }
+    }
+
+    for event in events {
+        let involved = &event.involved_object;
+        let reason = event.reason.as_deref().unwrap_or_default();
+        if involved.name.as_deref() != Some(pod_name)
+            || involved.field_path.as_deref() != Some(field_path)
+            || !RESTART_REASONS.contains(&reason)
+        {
+            continue;
+        }
+        if let Some(last) = &event.last_timestamp {
+            let count = event.count.unwrap_or(1);
+            let text = if count > 1 {
+                format!("{reason} x{count}")
+            } else {
+                reason.to_string()
+            };
+            entries.push((last.0, text));
+        }
+    }
+
+    entries.sort_by(|a, b| b.0.cmp(&a.0));
+    entries
+        .into_iter()
+        .take(MAX_RESTART_HISTORY)
+        .map(|(time, reason)| ContainerRestart {
+            age: format!("{} ago", format_duration(now.signed_duration_since(time))),
+            reason,
+        })
+        .collect()
+}
+
 /// # Errors
 ///
 /// Will return `Err` if data can not be retrieved from k8s cluster api
 #[allow(clippy::significant_drop_tightening)]
 #[allow(clippy::too_many_lines)]
 pub async fn list(selector: BTreeMap<String, String>, pod_name: String) -> Result<Vec<Container>> {
-    let client = Client::try_default().await?;
+    let client = shared().await?;
 
     let label_selector = format_label_selector(&selector);
 
     let lp = ListParams::default().labels(&label_selector);
 
     // Assuming there should be a single pod matching the selector and name
-    let pod_list: ObjectList<Pod> = Api::default_namespaced(client).list(&lp).await?;
+    let pod_list: ObjectList<Pod> = Api::namespaced(Client::clone(&client), &current_namespace())
+        .list(&lp)
+        .await?;
+
+    let events = list_k8sevents(Client::clone(&client)).await?;
+    let now = Utc::now();
 
     let mut container_vec = Vec::new();
 
     for pod in pod_list.items {
-        let container_statuses = pod
-            .status
-            .as_ref()
-            .and_then(|status| status.container_statuses.clone())
-            .unwrap_or_default();
+        let pod_status = pod.status.unwrap_or_default();
+        let container_statuses = pod_status.container_statuses.unwrap_or_default();
+        let init_statuses = pod_status.init_container_statuses.unwrap_or_default();
 
         if let Some(name) = pod.metadata.name {
             let container_selectors = pod.metadata.labels;
             if name == pod_name.clone() {
                 if let Some(spec) = pod.spec {
-                    for container in spec.containers {
-                        let image = container.image.unwrap_or_else(|| "unknown".to_string());
-                        let ports = format_ports(container.ports);
-                        let restarts = container_statuses
+                    let (config_map_names, secret_names) = env_source_names(
+                        spec.init_containers
                             .iter()
-                            .find(|cs| cs.name == container.name)
-                            .map_or(0, |cs| cs.restart_count)
-                            .to_string();
+                            .flatten()
+                            .chain(&spec.containers),
+                    );
+                    let env_sources =
+                        fetch_env_sources(&client, config_map_names, secret_names).await;
+                    // init containers run first, and one stuck is why the rest never start
+                    let init_containers = spec
+                        .init_containers
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|container| (container, true));
+                    let containers = spec
+                        .containers
+                        .into_iter()
+                        .map(|container| (container, false));
+                    for (container, init) in init_containers.chain(containers) {
+                        let envvars = resolve_env(&container, &env_sources);
+                        let statuses = if init {
+                            &init_statuses
+                        } else {
+                            &container_statuses
+                        };
+                        let status = statuses.iter().find(|cs| cs.name == container.name);
+                        let memory_limit = memory_limit(&container);
+                        let command = command_line(&container);
+                        let image = container.image.unwrap_or_else(|| "unknown".to_string());
+                        let ports = if init {
+                            String::new()
+                        } else {
+                            format_ports(container.ports)
+                        };
+                        let restarts = status.map_or(0, |cs| cs.restart_count).to_string();
+                        let probes = container_probes(
+                            container.liveness_probe.as_ref(),
+                            container.readiness_probe.as_ref(),
+                            status,
+                        );
+                        let restart_history = restart_timeline(
+                            status,
+                            &events,
+                            &name,
+                            &field_path(&container.name, init),
+                            now,
+                        );
 
                         let volume_mounts = container.volume_mounts;
                         let mounts: Vec<ContainerMount> = volume_mounts
@@ -73,73 +546,27 @@ pub async fn list(selector: BTreeMap<String, String>, pod_name: String) -> Resul
                             })
                             .collect();
 
-                        let env = container.env;
-                        let envvars: Vec<ContainerEnvVar> = env
-                            .unwrap_or_else(Vec::new)
-                            .into_iter()
-                            .map(|e| ContainerEnvVar {
-                                name: e.name,
-                                value: e.value.unwrap_or_default(),
-                            })
-                            .collect();
-
+                        let description = if init { INIT_BADGE } else { "a pod container" };
                         let c = Container {
                             name: container.name,
-                            description: "a pod container".to_string(),
+                            description: description.to_string(),
                             restarts,
+                            readiness: container_readiness(status),
                             image,
                             ports,
                             mounts,
                             envvars,
+                            probes,
+                            restart_history,
+                            state_reason: state_reason(status),
+                            oom_killed: status.is_some_and(oom_killed),
+                            memory_limit,
+                            command,
                             selectors: container_selectors.clone(),
                             pod_name: pod_name.clone(),
                         };
                         container_vec.push(c);
                     }
-
-                    if let Some(init_containers) = spec.init_containers {
-                        for container in init_containers {
-                            let image = container.image.unwrap_or_else(|| "unknown".to_string());
-                            let restarts = container_statuses
-                                .iter()
-                                .find(|cs| cs.name == container.name)
-                                .map_or(0, |cs| cs.restart_count)
-                                .to_string();
-
-                            let volume_mounts = container.volume_mounts;
-                            let mounts: Vec<ContainerMount> = volume_mounts
-                                .unwrap_or_else(Vec::new)
-                                .into_iter()
-                                .map(|vm| ContainerMount {
-                                    name: vm.name,
-                                    value: vm.mount_path,
-                                })
-                                .collect();
-
-                            let env = container.env;
-                            let envvars: Vec<ContainerEnvVar> = env
-                                .unwrap_or_else(Vec::new)
-                                .into_iter()
-                                .map(|e| ContainerEnvVar {
-                                    name: e.name,
-                                    value: e.value.unwrap_or_default(),
-                                })
-                                .collect();
-
-                            let c = Container {
-                                name: container.name,
-                                description: "an init container".to_string(), // Distinguish init containers
-                                restarts,
-                                image,
-                                ports: String::new(),
-                                mounts,
-                                envvars,
-                                selectors: container_selectors.clone(),
-                                pod_name: pod_name.clone(),
-                            };
-                            container_vec.push(c);
-                        }
-                    }
                 }
             }
         }
@@ -148,6 +575,9 @@ pub async fn list(selector: BTreeMap<String, String>, pod_name: String) -> Resul
     Ok(container_vec)
 }
 
+/// With `previous` set the logs come from the last terminated instance of the
+/// container, which is empty if it has never restarted.
+///
 /// # Errors
 ///
 /// Will return `Err` if data can not be retrieved from k8s cluster api
@@ -156,9 +586,11 @@ pub async fn logs(
     selector: BTreeMap<String, String>,
     pod_name: String,
     container_name: String,
+    previous: bool,
+    since: Option<Duration>,
 ) -> Result<Vec<LogRec>> {
-    let client = new(None).await?;
-    let pods: Api<Pod> = Api::default_namespaced(client);
+    let client = shared().await?;
+    let pods: Api<Pod> = Api::namespaced(Client::clone(&client), &current_namespace());
 
     let label_selector = format_label_selector(&selector);
 
@@ -177,11 +609,18 @@ pub async fn logs(
         let log_params = LogParams {
             container: Some(container_name.clone()),
             tail_lines: Some(100), // Adjust based on how many lines you want
+            since_seconds: since.and_then(|since| i64::try_from(since.as_secs()).ok()),
+            previous,
             ..Default::default()
         };
 
         // Fetch logs for the specified container
-        let logs = pods.logs(&pod.name_any(), &log_params).await?;
+        let logs = match pods.logs(&pod.name_any(), &log_params).await {
+            Ok(logs) => logs,
+            // the api answers 400 when the container has never restarted
+            Err(kube::Error::Api(response)) if previous && response.code == 400 => String::new(),
+            Err(e) => return Err(e.into()),
+        };
 
         // Parse and map logs to Vec<Log>
         logs.lines().for_each(|line| {
@@ -196,3 +635,234 @@ pub async fn logs(
 
     Ok(log_vec)
 }
+
+/// The last `lines` lines logged by the first container of the named pod, oldest
+/// first, only the ones within `since` when it is given
+///
+/// # Errors
+///
+/// Will return `Err` if the pod can not be read or has no logs yet, e.g. while its
+/// image is still being pulled
+pub async fn tail_logs(pod_name: &str, lines: i64, since: Option<Duration>) -> Result<Vec<String>> {
+    let client = shared().await?;
+    let pods: Api<Pod> = Api::namespaced(Client::clone(&client), &current_namespace());
+    let pod = pods.get(pod_name).await?;
+    let log_params = LogParams {
+        container: pod
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.containers.first())
+            .map(|container| container.name.clone()),
+        tail_lines: Some(lines),
+        since_seconds: since.and_then(|since| i64::try_from(since.as_secs()).ok()),
+        ..Default::default()
+    };
+    let logs = pods.logs(pod_name, &log_params).await?;
+    Ok(logs.lines().map(str::to_string).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        command_line, container_probes, container_readiness, field_path, resolve_env,
+        restart_timeline, EnvSources,
+    };
+    use crate::tui::data::ContainerReadiness;
+    use chrono::{Duration, Utc};
+    use k8s_openapi::api::core::v1::{
+        ConfigMapEnvSource, ConfigMapKeySelector, Container, ContainerState, ContainerStateRunning,
+        ContainerStateTerminated, ContainerStatus, EnvFromSource, EnvVar, EnvVarSource,
+        HTTPGetAction, Probe, SecretKeySelector,
+    };
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+    use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+    #[test]
+    fn test_container_probes_describe_missing_and_http() {
+        let readiness = Probe {
+            http_get: Some(HTTPGetAction {
+                path: Some("/healthz".to_string()),
+                port: IntOrString::Int(8080),
+                ..HTTPGetAction::default()
+            }),
+            period_seconds: Some(5),
+            ..Probe::default()
+        };
+        let probes = container_probes(None, Some(&readiness), None);
+        assert_eq!(probes[0].value, "no probe configured");
+        assert_eq!(
+            probes[1].value,
+            "httpGet /healthz :8080 (delay 0s, period 5s, timeout 1s, success 1, failure 3)"
+        );
+    }
+
+    #[test]
+    fn test_restart_timeline_newest_first() {
+        let now = Utc::now();
+        let status = ContainerStatus {
+            name: "app".to_string(),
+            restart_count: 1,
+            state: Some(ContainerState {
+                running: Some(ContainerStateRunning {
+                    started_at: Some(Time(now - Duration::minutes(2))),
+                }),
+                ..ContainerState::default()
+            }),
+            last_state: Some(ContainerState {
+                terminated: Some(ContainerStateTerminated {
+                    reason: Some("OOMKilled".to_string()),
+                    exit_code: 137,
+                    finished_at: Some(Time(now - Duration::minutes(3))),
+                    ..ContainerStateTerminated::default()
+                }),
+                ..ContainerState::default()
+            }),
+            ..ContainerStatus::default()
+        };
+        let timeline = restart_timeline(Some(&status), &[], "pod", &field_path("app", false), now);
+        assert_eq!(timeline.len(), 2);
+        assert_eq!(timeline[0].reason, "Started");
+        assert_eq!(timeline[0].age, "2m ago");
+        assert_eq!(timeline[1].reason, "OOMKilled (exit 137)");
+    }
+
+    #[test]
+    fn test_field_path_names_init_containers() {
+        assert_eq!(field_path("app", false), "spec.containers{app}");
+        assert_eq!(field_path("migrate", true), "spec.initContainers{migrate}");
+    }
+
+    #[test]
+    fn test_command_line_falls_back_to_the_image_entrypoint() {
+        let words = |words: &[&str]| Some(words.iter().map(ToString::to_string).collect());
+        let nginx = Container {
+            command: words(&["nginx"]),
+            args: words(&["-g", "daemon off;"]),
+            ..Container::default()
+        };
+        assert_eq!(command_line(&nginx), "nginx -g 'daemon off;'");
+        let args_only = Container {
+            args: words(&["--port", "8080"]),
+            ..Container::default()
+        };
+        assert_eq!(
+            command_line(&args_only),
+            "<image default entrypoint> --port 8080"
+        );
+        assert_eq!(
+            command_line(&Container::default()),
+            "image default entrypoint"
+        );
+    }
+
+    #[test]
+    fn test_container_readiness() {
+        let running = ContainerStatus {
+            started: Some(true),
+            ..ContainerStatus::default()
+        };
+        assert_eq!(
+            container_readiness(Some(&running)),
+            ContainerReadiness::Started
+        );
+        let ready = ContainerStatus {
+            ready: true,
+            ..running
+        };
+        assert_eq!(container_readiness(Some(&ready)), ContainerReadiness::Ready);
+        assert_eq!(container_readiness(None), ContainerReadiness::Waiting);
+    }
+
+    #[test]
+    fn test_resolve_env_reads_references_and_lets_env_win() {
+        let container = Container {
+            env_from: Some(vec![EnvFromSource {
+                config_map_ref: Some(ConfigMapEnvSource {
+                    name: "app-config".to_string(),
+                    ..ConfigMapEnvSource::default()
+                }),
+                ..EnvFromSource::default()
+            }]),
+            env: Some(vec![
+                EnvVar {
+                    name: "LOG_LEVEL".to_string(),
+                    value: Some("debug".to_string()),
+                    ..EnvVar::default()
+                },
+                EnvVar {
+                    name: "DB_PASSWORD".to_string(),
+                    value_from: Some(EnvVarSource {
+                        secret_key_ref: Some(SecretKeySelector {
+                            name: "db".to_string(),
+                            key: "password".to_string(),
+                            ..SecretKeySelector::default()
+                        }),
+                        ..EnvVarSource::default()
+                    }),
+                    ..EnvVar::default()
+                },
+                EnvVar {
+                    name: "REGION".to_string(),
+                    value_from: Some(EnvVarSource {
+                        config_map_key_ref: Some(ConfigMapKeySelector {
+                            name: "gone".to_string(),
+                            key: "region".to_string(),
+                            ..ConfigMapKeySelector::default()
+                        }),
+                        ..EnvVarSource::default()
+                    }),
+                    ..EnvVar::default()
+                },
+            ]),
+            ..Container::default()
+        };
+        let data = |pairs: &[(&str, &str)]| {
+            Some(
+                pairs
+                    .iter()
+                    .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+                    .collect(),
+            )
+        };
+        let mut sources = EnvSources::default();
+        sources.config_maps.insert(
+            "app-config".to_string(),
+            data(&[("DB_URL", "postgres://db"), ("LOG_LEVEL", "info")]),
+        );
+        sources.config_maps.insert("gone".to_string(), None);
+        sources
+            .secrets
+            .insert("db".to_string(), data(&[("password", "hunter2")]));
+
+        let vars: Vec<(String, String, Option<String>, Option<String>)> =
+            resolve_env(&container, &sources)
+                .into_iter()
+                .map(|var| (var.name, var.value, var.source, var.secret_value))
+                .collect();
+        let text = |s: &str| s.to_string();
+        assert_eq!(
+            vars,
+            [
+                (
+                    text("DB_URL"),
+                    text("postgres://db"),
+                    Some(text("configmap/app-config")),
+                    None
+                ),
+                (text("LOG_LEVEL"), text("debug"), None, None),
+                (
+                    text("DB_PASSWORD"),
+                    String::new(),
+                    Some(text("secret/db")),
+                    Some(text("hunter2"))
+                ),
+                (
+                    text("REGION"),
+                    text("<not found>"),
+                    Some(text("configmap/gone")),
+                    None
+                ),
+            ]
+        );
+    }
+}
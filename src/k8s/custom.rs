@@ -0,0 +1,208 @@
+use crate::error::{Error, Result};
+use crate::k8s::events::age_of;
+use crate::k8s::utils::list_paged;
+use crate::tui::data::{ApiKind, CustomResource};
+use k8s_openapi::serde_json::Value;
+use kube::api::{ApiResource, DynamicObject, GroupVersionKind, ListParams};
+use kube::discovery::{verbs, Discovery, Scope};
+use kube::{Api, Client};
+
+use super::client::{current_namespace, shared};
+
+/// Groups served by Kubernetes itself rather than by an operator's CRDs
+fn is_builtin_group(group: &str) -> bool {
+    !group.contains('.') || group.ends_with(".k8s.io")
+}
+
+fn to_api_kind(resource: ApiResource) -> ApiKind {
+    ApiKind {
+        name: format!("{}.{}", resource.plural, resource.group),
+        kind: resource.kind,
+        group: resource.group,
+        version: resource.version,
+        plural: resource.plural,
+    }
+}
+
+/// The `ApiResource` the dynamic api needs to list objects of `kind`
+pub(crate) fn api_resource(kind: &ApiKind) -> ApiResource {
+    let api_version = if kind.group.is_empty() {
+        kind.version.clone()
+    } else {
+        format!("{}/{}", kind.group, kind.version)
+    };
+    ApiResource {
+        group: kind.group.clone(),
+        version: kind.version.clone(),
+        api_version,
+        kind: kind.kind.clone(),
+        plural: kind.plural.clone(),
+    }
+}
+
+/// A kind given as `group/version/Kind`, or `version/Kind` for the core group, e.g.
+/// `argoproj.io/v1alpha1/Rollout`
+///
+/// # Errors
+///
+/// Will return `Err` if `spec` does not have a version and a kind
+pub fn parse_kind(spec: &str) -> Result<ApiKind> {
+    let invalid = || {
+        Error::InvalidConfig(format!(
+            "--resource {spec} is not group/version/Kind, e.g. argoproj.io/v1alpha1/Rollout"
+        ))
+    };
+    let (group_version, kind) = spec.rsplit_once('/').ok_or_else(invalid)?;
+    let (group, version) = group_version
+        .rsplit_once('/')
+        .unwrap_or(("", group_version));
+    if kind.is_empty() || version.is_empty() {
+        return Err(invalid());
+    }
+    Ok(to_api_kind(ApiResource::from_gvk(&GroupVersionKind::gvk(
+        group, version, kind,
+    ))))
+}
+
+/// Every namespaced kind the cluster serves beyond the built in groups, found through
+/// discovery, at the version the server prefers
+///
+/// # Errors
+///
+/// Will return `Err` if discovery against the k8s cluster api fails
+pub async fn list_api_kinds() -> Result<Vec<ApiKind>> {
+    let client = shared().await?;
+    let discovery = Discovery::new(Client::clone(&client)).run().await?;
+    let mut kinds: Vec<ApiKind> = discovery
+        .groups()
+        .filter(|group| !is_builtin_group(group.name()))
+        .flat_map(|group| group.recommended_resources())
+        .filter(|(_, capabilities)| {
+            matches!(capabilities.scope, Scope::Namespaced)
+                && capabilities.supports_operation(verbs::LIST)
+        })
+        .map(|(resource, _)| to_api_kind(resource))
+        .collect();
+    kinds.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.group.cmp(&b.group)));
+    Ok(kinds)
+}
+
+/// `status.phase` or `status.state`, then the Ready (or Available) condition, and every
+/// condition as type and `status: message`
+fn status_fields(data: &Value) -> (String, String, Vec<(String, String)>) {
+    let status = &data["status"];
+    let phase = ["phase", "state"]
+        .iter()
+        .find_map(|field| status[*field].as_str())
+        .unwrap_or_default()
+        .to_string();
+    let conditions: Vec<&Value> = status["conditions"]
+        .as_array()
+        .map(|conditions| conditions.iter().collect())
+        .unwrap_or_default();
+    let ready = ["Ready", "Available"]
+        .iter()
+        .find_map(|wanted| {
+            conditions
+                .iter()
+                .find(|condition| condition["type"].as_str() == Some(*wanted))
+                .and_then(|condition| condition["status"].as_str())
+        })
+        .unwrap_or_default()
+        .to_string();
+    let conditions = conditions
+        .iter()
+        .map(|condition| {
+            let state = condition["status"].as_str().unwrap_or_default();
+            let text = match condition["message"].as_str() {
+                Some(message) if !message.is_empty() => format!("{state}: {message}"),
+                _ => state.to_string(),
+            };
+            (
+                condition["type"].as_str().unwrap_or_default().to_string(),
+                text,
+            )
+        })
+        .collect();
+    (phase, ready, conditions)
+}
+
+fn to_custom_resource(object: &DynamicObject) -> CustomResource {
+    let (status, ready, conditions) = status_fields(&object.data);
+    CustomResource {
+        name: object.metadata.name.clone().unwrap_or_default(),
+        status,
+        ready,
+        age: age_of(&object.metadata),
+        conditions,
+    }
+}
+
+/// The objects of `kind` in the current namespace
+///
+/// # Errors
+///
+/// Will return `Err` if data can not be retrieved from k8s cluster api
+pub async fn list_custom_resources(kind: &ApiKind) -> Result<Vec<CustomResource>> {
+    let client = shared().await?;
+    let api: Api<DynamicObject> = Api::namespaced_with(
+        Client::clone(&client),
+        &current_namespace(),
+        &api_resource(kind),
+    );
+    let objects = list_paged(&api, &ListParams::default()).await?;
+    Ok(objects.iter().map(to_custom_resource).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_builtin_group, parse_kind, status_fields};
+    use k8s_openapi::serde_json::json;
+
+    #[test]
+    fn test_parse_kind() {
+        let rollout = parse_kind("argoproj.io/v1alpha1/Rollout").unwrap();
+        assert_eq!(rollout.group, "argoproj.io");
+        assert_eq!(rollout.version, "v1alpha1");
+        assert_eq!(rollout.plural, "rollouts");
+        assert_eq!(rollout.name, "rollouts.argoproj.io");
+
+        let pod = parse_kind("v1/Pod").unwrap();
+        assert_eq!(pod.group, "");
+        assert_eq!(pod.version, "v1");
+
+        assert!(parse_kind("Rollout").is_err());
+        assert!(parse_kind("argoproj.io/v1alpha1/").is_err());
+    }
+
+    #[test]
+    fn test_is_builtin_group() {
+        assert!(is_builtin_group(""));
+        assert!(is_builtin_group("apps"));
+        assert!(is_builtin_group("networking.k8s.io"));
+        assert!(!is_builtin_group("argoproj.io"));
+    }
+
+    #[test]
+    fn test_status_fields() {
+        let data = json!({
+            "status": {
+                "phase": "Healthy",
+                "conditions": [
+                    {"type": "Progressing", "status": "True"},
+                    {"type": "Ready", "status": "False", "message": "waiting for canary"}
+                ]
+            }
+        });
+        let (phase, ready, conditions) = status_fields(&data);
+        assert_eq!(phase, "Healthy");
+        assert_eq!(ready, "False");
+        assert_eq!(
+            conditions[1],
+            ("Ready".to_string(), "False: waiting for canary".to_string())
+        );
+
+        let (phase, ready, conditions) = status_fields(&json!({"spec": {}}));
+        assert!(phase.is_empty() && ready.is_empty() && conditions.is_empty());
+    }
+}
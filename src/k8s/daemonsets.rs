@@ -0,0 +1,55 @@
+use crate::error::Result;
+use crate::k8s::events::age_of;
+use crate::tui::data::DaemonSet as DaemonSetData;
+use k8s_openapi::api::apps::v1::DaemonSet;
+use kube::api::{ListParams, ObjectList};
+use kube::{Api, Client};
+
+use super::client::{current_namespace, shared};
+
+/// # Errors
+///
+/// Will return `Err` if data can not be retrieved from k8s cluster api
+pub async fn list_daemonsets() -> Result<Vec<DaemonSetData>> {
+    let client = shared().await?;
+
+    let ds_list: ObjectList<DaemonSet> =
+        Api::namespaced(Client::clone(&client), &current_namespace())
+            .list(&ListParams::default())
+            .await?;
+
+    Ok(ds_list
+        .items
+        .iter()
+        .map(|ds| {
+            let (desired, current, ready) = ds.status.as_ref().map_or((0, 0, 0), |status| {
+                (
+                    status.desired_number_scheduled,
+                    status.current_number_scheduled,
+                    status.number_ready,
+                )
+            });
+            let strategy = ds
+                .spec
+                .as_ref()
+                .and_then(|spec| spec.update_strategy.as_ref())
+                .and_then(|strategy| strategy.type_.clone())
+                .unwrap_or_else(|| "RollingUpdate".to_string());
+            // pods are matched by the selector, not by the DaemonSet's own labels
+            let selectors = ds
+                .spec
+                .as_ref()
+                .and_then(|spec| spec.selector.match_labels.clone());
+
+            DaemonSetData {
+                name: ds.metadata.name.clone().unwrap_or_default(),
+                desired: desired.to_string(),
+                current: current.to_string(),
+                ready: ready.to_string(),
+                age: age_of(&ds.metadata),
+                strategy,
+                selectors,
+            }
+        })
+        .collect())
+}
@@ -0,0 +1,136 @@
+use crate::config;
+use crate::error::{Error, Result};
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::sleep;
+use tracing::debug;
+
+/// Tries made by `with_retry` before a transient failure is reported
+const RETRY_ATTEMPTS: u32 = 4;
+/// Wait before the first retry, doubled for each one after it
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
+
+/// Broad categories of api failures, used to pick the message shown to the user
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ApiErrorKind {
+    Forbidden,
+    NotFound,
+    Network,
+    Other,
+}
+
+impl ApiErrorKind {
+    #[must_use]
+    pub fn classify(err: &Error) -> Self {
+        match err {
+            Error::Kube(kube::Error::Api(response)) => match response.code {
+                403 => Self::Forbidden,
+                404 => Self::NotFound,
+                _ => Self::Other,
+            },
+            Error::Kube(kube::Error::HyperError(_) | kube::Error::Service(_)) => Self::Network,
+            _ => Self::Other,
+        }
+    }
+
+    /// true for failures that may go away on their own: timeouts, throttling, 5xx
+    /// responses and dropped connections. Permission and missing resource errors are final.
+    #[must_use]
+    pub fn is_transient(err: &Error) -> bool {
+        match err {
+            Error::Kube(kube::Error::Api(response)) => {
+                response.code == 408 || response.code == 429 || response.code >= 500
+            }
+            Error::Kube(_) => Self::classify(err) == Self::Network,
+            _ => false,
+        }
+    }
+}
+
+/// Run the list call `list`, retrying transient failures with exponential backoff
+///
+/// # Errors
+///
+/// Will return `Err` straight away for a non transient failure, or the last failure once
+/// every attempt has failed
+pub async fn with_retry<T, F, Fut>(resource: &str, mut list: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut delay = RETRY_BASE_DELAY;
+    let mut attempt = 1;
+    loop {
+        match list().await {
+            Err(e) if attempt < RETRY_ATTEMPTS && ApiErrorKind::is_transient(&e) => {
+                debug!("listing {resource} failed (attempt {attempt}), retrying in {delay:?}: {e}");
+                sleep(delay).await;
+                delay *= 2;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// A user-facing description of a failed attempt to list `resource`
+#[must_use]
+pub fn list_error_message(err: &Error, resource: &str) -> String {
+    let namespace = config::current()
+        .namespace
+        .unwrap_or_else(|| "the current namespace".to_string());
+    match ApiErrorKind::classify(err) {
+        ApiErrorKind::Forbidden => {
+            format!("insufficient permissions to list {resource} in namespace {namespace}")
+        }
+        ApiErrorKind::NotFound => format!("{resource} not found in namespace {namespace}"),
+        ApiErrorKind::Network => format!("could not reach the cluster to list {resource}: {err}"),
+        ApiErrorKind::Other => format!("failed to list {resource}: {err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::k8s::errors::ApiErrorKind;
+    use kube::error::ErrorResponse;
+
+    fn api_error(code: u16) -> Error {
+        Error::Kube(kube::Error::Api(ErrorResponse {
+            status: "Failure".to_string(),
+            message: String::new(),
+            reason: String::new(),
+            code,
+        }))
+    }
+
+    #[test]
+    fn test_classify_api_errors() {
+        assert_eq!(
+            ApiErrorKind::Forbidden,
+            ApiErrorKind::classify(&api_error(403))
+        );
+        assert_eq!(
+            ApiErrorKind::NotFound,
+            ApiErrorKind::classify(&api_error(404))
+        );
+        assert_eq!(ApiErrorKind::Other, ApiErrorKind::classify(&api_error(500)));
+    }
+
+    #[test]
+    fn test_is_transient() {
+        assert!(ApiErrorKind::is_transient(&api_error(503)));
+        assert!(ApiErrorKind::is_transient(&api_error(429)));
+        assert!(!ApiErrorKind::is_transient(&api_error(403)));
+        assert!(!ApiErrorKind::is_transient(&api_error(404)));
+        assert!(!ApiErrorKind::is_transient(&Error::InvalidConfig(
+            "bad".to_string()
+        )));
+    }
+
+    #[test]
+    fn test_classify_non_api_errors() {
+        let err = Error::InvalidConfig("bad".to_string());
+        assert_eq!(ApiErrorKind::Other, ApiErrorKind::classify(&err));
+    }
+}
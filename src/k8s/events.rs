@@ -2,19 +2,13 @@ use crate::error::Result as NvResult;
 use crate::tui::data::ResourceEvent;
 use k8s_openapi::api::core::v1::Event;
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
-use kube::api::ListParams;
+use kube::api::{ListParams, ObjectMeta};
 use kube::{Api, Client};
 
-use chrono::{DateTime, Duration, Utc};
+use super::client::current_namespace;
 
-fn calculate_event_age(event_time: Option<&Time>) -> String {
-    event_time.map_or_else(String::new, |time| {
-        let now = Utc::now();
-        let event_datetime: DateTime<Utc> = time.0;
-        let duration = now.signed_duration_since(event_datetime);
-        format_duration(duration)
-    })
-}
+use chrono::{Duration, Utc};
+use std::time::Duration as StdDuration;
 
 fn convert_event_to_resource_event(event: &Event, rs_name: &str) -> ResourceEvent {
     let message = event
@@ -30,7 +24,11 @@ fn convert_event_to_resource_event(event: &Event, rs_name: &str) -> ResourceEven
     let reason = event.reason.clone().unwrap_or_default();
     let object = event.involved_object.name.clone().unwrap_or_default();
     let type_ = event.type_.clone().unwrap_or_default();
-    let age = calculate_event_age(event.last_timestamp.as_ref());
+    let count = event.count.unwrap_or(1).to_string();
+    let age = event
+        .last_timestamp
+        .as_ref()
+        .map_or_else(String::new, since);
 
     ResourceEvent {
         resource_name: rs_name.to_string(),
@@ -38,6 +36,7 @@ fn convert_event_to_resource_event(event: &Event, rs_name: &str) -> ResourceEven
         message,
         reason,
         type_,
+        count,
         age,
     }
 }
@@ -48,7 +47,10 @@ fn convert_event_to_resource_event(event: &Event, rs_name: &str) -> ResourceEven
 pub async fn list_k8sevents(client: Client) -> Result<Vec<Event>, kube::Error> {
     let lp = ListParams::default();
 
-    let mut unfiltered_events: Vec<Event> = Api::default_namespaced(client).list(&lp).await?.items;
+    let mut unfiltered_events: Vec<Event> = Api::namespaced(client, &current_namespace())
+        .list(&lp)
+        .await?
+        .items;
 
     unfiltered_events.sort_by(|a, b| {
         b.last_timestamp
@@ -64,18 +66,27 @@ pub async fn list_k8sevents(client: Client) -> Result<Vec<Event>, kube::Error> {
     Ok(unfiltered_events)
 }
 
+/// The events of the namespace, newest first, only the ones seen within `since` when
+/// it is given
+///
 /// # Errors
 ///
 /// Will return `Err` if events cannot be retrieved from k8s cluster api
-pub async fn list_all() -> NvResult<Vec<ResourceEvent>> {
+pub async fn list_all(since: Option<StdDuration>) -> NvResult<Vec<ResourceEvent>> {
     let lp = ListParams::default();
+    let client = super::client::shared().await?;
 
     let mut unfiltered_events: Vec<Event> =
-        Api::default_namespaced(super::client::new(None).await?)
+        Api::namespaced(Client::clone(&client), &current_namespace())
             .list(&lp)
             .await?
             .items;
 
+    if let Some(cutoff) = since.and_then(|since| Duration::from_std(since).ok()) {
+        let oldest = Utc::now() - cutoff;
+        unfiltered_events.retain(|e| e.last_timestamp.as_ref().is_some_and(|t| t.0 >= oldest));
+    }
+
     unfiltered_events.sort_by(|a, b| {
         b.last_timestamp
             .clone()
@@ -140,8 +151,11 @@ pub async fn list_events_for_resource(
     Ok(resource_events)
 }
 
+/// `duration` in its largest whole unit, e.g. `3d`. A negative one, from a timestamp
+/// a skewed clock put in the future, reads as `0s`.
 #[must_use]
 pub fn format_duration(duration: Duration) -> String {
+    let duration = duration.max(Duration::zero());
     if duration.num_days() > 0 {
         format!("{}d", duration.num_days())
     } else if duration.num_hours() > 0 {
@@ -152,3 +166,38 @@ pub fn format_duration(duration: Duration) -> String {
         format!("{}s", duration.num_seconds())
     }
 }
+
+/// Time passed since `time`, see [`format_duration`]
+#[must_use]
+pub fn since(time: &Time) -> String {
+    format_duration(Utc::now().signed_duration_since(time.0))
+}
+
+/// How long ago the object `metadata` belongs to was created, `Unk` when it carries no
+/// creation timestamp
+#[must_use]
+pub fn age_of(metadata: &ObjectMeta) -> String {
+    metadata
+        .creation_timestamp
+        .as_ref()
+        .map_or_else(|| "Unk".to_string(), since)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::age_of;
+    use chrono::{Duration, Utc};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+    use kube::api::ObjectMeta;
+
+    #[test]
+    fn test_age_of_clamps_future_timestamps() {
+        // created "in 30 seconds" by a node whose clock runs ahead
+        let skewed = ObjectMeta {
+            creation_timestamp: Some(Time(Utc::now() + Duration::seconds(30))),
+            ..ObjectMeta::default()
+        };
+        assert_eq!(age_of(&skewed), "0s");
+        assert_eq!(age_of(&ObjectMeta::default()), "Unk");
+    }
+}
@@ -0,0 +1,87 @@
+use crate::error::Result;
+use crate::k8s::rs_ingress::get_rs_ingress_info;
+use crate::tui::data::{FinderEntry, FinderTarget, Rs};
+use k8s_openapi::api::core::v1::{Pod, Service};
+use k8s_openapi::api::networking::v1::Ingress;
+use kube::api::{ListParams, ObjectList};
+use kube::{Api, Client};
+
+use super::client::{current_namespace, shared};
+
+/// Every rule of `ingress` as the ingress view shows it
+fn ingress_rows(ingress: &Ingress) -> Vec<crate::tui::data::Ingress> {
+    ingress
+        .spec
+        .iter()
+        .flat_map(|spec| spec.rules.iter().flatten())
+        .flat_map(|rule| {
+            rule.http.iter().flat_map(move |http| {
+                http.paths.iter().filter_map(move |path| {
+                    path.backend.service.as_ref().and_then(|backend| {
+                        get_rs_ingress_info(ingress, rule.host.as_deref(), path, backend)
+                    })
+                })
+            })
+        })
+        .collect()
+}
+
+/// The index for the global finder: the ReplicaSets already listed by the root view plus
+/// one list call each for pods, services and ingresses in the current namespace
+///
+/// # Errors
+///
+/// Will return `Err` if data can not be retrieved from k8s cluster api
+pub async fn list_finder_entries(replicasets: &[Rs]) -> Result<Vec<FinderEntry>> {
+    let client = shared().await?;
+    let namespace = current_namespace();
+    let lp = ListParams::default();
+
+    let mut entries: Vec<FinderEntry> = replicasets
+        .iter()
+        .filter_map(|rs| {
+            rs.selectors.clone().map(|selector| FinderEntry {
+                kind: "ReplicaSet".to_string(),
+                name: rs.name.clone(),
+                target: FinderTarget::Pods(selector),
+            })
+        })
+        .collect();
+
+    let pods: ObjectList<Pod> = Api::namespaced(Client::clone(&client), &namespace)
+        .list(&lp)
+        .await?;
+    entries.extend(pods.items.into_iter().filter_map(|pod| {
+        let name = pod.metadata.name?;
+        pod.metadata.labels.map(|selector| FinderEntry {
+            kind: "Pod".to_string(),
+            name: name.clone(),
+            target: FinderTarget::Pod { selector, name },
+        })
+    }));
+
+    let services: ObjectList<Service> = Api::namespaced(Client::clone(&client), &namespace)
+        .list(&lp)
+        .await?;
+    entries.extend(services.items.into_iter().filter_map(|service| {
+        let selector = service.spec?.selector?;
+        Some(FinderEntry {
+            kind: "Service".to_string(),
+            name: service.metadata.name?,
+            target: FinderTarget::Pods(selector),
+        })
+    }));
+
+    let ingresses: ObjectList<Ingress> = Api::namespaced(Client::clone(&client), &namespace)
+        .list(&lp)
+        .await?;
+    entries.extend(ingresses.items.iter().filter_map(|ingress| {
+        Some(FinderEntry {
+            kind: "Ingress".to_string(),
+            name: ingress.metadata.name.clone()?,
+            target: FinderTarget::Ingress(ingress_rows(ingress)),
+        })
+    }));
+
+    Ok(entries)
+}
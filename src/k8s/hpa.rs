@@ -0,0 +1,188 @@
+use crate::tui::data::Autoscaler;
+use k8s_openapi::api::autoscaling::v2::{
+    HorizontalPodAutoscaler, MetricSpec, MetricStatus, MetricValueStatus,
+};
+use kube::api::ListParams;
+use kube::{Api, Client};
+
+use super::client::current_namespace;
+
+/// # Errors
+///
+/// Will return `Err` if autoscalers cannot be retrieved from k8s cluster api
+pub async fn list_hpas(client: Client) -> Result<Vec<HorizontalPodAutoscaler>, kube::Error> {
+    let lp = ListParams::default();
+    Ok(Api::namespaced(client, &current_namespace())
+        .list(&lp)
+        .await?
+        .items)
+}
+
+fn format_value(value: &MetricValueStatus) -> String {
+    value
+        .average_utilization
+        .map(|utilization| format!("{utilization}%"))
+        .or_else(|| value.average_value.as_ref().map(|q| q.0.clone()))
+        .or_else(|| value.value.as_ref().map(|q| q.0.clone()))
+        .unwrap_or_else(|| "?".to_string())
+}
+
+/// target of the resource metric `name`, e.g. "80%"
+fn resource_target(specs: &[MetricSpec], name: &str) -> Option<String> {
+    let target = &specs
+        .iter()
+        .filter_map(|spec| spec.resource.as_ref())
+        .find(|resource| resource.name == name)?
+        .target;
+    target
+        .average_utilization
+        .map(|utilization| format!("{utilization}%"))
+        .or_else(|| target.average_value.as_ref().map(|q| q.0.clone()))
+        .or_else(|| target.value.as_ref().map(|q| q.0.clone()))
+}
+
+/// "cpu 45% (target 80%)" for resource metrics, "name value" for the rest
+fn format_metric(status: &MetricStatus, specs: &[MetricSpec]) -> Option<String> {
+    if let Some(resource) = &status.resource {
+        let current = format_value(&resource.current);
+        return Some(resource_target(specs, &resource.name).map_or_else(
+            || format!("{} {current}", resource.name),
+            |target| format!("{} {current} (target {target})", resource.name),
+        ));
+    }
+    if let Some(resource) = &status.container_resource {
+        return Some(format!(
+            "{}/{} {}",
+            resource.container,
+            resource.name,
+            format_value(&resource.current)
+        ));
+    }
+    if let Some(pods) = &status.pods {
+        return Some(format!(
+            "{} {}",
+            pods.metric.name,
+            format_value(&pods.current)
+        ));
+    }
+    if let Some(object) = &status.object {
+        return Some(format!(
+            "{} {}",
+            object.metric.name,
+            format_value(&object.current)
+        ));
+    }
+    status.external.as_ref().map(|external| {
+        format!(
+            "{} {}",
+            external.metric.name,
+            format_value(&external.current)
+        )
+    })
+}
+
+/// The autoscaler whose `scaleTargetRef` is the `kind`/`name` workload, if any
+#[must_use]
+pub fn autoscaler_for(
+    hpas: &[HorizontalPodAutoscaler],
+    kind: &str,
+    name: &str,
+) -> Option<Autoscaler> {
+    let hpa = hpas.iter().find(|hpa| {
+        hpa.spec.as_ref().is_some_and(|spec| {
+            spec.scale_target_ref.kind == kind && spec.scale_target_ref.name == name
+        })
+    })?;
+    let spec = hpa.spec.as_ref()?;
+    let status = hpa.status.as_ref();
+    let specs = spec.metrics.clone().unwrap_or_default();
+    let metrics = status
+        .and_then(|status| status.current_metrics.as_ref())
+        .map(|current| {
+            current
+                .iter()
+                .filter_map(|metric| format_metric(metric, &specs))
+                .collect::<Vec<String>>()
+                .join(", ")
+        })
+        .filter(|metrics| !metrics.is_empty())
+        .unwrap_or_else(|| "no metrics reported".to_string());
+
+    Some(Autoscaler {
+        name: hpa.metadata.name.clone().unwrap_or_default(),
+        current: status
+            .and_then(|status| status.current_replicas)
+            .unwrap_or(0),
+        desired: status.map_or(0, |status| status.desired_replicas),
+        min: spec.min_replicas.unwrap_or(1),
+        max: spec.max_replicas,
+        metrics,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::autoscaler_for;
+    use k8s_openapi::api::autoscaling::v2::{
+        CrossVersionObjectReference, HorizontalPodAutoscaler, HorizontalPodAutoscalerSpec,
+        HorizontalPodAutoscalerStatus, MetricSpec, MetricStatus, MetricTarget, MetricValueStatus,
+        ResourceMetricSource, ResourceMetricStatus,
+    };
+    use kube::api::ObjectMeta;
+
+    #[test]
+    fn test_autoscaler_for_matches_scale_target() {
+        let hpa = HorizontalPodAutoscaler {
+            metadata: ObjectMeta {
+                name: Some("web".to_string()),
+                ..ObjectMeta::default()
+            },
+            spec: Some(HorizontalPodAutoscalerSpec {
+                scale_target_ref: CrossVersionObjectReference {
+                    kind: "Deployment".to_string(),
+                    name: "web".to_string(),
+                    api_version: Some("apps/v1".to_string()),
+                },
+                min_replicas: Some(2),
+                max_replicas: 10,
+                metrics: Some(vec![MetricSpec {
+                    type_: "Resource".to_string(),
+                    resource: Some(ResourceMetricSource {
+                        name: "cpu".to_string(),
+                        target: MetricTarget {
+                            type_: "Utilization".to_string(),
+                            average_utilization: Some(80),
+                            ..MetricTarget::default()
+                        },
+                    }),
+                    ..MetricSpec::default()
+                }]),
+                ..HorizontalPodAutoscalerSpec::default()
+            }),
+            status: Some(HorizontalPodAutoscalerStatus {
+                current_replicas: Some(3),
+                desired_replicas: 4,
+                current_metrics: Some(vec![MetricStatus {
+                    type_: "Resource".to_string(),
+                    resource: Some(ResourceMetricStatus {
+                        name: "cpu".to_string(),
+                        current: MetricValueStatus {
+                            average_utilization: Some(45),
+                            ..MetricValueStatus::default()
+                        },
+                    }),
+                    ..MetricStatus::default()
+                }]),
+                ..HorizontalPodAutoscalerStatus::default()
+            }),
+        };
+        let hpas = vec![hpa];
+
+        assert!(autoscaler_for(&hpas, "Deployment", "api").is_none());
+        let autoscaler = autoscaler_for(&hpas, "Deployment", "web");
+        assert_eq!(
+            autoscaler.map(|a| (a.current, a.desired, a.min, a.max, a.metrics)),
+            Some((3, 4, 2, 10, "cpu 45% (target 80%)".to_string()))
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use crate::error::Result;
+use crate::k8s::events::{age_of, since};
+use crate::tui::data::Job as JobData;
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use kube::api::{ListParams, ObjectList};
+use kube::{Api, Client};
+
+use super::client::{current_namespace, shared};
+
+/// name of the `CronJob` that created the job, if any
+fn cronjob_owner(job: &Job) -> Option<String> {
+    job.metadata.owner_references.as_ref().and_then(|owners| {
+        owners
+            .iter()
+            .find(|owner| owner.kind == "CronJob")
+            .map(|owner| owner.name.clone())
+    })
+}
+
+fn has_condition(job: &Job, type_: &str) -> bool {
+    job.status
+        .as_ref()
+        .and_then(|status| status.conditions.as_ref())
+        .is_some_and(|conditions| {
+            conditions
+                .iter()
+                .any(|c| c.type_ == type_ && c.status == "True")
+        })
+}
+
+fn job_data(job: &Job) -> JobData {
+    let (active, succeeded, failed) = job.status.as_ref().map_or((0, 0, 0), |status| {
+        (
+            status.active.unwrap_or(0),
+            status.succeeded.unwrap_or(0),
+            status.failed.unwrap_or(0),
+        )
+    });
+    let completions = job
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.completions)
+        .map_or_else(|| succeeded.to_string(), |c| format!("{succeeded}/{c}"));
+    let selectors = job
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.selector.as_ref())
+        .and_then(|selector| selector.match_labels.clone());
+
+    JobData {
+        name: job.metadata.name.clone().unwrap_or_default(),
+        kind: "Job".to_string(),
+        completions,
+        status: format!("{active} active, {succeeded} succeeded, {failed} failed"),
+        age: age_of(&job.metadata),
+        complete: has_condition(job, "Complete"),
+        failed: has_condition(job, "Failed"),
+        suspended: job
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.suspend)
+            .unwrap_or(false),
+        selectors,
+    }
+}
+
+fn cronjob_data(cronjob: &CronJob) -> JobData {
+    let (schedule, suspended) = cronjob
+        .spec
+        .as_ref()
+        .map_or((String::new(), false), |spec| {
+            (spec.schedule.clone(), spec.suspend.unwrap_or(false))
+        });
+    let last_run = cronjob
+        .status
+        .as_ref()
+        .and_then(|status| status.last_schedule_time.as_ref())
+        .map_or_else(
+            || "never run".to_string(),
+            |t| format!("last run {} ago", since(t)),
+        );
+    let status = if suspended {
+        format!("suspended, {last_run}")
+    } else {
+        last_run
+    };
+
+    JobData {
+        name: cronjob.metadata.name.clone().unwrap_or_default(),
+        kind: "CronJob".to_string(),
+        completions: schedule,
+        status,
+        age: age_of(&cronjob.metadata),
+        complete: false,
+        failed: false,
+        suspended,
+        selectors: None,
+    }
+}
+
+/// All `CronJobs` followed by the Jobs no `CronJob` owns, or only the Jobs of
+/// `cronjob` when one is given.
+///
+/// # Errors
+///
+/// Will return `Err` if data can not be retrieved from k8s cluster api
+pub async fn list_jobs(cronjob: Option<&str>) -> Result<Vec<JobData>> {
+    let client = shared().await?;
+    let namespace = current_namespace();
+
+    let job_list: ObjectList<Job> = Api::namespaced(Client::clone(&client), &namespace)
+        .list(&ListParams::default())
+        .await?;
+    let jobs = job_list
+        .items
+        .iter()
+        .filter(|job| cronjob_owner(job).as_deref() == cronjob)
+        .map(job_data);
+
+    if cronjob.is_some() {
+        return Ok(jobs.collect());
+    }
+
+    let cronjob_list: ObjectList<CronJob> = Api::namespaced(Client::clone(&client), &namespace)
+        .list(&ListParams::default())
+        .await?;
+
+    Ok(cronjob_list
+        .items
+        .iter()
+        .map(cronjob_data)
+        .chain(jobs)
+        .collect())
+}
@@ -0,0 +1,187 @@
+use crate::error::{Error, Result};
+use crate::labels::MetadataEdit;
+use k8s_openapi::serde_json::json;
+use k8s_openapi::NamespaceResourceScope;
+use kube::api::{Patch, PatchParams};
+use kube::{Api, Client, Resource, ResourceExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Debug;
+
+use super::client::{current_namespace, shared};
+
+/// `object` as YAML, without the server side apply bookkeeping nobody wants to paste
+fn to_manifest<K: Resource + Serialize>(mut object: K) -> Result<String> {
+    object.meta_mut().managed_fields = None;
+    Ok(serde_yaml::to_string(&object)?)
+}
+
+/// The full YAML of the named resource in the current namespace
+///
+/// # Errors
+///
+/// Will return `Err` if the resource can not be read from the k8s cluster api
+pub async fn get_manifest<K>(name: &str) -> Result<String>
+where
+    K: Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug + Serialize,
+    K::DynamicType: Default,
+{
+    let client = shared().await?;
+    let object = Api::<K>::namespaced(Client::clone(&client), &current_namespace())
+        .get(name)
+        .await?;
+    to_manifest(object)
+}
+
+/// Parse an edited manifest, refusing one that no longer describes the resource `name`
+fn parse_manifest<K: Resource + DeserializeOwned>(name: &str, yaml: &str) -> Result<K> {
+    let mut object: K = serde_yaml::from_str(yaml)?;
+    if object.name_any() != name {
+        return Err(Error::InvalidManifest(format!(
+            "the edited manifest names {}, not {name}",
+            object.name_any()
+        )));
+    }
+    // the server owns these, sending them back only invites conflicts
+    object.meta_mut().managed_fields = None;
+    object.meta_mut().resource_version = None;
+    Ok(object)
+}
+
+/// Apply an edited manifest of the named resource in the current namespace with a
+/// server side apply patch, like `kubectl edit`, returning the YAML of the resource as
+/// the api server made it. A `dry_run` is checked and defaulted by the server the same
+/// way, but not stored. Fields owned by another manager are only taken over when
+/// `force` is set, otherwise the api server answers with a conflict.
+///
+/// # Errors
+///
+/// Will return `Err` if the YAML does not parse, names another resource or the k8s
+/// cluster api rejects it
+pub async fn apply_manifest<K>(name: &str, yaml: &str, dry_run: bool, force: bool) -> Result<String>
+where
+    K: Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug + Serialize,
+    K::DynamicType: Default,
+{
+    let object: K = parse_manifest(name, yaml)?;
+    let client = shared().await?;
+    let mut params = PatchParams::apply("navipod");
+    params.dry_run = dry_run;
+    params.force = force;
+    let applied = Api::<K>::namespaced(Client::clone(&client), &current_namespace())
+        .patch(name, &params, &Patch::Apply(&object))
+        .await?;
+    to_manifest(applied)
+}
+
+/// The lines gone from `before` marked `-` and the lines new in `after` marked `+`, in
+/// the order they appear, unchanged lines left out
+#[must_use]
+pub fn diff_lines(before: &str, after: &str) -> Vec<String> {
+    let old: Vec<&str> = before.lines().collect();
+    let new: Vec<&str> = after.lines().collect();
+    // longest common subsequence of the lines from each position to the end
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::new();
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+    lines
+}
+
+/// Set or remove one label or annotation of the named resource in the current namespace
+/// with a strategic merge patch, like `kubectl label` and `kubectl annotate`
+///
+/// # Errors
+///
+/// Will return `Err` if the k8s cluster api rejects the patch
+pub async fn patch_metadata<K>(name: &str, edit: &MetadataEdit) -> Result<()>
+where
+    K: Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug,
+    K::DynamicType: Default,
+{
+    let field = if edit.annotation {
+        "annotations"
+    } else {
+        "labels"
+    };
+    // a null value is how a merge patch removes a key
+    let patch = json!({ "metadata": { field: { &edit.key: edit.value } } });
+    let client = shared().await?;
+    Api::<K>::namespaced(Client::clone(&client), &current_namespace())
+        .patch(name, &PatchParams::default(), &Patch::Strategic(&patch))
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff_lines, parse_manifest, to_manifest};
+    use k8s_openapi::api::core::v1::Pod;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry;
+    use kube::api::ObjectMeta;
+
+    #[test]
+    fn test_to_manifest_drops_managed_fields() {
+        let pod = Pod {
+            metadata: ObjectMeta {
+                name: Some("web-0".to_string()),
+                managed_fields: Some(vec![ManagedFieldsEntry {
+                    manager: Some("kubectl".to_string()),
+                    ..ManagedFieldsEntry::default()
+                }]),
+                ..ObjectMeta::default()
+            },
+            ..Pod::default()
+        };
+
+        let yaml = to_manifest(pod).unwrap_or_default();
+        assert!(yaml.contains("name: web-0"));
+        assert!(!yaml.contains("managedFields"));
+    }
+
+    #[test]
+    fn test_parse_manifest() {
+        let yaml =
+            "apiVersion: v1\nkind: Pod\nmetadata:\n  name: web-0\n  resourceVersion: \"42\"\n";
+        let pod: Pod = parse_manifest("web-0", yaml).unwrap();
+        assert_eq!(pod.metadata.resource_version, None);
+
+        assert!(parse_manifest::<Pod>("web-1", yaml).is_err());
+        assert!(parse_manifest::<Pod>("web-0", "metadata: [").is_err());
+    }
+
+    #[test]
+    fn test_diff_lines_marks_only_changes() {
+        let before = "spec:\n  replicas: 2\n  paused: false\n";
+        let after = "spec:\n  replicas: 3\n  paused: false\n  minReadySeconds: 5\n";
+        assert_eq!(
+            diff_lines(before, after),
+            vec![
+                "-   replicas: 2",
+                "+   replicas: 3",
+                "+   minReadySeconds: 5"
+            ]
+        );
+        assert!(diff_lines(before, before).is_empty());
+    }
+}
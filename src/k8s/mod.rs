@@ -1,9 +1,27 @@
+pub mod bookmarks;
 pub mod client;
+pub mod configmaps;
 pub mod containers;
+pub mod custom;
+pub mod daemonsets;
+pub mod errors;
 pub mod events;
+pub mod finder;
+pub mod hpa;
+pub mod jobs;
+pub mod manifests;
+pub mod namespaces;
+pub mod nodes;
+pub mod pending;
 pub mod pod_ingress;
 pub mod pods;
+pub mod problems;
+pub mod pvcs;
+pub mod quota;
+pub mod reasons;
+pub mod related;
 pub mod rs;
 pub mod rs_ingress;
 pub mod scan;
+pub mod services;
 pub mod utils;
@@ -0,0 +1,48 @@
+use crate::error::Result;
+use crate::k8s::events::age_of;
+use crate::tui::data::{sort_namespaces, Namespace as NamespaceData};
+use k8s_openapi::api::core::v1::Namespace;
+use kube::api::{ListParams, ObjectList};
+use kube::{Api, Client};
+
+use super::client::shared;
+
+/// true when the cluster has a namespace called `name`
+///
+/// # Errors
+///
+/// Will return `Err` if data can not be retrieved from k8s cluster api
+pub async fn namespace_exists(name: &str) -> Result<bool> {
+    let client = shared().await?;
+    let namespaces: Api<Namespace> = Api::all(Client::clone(&client));
+    Ok(namespaces.get_opt(name).await?.is_some())
+}
+
+/// # Errors
+///
+/// Will return `Err` if data can not be retrieved from k8s cluster api
+pub async fn list_namespaces(pinned: &[String]) -> Result<Vec<NamespaceData>> {
+    let client = shared().await?;
+
+    let ns_list: ObjectList<Namespace> = Api::all(Client::clone(&client))
+        .list(&ListParams::default())
+        .await?;
+
+    let mut namespaces: Vec<NamespaceData> = ns_list
+        .items
+        .iter()
+        .map(|ns| NamespaceData {
+            pin: String::new(),
+            name: ns.metadata.name.clone().unwrap_or_default(),
+            status: ns
+                .status
+                .as_ref()
+                .and_then(|status| status.phase.clone())
+                .unwrap_or_default(),
+            age: age_of(&ns.metadata),
+        })
+        .collect();
+    sort_namespaces(&mut namespaces, pinned);
+
+    Ok(namespaces)
+}
@@ -0,0 +1,248 @@
+use crate::error::Result;
+use crate::k8s::events::age_of;
+use crate::k8s::utils::list_paged;
+use crate::tui::data::Node as NodeData;
+use k8s_openapi::api::core::v1::{Node, Pod};
+use k8s_openapi::serde_json::json;
+use kube::api::{EvictParams, ListParams, Patch, PatchParams};
+use kube::{Api, Client};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::time::sleep;
+
+use super::client::shared;
+
+/// Label prefix the control plane tooling uses to mark a node's roles
+const ROLE_LABEL_PREFIX: &str = "node-role.kubernetes.io/";
+
+/// Annotation the kubelet puts on the mirror of a static pod, which can not be evicted
+const MIRROR_POD_ANNOTATION: &str = "kubernetes.io/config.mirror";
+
+/// How long to wait before evicting again the pods a `PodDisruptionBudget` turned away
+const EVICTION_RETRY: Duration = Duration::from_secs(5);
+
+/// `Ready` or `NotReady`, with `SchedulingDisabled` appended once the node is cordoned
+fn node_status(node: &Node) -> String {
+    let ready = node
+        .status
+        .as_ref()
+        .and_then(|status| status.conditions.as_ref())
+        .and_then(|conditions| conditions.iter().find(|c| c.type_ == "Ready"))
+        .is_some_and(|condition| condition.status == "True");
+    let status = if ready { "Ready" } else { "NotReady" };
+    if is_unschedulable(node) {
+        format!("{status},SchedulingDisabled")
+    } else {
+        status.to_string()
+    }
+}
+
+fn is_unschedulable(node: &Node) -> bool {
+    node.spec
+        .as_ref()
+        .and_then(|spec| spec.unschedulable)
+        .unwrap_or(false)
+}
+
+fn node_roles(node: &Node) -> String {
+    let roles = node
+        .metadata
+        .labels
+        .as_ref()
+        .map(|labels| {
+            labels
+                .keys()
+                .filter_map(|key| key.strip_prefix(ROLE_LABEL_PREFIX))
+                .filter(|role| !role.is_empty())
+                .collect::<Vec<&str>>()
+        })
+        .unwrap_or_default();
+    if roles.is_empty() {
+        "<none>".to_string()
+    } else {
+        roles.join(",")
+    }
+}
+
+fn to_node_data(node: &Node) -> NodeData {
+    let status = node.status.as_ref();
+    let info = status.and_then(|status| status.node_info.as_ref());
+    NodeData {
+        name: node.metadata.name.clone().unwrap_or_default(),
+        status: node_status(node),
+        roles: node_roles(node),
+        version: info
+            .map(|info| info.kubelet_version.clone())
+            .unwrap_or_default(),
+        age: age_of(&node.metadata),
+        unschedulable: is_unschedulable(node),
+        internal_ip: status
+            .and_then(|status| status.addresses.as_ref())
+            .and_then(|addresses| addresses.iter().find(|a| a.type_ == "InternalIP"))
+            .map(|address| address.address.clone())
+            .unwrap_or_default(),
+        os_image: info.map(|info| info.os_image.clone()).unwrap_or_default(),
+        taints: node
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.taints.as_ref())
+            .map(|taints| {
+                taints
+                    .iter()
+                    .map(|taint| {
+                        taint.value.as_ref().map_or_else(
+                            || format!("{}:{}", taint.key, taint.effect),
+                            |value| format!("{}={value}:{}", taint.key, taint.effect),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+    }
+}
+
+/// The nodes of the cluster
+///
+/// # Errors
+///
+/// Will return `Err` if data can not be retrieved from k8s cluster api
+pub async fn list_nodes() -> Result<Vec<NodeData>> {
+    let client = shared().await?;
+    let nodes: Vec<Node> =
+        list_paged(&Api::all(Client::clone(&client)), &ListParams::default()).await?;
+    Ok(nodes.iter().map(to_node_data).collect())
+}
+
+/// Cordon the named node so no new pods are scheduled on it, or uncordon it again
+///
+/// # Errors
+///
+/// Will return `Err` if the node can not be patched
+pub async fn set_unschedulable(name: &str, unschedulable: bool) -> Result<()> {
+    let client = shared().await?;
+    let nodes: Api<Node> = Api::all(Client::clone(&client));
+    let patch = json!({ "spec": { "unschedulable": unschedulable } });
+    nodes
+        .patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await?;
+    Ok(())
+}
+
+/// Where a drain has got to, reported after every eviction attempt
+#[derive(Clone, Debug, Default)]
+pub struct DrainProgress {
+    pub evicted: usize,
+    pub total: usize,
+    /// the pods a `PodDisruptionBudget` does not let go of yet
+    pub blocked: Vec<String>,
+}
+
+/// Pods a drain leaves alone: the ones a `DaemonSet` would recreate on the node right
+/// away, mirrors of static pods, and pods that have already finished
+fn is_drain_exempt(pod: &Pod) -> bool {
+    let daemon = pod
+        .metadata
+        .owner_references
+        .as_ref()
+        .is_some_and(|owners| owners.iter().any(|owner| owner.kind == "DaemonSet"));
+    let mirror = pod
+        .metadata
+        .annotations
+        .as_ref()
+        .is_some_and(|annotations| annotations.contains_key(MIRROR_POD_ANNOTATION));
+    let finished = pod
+        .status
+        .as_ref()
+        .and_then(|status| status.phase.as_deref())
+        .is_some_and(|phase| phase == "Succeeded" || phase == "Failed");
+    daemon || mirror || finished
+}
+
+/// Cordon the named node and evict its pods through the eviction api, so every
+/// `PodDisruptionBudget` is respected. Pods a budget turns away are tried again until
+/// they go or `cancel` is set, `progress` is called after each round of evictions.
+/// Returns `false` when the drain was cancelled before the node was empty.
+///
+/// # Errors
+///
+/// Will return `Err` if the node can not be cordoned, its pods listed, or an eviction
+/// fails for any other reason than a disruption budget
+pub async fn drain_node(
+    name: &str,
+    cancel: &AtomicBool,
+    progress: impl Fn(&DrainProgress),
+) -> Result<bool> {
+    set_unschedulable(name, true).await?;
+
+    let client = shared().await?;
+    let params = ListParams::default().fields(&format!("spec.nodeName={name}"));
+    let pods: Vec<Pod> = list_paged(&Api::all(Client::clone(&client)), &params).await?;
+    let mut remaining: Vec<(String, String)> = pods
+        .iter()
+        .filter(|pod| !is_drain_exempt(pod))
+        .map(|pod| {
+            (
+                pod.metadata.namespace.clone().unwrap_or_default(),
+                pod.metadata.name.clone().unwrap_or_default(),
+            )
+        })
+        .collect();
+
+    let mut state = DrainProgress {
+        total: remaining.len(),
+        ..DrainProgress::default()
+    };
+    progress(&state);
+
+    while !remaining.is_empty() {
+        let mut blocked = Vec::new();
+        for (namespace, pod) in remaining {
+            if cancel.load(Ordering::Relaxed) {
+                return Ok(false);
+            }
+            let pods: Api<Pod> = Api::namespaced(Client::clone(&client), &namespace);
+            match pods.evict(&pod, &EvictParams::default()).await {
+                // a pod that is already gone needs no eviction
+                Ok(_) => state.evicted += 1,
+                Err(kube::Error::Api(e)) if e.code == 404 => state.evicted += 1,
+                // the eviction would violate a disruption budget
+                Err(kube::Error::Api(e)) if e.code == 429 => blocked.push((namespace, pod)),
+                Err(e) => return Err(e.into()),
+            }
+        }
+        state.blocked = blocked.iter().map(|(_, pod)| pod.clone()).collect();
+        progress(&state);
+        remaining = blocked;
+        if !remaining.is_empty() {
+            sleep(EVICTION_RETRY).await;
+        }
+    }
+    Ok(!cancel.load(Ordering::Relaxed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_drain_exempt;
+    use k8s_openapi::api::core::v1::Pod;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+    use kube::api::ObjectMeta;
+
+    fn owned_by(kind: &str) -> Pod {
+        Pod {
+            metadata: ObjectMeta {
+                owner_references: Some(vec![OwnerReference {
+                    kind: kind.to_string(),
+                    ..OwnerReference::default()
+                }]),
+                ..ObjectMeta::default()
+            },
+            ..Pod::default()
+        }
+    }
+
+    #[test]
+    fn test_is_drain_exempt_skips_daemonset_pods() {
+        assert!(is_drain_exempt(&owned_by("DaemonSet")));
+        assert!(!is_drain_exempt(&owned_by("ReplicaSet")));
+    }
+}
@@ -0,0 +1,361 @@
+use crate::error::Result;
+use crate::k8s::quota::{format_amount, parse_quantity};
+use crate::k8s::utils::list_paged;
+use k8s_openapi::api::core::v1::{
+    Container, Node, NodeSelectorRequirement, NodeSelectorTerm, Pod, Taint, Toleration,
+};
+use kube::api::ListParams;
+use kube::{Api, Client};
+use std::cmp::{Ordering, Reverse};
+use std::collections::BTreeMap;
+
+use super::client::{current_namespace, shared};
+
+/// the resources a node runs out of for a pending pod, as named in requests
+const RESOURCES: [&str; 2] = ["cpu", "memory"];
+
+/// nodes named after a reason before the rest are only counted
+const NAMED_NODES: usize = 3;
+
+/// What `pod` asks of a node for `resource`: the sum over its containers, or the
+/// largest init container when that is more, since those run one at a time first
+fn pod_request(pod: &Pod, resource: &str) -> f64 {
+    let request = |container: &Container| {
+        container
+            .resources
+            .as_ref()
+            .and_then(|resources| resources.requests.as_ref())
+            .and_then(|requests| requests.get(resource))
+            .and_then(|quantity| parse_quantity(&quantity.0))
+            .unwrap_or(0.0)
+    };
+    pod.spec.as_ref().map_or(0.0, |spec| {
+        let containers: f64 = spec.containers.iter().map(request).sum();
+        let init = spec
+            .init_containers
+            .iter()
+            .flatten()
+            .map(request)
+            .fold(0.0, f64::max);
+        containers.max(init)
+    })
+}
+
+/// `resource` the node has left for new pods: its allocatable less what the pods
+/// already placed on it request
+fn free_on(node: &Node, placed: &[Pod], resource: &str) -> f64 {
+    let name = node.metadata.name.as_deref().unwrap_or_default();
+    let allocatable = node
+        .status
+        .as_ref()
+        .and_then(|status| status.allocatable.as_ref())
+        .and_then(|allocatable| allocatable.get(resource))
+        .and_then(|quantity| parse_quantity(&quantity.0))
+        .unwrap_or(0.0);
+    let used: f64 = placed
+        .iter()
+        .filter(|pod| pod.spec.as_ref().and_then(|spec| spec.node_name.as_deref()) == Some(name))
+        .map(|pod| pod_request(pod, resource))
+        .sum();
+    allocatable - used
+}
+
+fn tolerates(toleration: &Toleration, taint: &Taint) -> bool {
+    let key_matches = match toleration.key.as_deref() {
+        None | Some("") => toleration.operator.as_deref() == Some("Exists"),
+        Some(key) => key == taint.key,
+    };
+    let value_matches = toleration.operator.as_deref() == Some("Exists")
+        || toleration.value.as_deref().unwrap_or_default()
+            == taint.value.as_deref().unwrap_or_default();
+    let effect_matches = toleration
+        .effect
+        .as_deref()
+        .map_or(true, |effect| effect.is_empty() || effect == taint.effect);
+    key_matches && value_matches && effect_matches
+}
+
+fn taint_text(taint: &Taint) -> String {
+    taint.value.as_ref().map_or_else(
+        || format!("{}:{}", taint.key, taint.effect),
+        |value| format!("{}={value}:{}", taint.key, taint.effect),
+    )
+}
+
+fn requirement_matches(requirement: &NodeSelectorRequirement, value: Option<&str>) -> bool {
+    let values = requirement.values.as_deref().unwrap_or_default();
+    let compare = |ordering: Ordering| {
+        let wanted = values.first().and_then(|wanted| wanted.parse::<i64>().ok());
+        let actual = value.and_then(|actual| actual.parse::<i64>().ok());
+        matches!((actual, wanted), (Some(actual), Some(wanted)) if actual.cmp(&wanted) == ordering)
+    };
+    match requirement.operator.as_str() {
+        "In" => value.is_some_and(|value| values.iter().any(|wanted| wanted == value)),
+        "NotIn" => !value.is_some_and(|value| values.iter().any(|wanted| wanted == value)),
+        "Exists" => value.is_some(),
+        "DoesNotExist" => value.is_none(),
+        "Gt" => compare(Ordering::Greater),
+        "Lt" => compare(Ordering::Less),
+        _ => false,
+    }
+}
+
+fn term_matches(term: &NodeSelectorTerm, node: &Node) -> bool {
+    let labels = node.metadata.labels.clone().unwrap_or_default();
+    let expressions = term.match_expressions.iter().flatten().all(|requirement| {
+        requirement_matches(
+            requirement,
+            labels.get(&requirement.key).map(String::as_str),
+        )
+    });
+    let fields = term.match_fields.iter().flatten().all(|requirement| {
+        let value = (requirement.key == "metadata.name")
+            .then_some(node.metadata.name.as_deref())
+            .flatten();
+        requirement_matches(requirement, value)
+    });
+    expressions && fields
+}
+
+/// Why `node` can not take `pod`, empty when it could
+fn node_misses(pod: &Pod, node: &Node, placed: &[Pod]) -> Vec<String> {
+    let mut misses = vec![];
+    let Some(spec) = pod.spec.as_ref() else {
+        return misses;
+    };
+    if node
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.unschedulable)
+        .unwrap_or(false)
+    {
+        misses.push("cordoned".to_string());
+    }
+    let labels = node.metadata.labels.clone().unwrap_or_default();
+    for (key, value) in spec.node_selector.iter().flatten() {
+        if labels.get(key) != Some(value) {
+            misses.push(format!("no label {key}={value} for the node selector"));
+        }
+    }
+    let required = spec
+        .affinity
+        .as_ref()
+        .and_then(|affinity| affinity.node_affinity.as_ref())
+        .and_then(|affinity| {
+            affinity
+                .required_during_scheduling_ignored_during_execution
+                .as_ref()
+        });
+    if let Some(required) = required {
+        if !required
+            .node_selector_terms
+            .iter()
+            .any(|term| term_matches(term, node))
+        {
+            misses.push("required node affinity does not match".to_string());
+        }
+    }
+    let tolerations = spec.tolerations.clone().unwrap_or_default();
+    for taint in node
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.taints.as_ref())
+        .into_iter()
+        .flatten()
+        .filter(|taint| taint.effect == "NoSchedule" || taint.effect == "NoExecute")
+    {
+        if !tolerations
+            .iter()
+            .any(|toleration| tolerates(toleration, taint))
+        {
+            misses.push(format!("taint {} not tolerated", taint_text(taint)));
+        }
+    }
+    for resource in RESOURCES {
+        let wanted = pod_request(pod, resource);
+        if wanted > 0.0 && wanted > free_on(node, placed, resource) {
+            misses.push(format!("insufficient {resource}"));
+        }
+    }
+    misses
+}
+
+fn node_list(names: &[String]) -> String {
+    let shown = names[..names.len().min(NAMED_NODES)].join(", ");
+    match names.len() {
+        1 => format!("1 node ({shown})"),
+        n if n <= NAMED_NODES => format!("{n} nodes ({shown})"),
+        n => format!("{n} nodes ({shown}, ...)"),
+    }
+}
+
+/// Lines explaining why `pod` finds no node among `nodes`, given the pods already
+/// `placed` on them: what it requests, each reason with the nodes it rules out, and
+/// the most of a lacking resource free on a node nothing else rules out
+#[must_use]
+pub fn diagnose(pod: &Pod, nodes: &[Node], placed: &[Pod]) -> Vec<String> {
+    let requests: Vec<String> = RESOURCES
+        .iter()
+        .map(|resource| {
+            let wanted = pod_request(pod, resource);
+            if wanted > 0.0 {
+                format!("{resource} {}", format_amount(resource, wanted))
+            } else {
+                format!("no {resource}")
+            }
+        })
+        .collect();
+    let mut lines = vec![format!("Requests {}", requests.join(", "))];
+    if nodes.is_empty() {
+        lines.push("The cluster lists no nodes".to_string());
+        return lines;
+    }
+
+    let mut reasons: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut fitting = vec![];
+    // nodes only too full, whose free room is worth comparing with the request
+    let mut crowded = vec![];
+    for node in nodes {
+        let name = node.metadata.name.clone().unwrap_or_default();
+        let misses = node_misses(pod, node, placed);
+        if misses.is_empty() {
+            fitting.push(name);
+            continue;
+        }
+        if misses.iter().all(|miss| miss.starts_with("insufficient ")) {
+            crowded.push(node);
+        }
+        for miss in misses {
+            reasons.entry(miss).or_default().push(name.clone());
+        }
+    }
+    let mut by_reach: Vec<(String, Vec<String>)> = reasons.into_iter().collect();
+    by_reach.sort_by_key(|(_, names)| Reverse(names.len()));
+    lines.extend(
+        by_reach
+            .iter()
+            .map(|(reason, names)| format!("{}: {reason}", node_list(names))),
+    );
+
+    for resource in RESOURCES {
+        let wanted = pod_request(pod, resource);
+        let short = format!("insufficient {resource}");
+        if !crowded.is_empty() && by_reach.iter().any(|(reason, _)| *reason == short) {
+            let most = crowded
+                .iter()
+                .map(|node| free_on(node, placed, resource))
+                .fold(0.0, f64::max);
+            lines.push(format!(
+                "Most {resource} free on one node is {} of the {} requested",
+                format_amount(resource, most),
+                format_amount(resource, wanted)
+            ));
+        }
+    }
+    if !fitting.is_empty() {
+        lines.push(format!(
+            "{} could take it now, the scheduler may not have retried yet",
+            node_list(&fitting)
+        ));
+    }
+    lines
+}
+
+/// Why the named pod in the current namespace is not scheduled, from its constraints,
+/// the nodes and what the pods already on them request
+///
+/// # Errors
+///
+/// Will return `Err` if data can not be retrieved from k8s cluster api
+pub async fn explain_pending(name: &str) -> Result<Vec<String>> {
+    let client = shared().await?;
+    let pod = Api::<Pod>::namespaced(Client::clone(&client), &current_namespace())
+        .get(name)
+        .await?;
+    let nodes: Vec<Node> =
+        list_paged(&Api::all(Client::clone(&client)), &ListParams::default()).await?;
+    // only pods on a node hold resources there, finished ones no longer do
+    let placed: Vec<Pod> = list_paged(
+        &Api::all(Client::clone(&client)),
+        &ListParams::default()
+            .fields("spec.nodeName!=,status.phase!=Succeeded,status.phase!=Failed"),
+    )
+    .await?;
+    Ok(diagnose(&pod, &nodes, &placed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diagnose;
+    use k8s_openapi::api::core::v1::{
+        Container, Node, NodeSpec, NodeStatus, Pod, PodSpec, ResourceRequirements, Taint,
+    };
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    fn quantities(pairs: &[(&str, &str)]) -> std::collections::BTreeMap<String, Quantity> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), Quantity((*v).to_string())))
+            .collect()
+    }
+
+    fn node(name: &str, cpu: &str, taints: Option<Vec<Taint>>) -> Node {
+        Node {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                ..ObjectMeta::default()
+            },
+            spec: Some(NodeSpec {
+                taints,
+                ..NodeSpec::default()
+            }),
+            status: Some(NodeStatus {
+                allocatable: Some(quantities(&[("cpu", cpu), ("memory", "4Gi")])),
+                ..NodeStatus::default()
+            }),
+        }
+    }
+
+    fn pod(cpu: &str, node_name: Option<&str>) -> Pod {
+        Pod {
+            spec: Some(PodSpec {
+                node_name: node_name.map(str::to_string),
+                containers: vec![Container {
+                    resources: Some(ResourceRequirements {
+                        requests: Some(quantities(&[("cpu", cpu)])),
+                        ..ResourceRequirements::default()
+                    }),
+                    ..Container::default()
+                }],
+                ..PodSpec::default()
+            }),
+            ..Pod::default()
+        }
+    }
+
+    #[test]
+    fn test_diagnose_names_the_reason_per_node() {
+        let gpu = Taint {
+            key: "gpu".to_string(),
+            effect: "NoSchedule".to_string(),
+            ..Taint::default()
+        };
+        let nodes = vec![
+            node("busy", "2", None),
+            node("small", "500m", None),
+            node("gpu-1", "8", Some(vec![gpu])),
+        ];
+        let placed = vec![pod("1800m", Some("busy"))];
+        let lines = diagnose(&pod("1", None), &nodes, &placed);
+        assert_eq!(
+            lines,
+            vec![
+                "Requests cpu 1.00, no memory".to_string(),
+                "2 nodes (busy, small): insufficient cpu".to_string(),
+                "1 node (gpu-1): taint gpu:NoSchedule not tolerated".to_string(),
+                "Most cpu free on one node is 500m of the 1.00 requested".to_string(),
+            ]
+        );
+    }
+}
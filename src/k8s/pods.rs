@@ -1,29 +1,29 @@
+use crate::config;
 use crate::error::Result;
-use crate::k8s::events::{format_duration, list_events_for_resource, list_k8sevents};
-use crate::k8s::utils::format_label_selector;
-use crate::tui::data::RsPod;
+use crate::k8s::events::{age_of, format_duration, list_events_for_resource, list_k8sevents};
+use crate::k8s::reasons::{memory_limit, oom_killed, state_reason};
+use crate::k8s::utils::{list_paged, record_api_call};
+use crate::labels::{format_label_selector, with_api_selector};
+use crate::tui::data::{PodScheduling, ResourceEvent, RsPod};
 use chrono::{DateTime, Utc};
-use k8s_openapi::api::core::v1::Pod;
-use kube::api::ListParams;
-use kube::api::ObjectList;
-use kube::Api;
+use futures::future::{join_all, ready};
+use futures::{Stream, StreamExt};
+use k8s_openapi::api::core::v1::{
+    Container, NodeSelectorRequirement, NodeSelectorTerm, Pod, PodAffinityTerm, PodSpec,
+    Toleration, WeightedPodAffinityTerm,
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement;
+use kube::api::{DeleteParams, ListParams, Preconditions};
+use kube::runtime::{watcher, WatchStreamExt};
+use kube::{Api, Client};
 use std::collections::BTreeMap;
+use std::time::Instant;
+use tracing::field::Empty;
+use tracing::{instrument, Span};
 
-use super::client::new;
-
-fn calculate_pod_age(pod: &Pod) -> String {
-    pod.metadata.creation_timestamp.as_ref().map_or_else(
-        || "Unk".to_string(),
-        |creation_timestamp| {
-            let ts: DateTime<_> = creation_timestamp.0;
-            let now = Utc::now();
-            let duration = now.signed_duration_since(ts);
-            format_duration(duration)
-        },
-    )
-}
+use super::client::{current_namespace, shared};
 
-fn get_pod_state(pod: &Pod) -> String {
+pub(crate) fn get_pod_state(pod: &Pod) -> String {
     // Check if the pod is marked for deletion
     if pod.metadata.deletion_timestamp.is_some() {
         return "Terminating".to_string();
@@ -55,71 +55,712 @@ fn get_pod_state(pod: &Pod) -> String {
     "Unknown".to_string()
 }
 
+/// Tolerations the admission controller adds to every pod, which say nothing about it
+const DEFAULT_TOLERATIONS: [&str; 2] = [
+    "node.kubernetes.io/not-ready",
+    "node.kubernetes.io/unreachable",
+];
+
+fn format_requirement(key: &str, operator: &str, values: Option<&Vec<String>>) -> String {
+    match values {
+        Some(values) if !values.is_empty() => format!("{key} {operator} ({})", values.join(", ")),
+        _ => format!("{key} {operator}"),
+    }
+}
+
+fn format_node_term(term: &NodeSelectorTerm) -> String {
+    term.match_expressions
+        .iter()
+        .chain(term.match_fields.iter())
+        .flatten()
+        .map(|r: &NodeSelectorRequirement| {
+            format_requirement(&r.key, &r.operator, r.values.as_ref())
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn format_pod_term(term: &PodAffinityTerm) -> String {
+    let mut parts = Vec::new();
+    if let Some(selector) = &term.label_selector {
+        if let Some(labels) = &selector.match_labels {
+            parts.push(format_label_selector(labels));
+        }
+        parts.extend(selector.match_expressions.iter().flatten().map(
+            |r: &LabelSelectorRequirement| {
+                format_requirement(&r.key, &r.operator, r.values.as_ref())
+            },
+        ));
+    }
+    format!("{} per {}", parts.join(", "), term.topology_key)
+}
+
+fn format_toleration(toleration: &Toleration) -> String {
+    let key = toleration.key.as_deref().unwrap_or("*");
+    let mut text = match (toleration.operator.as_deref(), &toleration.value) {
+        (Some("Exists"), _) | (_, None) => key.to_string(),
+        (_, Some(value)) => format!("{key}={value}"),
+    };
+    if let Some(effect) = &toleration.effect {
+        text.push_str(&format!(":{effect}"));
+    }
+    text
+}
+
+/// Rows for pod affinity or anti-affinity, which share a shape but not a type
+fn pod_term_rows(
+    name: &str,
+    required: Option<&Vec<PodAffinityTerm>>,
+    preferred: Option<&Vec<WeightedPodAffinityTerm>>,
+) -> Vec<PodScheduling> {
+    let required = required.into_iter().flatten().map(format_pod_term);
+    let preferred = preferred.into_iter().flatten().map(|term| {
+        format!(
+            "{} (preferred, weight {})",
+            format_pod_term(&term.pod_affinity_term),
+            term.weight
+        )
+    });
+    required
+        .chain(preferred)
+        .map(|value| PodScheduling {
+            name: name.to_string(),
+            value,
+        })
+        .collect()
+}
+
+/// The node selector, affinity rules and tolerations of `spec`, one row per rule
+pub(crate) fn scheduling_constraints(spec: &PodSpec) -> Vec<PodScheduling> {
+    let row = |name: &str, value: String| PodScheduling {
+        name: name.to_string(),
+        value,
+    };
+    let mut rows = Vec::new();
+
+    if let Some(selector) = spec.node_selector.as_ref().filter(|s| !s.is_empty()) {
+        rows.push(row("Node Selector", format_label_selector(selector)));
+    }
+
+    let affinity = spec.affinity.as_ref();
+    if let Some(node) = affinity.and_then(|a| a.node_affinity.as_ref()) {
+        let required = node
+            .required_during_scheduling_ignored_during_execution
+            .iter()
+            .flat_map(|selector| selector.node_selector_terms.iter());
+        rows.extend(required.map(|term| row("Node Affinity", format_node_term(term))));
+        let preferred = node
+            .preferred_during_scheduling_ignored_during_execution
+            .iter()
+            .flatten();
+        rows.extend(preferred.map(|term| {
+            row(
+                "Node Affinity",
+                format!(
+                    "{} (preferred, weight {})",
+                    format_node_term(&term.preference),
+                    term.weight
+                ),
+            )
+        }));
+    }
+
+    if let Some(pod) = affinity.and_then(|a| a.pod_affinity.as_ref()) {
+        rows.extend(pod_term_rows(
+            "Pod Affinity",
+            pod.required_during_scheduling_ignored_during_execution
+                .as_ref(),
+            pod.preferred_during_scheduling_ignored_during_execution
+                .as_ref(),
+        ));
+    }
+    if let Some(anti) = affinity.and_then(|a| a.pod_anti_affinity.as_ref()) {
+        rows.extend(pod_term_rows(
+            "Pod Anti-Affinity",
+            anti.required_during_scheduling_ignored_during_execution
+                .as_ref(),
+            anti.preferred_during_scheduling_ignored_during_execution
+                .as_ref(),
+        ));
+    }
+
+    rows.extend(
+        spec.tolerations
+            .iter()
+            .flatten()
+            .filter(|t| {
+                !(t.toleration_seconds.is_some()
+                    && t.key
+                        .as_deref()
+                        .is_some_and(|key| DEFAULT_TOLERATIONS.contains(&key)))
+            })
+            .map(|t| row("Toleration", format_toleration(t))),
+    );
+
+    rows
+}
+
+/// The QoS class the api server recorded, or the one it would derive from the
+/// containers' requests and limits when the status does not carry it yet
+fn qos_class(pod: &Pod) -> String {
+    pod.status
+        .as_ref()
+        .and_then(|status| status.qos_class.clone())
+        .unwrap_or_else(|| {
+            let containers = pod
+                .spec
+                .as_ref()
+                .map(|spec| spec.containers.as_slice())
+                .unwrap_or_default();
+            derive_qos_class(containers).to_string()
+        })
+}
+
+/// `BestEffort` without any requests or limits, `Guaranteed` when every container
+/// limits cpu and memory and requests exactly that, `Burstable` otherwise. Quantities
+/// are compared as written, so `1` and `1000m` count as different.
+fn derive_qos_class(containers: &[Container]) -> &'static str {
+    let mut constrained = false;
+    let mut guaranteed = !containers.is_empty();
+    for container in containers {
+        let resources = container.resources.as_ref();
+        let requests = resources.and_then(|resources| resources.requests.as_ref());
+        let limits = resources.and_then(|resources| resources.limits.as_ref());
+        constrained |= requests.is_some_and(|requests| !requests.is_empty())
+            || limits.is_some_and(|limits| !limits.is_empty());
+        for resource in ["cpu", "memory"] {
+            let limit = limits.and_then(|limits| limits.get(resource));
+            // a request left out defaults to the limit
+            let request = requests
+                .and_then(|requests| requests.get(resource))
+                .or(limit);
+            if limit.is_none() || request != limit {
+                guaranteed = false;
+            }
+        }
+    }
+    if !constrained {
+        "BestEffort"
+    } else if guaranteed {
+        "Guaranteed"
+    } else {
+        "Burstable"
+    }
+}
+
+/// When the named condition last turned true
+fn condition_since(pod: &Pod, type_: &str) -> Option<DateTime<Utc>> {
+    pod.status
+        .as_ref()
+        .and_then(|status| status.conditions.as_ref())
+        .and_then(|conditions| {
+            conditions
+                .iter()
+                .find(|condition| condition.type_ == type_ && condition.status == "True")
+        })
+        .and_then(|condition| condition.last_transition_time.as_ref())
+        .map(|time| time.0)
+}
+
+/// How long the pod waited for a node and then for its containers to become ready, which
+/// tells scheduling contention from slow image pulls
+fn startup_latency(pod: &Pod) -> String {
+    latency_text(
+        pod.metadata.creation_timestamp.as_ref().map(|time| time.0),
+        condition_since(pod, "PodScheduled"),
+        condition_since(pod, "Ready"),
+    )
+}
+
+/// "scheduled in 2s, ready in 14s", with `-` for a step that has not happened
+fn latency_text(
+    created: Option<DateTime<Utc>>,
+    scheduled: Option<DateTime<Utc>>,
+    ready: Option<DateTime<Utc>>,
+) -> String {
+    let between = |from: Option<DateTime<Utc>>, to: Option<DateTime<Utc>>| match (from, to) {
+        (Some(from), Some(to)) => format_duration(to.signed_duration_since(from)),
+        _ => "-".to_string(),
+    };
+    format!(
+        "scheduled in {}, ready in {}",
+        between(created, scheduled),
+        between(scheduled, ready)
+    )
+}
+
+/// `spec.priority` followed by the priority class that set it, if any
+fn pod_priority(pod: &Pod) -> String {
+    let spec = pod.spec.as_ref();
+    let priority = spec.and_then(|spec| spec.priority).unwrap_or(0);
+    spec.and_then(|spec| spec.priority_class_name.as_deref())
+        .map_or_else(
+            || priority.to_string(),
+            |class| format!("{priority} ({class})"),
+        )
+}
+
 /// # Errors
 ///
 /// Will return `Err` if data can not be retrieved from k8s cluster api
 #[allow(clippy::significant_drop_tightening)]
+#[instrument(skip_all, fields(
+    namespace = Empty,
+    selector = %format_label_selector(&selector),
+    count = Empty,
+    elapsed_ms = Empty,
+))]
 pub async fn list_rspods(selector: BTreeMap<String, String>) -> Result<Vec<RsPod>> {
-    let client = new(None).await?;
+    let started = Instant::now();
+    let client = shared().await?;
+    let namespace = current_namespace();
+    Span::current().record("namespace", namespace.as_str());
 
     // Format the label selector from the BTreeMap
-    let label_selector = format_label_selector(&selector);
+    let label_selector = with_api_selector(&format_label_selector(&selector));
 
     // Apply the label selector in ListParams
     let lp = ListParams::default().labels(&label_selector);
 
-    let pod_list: ObjectList<Pod> = Api::default_namespaced(client.clone()).list(&lp).await?;
+    let pod_list: Vec<Pod> =
+        list_paged(&Api::namespaced(Client::clone(&client), &namespace), &lp).await?;
 
     let mut pod_vec = Vec::new();
+    let annotation = config::current().protection_annotation;
 
     // get all events from the cluster to avoid calls for each pod
-    let events = list_k8sevents(client).await?;
-
-    for pod in pod_list.items {
-        if let Some(owners) = &pod.metadata.owner_references {
-            for owner in owners {
-                let instance_name = &pod
-                    .metadata
-                    .name
-                    .clone()
-                    .unwrap_or_else(|| "unknown".to_string()); // Fixed typo in "unknown"
-
-                // Adjusted actual container count to reflect only ready containers
-                let actual_container_count = pod.status.as_ref().map_or(0, |status| {
-                    status
-                        .container_statuses
-                        .as_ref()
-                        .map_or(0, |container_statuses| {
-                            container_statuses.iter().filter(|cs| cs.ready).count()
-                        })
-                });
-
-                // Desired container count remains the same
-                let desired_container_count =
-                    pod.spec.as_ref().map_or(0, |spec| spec.containers.len());
-                let kind = &owner.kind;
-
-                let age = calculate_pod_age(&pod);
-                let status = get_pod_state(&pod);
-                let selectors = pod.metadata.labels.clone();
-
-                let resource_events =
-                    list_events_for_resource(events.clone(), instance_name).await?;
-
-                let data = RsPod {
-                    name: instance_name.to_string(),
-                    status: status.to_string(),
-                    description: kind.to_string(),
-                    age,
-                    containers: format!("{actual_container_count}/{desired_container_count}"),
-                    selectors,
-                    events: resource_events,
-                };
-
-                pod_vec.push(data);
-            }
-        }
+    let events = list_k8sevents(Client::clone(&client)).await?;
+
+    for pod in pod_list {
+        let instance_name = pod.metadata.name.as_deref().unwrap_or("unknown");
+        let resource_events = list_events_for_resource(events.clone(), instance_name).await?;
+        pod_vec.push(to_rspod(&pod, resource_events, &annotation));
     }
 
+    record_api_call(started, pod_vec.len());
     Ok(pod_vec)
 }
+
+/// `pod` as a row of the pod view, `protected` when it carries `annotation`
+fn to_rspod(pod: &Pod, events: Vec<ResourceEvent>, annotation: &str) -> RsPod {
+    let instance_name = &pod
+        .metadata
+        .name
+        .clone()
+        .unwrap_or_else(|| "unknown".to_string()); // Fixed typo in "unknown"
+
+    // Adjusted actual container count to reflect only ready containers
+    let actual_container_count = pod.status.as_ref().map_or(0, |status| {
+        status
+            .container_statuses
+            .as_ref()
+            .map_or(0, |container_statuses| {
+                container_statuses.iter().filter(|cs| cs.ready).count()
+            })
+    });
+
+    // Desired container count remains the same
+    let desired_container_count = pod.spec.as_ref().map_or(0, |spec| spec.containers.len());
+
+    let age = age_of(&pod.metadata);
+    let status = get_pod_state(pod);
+    let selectors = pod.metadata.labels.clone();
+
+    RsPod {
+        name: instance_name.to_string(),
+        status: status.to_string(),
+        description: owner_description(pod),
+        age,
+        containers: format!("{actual_container_count}/{desired_container_count}"),
+        node: node_name(pod),
+        ip: pod_ip(pod),
+        qos: qos_class(pod),
+        priority: pod_priority(pod),
+        startup: startup_latency(pod),
+        reason: not_ready_reason(pod),
+        oom_killed: oom_kills(pod),
+        created: pod.metadata.creation_timestamp.as_ref().map(|t| t.0),
+        protected: is_protected(pod, annotation),
+        selectors,
+        events,
+        scheduling: pod
+            .spec
+            .as_ref()
+            .map(scheduling_constraints)
+            .unwrap_or_default(),
+    }
+}
+
+/// The named pod each time the api reports a change to it, e.g. a container becoming
+/// ready, as it happens rather than at the next poll. The pods come without events,
+/// those are left to the poll.
+///
+/// # Errors
+///
+/// Will return `Err` if no client for the k8s cluster api can be built
+pub async fn watch_rspod(name: &str) -> Result<impl Stream<Item = RsPod>> {
+    let client = shared().await?;
+    let api: Api<Pod> = Api::namespaced(Client::clone(&client), &current_namespace());
+    let annotation = config::current().protection_annotation;
+    let config = watcher::Config::default().fields(&format!("metadata.name={name}"));
+    Ok(watcher(api, config)
+        .default_backoff()
+        .applied_objects()
+        .filter_map(move |pod| ready(pod.ok().map(|pod| to_rspod(&pod, vec![], &annotation)))))
+}
+
+/// The waiting or terminated reason of the first container that is not ready, init
+/// containers first, e.g. `CrashLoopBackOff`
+fn not_ready_reason(pod: &Pod) -> String {
+    let Some(status) = pod.status.as_ref() else {
+        return String::new();
+    };
+    status
+        .init_container_statuses
+        .iter()
+        .chain(status.container_statuses.iter())
+        .flatten()
+        .filter(|container| !container.ready)
+        .map(|container| state_reason(Some(container)))
+        .find(|reason| !reason.is_empty())
+        .unwrap_or_default()
+}
+
+/// The containers of `pod` killed for running out of memory, each with the limit it hit,
+/// e.g. `api (512Mi)`, empty when none was
+fn oom_kills(pod: &Pod) -> String {
+    let (Some(spec), Some(status)) = (pod.spec.as_ref(), pod.status.as_ref()) else {
+        return String::new();
+    };
+    status
+        .container_statuses
+        .iter()
+        .flatten()
+        .filter(|status| oom_killed(status))
+        .map(|status| {
+            let limit = spec
+                .containers
+                .iter()
+                .find(|container| container.name == status.name)
+                .map_or_else(|| "no memory limit".to_string(), memory_limit);
+            format!("{} ({limit})", status.name)
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The node the pod was scheduled on, `-` while it is still waiting for one
+fn node_name(pod: &Pod) -> String {
+    pod.spec
+        .as_ref()
+        .and_then(|spec| spec.node_name.clone())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// The pod's primary IP, `status.podIP` is the first of `status.podIPs` on dual-stack
+/// clusters, `-` until one is assigned
+fn pod_ip(pod: &Pod) -> String {
+    pod.status
+        .as_ref()
+        .and_then(|status| status.pod_ip.clone())
+        .filter(|ip| !ip.is_empty())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Whether `pod` carries `annotation` set to `true`
+pub(crate) fn is_protected(pod: &Pod, annotation: &str) -> bool {
+    pod.metadata
+        .annotations
+        .as_ref()
+        .and_then(|annotations| annotations.get(annotation))
+        .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"))
+}
+
+/// What created the pod as `kind/name`, e.g. `Job/backup-28712`, or `Pod` for one
+/// created on its own, such as a debug pod started by hand
+fn owner_description(pod: &Pod) -> String {
+    let owners = pod
+        .metadata
+        .owner_references
+        .as_ref()
+        .map(|owners| {
+            owners
+                .iter()
+                .map(|owner| format!("{}/{}", owner.kind, owner.name))
+                .collect::<Vec<String>>()
+        })
+        .unwrap_or_default();
+    if owners.is_empty() {
+        "Pod".to_string()
+    } else {
+        owners.join(",")
+    }
+}
+
+/// Delete the named pods from `namespace` in parallel, returning the ones that could not
+/// be deleted along with the reason. A `dry_run` only asks the api server whether it
+/// would. Each pod is read again first, one protected since the view last listed it is
+/// left alone and reported.
+///
+/// # Errors
+///
+/// Will return `Err` if no client for the k8s cluster api can be built
+pub async fn delete_pods(
+    namespace: &str,
+    names: &[String],
+    dry_run: bool,
+) -> Result<Vec<(String, String)>> {
+    let client = shared().await?;
+    let api: Api<Pod> = Api::namespaced(Client::clone(&client), namespace);
+    let annotation = config::current().protection_annotation;
+
+    let results = join_all(
+        names
+            .iter()
+            .map(|name| delete_unprotected(&api, name, &annotation, dry_run)),
+    )
+    .await;
+
+    Ok(names
+        .iter()
+        .zip(results)
+        .filter_map(|(name, result)| result.err().map(|reason| (name.clone(), reason)))
+        .collect())
+}
+
+/// Delete `name` unless it carries the protection `annotation` now. The delete is bound
+/// to the version read, so a pod changed in between is refused by the api server.
+async fn delete_unprotected(
+    api: &Api<Pod>,
+    name: &str,
+    annotation: &str,
+    dry_run: bool,
+) -> std::result::Result<(), String> {
+    let pod = api.get(name).await.map_err(|e| e.to_string())?;
+    if let Some(reason) = protection_refusal(&pod, annotation) {
+        return Err(reason);
+    }
+    let params = DeleteParams {
+        dry_run,
+        preconditions: Some(Preconditions {
+            resource_version: pod.metadata.resource_version,
+            uid: pod.metadata.uid,
+        }),
+        ..DeleteParams::default()
+    };
+    api.delete(name, &params)
+        .await
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// Why `pod` is not deleted, `None` unless it carries `annotation` set to `true`
+fn protection_refusal(pod: &Pod, annotation: &str) -> Option<String> {
+    is_protected(pod, annotation).then(|| format!("protected by {annotation}=true"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        derive_qos_class, is_protected, latency_text, owner_description, protection_refusal,
+        scheduling_constraints,
+    };
+    use chrono::{TimeZone, Utc};
+    use k8s_openapi::api::core::v1::{
+        Affinity, Container, NodeAffinity, NodeSelector, NodeSelectorRequirement, NodeSelectorTerm,
+        PodSpec, ResourceRequirements, Toleration,
+    };
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_scheduling_constraints_skips_default_tolerations() {
+        let spec = PodSpec {
+            node_selector: Some(BTreeMap::from([(
+                "disktype".to_string(),
+                "ssd".to_string(),
+            )])),
+            affinity: Some(Affinity {
+                node_affinity: Some(NodeAffinity {
+                    required_during_scheduling_ignored_during_execution: Some(NodeSelector {
+                        node_selector_terms: vec![NodeSelectorTerm {
+                            match_expressions: Some(vec![NodeSelectorRequirement {
+                                key: "zone".to_string(),
+                                operator: "In".to_string(),
+                                values: Some(vec!["a".to_string(), "b".to_string()]),
+                            }]),
+                            match_fields: None,
+                        }],
+                    }),
+                    preferred_during_scheduling_ignored_during_execution: None,
+                }),
+                ..Affinity::default()
+            }),
+            tolerations: Some(vec![
+                Toleration {
+                    key: Some("dedicated".to_string()),
+                    operator: Some("Equal".to_string()),
+                    value: Some("gpu".to_string()),
+                    effect: Some("NoSchedule".to_string()),
+                    toleration_seconds: None,
+                },
+                Toleration {
+                    key: Some("node.kubernetes.io/not-ready".to_string()),
+                    operator: Some("Exists".to_string()),
+                    value: None,
+                    effect: Some("NoExecute".to_string()),
+                    toleration_seconds: Some(300),
+                },
+            ]),
+            ..PodSpec::default()
+        };
+
+        let rows = scheduling_constraints(&spec)
+            .into_iter()
+            .map(|row| (row.name, row.value))
+            .collect::<Vec<(String, String)>>();
+        assert_eq!(
+            rows,
+            vec![
+                ("Node Selector".to_string(), "disktype=ssd".to_string()),
+                ("Node Affinity".to_string(), "zone In (a, b)".to_string()),
+                (
+                    "Toleration".to_string(),
+                    "dedicated=gpu:NoSchedule".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_derive_qos_class() {
+        let container = |requests: &[(&str, &str)], limits: &[(&str, &str)]| {
+            let quantities = |pairs: &[(&str, &str)]| {
+                pairs
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), Quantity(value.to_string())))
+                    .collect::<BTreeMap<String, Quantity>>()
+            };
+            Container {
+                resources: Some(ResourceRequirements {
+                    requests: Some(quantities(requests)),
+                    limits: Some(quantities(limits)),
+                    ..ResourceRequirements::default()
+                }),
+                ..Container::default()
+            }
+        };
+        assert_eq!(derive_qos_class(&[container(&[], &[])]), "BestEffort");
+        assert_eq!(
+            derive_qos_class(&[container(&[("memory", "64Mi")], &[])]),
+            "Burstable"
+        );
+        assert_eq!(
+            derive_qos_class(&[container(&[], &[("cpu", "1"), ("memory", "64Mi")])]),
+            "Guaranteed"
+        );
+        assert_eq!(
+            derive_qos_class(&[container(
+                &[("cpu", "500m"), ("memory", "64Mi")],
+                &[("cpu", "1"), ("memory", "64Mi")]
+            )]),
+            "Burstable"
+        );
+    }
+
+    #[test]
+    fn test_latency_text_marks_missing_steps() {
+        let at = |seconds| Utc.timestamp_opt(seconds, 0).single();
+        assert_eq!(
+            latency_text(at(0), at(2), at(16)),
+            "scheduled in 2s, ready in 14s"
+        );
+        assert_eq!(
+            latency_text(at(0), at(2), None),
+            "scheduled in 2s, ready in -"
+        );
+        assert_eq!(
+            latency_text(at(0), None, None),
+            "scheduled in -, ready in -"
+        );
+    }
+
+    #[test]
+    fn test_owner_description_names_the_owner() {
+        use k8s_openapi::api::core::v1::Pod;
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+        use kube::api::ObjectMeta;
+
+        assert_eq!(owner_description(&Pod::default()), "Pod");
+        let owned = Pod {
+            metadata: ObjectMeta {
+                owner_references: Some(vec![OwnerReference {
+                    kind: "Job".to_string(),
+                    name: "backup-28712".to_string(),
+                    ..OwnerReference::default()
+                }]),
+                ..ObjectMeta::default()
+            },
+            ..Pod::default()
+        };
+        assert_eq!(owner_description(&owned), "Job/backup-28712");
+    }
+
+    #[test]
+    fn test_is_protected_needs_the_annotation_set_to_true() {
+        use k8s_openapi::api::core::v1::Pod;
+        use kube::api::ObjectMeta;
+
+        let annotated = |value: &str| Pod {
+            metadata: ObjectMeta {
+                annotations: Some(BTreeMap::from([(
+                    "k8p.navicore.tech/protected".to_string(),
+                    value.to_string(),
+                )])),
+                ..ObjectMeta::default()
+            },
+            ..Pod::default()
+        };
+        assert!(is_protected(
+            &annotated("True"),
+            "k8p.navicore.tech/protected"
+        ));
+        assert!(!is_protected(
+            &annotated("false"),
+            "k8p.navicore.tech/protected"
+        ));
+        assert!(!is_protected(&annotated("true"), "example.com/keep"));
+        assert!(!is_protected(
+            &Pod::default(),
+            "k8p.navicore.tech/protected"
+        ));
+    }
+
+    #[test]
+    fn test_protection_refusal_reports_a_pod_protected_since_it_was_listed() {
+        use k8s_openapi::api::core::v1::Pod;
+        use kube::api::ObjectMeta;
+
+        // the view listed the pod bare, a label and annotate with `+` protected it since
+        let listed = Pod::default();
+        let fresh = Pod {
+            metadata: ObjectMeta {
+                annotations: Some(BTreeMap::from([(
+                    "k8p.navicore.tech/protected".to_string(),
+                    "true".to_string(),
+                )])),
+                ..ObjectMeta::default()
+            },
+            ..Pod::default()
+        };
+        assert_eq!(
+            protection_refusal(&listed, "k8p.navicore.tech/protected"),
+            None
+        );
+        assert_eq!(
+            protection_refusal(&fresh, "k8p.navicore.tech/protected"),
+            Some("protected by k8p.navicore.tech/protected=true".to_string())
+        );
+    }
+}
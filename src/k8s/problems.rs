@@ -0,0 +1,137 @@
+use crate::error::Result;
+use crate::k8s::events::{age_of, list_all};
+use crate::k8s::pods::get_pod_state;
+use crate::k8s::rs::list_replicas;
+use crate::tui::data::Problem;
+use k8s_openapi::api::core::v1::Pod;
+use kube::api::{ListParams, ObjectList};
+use kube::{Api, Client};
+
+use super::client::{current_namespace, shared};
+
+/// Ranks of the kinds of problem, most urgent first
+const POD_RANK: u8 = 0;
+const RS_RANK: u8 = 1;
+const RESTART_RANK: u8 = 2;
+const EVENT_RANK: u8 = 3;
+
+/// true for a "ready/desired" count with fewer ready than desired
+fn under_replicated(pods: &str) -> bool {
+    pods.split_once('/')
+        .and_then(|(ready, desired)| {
+            Some((ready.parse::<i32>().ok()?, desired.parse::<i32>().ok()?))
+        })
+        .is_some_and(|(ready, desired)| ready < desired)
+}
+
+/// (container, restart count) for every container of `pod` that has restarted
+fn restarted_containers(pod: &Pod) -> Vec<(String, i32)> {
+    pod.status
+        .as_ref()
+        .and_then(|status| status.container_statuses.as_ref())
+        .map(|statuses| {
+            statuses
+                .iter()
+                .filter(|status| status.restart_count > 0)
+                .map(|status| (status.name.clone(), status.restart_count))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn pod_problems(pod: &Pod) -> Vec<Problem> {
+    let name = pod.metadata.name.clone().unwrap_or_default();
+    let age = age_of(&pod.metadata);
+    let mut problems = Vec::new();
+
+    let state = get_pod_state(pod);
+    if state != "Running" && state != "Succeeded" {
+        problems.push(Problem {
+            rank: POD_RANK,
+            kind: "Pod".to_string(),
+            name: name.clone(),
+            problem: state,
+            age: age.clone(),
+            selectors: pod.metadata.labels.clone(),
+            pod: Some(name.clone()),
+        });
+    }
+
+    for (container, restarts) in restarted_containers(pod) {
+        problems.push(Problem {
+            rank: RESTART_RANK,
+            kind: "Container".to_string(),
+            name: format!("{name}/{container}"),
+            problem: format!("{restarts} restart(s)"),
+            age: age.clone(),
+            selectors: pod.metadata.labels.clone(),
+            pod: Some(name.clone()),
+        });
+    }
+
+    problems
+}
+
+/// Everything unhealthy in the current namespace, most urgent first: pods that are not
+/// running, ReplicaSets short of ready pods, containers that restarted and Warning events
+///
+/// # Errors
+///
+/// Will return `Err` if data can not be retrieved from k8s cluster api
+pub async fn list_problems() -> Result<Vec<Problem>> {
+    let client = shared().await?;
+
+    let pod_list: ObjectList<Pod> = Api::namespaced(Client::clone(&client), &current_namespace())
+        .list(&ListParams::default())
+        .await?;
+    let mut problems: Vec<Problem> = pod_list.items.iter().flat_map(pod_problems).collect();
+
+    problems.extend(
+        list_replicas()
+            .await?
+            .into_iter()
+            .filter(|rs| under_replicated(&rs.pods))
+            .map(|rs| Problem {
+                rank: RS_RANK,
+                kind: "ReplicaSet".to_string(),
+                problem: format!("{} ready", rs.pods),
+                name: rs.name,
+                age: rs.age,
+                selectors: rs.selectors,
+                pod: None,
+            }),
+    );
+
+    problems.extend(
+        list_all(None)
+            .await?
+            .into_iter()
+            .filter(|event| event.type_ == "Warning")
+            .map(|event| Problem {
+                rank: EVENT_RANK,
+                kind: "Event".to_string(),
+                name: event.object,
+                problem: format!("{}: {}", event.reason, event.message),
+                age: event.age,
+                selectors: None,
+                pod: None,
+            }),
+    );
+
+    // the event list is newest first already, so a stable sort keeps that order
+    problems.sort_by_key(|problem| problem.rank);
+
+    Ok(problems)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::under_replicated;
+
+    #[test]
+    fn test_under_replicated() {
+        assert!(under_replicated("1/3"));
+        assert!(!under_replicated("3/3"));
+        assert!(!under_replicated("unknown"));
+    }
+}
@@ -0,0 +1,148 @@
+use crate::error::Result;
+use crate::k8s::events::age_of;
+use crate::tui::data::Pvc as PvcData;
+use k8s_openapi::api::core::v1::{PersistentVolumeClaim, Pod};
+use kube::api::{ListParams, ObjectList};
+use kube::{Api, Client};
+
+use super::client::{current_namespace, shared};
+
+/// the provisioned size once bound, otherwise the size asked for
+fn capacity(pvc: &PersistentVolumeClaim) -> String {
+    pvc.status
+        .as_ref()
+        .and_then(|status| status.capacity.as_ref())
+        .or_else(|| {
+            pvc.spec
+                .as_ref()
+                .and_then(|spec| spec.resources.as_ref())
+                .and_then(|resources| resources.requests.as_ref())
+        })
+        .and_then(|sizes| sizes.get("storage"))
+        .map_or_else(String::new, |quantity| quantity.0.clone())
+}
+
+/// Names of the claims `pod` mounts, including the ones generated for its ephemeral volumes
+pub(crate) fn pod_claim_names(pod: &Pod) -> Vec<String> {
+    let pod_name = pod.metadata.name.clone().unwrap_or_default();
+    pod.spec
+        .as_ref()
+        .and_then(|spec| spec.volumes.as_ref())
+        .map(|volumes| {
+            volumes
+                .iter()
+                .filter_map(|volume| {
+                    if let Some(claim) = &volume.persistent_volume_claim {
+                        Some(claim.claim_name.clone())
+                    } else {
+                        // the controller names the claim of an ephemeral volume <pod>-<volume>
+                        volume
+                            .ephemeral
+                            .as_ref()
+                            .map(|_| format!("{pod_name}-{}", volume.name))
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn to_pvc_data(pvc: &PersistentVolumeClaim) -> PvcData {
+    let spec = pvc.spec.as_ref();
+    PvcData {
+        name: pvc.metadata.name.clone().unwrap_or_default(),
+        status: pvc
+            .status
+            .as_ref()
+            .and_then(|status| status.phase.clone())
+            .unwrap_or_default(),
+        capacity: capacity(pvc),
+        storage_class: spec
+            .and_then(|spec| spec.storage_class_name.clone())
+            .unwrap_or_default(),
+        age: age_of(&pvc.metadata),
+        volume: spec
+            .and_then(|spec| spec.volume_name.clone())
+            .unwrap_or_default(),
+        access_modes: spec
+            .and_then(|spec| spec.access_modes.as_ref())
+            .map(|modes| modes.join(", "))
+            .unwrap_or_default(),
+    }
+}
+
+/// The claims in the current namespace, or only those mounted by `pod` when one is named
+///
+/// # Errors
+///
+/// Will return `Err` if data can not be retrieved from k8s cluster api
+pub async fn list_pvcs(pod: Option<&str>) -> Result<Vec<PvcData>> {
+    let client = shared().await?;
+    let namespace = current_namespace();
+
+    let claims = match pod {
+        Some(name) => {
+            let pod = Api::<Pod>::namespaced(Client::clone(&client), &namespace)
+                .get(name)
+                .await?;
+            Some(pod_claim_names(&pod))
+        }
+        None => None,
+    };
+
+    let pvc_list: ObjectList<PersistentVolumeClaim> =
+        Api::namespaced(Client::clone(&client), &namespace)
+            .list(&ListParams::default())
+            .await?;
+
+    let mut pvcs: Vec<PvcData> = pvc_list.items.iter().map(to_pvc_data).collect();
+    if let Some(claims) = claims {
+        pvcs.retain(|pvc| claims.contains(&pvc.name));
+    }
+
+    Ok(pvcs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pod_claim_names;
+    use k8s_openapi::api::core::v1::{
+        EphemeralVolumeSource, PersistentVolumeClaimVolumeSource, Pod, PodSpec, Volume,
+    };
+    use kube::api::ObjectMeta;
+
+    #[test]
+    fn test_pod_claim_names_includes_ephemeral_claims() {
+        let pod = Pod {
+            metadata: ObjectMeta {
+                name: Some("db-0".to_string()),
+                ..ObjectMeta::default()
+            },
+            spec: Some(PodSpec {
+                volumes: Some(vec![
+                    Volume {
+                        name: "data".to_string(),
+                        persistent_volume_claim: Some(PersistentVolumeClaimVolumeSource {
+                            claim_name: "data-db-0".to_string(),
+                            read_only: None,
+                        }),
+                        ..Volume::default()
+                    },
+                    Volume {
+                        name: "scratch".to_string(),
+                        ephemeral: Some(EphemeralVolumeSource::default()),
+                        ..Volume::default()
+                    },
+                    Volume {
+                        name: "config".to_string(),
+                        ..Volume::default()
+                    },
+                ]),
+                ..PodSpec::default()
+            }),
+            ..Pod::default()
+        };
+
+        assert_eq!(pod_claim_names(&pod), vec!["data-db-0", "db-0-scratch"]);
+    }
+}
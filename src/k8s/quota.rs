@@ -0,0 +1,164 @@
+use crate::error::Result;
+use crate::tui::data::ResourceUsage;
+use k8s_openapi::api::core::v1::{Pod, ResourceQuota};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use kube::api::ListParams;
+use kube::{Api, Client};
+use std::collections::BTreeMap;
+
+use super::client::shared;
+
+/// binary suffixes first, so `Mi` is not read as `M` followed by junk
+const SUFFIXES: [(&str, f64); 15] = [
+    ("Ki", 1024.0),
+    ("Mi", 1_048_576.0),
+    ("Gi", 1_073_741_824.0),
+    ("Ti", 1_099_511_627_776.0),
+    ("Pi", 1_125_899_906_842_624.0),
+    ("Ei", 1_152_921_504_606_846_976.0),
+    ("n", 1e-9),
+    ("u", 1e-6),
+    ("m", 1e-3),
+    ("k", 1e3),
+    ("M", 1e6),
+    ("G", 1e9),
+    ("T", 1e12),
+    ("P", 1e15),
+    ("E", 1e18),
+];
+
+/// The rows of the summary: what the pods ask for, and the quota keys that cap it. A
+/// quota on plain `cpu` or `memory` caps the requests.
+const RESOURCES: [(&str, &str, &[&str]); 4] = [
+    ("cpu", "requests", &["requests.cpu", "cpu"]),
+    ("cpu", "limits", &["limits.cpu"]),
+    ("memory", "requests", &["requests.memory", "memory"]),
+    ("memory", "limits", &["limits.memory"]),
+];
+
+/// A quantity such as `250m`, `1.5`, `512Mi` or `1e3` in cores or bytes
+pub(crate) fn parse_quantity(text: &str) -> Option<f64> {
+    let text = text.trim();
+    let (number, scale) = SUFFIXES
+        .iter()
+        .find_map(|(suffix, scale)| text.strip_suffix(suffix).map(|number| (number, *scale)))
+        .unwrap_or((text, 1.0));
+    number.parse::<f64>().ok().map(|number| number * scale)
+}
+
+/// `amount` in the unit kubectl would show, e.g. `250m` cores or `1.5Gi`
+pub(crate) fn format_amount(resource: &str, amount: f64) -> String {
+    if resource == "cpu" {
+        if amount < 1.0 {
+            format!("{:.0}m", amount * 1000.0)
+        } else {
+            format!("{amount:.2}")
+        }
+    } else {
+        [("Gi", 1_073_741_824.0), ("Mi", 1_048_576.0), ("Ki", 1024.0)]
+            .iter()
+            .find(|(_, scale)| amount >= *scale)
+            .map_or_else(
+                || format!("{amount:.0}"),
+                |(suffix, scale)| format!("{:.1}{suffix}", amount / scale),
+            )
+    }
+}
+
+/// `requests` or `limits` of `resource` summed over the containers of `pods`. Finished
+/// pods are left out, they no longer count against a quota.
+fn sum_pods(pods: &[Pod], kind: &str, resource: &str) -> f64 {
+    pods.iter()
+        .filter(|pod| {
+            !pod.status
+                .as_ref()
+                .and_then(|status| status.phase.as_deref())
+                .is_some_and(|phase| phase == "Succeeded" || phase == "Failed")
+        })
+        .filter_map(|pod| pod.spec.as_ref())
+        .flat_map(|spec| spec.containers.iter())
+        .filter_map(|container| container.resources.as_ref())
+        .filter_map(|resources| match kind {
+            "requests" => resources.requests.as_ref(),
+            _ => resources.limits.as_ref(),
+        })
+        .filter_map(|amounts| amounts.get(resource))
+        .filter_map(|quantity| parse_quantity(&quantity.0))
+        .sum()
+}
+
+/// The tightest cap any of `quotas` puts on one of `keys`
+fn hard_limit(quotas: &[BTreeMap<String, Quantity>], keys: &[&str]) -> Option<f64> {
+    quotas
+        .iter()
+        .flat_map(|hard| keys.iter().filter_map(|key| hard.get(*key)))
+        .filter_map(|quantity| parse_quantity(&quantity.0))
+        .min_by(f64::total_cmp)
+}
+
+fn usage_rows(pods: &[Pod], quotas: &[BTreeMap<String, Quantity>]) -> Vec<ResourceUsage> {
+    RESOURCES
+        .iter()
+        .map(|(resource, kind, keys)| {
+            let used = sum_pods(pods, kind, resource);
+            let hard = hard_limit(quotas, keys);
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let percent = hard
+                .filter(|hard| *hard > 0.0)
+                .map(|hard| (used / hard * 100.0).round() as u64);
+            ResourceUsage {
+                name: format!("{kind}.{resource}"),
+                used: format_amount(resource, used),
+                hard: hard.map(|hard| format_amount(resource, hard)),
+                percent,
+            }
+        })
+        .collect()
+}
+
+/// The cpu and memory requests and limits of the pods in `namespace`, next to what its
+/// ResourceQuotas allow
+///
+/// # Errors
+///
+/// Will return `Err` if the pods or quotas can not be retrieved from k8s cluster api
+pub async fn namespace_usage(namespace: &str) -> Result<Vec<ResourceUsage>> {
+    let client = shared().await?;
+    let lp = ListParams::default();
+
+    let pods = Api::<Pod>::namespaced(Client::clone(&client), namespace)
+        .list(&lp)
+        .await?
+        .items;
+    let quotas: Vec<BTreeMap<String, Quantity>> =
+        Api::<ResourceQuota>::namespaced(Client::clone(&client), namespace)
+            .list(&lp)
+            .await?
+            .items
+            .into_iter()
+            .filter_map(|quota| quota.spec.and_then(|spec| spec.hard))
+            .collect();
+
+    Ok(usage_rows(&pods, &quotas))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_amount, parse_quantity};
+
+    fn parsed(text: &str) -> f64 {
+        parse_quantity(text).unwrap_or(f64::NAN)
+    }
+
+    #[test]
+    fn test_parse_quantity_units() {
+        assert!((parsed("250m") - 0.25).abs() < 1e-9);
+        assert!((parsed("2") - 2.0).abs() < 1e-9);
+        assert!((parsed("512Mi") - 536_870_912.0).abs() < 1e-3);
+        assert!((parsed("1e3") - 1000.0).abs() < 1e-9);
+        assert!((parsed("1G") - 1e9).abs() < 1e-3);
+        assert!(parse_quantity("lots").is_none());
+        assert_eq!(format_amount("cpu", 0.25), "250m");
+        assert_eq!(format_amount("memory", 536_870_912.0), "512.0Mi");
+    }
+}
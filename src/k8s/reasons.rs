@@ -0,0 +1,144 @@
+//! Plain-English hints for the reasons the kubelet gives when a container is waiting
+//! or has terminated, shown next to the reason in the detail panes.
+use k8s_openapi::api::core::v1::{Container, ContainerStatus};
+
+const HINTS: [(&str, &str); 14] = [
+    (
+        "ImagePullBackOff",
+        "Kubernetes can't pull the image; check the image name and registry credentials",
+    ),
+    (
+        "ErrImagePull",
+        "pulling the image failed; check the image name, tag and registry credentials",
+    ),
+    (
+        "InvalidImageName",
+        "the image reference is malformed; check the image field for typos",
+    ),
+    (
+        "CrashLoopBackOff",
+        "the container keeps exiting and is restarted with growing delays; check its logs",
+    ),
+    (
+        "CreateContainerConfigError",
+        "a ConfigMap, Secret or key the container refers to is missing",
+    ),
+    (
+        "CreateContainerError",
+        "the runtime could not create the container; check the command and mounts",
+    ),
+    (
+        "RunContainerError",
+        "the container could not be started; check the command, entrypoint and mounts",
+    ),
+    (
+        "ContainerCreating",
+        "the image is being pulled and volumes mounted, usually brief",
+    ),
+    (
+        "PodInitializing",
+        "init containers are still running, this container starts after them",
+    ),
+    (
+        "OOMKilled",
+        "the container used more memory than its limit and was killed",
+    ),
+    (
+        "Error",
+        "the container exited with a non-zero code; check its logs",
+    ),
+    ("Completed", "the container ran to completion and exited 0"),
+    (
+        "ContainerCannotRun",
+        "the runtime refused to run the container; check the command and image platform",
+    ),
+    (
+        "DeadlineExceeded",
+        "the container ran past its activeDeadlineSeconds and was stopped",
+    ),
+];
+
+/// A one-line hint for `reason`, or the reason itself when there is none for it
+#[must_use]
+pub fn explain_reason(reason: &str) -> String {
+    HINTS
+        .iter()
+        .find(|(known, _)| *known == reason)
+        .map_or_else(|| reason.to_string(), |(_, hint)| (*hint).to_string())
+}
+
+/// Why the container is not running: its waiting reason, or the reason it terminated,
+/// empty while it runs
+#[must_use]
+pub fn state_reason(status: Option<&ContainerStatus>) -> String {
+    let Some(state) = status.and_then(|status| status.state.as_ref()) else {
+        return String::new();
+    };
+    state
+        .waiting
+        .as_ref()
+        .and_then(|waiting| waiting.reason.clone())
+        .or_else(|| {
+            state
+                .terminated
+                .as_ref()
+                .and_then(|terminated| terminated.reason.clone())
+        })
+        .unwrap_or_default()
+}
+
+/// Whether the container was killed for running out of memory, in its current state or
+/// the termination before its current run
+#[must_use]
+pub fn oom_killed(status: &ContainerStatus) -> bool {
+    [status.state.as_ref(), status.last_state.as_ref()]
+        .into_iter()
+        .flatten()
+        .filter_map(|state| state.terminated.as_ref())
+        .any(|terminated| terminated.reason.as_deref() == Some("OOMKilled"))
+}
+
+/// The memory limit of `container` as its spec writes it, e.g. `512Mi`
+#[must_use]
+pub fn memory_limit(container: &Container) -> String {
+    container
+        .resources
+        .as_ref()
+        .and_then(|resources| resources.limits.as_ref())
+        .and_then(|limits| limits.get("memory"))
+        .map_or_else(|| "no memory limit".to_string(), |limit| limit.0.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{explain_reason, oom_killed};
+    use k8s_openapi::api::core::v1::{ContainerState, ContainerStateTerminated, ContainerStatus};
+
+    #[test]
+    fn test_explain_reason_falls_back_to_the_reason() {
+        assert!(explain_reason("ImagePullBackOff").contains("can't pull the image"));
+        assert_eq!(explain_reason("SomethingNew"), "SomethingNew");
+    }
+
+    #[test]
+    fn test_oom_killed_looks_at_the_last_termination() {
+        let terminated = |reason: &str| ContainerState {
+            terminated: Some(ContainerStateTerminated {
+                reason: Some(reason.to_string()),
+                exit_code: 137,
+                ..ContainerStateTerminated::default()
+            }),
+            ..ContainerState::default()
+        };
+        let restarted = ContainerStatus {
+            last_state: Some(terminated("OOMKilled")),
+            ..ContainerStatus::default()
+        };
+        let errored = ContainerStatus {
+            last_state: Some(terminated("Error")),
+            ..ContainerStatus::default()
+        };
+        assert!(oom_killed(&restarted));
+        assert!(!oom_killed(&errored));
+    }
+}
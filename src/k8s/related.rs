@@ -0,0 +1,226 @@
+use crate::error::Result;
+use crate::k8s::pvcs::pod_claim_names;
+use crate::tui::data::{FinderEntry, FinderTarget};
+use k8s_openapi::api::core::v1::{Pod, Service, Volume};
+use kube::api::{ListParams, ObjectList};
+use kube::{Api, Client};
+use std::collections::BTreeSet;
+
+use super::client::{current_namespace, shared};
+
+fn entry(kind: &str, name: String, target: FinderTarget) -> FinderEntry {
+    FinderEntry {
+        kind: kind.to_string(),
+        name,
+        target,
+    }
+}
+
+/// ConfigMaps and Secrets a volume mounts, directly or projected, as `(is_secret, name)`
+fn volume_sources(volume: &Volume) -> Vec<(bool, String)> {
+    let mut sources = vec![];
+    if let Some(config_map) = &volume.config_map {
+        sources.push((false, config_map.name.clone()));
+    }
+    if let Some(secret) = volume.secret.as_ref().and_then(|s| s.secret_name.clone()) {
+        sources.push((true, secret));
+    }
+    let projected = volume
+        .projected
+        .iter()
+        .flat_map(|projected| projected.sources.iter().flatten());
+    for source in projected {
+        if let Some(config_map) = &source.config_map {
+            sources.push((false, config_map.name.clone()));
+        }
+        if let Some(secret) = &source.secret {
+            sources.push((true, secret.name.clone()));
+        }
+    }
+    sources
+}
+
+/// Services whose selector picks `pod`, services without a selector pick nothing
+fn selecting_services<'a>(pod: &Pod, services: &'a [Service]) -> Vec<&'a Service> {
+    let labels = pod.metadata.labels.clone().unwrap_or_default();
+    services
+        .iter()
+        .filter(|service| {
+            service
+                .spec
+                .as_ref()
+                .and_then(|spec| spec.selector.as_ref())
+                .is_some_and(|selector| {
+                    !selector.is_empty()
+                        && selector
+                            .iter()
+                            .all(|(key, value)| labels.get(key) == Some(value))
+                })
+        })
+        .collect()
+}
+
+/// What `pod` points at or is pointed at by: its owner, the services selecting it, the
+/// ConfigMaps, Secrets and claims it mounts and its service account
+fn pod_relations(pod: &Pod, services: &[Service]) -> Vec<FinderEntry> {
+    let mut entries: Vec<FinderEntry> = pod
+        .metadata
+        .owner_references
+        .iter()
+        .flatten()
+        .map(|owner| {
+            let target = match owner.kind.as_str() {
+                "ReplicaSet" => FinderTarget::ReplicaSet(owner.name.clone()),
+                "Job" => FinderTarget::Job(owner.name.clone()),
+                _ => FinderTarget::NoView,
+            };
+            entry(&owner.kind, owner.name.clone(), target)
+        })
+        .collect();
+
+    entries.extend(
+        selecting_services(pod, services)
+            .into_iter()
+            .filter_map(|service| {
+                let name = service.metadata.name.clone()?;
+                Some(entry("Service", name.clone(), FinderTarget::Service(name)))
+            }),
+    );
+
+    // a volume set listing the same ConfigMap twice is still one relation
+    let mounted: BTreeSet<(bool, String)> = pod
+        .spec
+        .iter()
+        .flat_map(|spec| spec.volumes.iter().flatten())
+        .flat_map(volume_sources)
+        .collect();
+    entries.extend(mounted.into_iter().map(|(secret, name)| {
+        if secret {
+            entry("Secret", name.clone(), FinderTarget::Secret(name))
+        } else {
+            entry("ConfigMap", name.clone(), FinderTarget::ConfigMap(name))
+        }
+    }));
+
+    let pod_name = pod.metadata.name.clone().unwrap_or_default();
+    entries.extend(
+        pod_claim_names(pod)
+            .into_iter()
+            .map(|claim| entry("PVC", claim, FinderTarget::Claims(pod_name.clone()))),
+    );
+
+    let account = pod
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.service_account_name.clone())
+        .unwrap_or_else(|| "default".to_string());
+    entries.push(entry("ServiceAccount", account, FinderTarget::NoView));
+    entries
+}
+
+/// The resources related to the named pod in the current namespace. The relations come
+/// from the pod itself, only the services selecting it need a list call.
+///
+/// # Errors
+///
+/// Will return `Err` if data can not be retrieved from k8s cluster api
+pub async fn related_resources(name: &str) -> Result<Vec<FinderEntry>> {
+    let client = shared().await?;
+    let namespace = current_namespace();
+    let pod = Api::<Pod>::namespaced(Client::clone(&client), &namespace)
+        .get(name)
+        .await?;
+    let services: ObjectList<Service> = Api::namespaced(Client::clone(&client), &namespace)
+        .list(&ListParams::default())
+        .await?;
+    Ok(pod_relations(&pod, &services.items))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pod_relations;
+    use k8s_openapi::api::core::v1::{
+        ConfigMapVolumeSource, Pod, PodSpec, SecretVolumeSource, Service, ServiceSpec, Volume,
+    };
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
+    use std::collections::BTreeMap;
+
+    fn labels(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    fn service(name: &str, selector: &[(&str, &str)]) -> Service {
+        Service {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                ..ObjectMeta::default()
+            },
+            spec: Some(ServiceSpec {
+                selector: Some(labels(selector)),
+                ..ServiceSpec::default()
+            }),
+            ..Service::default()
+        }
+    }
+
+    #[test]
+    fn test_pod_relations_lists_owner_services_mounts_and_account() {
+        let pod = Pod {
+            metadata: ObjectMeta {
+                name: Some("api-7d9f-x2k4p".to_string()),
+                labels: Some(labels(&[("app", "api"), ("tier", "web")])),
+                owner_references: Some(vec![OwnerReference {
+                    kind: "ReplicaSet".to_string(),
+                    name: "api-7d9f".to_string(),
+                    ..OwnerReference::default()
+                }]),
+                ..ObjectMeta::default()
+            },
+            spec: Some(PodSpec {
+                service_account_name: Some("api".to_string()),
+                volumes: Some(vec![
+                    Volume {
+                        name: "settings".to_string(),
+                        config_map: Some(ConfigMapVolumeSource {
+                            name: "api-settings".to_string(),
+                            ..ConfigMapVolumeSource::default()
+                        }),
+                        ..Volume::default()
+                    },
+                    Volume {
+                        name: "tls".to_string(),
+                        secret: Some(SecretVolumeSource {
+                            secret_name: Some("api-tls".to_string()),
+                            ..SecretVolumeSource::default()
+                        }),
+                        ..Volume::default()
+                    },
+                ]),
+                ..PodSpec::default()
+            }),
+            ..Pod::default()
+        };
+        let services = [
+            service("api", &[("app", "api")]),
+            service("worker", &[("app", "worker")]),
+            service("headless", &[]),
+        ];
+
+        let related: Vec<(String, String)> = pod_relations(&pod, &services)
+            .into_iter()
+            .map(|entry| (entry.kind, entry.name))
+            .collect();
+        let expected = [
+            ("ReplicaSet", "api-7d9f"),
+            ("Service", "api"),
+            ("ConfigMap", "api-settings"),
+            ("Secret", "api-tls"),
+            ("ServiceAccount", "api"),
+        ]
+        .map(|(kind, name)| (kind.to_string(), name.to_string()));
+        assert_eq!(related, expected);
+    }
+}
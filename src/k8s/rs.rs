@@ -1,51 +1,173 @@
 use crate::error::Result;
-use crate::k8s::events::{format_duration, list_events_for_resource, list_k8sevents};
-use crate::k8s::utils::format_label_selector;
-use crate::tui::data::Rs;
-use k8s_openapi::api::apps::v1::ReplicaSet;
+use crate::k8s::events::{age_of, list_events_for_resource, list_k8sevents};
+use crate::k8s::hpa::{autoscaler_for, list_hpas};
+use crate::k8s::utils::{list_paged, record_api_call};
+use crate::labels::format_label_selector;
+use crate::labels::with_api_selector;
+use crate::tui::data::{Rollout, RolloutState, Rs};
+use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet};
 use kube::api::ListParams;
 use kube::api::ObjectList;
 use kube::{Api, Client};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Instant;
+use tracing::field::Empty;
+use tracing::{debug, instrument, Span};
 
 use chrono::{DateTime, Utc};
 
-use super::client::new;
-
-fn calculate_rs_age(rs: &ReplicaSet) -> String {
-    rs.metadata.creation_timestamp.as_ref().map_or_else(
-        || "Unk".to_string(),
-        |creation_timestamp| {
-            let ts: DateTime<_> = creation_timestamp.0;
-            let now = Utc::now();
-            let duration = now.signed_duration_since(ts);
-            format_duration(duration)
-        },
-    )
+use super::client::{current_namespace, shared};
+
+/// set by the Deployment controller on each of its ReplicaSets, counting up per rollout
+const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+
+fn revision(rs: &ReplicaSet) -> String {
+    rs.metadata
+        .annotations
+        .as_ref()
+        .and_then(|annotations| annotations.get(REVISION_ANNOTATION))
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Group sets by owner with the newest revision first, falling back to the newest set
+/// for owners without revisions, and mark the newest one that still has replicas as the
+/// owner's current set
+fn order_revisions(mut rs_vec: Vec<(Option<DateTime<Utc>>, Rs)>) -> Vec<Rs> {
+    let revision_number = |rs: &Rs| rs.revision.parse::<u64>().ok();
+    rs_vec.sort_by(|(a_created, a), (b_created, b)| {
+        a.owner
+            .cmp(&b.owner)
+            .then_with(|| revision_number(b).cmp(&revision_number(a)))
+            .then_with(|| b_created.cmp(a_created))
+    });
+    let mut items: Vec<Rs> = rs_vec.into_iter().map(|(_, data)| data).collect();
+    let mut marked: BTreeSet<String> = BTreeSet::new();
+    for rs in &mut items {
+        if rs.desired_replicas() > 0 && marked.insert(rs.owner.clone()) {
+            rs.current = true;
+        }
+    }
+    items
+}
+
+/// The Deployments of the namespace. Like the autoscalers they only add context, so a
+/// cluster that hides Deployments reports none.
+async fn list_deployments(client: Client) -> Vec<Deployment> {
+    match Api::namespaced(client, &current_namespace())
+        .list(&ListParams::default())
+        .await
+    {
+        Ok(list) => list.items,
+        Err(e) => {
+            debug!("can not list deployments: {e}");
+            vec![]
+        }
+    }
+}
+
+/// Names of the Deployments whose rollouts are paused
+fn paused_deployments(deployments: &[Deployment]) -> BTreeSet<String> {
+    deployments
+        .iter()
+        .filter(|d| d.spec.as_ref().and_then(|spec| spec.paused) == Some(true))
+        .filter_map(|d| d.metadata.name.clone())
+        .collect()
+}
+
+/// The rollout of `deployment` judged the way `kubectl rollout status` does
+fn rollout_status(deployment: &Deployment) -> Rollout {
+    let status = deployment.status.clone().unwrap_or_default();
+    let desired = deployment
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.replicas)
+        .unwrap_or(1);
+    let updated = status.updated_replicas.unwrap_or(0);
+    let available = status.available_replicas.unwrap_or(0);
+    let total = status.replicas.unwrap_or(0);
+    let stalled = status.conditions.iter().flatten().any(|condition| {
+        condition.type_ == "Progressing"
+            && condition.reason.as_deref() == Some("ProgressDeadlineExceeded")
+    });
+    let observed = status.observed_generation >= deployment.metadata.generation;
+
+    let (state, message) = if stalled {
+        (
+            RolloutState::Stalled,
+            "progress deadline exceeded".to_string(),
+        )
+    } else if !observed {
+        (
+            RolloutState::Progressing,
+            "waiting for the spec update to be observed".to_string(),
+        )
+    } else if updated < desired {
+        (
+            RolloutState::Progressing,
+            format!("{updated} of {desired} new replicas have been updated"),
+        )
+    } else if total > updated {
+        (
+            RolloutState::Progressing,
+            format!("{} old replicas are pending termination", total - updated),
+        )
+    } else if available < updated {
+        (
+            RolloutState::Progressing,
+            format!("{available} of {updated} updated replicas are available"),
+        )
+    } else {
+        (RolloutState::Complete, String::new())
+    };
+    Rollout {
+        desired,
+        updated,
+        available,
+        state,
+        message,
+    }
 }
 
 /// # Errors
 ///
 /// Will return `Err` if data can not be retrieved from k8s cluster api
 #[allow(clippy::significant_drop_tightening)]
+#[instrument(skip_all, fields(namespace = Empty, count = Empty, elapsed_ms = Empty))]
 pub async fn list_replicas() -> Result<Vec<Rs>> {
-    let client = new(None).await?;
+    let started = Instant::now();
+    let client = shared().await?;
+    let namespace = current_namespace();
+    Span::current().record("namespace", namespace.as_str());
 
-    let rs_list: ObjectList<ReplicaSet> = Api::default_namespaced(client.clone())
-        .list(&ListParams::default())
-        .await?;
+    let rs_list: Vec<ReplicaSet> = list_paged(
+        &Api::namespaced(Client::clone(&client), &namespace),
+        &ListParams::default().labels(&with_api_selector("")),
+    )
+    .await?;
 
-    let mut rs_vec = Vec::new();
+    let mut rs_vec: Vec<(Option<DateTime<Utc>>, Rs)> = Vec::new();
 
     // get all events from the cluster to avoid calls for each rs
-    let events = list_k8sevents(client).await?;
+    let events = list_k8sevents(Client::clone(&client)).await?;
+    // autoscalers only add context, so a cluster that hides them still lists sets
+    let hpas = list_hpas(Client::clone(&client)).await.unwrap_or_else(|e| {
+        debug!("can not list autoscalers: {e}");
+        vec![]
+    });
+    let deployments = list_deployments(Client::clone(&client)).await;
+    let paused = paused_deployments(&deployments);
+    let rollouts: BTreeMap<String, Rollout> = deployments
+        .iter()
+        .filter_map(|d| Some((d.metadata.name.clone()?, rollout_status(d))))
+        .collect();
 
-    for rs in rs_list.items {
+    for rs in rs_list {
         if let Some(owners) = &rs.metadata.owner_references {
             for owner in owners {
                 let selectors = rs.metadata.labels.clone();
 
-                let age = calculate_rs_age(&rs);
+                let age = age_of(&rs.metadata);
                 let instance_name = &rs.metadata.name.as_deref().unwrap_or("unknown").to_string();
                 let f_instance_name = format!("{instance_name} "); //padding for just high level
                 let desired_replicas = &rs
@@ -61,39 +183,152 @@ pub async fn list_replicas() -> Result<Vec<Rs>> {
 
                 let resource_events =
                     list_events_for_resource(events.clone(), &f_instance_name).await?;
+                let autoscaler = autoscaler_for(&hpas, kind, owner_name)
+                    .or_else(|| autoscaler_for(&hpas, "ReplicaSet", instance_name));
                 let data = Rs {
                     name: instance_name.to_string(),
                     pods: format!("{ready_replicas}/{desired_replicas}"),
+                    revision: revision(&rs),
+                    current: false,
                     age,
                     description: kind.to_string(),
                     owner: owner_name.to_owned(),
                     selectors,
                     events: resource_events,
+                    autoscaler,
+                    paused: kind == "Deployment" && paused.contains(owner_name),
+                    rollout: rollouts
+                        .get(owner_name)
+                        .filter(|_| kind == "Deployment")
+                        .cloned(),
                 };
 
-                if desired_replicas <= &0 {
-                    continue;
-                };
-                rs_vec.push(data);
+                let created = rs.metadata.creation_timestamp.as_ref().map(|t| t.0);
+                rs_vec.push((created, data));
             }
         }
     }
 
-    Ok(rs_vec)
+    // group rows by owner so a Deployment's sets sit together, its rollout history in order
+    record_api_call(started, rs_vec.len());
+    Ok(order_revisions(rs_vec))
 }
 
 /// # Errors
 ///
 /// Will return `Err` if data can not be retrieved from k8s cluster api
+#[instrument(skip_all, fields(
+    namespace = Empty,
+    selector = %format_label_selector(&selector),
+    count = Empty,
+    elapsed_ms = Empty,
+))]
 pub async fn get_replicaset(selector: BTreeMap<String, String>) -> Result<Option<ReplicaSet>> {
-    let client = Client::try_default().await?;
+    let started = Instant::now();
+    let client = shared().await?;
+    let namespace = current_namespace();
+    Span::current().record("namespace", namespace.as_str());
 
     let label_selector = format_label_selector(&selector);
 
     let lp = ListParams::default().labels(&label_selector);
 
-    let rs_list: ObjectList<ReplicaSet> = Api::default_namespaced(client.clone()).list(&lp).await?;
+    let rs_list: ObjectList<ReplicaSet> = Api::namespaced(Client::clone(&client), &namespace)
+        .list(&lp)
+        .await?;
 
+    record_api_call(started, rs_list.items.len());
     let rs = rs_list.into_iter().next();
     Ok(rs)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{order_revisions, rollout_status};
+    use crate::tui::data::{RolloutState, Rs};
+    use k8s_openapi::api::apps::v1::{
+        Deployment, DeploymentCondition, DeploymentSpec, DeploymentStatus,
+    };
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    fn rs(name: &str, revision: &str, pods: &str) -> (Option<chrono::DateTime<chrono::Utc>>, Rs) {
+        let data = Rs {
+            name: name.to_string(),
+            owner: "api".to_string(),
+            description: "Deployment".to_string(),
+            age: "1d".to_string(),
+            pods: pods.to_string(),
+            revision: revision.to_string(),
+            current: false,
+            selectors: None,
+            events: vec![],
+            autoscaler: None,
+            paused: false,
+            rollout: None,
+        };
+        (None, data)
+    }
+
+    #[test]
+    fn test_order_revisions_puts_the_current_set_first() {
+        // mid rollout: revision 10 is scaling up, 9 still serves, 8 was scaled down
+        let ordered = order_revisions(vec![
+            rs("api-8", "8", "0/0"),
+            rs("api-10", "10", "1/3"),
+            rs("api-9", "9", "2/2"),
+        ]);
+        let names: Vec<(&str, bool)> = ordered
+            .iter()
+            .map(|rs| (rs.name.as_str(), rs.current))
+            .collect();
+        assert_eq!(
+            names,
+            [("api-10", true), ("api-9", false), ("api-8", false)]
+        );
+    }
+
+    fn deployment(updated: i32, available: i32, total: i32, reason: Option<&str>) -> Deployment {
+        Deployment {
+            metadata: ObjectMeta {
+                generation: Some(4),
+                ..ObjectMeta::default()
+            },
+            spec: Some(DeploymentSpec {
+                replicas: Some(3),
+                ..DeploymentSpec::default()
+            }),
+            status: Some(DeploymentStatus {
+                observed_generation: Some(4),
+                replicas: Some(total),
+                updated_replicas: Some(updated),
+                available_replicas: Some(available),
+                conditions: reason.map(|reason| {
+                    vec![DeploymentCondition {
+                        type_: "Progressing".to_string(),
+                        status: "False".to_string(),
+                        reason: Some(reason.to_string()),
+                        ..DeploymentCondition::default()
+                    }]
+                }),
+                ..DeploymentStatus::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn test_rollout_status_like_kubectl() {
+        let done = rollout_status(&deployment(3, 3, 3, None));
+        assert_eq!(done.state, RolloutState::Complete);
+        assert!((done.progress() - 1.0).abs() < f64::EPSILON);
+
+        let rolling = rollout_status(&deployment(1, 3, 4, None));
+        assert_eq!(rolling.state, RolloutState::Progressing);
+        assert_eq!(rolling.message, "1 of 3 new replicas have been updated");
+
+        let draining = rollout_status(&deployment(3, 3, 4, None));
+        assert_eq!(draining.message, "1 old replicas are pending termination");
+
+        let stuck = rollout_status(&deployment(1, 2, 3, Some("ProgressDeadlineExceeded")));
+        assert_eq!(stuck.state, RolloutState::Stalled);
+    }
+}
@@ -6,7 +6,7 @@ use k8s_openapi::api::networking::v1::Ingress;
 use kube::api::ListParams;
 use kube::{Api, Client};
 
-use super::client::new;
+use super::client::shared;
 
 fn matches_rs_labels(
     rs: &ReplicaSet,
@@ -44,9 +44,9 @@ async fn services_for_rs(client: &Client, rs: &ReplicaSet, namespace: &str) -> R
 ///
 /// Will return `Err` if data can not be retrieved from k8s cluster api
 pub async fn list_ingresses(rs: &ReplicaSet, namespace: &str) -> Result<Vec<data::Ingress>> {
-    let client = new(None).await?;
+    let client = shared().await?;
 
-    let ingresses: Api<Ingress> = Api::namespaced(client.clone(), namespace);
+    let ingresses: Api<Ingress> = Api::namespaced(Client::clone(&client), namespace);
     let services = services_for_rs(&client, rs, namespace).await?;
     drop(client);
 
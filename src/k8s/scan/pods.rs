@@ -1,10 +1,7 @@
 use crate::k8s::scan::metrics;
 use k8s_openapi::api::core::v1::Pod;
 use kube::api::ObjectList;
-use kube::{
-    api::{Api, ListParams},
-    Client,
-};
+use kube::api::{Api, ListParams};
 use sqlx::sqlite::SqlitePool;
 use tracing::error;
 
@@ -14,7 +11,7 @@ use tracing::error;
 pub async fn fetch(
     namespace: String,
 ) -> Result<(ObjectList<Pod>, Api<Pod>), Box<dyn std::error::Error>> {
-    let client = Client::try_default()
+    let client = crate::k8s::client::new(None)
         .await
         .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
 
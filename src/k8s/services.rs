@@ -0,0 +1,194 @@
+use crate::error::Result;
+use crate::k8s::events::age_of;
+use crate::tui::data::{Service as ServiceData, ServiceEndpoint};
+use k8s_openapi::api::core::v1::Service;
+use k8s_openapi::api::discovery::v1::EndpointSlice;
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::api::{ListParams, ObjectList};
+use kube::{Api, Client};
+use std::collections::BTreeMap;
+
+use super::client::{current_namespace, shared};
+
+/// Label the EndpointSlice controller puts on every slice it manages for a service
+const SERVICE_NAME_LABEL: &str = "kubernetes.io/service-name";
+
+/// Pod selector of the named service in the current namespace, `None` when
+/// the service does not exist or selects no pods itself.
+///
+/// # Errors
+///
+/// Will return `Err` if data can not be retrieved from k8s cluster api
+pub async fn get_service_selector(name: &str) -> Result<Option<BTreeMap<String, String>>> {
+    let client = shared().await?;
+    let services: Api<Service> = Api::namespaced(Client::clone(&client), &current_namespace());
+    let service = services.get_opt(name).await?;
+    Ok(service
+        .and_then(|service| service.spec)
+        .and_then(|spec| spec.selector)
+        .filter(|selector| !selector.is_empty()))
+}
+
+/// "80/TCP, 443→8443/TCP"
+fn format_service_ports(service: &Service) -> String {
+    service
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.ports.as_ref())
+        .map(|ports| {
+            ports
+                .iter()
+                .map(|port| {
+                    let protocol = port.protocol.as_deref().unwrap_or("TCP");
+                    match &port.target_port {
+                        Some(target) => {
+                            let target = match target {
+                                IntOrString::Int(number) => number.to_string(),
+                                IntOrString::String(name) => name.clone(),
+                            };
+                            if target == port.port.to_string() {
+                                format!("{}/{protocol}", port.port)
+                            } else {
+                                format!("{}→{target}/{protocol}", port.port)
+                            }
+                        }
+                        None => format!("{}/{protocol}", port.port),
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+        })
+        .unwrap_or_default()
+}
+
+/// Every address in `slices`. The API leaves `ready` unset when the state is unknown,
+/// which consumers are told to treat as ready.
+fn slice_endpoints(slices: &[&EndpointSlice]) -> Vec<ServiceEndpoint> {
+    slices
+        .iter()
+        .flat_map(|slice| slice.endpoints.iter())
+        .flat_map(|endpoint| {
+            let ready = endpoint
+                .conditions
+                .as_ref()
+                .and_then(|conditions| conditions.ready)
+                .unwrap_or(true);
+            let target = endpoint
+                .target_ref
+                .as_ref()
+                .and_then(|target| target.name.clone())
+                .unwrap_or_default();
+            endpoint
+                .addresses
+                .iter()
+                .map(move |address| ServiceEndpoint {
+                    address: address.clone(),
+                    target: target.clone(),
+                    ready,
+                })
+        })
+        .collect()
+}
+
+fn to_service_data(service: &Service, slices: &[&EndpointSlice]) -> ServiceData {
+    let spec = service.spec.as_ref();
+    let addresses = slice_endpoints(slices);
+    let ready_endpoints = addresses.iter().filter(|endpoint| endpoint.ready).count();
+    ServiceData {
+        name: service.metadata.name.clone().unwrap_or_default(),
+        type_: spec
+            .and_then(|spec| spec.type_.clone())
+            .unwrap_or_else(|| "ClusterIP".to_string()),
+        cluster_ip: spec
+            .and_then(|spec| spec.cluster_ip.clone())
+            .unwrap_or_default(),
+        ports: format_service_ports(service),
+        endpoints: format!("{ready_endpoints}/{}", addresses.len()),
+        age: age_of(&service.metadata),
+        ready_endpoints,
+        addresses,
+        selector: spec
+            .and_then(|spec| spec.selector.clone())
+            .filter(|selector| !selector.is_empty()),
+    }
+}
+
+/// The services in the current namespace with the endpoints their `EndpointSlice`s list
+///
+/// # Errors
+///
+/// Will return `Err` if data can not be retrieved from k8s cluster api
+pub async fn list_services() -> Result<Vec<ServiceData>> {
+    let client = shared().await?;
+    let namespace = current_namespace();
+    let lp = ListParams::default();
+
+    let service_list: ObjectList<Service> = Api::namespaced(Client::clone(&client), &namespace)
+        .list(&lp)
+        .await?;
+    let slice_list: ObjectList<EndpointSlice> = Api::namespaced(Client::clone(&client), &namespace)
+        .list(&lp)
+        .await?;
+
+    Ok(service_list
+        .items
+        .iter()
+        .map(|service| {
+            let name = service.metadata.name.as_deref();
+            let slices: Vec<&EndpointSlice> = slice_list
+                .items
+                .iter()
+                .filter(|slice| {
+                    slice
+                        .metadata
+                        .labels
+                        .as_ref()
+                        .and_then(|labels| labels.get(SERVICE_NAME_LABEL))
+                        .map(String::as_str)
+                        == name
+                })
+                .collect();
+            to_service_data(service, &slices)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::slice_endpoints;
+    use k8s_openapi::api::core::v1::ObjectReference;
+    use k8s_openapi::api::discovery::v1::{Endpoint, EndpointConditions, EndpointSlice};
+
+    #[test]
+    fn test_slice_endpoints_treats_unknown_as_ready() {
+        let slice = EndpointSlice {
+            address_type: "IPv4".to_string(),
+            endpoints: vec![
+                Endpoint {
+                    addresses: vec!["10.0.0.7".to_string()],
+                    conditions: Some(EndpointConditions {
+                        ready: Some(false),
+                        ..EndpointConditions::default()
+                    }),
+                    target_ref: Some(ObjectReference {
+                        name: Some("web-7d9f-x7kqz".to_string()),
+                        ..ObjectReference::default()
+                    }),
+                    ..Endpoint::default()
+                },
+                Endpoint {
+                    addresses: vec!["10.0.0.8".to_string()],
+                    ..Endpoint::default()
+                },
+            ],
+            ..EndpointSlice::default()
+        };
+
+        let endpoints = slice_endpoints(&[&slice]);
+        assert_eq!(endpoints.len(), 2);
+        assert!(!endpoints[0].ready);
+        assert_eq!(endpoints[0].target, "web-7d9f-x7kqz");
+        assert!(endpoints[1].ready);
+        assert_eq!(endpoints[1].target, "");
+    }
+}
@@ -1,9 +1,36 @@
-use std::collections::BTreeMap;
+use crate::config;
+use crate::error::Result;
+use kube::api::ListParams;
+use kube::Api;
+use serde::de::DeserializeOwned;
+use std::fmt::Debug;
+use std::time::Instant;
+use tracing::{debug, Span};
 
-pub(crate) fn format_label_selector(selector: &BTreeMap<String, String>) -> String {
-    selector
-        .iter()
-        .map(|(key, value)| format!("{key}={value}"))
-        .collect::<Vec<String>>()
-        .join(",")
+/// Every object `params` matches, fetched `--page-size` at a time with continue tokens
+/// so a namespace with thousands of pods is not pulled in one response
+pub(crate) async fn list_paged<K>(api: &Api<K>, params: &ListParams) -> Result<Vec<K>>
+where
+    K: Clone + DeserializeOwned + Debug,
+{
+    let mut params = params.clone().limit(config::current().page_size);
+    let mut items = Vec::new();
+    loop {
+        let page = api.list(&params).await?;
+        items.extend(page.items);
+        match page.metadata.continue_ {
+            Some(token) if !token.is_empty() => params = params.continue_token(&token),
+            _ => return Ok(items),
+        }
+    }
+}
+
+/// Record how many objects an api call returned and how long it took on the current
+/// span, logging them too so they show up with `RUST_LOG=navipod=debug`
+pub(crate) fn record_api_call(started: Instant, count: usize) {
+    let elapsed_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+    let span = Span::current();
+    span.record("count", count);
+    span.record("elapsed_ms", elapsed_ms);
+    debug!(count, elapsed_ms, "api call finished");
 }
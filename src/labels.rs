@@ -0,0 +1,194 @@
+use crate::config;
+use std::collections::BTreeMap;
+
+/// A label map in the `key=value,key=value` form the api and kubectl take as a selector
+pub fn format_label_selector(selector: &BTreeMap<String, String>) -> String {
+    selector
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// The reverse of `format_label_selector`: `key=value` (or `key==value`) terms separated
+/// by commas. Set based terms such as `env in (a,b)` or `!key` are rejected since they do
+/// not fit a label map. An empty selector gives an empty map.
+///
+/// # Errors
+///
+/// Will return `Err` naming the first term that is not an equality
+pub fn parse_label_selector(selector: &str) -> Result<BTreeMap<String, String>, String> {
+    selector
+        .split(',')
+        .map(str::trim)
+        .filter(|term| !term.is_empty())
+        .map(|term| {
+            let (key, value) = term
+                .split_once("==")
+                .or_else(|| term.split_once('='))
+                .filter(|(key, _)| !key.trim().is_empty() && !key.ends_with('!'))
+                .ok_or_else(|| format!("{term} is not a key=value term"))?;
+            Ok((key.trim().to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+/// the longest name part of a label or annotation key, and of a label value
+const MAX_NAME_LEN: usize = 63;
+/// the longest DNS subdomain prefix of a key, as in `app.kubernetes.io/`
+const MAX_PREFIX_LEN: usize = 253;
+
+/// A label or annotation to set on a resource, or to remove when `value` is `None`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataEdit {
+    pub annotation: bool,
+    pub key: String,
+    pub value: Option<String>,
+}
+
+impl MetadataEdit {
+    /// `label` or `annotation`
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        if self.annotation {
+            "annotation"
+        } else {
+            "label"
+        }
+    }
+}
+
+/// Alphanumeric at both ends with only `-`, `_` and `.` between, as names in keys and
+/// label values must be
+fn is_name(text: &str) -> bool {
+    let ends = |c: Option<char>| c.is_some_and(|c| c.is_ascii_alphanumeric());
+    text.len() <= MAX_NAME_LEN
+        && ends(text.chars().next())
+        && ends(text.chars().last())
+        && text
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+fn check_key(key: &str) -> Result<(), String> {
+    let (prefix, name) = key.rsplit_once('/').unwrap_or(("", key));
+    let prefix_ok = prefix.is_empty()
+        || (prefix.len() <= MAX_PREFIX_LEN
+            && prefix.split('.').all(|part| {
+                !part.is_empty()
+                    && part
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+                    && !part.starts_with('-')
+                    && !part.ends_with('-')
+            }));
+    if prefix_ok && is_name(name) {
+        Ok(())
+    } else {
+        Err(format!(
+            "{key} is not a valid key, expected an optional dns prefix and a name such as example.com/team"
+        ))
+    }
+}
+
+/// kubectl's form: `key=value` sets a label and `key-` removes it, `@` in front of the
+/// key makes it an annotation. Keys are checked as the api would, label values too,
+/// while annotation values may hold anything.
+///
+/// # Errors
+///
+/// Will return `Err` saying what is wrong with the key or value
+pub fn parse_metadata_edit(text: &str) -> Result<MetadataEdit, String> {
+    let text = text.trim();
+    let (annotation, text) = text
+        .strip_prefix('@')
+        .map_or((false, text), |text| (true, text));
+    let (key, value) = match text.split_once('=') {
+        Some((key, value)) => (key.trim(), Some(value.trim().to_string())),
+        None => match text.strip_suffix('-') {
+            Some(key) => (key.trim(), None),
+            None => return Err(format!("{text} is neither key=value nor key-")),
+        },
+    };
+    check_key(key)?;
+    if let Some(value) = value.as_deref() {
+        if !annotation && !value.is_empty() && !is_name(value) {
+            return Err(format!(
+                "{value} is not a valid label value, at most 63 letters, digits, '-', '_' or '.'"
+            ));
+        }
+    }
+    Ok(MetadataEdit {
+        annotation,
+        key: key.to_string(),
+        value,
+    })
+}
+
+/// `selector` narrowed further by the one pushed to the api from the TUI, if any
+pub fn with_api_selector(selector: &str) -> String {
+    let api = format_label_selector(&config::current().api_selector);
+    match (selector.is_empty(), api.is_empty()) {
+        (_, true) => selector.to_string(),
+        (true, false) => api,
+        (false, false) => format!("{selector},{api}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_label_selector() {
+        let selector = BTreeMap::from([
+            ("pod-template-hash".to_string(), "5d8f7c9b4".to_string()),
+            ("app".to_string(), "api".to_string()),
+        ]);
+        assert_eq!(
+            format_label_selector(&selector),
+            "app=api,pod-template-hash=5d8f7c9b4"
+        );
+        assert_eq!(format_label_selector(&BTreeMap::new()), "");
+    }
+
+    #[test]
+    fn test_parse_label_selector() {
+        let parsed = parse_label_selector(" app=api, tier==backend ");
+        assert_eq!(
+            parsed,
+            Ok(BTreeMap::from([
+                ("app".to_string(), "api".to_string()),
+                ("tier".to_string(), "backend".to_string()),
+            ]))
+        );
+        assert_eq!(parse_label_selector(""), Ok(BTreeMap::new()));
+        assert!(parse_label_selector("app!=api").is_err());
+        assert!(parse_label_selector("env in (a,b)").is_err());
+    }
+
+    #[test]
+    fn test_parse_metadata_edit() {
+        assert_eq!(
+            parse_metadata_edit("debug=true"),
+            Ok(MetadataEdit {
+                annotation: false,
+                key: "debug".to_string(),
+                value: Some("true".to_string()),
+            })
+        );
+        assert_eq!(
+            parse_metadata_edit("@k8p.navicore.tech/protected-"),
+            Ok(MetadataEdit {
+                annotation: true,
+                key: "k8p.navicore.tech/protected".to_string(),
+                value: None,
+            })
+        );
+        assert!(parse_metadata_edit("@note=why this pod, see #123").is_ok());
+        assert!(parse_metadata_edit("note=why this pod").is_err());
+        assert!(parse_metadata_edit("-bad=x").is_err());
+        assert!(parse_metadata_edit("Example.com/team=a").is_err());
+        assert!(parse_metadata_edit("debug").is_err());
+    }
+}
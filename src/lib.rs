@@ -1,4 +1,7 @@
+pub mod config;
+pub mod config_file;
 pub mod error;
 pub mod k8s;
+pub mod labels;
 pub mod net;
 pub mod tui;
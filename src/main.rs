@@ -1,7 +1,10 @@
 use clap::{CommandFactory, Parser};
 use clap_complete::{generate, Shell};
-use kube::{config::KubeConfigOptions, Config};
+use std::path::PathBuf;
+use std::time::Duration;
 
+use navipod::config::{self, AppConfig, ExportFormat, StartView};
+use navipod::k8s::client;
 use navipod::k8s::pod_ingress;
 use navipod::k8s::scan::db;
 use navipod::k8s::scan::pods;
@@ -38,6 +41,46 @@ struct Args {
     /// Name of the namespace to walk
     #[arg(short, long)]
     namespace: Option<String>,
+    /// Kubeconfig context to use instead of the current context
+    #[arg(long)]
+    context: Option<String>,
+    /// Path to a kubeconfig file to use instead of the default
+    #[arg(long)]
+    kubeconfig: Option<PathBuf>,
+    /// Seconds between background refreshes in the TUI
+    #[arg(short, long, default_value_t = 5)]
+    poll_interval: u64,
+    /// Objects fetched per list request, larger namespaces are read in several pages
+    #[arg(long, default_value_t = config::DEFAULT_PAGE_SIZE)]
+    page_size: u32,
+    /// Format of the files written by the `w` key in the TUI
+    #[arg(long, value_enum, default_value_t = ExportFormat::Json)]
+    export_format: ExportFormat,
+    /// Draw the TUI without colors, also implied by a non-empty NO_COLOR
+    #[arg(long)]
+    no_color: bool,
+    /// Start the TUI in the default context and namespace instead of where the last run left off
+    #[arg(long)]
+    fresh: bool,
+    /// Open the TUI on the objects of a custom resource, as group/version/Kind
+    #[arg(long)]
+    resource: Option<String>,
+    /// View the TUI opens on, Esc from it quits, `deployments` is taken as replicasets
+    #[arg(long, value_enum)]
+    view: Option<StartView>,
+    /// Only show logs and events this recent, e.g. 5m or 1h, `s` steps it in the TUI
+    #[arg(long)]
+    since: Option<String>,
+    /// Print the names of the pods checked with space to stdout on exit, one per line
+    #[arg(long)]
+    print_selection: bool,
+    /// Disable every key in the TUI that would change the cluster, e.g. delete, edit and drain
+    #[arg(long)]
+    read_only: bool,
+    /// Send deletes and edits from the TUI as a server side dry run first and show what
+    /// the api server made of it before committing
+    #[arg(long)]
+    dry_run_first: bool,
     #[arg(short, long, default_value = "/tmp/navipod.db")]
     db_location: String,
 
@@ -45,25 +88,45 @@ struct Args {
     command: Option<Command>,
 }
 
+/// The namespace the commands outside the TUI work in, `--namespace` or else the default
+/// of the context. Builds the shared client the command then reuses.
+async fn cli_namespace() -> Result<String, Box<dyn std::error::Error>> {
+    client::shared().await?;
+    Ok(client::current_namespace())
+}
+
 async fn process_command() -> Result<(), Box<dyn std::error::Error>> {
     tracing_subscriber::fmt::init();
     let _ =
         rustls::crypto::CryptoProvider::install_default(rustls::crypto::ring::default_provider());
     let args = Args::parse();
-    let db_location = args.db_location;
-    let pool = db::init(db_location).await?;
-    let namespace = if let Some(n) = args.namespace {
-        n
-    } else {
-        let config = Config::from_kubeconfig(&KubeConfigOptions::default()).await?;
-        config.default_namespace
+    let app_config = AppConfig {
+        namespace: args.namespace.clone(),
+        context: args.context.clone(),
+        kubeconfig: args.kubeconfig.clone(),
+        poll_interval: Duration::from_secs(args.poll_interval),
+        page_size: args.page_size,
+        export_format: args.export_format,
+        monochrome: args.no_color || config::no_color_env(),
+        fresh: args.fresh,
+        resource: args.resource.clone(),
+        read_only: args.read_only,
+        dry_run_first: args.dry_run_first,
+        view: args.view,
+        print_selection: args.print_selection,
+        since: args.since.as_deref().map(config::parse_since).transpose()?,
+        ..AppConfig::default()
     };
+    app_config.validate()?;
+    config::install(app_config.clone());
 
+    let db_location = args.db_location;
+    let pool = db::init(db_location).await?;
     let command = args.command.unwrap_or(Command::Tui);
 
     match command {
         Command::Tui => {
-            tui::ui_loop::run().await?;
+            tui::ui_loop::run(app_config).await?;
         }
         Command::GenerateCompletion { shell } => {
             let app = Args::command();
@@ -75,9 +138,11 @@ async fn process_command() -> Result<(), Box<dyn std::error::Error>> {
             );
         }
         Command::ExplainPod { podname } => {
+            let namespace = cli_namespace().await?;
             pod_ingress::explain(&namespace, &podname).await?;
         }
         Command::ScanMetrics => {
+            let namespace = cli_namespace().await?;
             db::create_table(&pool).await?;
             let (pod_list, pods) = pods::fetch(namespace.clone()).await?;
             pods::gather_metrics(&pool, pod_list, &pods, namespace).await;
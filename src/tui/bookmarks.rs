@@ -0,0 +1,160 @@
+//! Resources bookmarked with `"` in the pod and ReplicaSet views, kept in the config
+//! file's `bookmarks` and listed with `'`. The list looks each one up as it opens, so
+//! Enter leads to what is there now and a resource that is gone shows as not found.
+use crate::config;
+use crate::config_file::{Bookmark, ConfigFile};
+use crate::k8s::client::current_namespace;
+use crate::tui::data::FinderTarget;
+use crate::tui::status::StatusMessage;
+use crate::tui::style::TableColors;
+use crate::tui::ui_loop::{target_app, Apps};
+use crate::tui::widgets::centered_rect;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use tracing::debug;
+
+/// Bookmark the `kind` named `name` in the current namespace, or drop the bookmark
+pub fn toggle_bookmark(kind: &str, name: &str) -> StatusMessage {
+    let bookmark = Bookmark::new(kind, &current_namespace(), name);
+    let mut saved = ConfigFile::load();
+    let kept = saved.toggle_bookmark(&bookmark);
+    if let Err(e) = saved.save() {
+        return StatusMessage::Error(format!("could not save the bookmark: {e}"));
+    }
+    let verb = if kept {
+        "bookmarked"
+    } else {
+        "dropped the bookmark of"
+    };
+    StatusMessage::Info(format!("{verb} {kind} {name}"))
+}
+
+/// The view of the resource `target` leads to, switching to the namespace of `bookmark`
+/// first when another one is open
+pub fn open_bookmark(bookmark: &Bookmark, target: FinderTarget) -> Option<Apps> {
+    if bookmark.namespace != current_namespace() {
+        debug!("switching to namespace {}...", bookmark.namespace);
+        let namespace = bookmark.namespace.clone();
+        config::update(|c| c.namespace = Some(namespace));
+    }
+    target_app(target)
+}
+
+/// What a key press in the list asks the owning view to do
+pub enum BookmarkAction {
+    Stay,
+    Close,
+    Jump(Bookmark, FinderTarget),
+}
+
+#[derive(Clone, Debug)]
+pub struct BookmarkList {
+    /// every bookmark with where it leads, `None` when it was not found
+    rows: Vec<(Bookmark, Option<FinderTarget>)>,
+    selected: usize,
+    /// the outcome of the last key, shown under the list
+    note: Option<StatusMessage>,
+}
+
+impl BookmarkList {
+    pub const fn new(rows: Vec<(Bookmark, Option<FinderTarget>)>) -> Self {
+        Self {
+            rows,
+            selected: 0,
+            note: None,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: &KeyEvent) -> BookmarkAction {
+        if key.kind != KeyEventKind::Press {
+            return BookmarkAction::Stay;
+        }
+        let rows = self.rows.len().max(1);
+        self.note = None;
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q' | '\'') => return BookmarkAction::Close,
+            KeyCode::Char('j') | KeyCode::Down => self.selected = (self.selected + 1) % rows,
+            KeyCode::Char('k') | KeyCode::Up => self.selected = (self.selected + rows - 1) % rows,
+            KeyCode::Enter => match self.rows.get(self.selected) {
+                Some((bookmark, Some(target))) => {
+                    return BookmarkAction::Jump(bookmark.clone(), target.clone());
+                }
+                Some((bookmark, None)) => {
+                    self.note = Some(StatusMessage::Error(format!(
+                        "{} {} was not found, d removes the bookmark",
+                        bookmark.kind, bookmark.name
+                    )));
+                }
+                None => {}
+            },
+            KeyCode::Char('d') => self.remove_selected(),
+            _ => {}
+        }
+        BookmarkAction::Stay
+    }
+
+    fn remove_selected(&mut self) {
+        if self.selected >= self.rows.len() {
+            return;
+        }
+        let (bookmark, _) = self.rows.remove(self.selected);
+        let mut saved = ConfigFile::load();
+        saved.bookmarks.retain(|kept| kept != &bookmark);
+        self.note = Some(match saved.save() {
+            Ok(()) => StatusMessage::Info(format!(
+                "removed the bookmark of {} {}",
+                bookmark.kind, bookmark.name
+            )),
+            Err(e) => StatusMessage::Error(format!("could not remove the bookmark: {e}")),
+        });
+        self.selected = self.selected.min(self.rows.len().saturating_sub(1));
+    }
+
+    pub fn render(&self, f: &mut Frame, colors: &TableColors) {
+        let style = Style::default().fg(colors.header_fg).bg(colors.header_bg);
+        let kind_width = self
+            .rows
+            .iter()
+            .map(|(bookmark, _)| bookmark.kind.len())
+            .max()
+            .unwrap_or(0);
+        let mut lines: Vec<Line> = Vec::new();
+        if self.rows.is_empty() {
+            lines.push(Line::from("  no bookmarks, \" bookmarks the selected row"));
+        }
+        lines.extend(self.rows.iter().enumerate().map(|(i, (bookmark, target))| {
+            let text = format!(
+                "  {:kind_width$}  {}/{}",
+                bookmark.kind, bookmark.namespace, bookmark.name
+            );
+            let line = if target.is_some() {
+                Line::from(text)
+            } else {
+                Line::from(format!("{text}  (not found, d removes it)"))
+                    .style(Style::new().fg(Color::DarkGray))
+            };
+            if i == self.selected {
+                line.patch_style(Style::new().add_modifier(Modifier::REVERSED))
+            } else {
+                line
+            }
+        }));
+        lines.push(Line::from(""));
+        match &self.note {
+            Some(StatusMessage::Info(text)) => lines.push(Line::from(format!(" {text}"))),
+            Some(StatusMessage::Error(text)) => {
+                lines.push(Line::from(format!(" {text}")).style(Style::new().fg(colors.error_fg)));
+            }
+            None => lines.push(Line::from(" j/k select  Enter open  d remove  Esc close")),
+        }
+
+        let area = centered_rect(70, 50, f.area());
+        let block = Paragraph::new(lines)
+            .style(style)
+            .block(Block::default().borders(Borders::ALL).title("Bookmarks"));
+
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+    }
+}
@@ -0,0 +1,248 @@
+use crate::tui::stream::Message;
+use crate::tui::table_ui::TuiTableState;
+use crate::tui::ui_loop::Apps;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Drawn between the crumbs, e.g. `nginx (Deployment) › nginx-abc (ReplicaSet)`
+const SEPARATOR: &str = " › ";
+
+/// Crumbs for the views under the current one, oldest first
+static TRAIL: OnceLock<RwLock<Vec<String>>> = OnceLock::new();
+
+/// Views under the current one, some of which leave no crumb
+static DEPTH: AtomicUsize = AtomicUsize::new(0);
+
+fn cell() -> &'static RwLock<Vec<String>> {
+    TRAIL.get_or_init(|| RwLock::new(Vec::new()))
+}
+
+/// What was selected in a view when the user drilled out of it
+fn crumbs(app: &Apps) -> Vec<String> {
+    match app.clone() {
+        Apps::Rs { mut app } => app.get_selected_item().map_or_else(Vec::new, |rs| {
+            vec![
+                format!("{} ({})", rs.owner, rs.description),
+                format!("{} (ReplicaSet)", rs.name),
+            ]
+        }),
+        Apps::Pod { mut app } => app.selection_crumb("Pod").into_iter().collect(),
+        Apps::Container { mut app } => app.selection_crumb("Container").into_iter().collect(),
+        Apps::Ingress { mut app } => app.selection_crumb("Ingress").into_iter().collect(),
+        Apps::Cert { mut app } => app.selection_crumb("Cert").into_iter().collect(),
+        Apps::Event { mut app } => app.selection_crumb("Event").into_iter().collect(),
+        Apps::Config { mut app } => {
+            let kind = app.kind.title();
+            app.selection_crumb(kind).into_iter().collect()
+        }
+        Apps::DaemonSet { mut app } => app.selection_crumb("DaemonSet").into_iter().collect(),
+        Apps::Job { mut app } => app.selection_crumb("Job").into_iter().collect(),
+        Apps::Namespace { mut app } => app.selection_crumb("Namespace").into_iter().collect(),
+        Apps::Node { mut app } => app.selection_crumb("Node").into_iter().collect(),
+        Apps::Pvc { mut app } => app.selection_crumb("PVC").into_iter().collect(),
+        Apps::Service { mut app } => app.selection_crumb("Service").into_iter().collect(),
+        Apps::ApiResource { .. } => vec![],
+        Apps::Custom { mut app } => {
+            let kind = app.kind.kind.clone();
+            app.selection_crumb(&kind).into_iter().collect()
+        }
+        Apps::Problem { mut app } => app
+            .get_selected_item()
+            .map(|problem| format!("{} ({})", problem.name, problem.kind))
+            .into_iter()
+            .collect(),
+        Apps::Log { .. } => vec![],
+    }
+}
+
+/// Where a view in the history stood when the user drilled out of it. Going back selects
+/// the same object again by its name, and the view's next refresh keeps it selected however
+/// the rows moved in the meantime.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Crumb {
+    pub kind: &'static str,
+    /// the labels the view lists pods by, empty for views that list a whole kind
+    pub selector: BTreeMap<String, String>,
+    /// key of the selected row
+    pub selected: Option<String>,
+}
+
+impl Crumb {
+    /// Where `app` stands now
+    pub fn of(app: &Apps) -> Self {
+        let (kind, selector, selected) = match app.clone() {
+            Apps::Rs { mut app } => ("ReplicaSet", BTreeMap::new(), app.get_selected_key()),
+            Apps::Pod { mut app } => ("Pod", app.selector.clone(), app.get_selected_key()),
+            Apps::Container { mut app } => ("Container", BTreeMap::new(), app.get_selected_key()),
+            Apps::Ingress { mut app } => ("Ingress", BTreeMap::new(), app.get_selected_key()),
+            Apps::Cert { mut app } => ("Cert", BTreeMap::new(), app.get_selected_key()),
+            Apps::Log { mut app } => ("Log", app.selector.clone(), app.get_selected_key()),
+            Apps::Event { mut app } => ("Event", BTreeMap::new(), app.get_selected_key()),
+            Apps::Config { mut app } => (app.kind.title(), BTreeMap::new(), app.get_selected_key()),
+            Apps::DaemonSet { mut app } => ("DaemonSet", BTreeMap::new(), app.get_selected_key()),
+            Apps::Job { mut app } => ("Job", BTreeMap::new(), app.get_selected_key()),
+            Apps::Namespace { mut app } => ("Namespace", BTreeMap::new(), app.get_selected_key()),
+            Apps::Node { mut app } => ("Node", BTreeMap::new(), app.get_selected_key()),
+            Apps::Pvc { mut app } => ("PVC", BTreeMap::new(), app.get_selected_key()),
+            Apps::Problem { mut app } => ("Problem", BTreeMap::new(), app.get_selected_key()),
+            Apps::Service { mut app } => ("Service", BTreeMap::new(), app.get_selected_key()),
+            Apps::ApiResource { mut app } => {
+                ("ApiResource", BTreeMap::new(), app.get_selected_key())
+            }
+            Apps::Custom { mut app } => ("Custom", BTreeMap::new(), app.get_selected_key()),
+        };
+        Self {
+            kind,
+            selector,
+            selected,
+        }
+    }
+}
+
+/// Select the row `crumb` names in `app`, the view it was taken from, leaving the selection
+/// alone when `app` lists another kind or selector. Meant for the view's first items after
+/// going back to it, see `ui_loop::handle_and_restore`.
+pub fn restore(app: &mut Apps, crumb: &Crumb) {
+    let here = Crumb::of(app);
+    if here.kind != crumb.kind || here.selector != crumb.selector {
+        return;
+    }
+    let selected = crumb.selected.as_deref();
+    match app {
+        Apps::Rs { app } => app.select_key(selected),
+        Apps::Pod { app } => app.select_key(selected),
+        Apps::Container { app } => app.select_key(selected),
+        Apps::Ingress { app } => app.select_key(selected),
+        Apps::Cert { app } => app.select_key(selected),
+        Apps::Log { app } => app.select_key(selected),
+        Apps::Event { app } => app.select_key(selected),
+        Apps::Config { app } => app.select_key(selected),
+        Apps::DaemonSet { app } => app.select_key(selected),
+        Apps::Job { app } => app.select_key(selected),
+        Apps::Namespace { app } => app.select_key(selected),
+        Apps::Node { app } => app.select_key(selected),
+        Apps::Pvc { app } => app.select_key(selected),
+        Apps::Problem { app } => app.select_key(selected),
+        Apps::Service { app } => app.select_key(selected),
+        Apps::ApiResource { app } => app.select_key(selected),
+        Apps::Custom { app } => app.select_key(selected),
+    }
+}
+
+/// Whether `event` brings a view its list of rows
+pub const fn lists_items(event: &Message) -> bool {
+    matches!(
+        event,
+        Message::Pod(_)
+            | Message::Rs(_)
+            | Message::Ingress(_)
+            | Message::Container(_)
+            | Message::Cert(_)
+            | Message::Log(_)
+            | Message::Event(_)
+            | Message::Config(_)
+            | Message::DaemonSet(_)
+            | Message::Job(_)
+            | Message::Namespace(_)
+            | Message::Node(_)
+            | Message::Pvc(_)
+            | Message::Problem(_)
+            | Message::Service(_)
+            | Message::ApiKind(_)
+            | Message::Custom(_)
+    )
+}
+
+/// Rebuild the trail from the navigation history, called whenever a view is pushed or popped
+pub fn set_trail(history: &[(Arc<Apps>, Crumb)]) {
+    let trail = history.iter().flat_map(|(app, _)| crumbs(app)).collect();
+    if let Ok(mut active) = cell().write() {
+        *active = trail;
+    }
+    DEPTH.store(history.len(), Ordering::Relaxed);
+}
+
+/// Whether the current view is the last one open, so `q` or Esc in it quits
+pub fn at_root() -> bool {
+    DEPTH.load(Ordering::Relaxed) == 0
+}
+
+/// The trail followed by the current view's own crumb, if it has one
+pub fn breadcrumb_text(current: Option<String>) -> String {
+    let mut trail = cell().read().map(|trail| trail.clone()).unwrap_or_default();
+    trail.extend(current);
+    join(&trail)
+}
+
+fn join(crumbs: &[String]) -> String {
+    crumbs.join(SEPARATOR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{join, Crumb};
+    use crate::tui::data::Namespace;
+    use crate::tui::namespace_app::app::App;
+    use crate::tui::stream::Message;
+    use crate::tui::ui_loop::{handle_and_restore, pop_view, Apps};
+    use std::sync::Arc;
+
+    fn namespaces(names: &[&str]) -> Vec<Namespace> {
+        names
+            .iter()
+            .map(|name| Namespace {
+                pin: String::new(),
+                name: (*name).to_string(),
+                status: "Active".to_string(),
+                age: "1d".to_string(),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_restore_reselects_by_name_after_pop() {
+        let mut app = App::new();
+        app.items = namespaces(&["default", "payments", "web"]);
+        app.state.select(Some(1));
+        let parent = Apps::Namespace { app };
+        let crumb = Crumb::of(&parent);
+        assert_eq!(crumb.selected.as_deref(), Some("payments"));
+        let mut history = vec![(Arc::new(parent), crumb.clone())];
+
+        let (popped, mut restore) = pop_view(&mut history)
+            .map(|(app, crumb)| (app, Some(crumb)))
+            .unwrap();
+        let Apps::Namespace { app: mut current } = popped else {
+            panic!("popped another view");
+        };
+        // waits for the items, anything else leaves it pending
+        handle_and_restore(&mut current, &Message::Tick, &mut restore)
+            .await
+            .unwrap();
+        assert_eq!(restore.as_ref(), Some(&crumb));
+
+        // the rows moved while the user was in a deeper view
+        let refreshed = Message::Namespace(namespaces(&["audit", "default", "web", "payments"]));
+        let restored = handle_and_restore(&mut current, &refreshed, &mut restore)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(restore.is_none());
+        assert_eq!(Crumb::of(&restored), crumb);
+    }
+
+    #[test]
+    fn test_join() {
+        let crumbs = vec![
+            "nginx (Deployment)".to_string(),
+            "nginx-abc (ReplicaSet)".to_string(),
+            "nginx-abc-xy12 (Pod)".to_string(),
+        ];
+        assert_eq!(
+            join(&crumbs),
+            "nginx (Deployment) › nginx-abc (ReplicaSet) › nginx-abc-xy12 (Pod)"
+        );
+        assert_eq!(join(&[]), "");
+    }
+}
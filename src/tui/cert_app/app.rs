@@ -1,9 +1,10 @@
 use crate::tui::cert_app;
 use crate::tui::data::{cert_constraint_len_calculator, Cert};
 use crate::tui::stream::Message;
-use crate::tui::style::{TableColors, ITEM_HEIGHT, PALETTES};
-use crate::tui::table_ui::TuiTableState;
+use crate::tui::style::{item_height, TableColors, PALETTES};
+use crate::tui::table_ui::{cycle_column_fit, TuiTableState};
 use crate::tui::ui_loop::{AppBehavior, Apps};
+use chrono::{DateTime, Local};
 use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
 use futures::{stream, Stream};
 use ratatui::prelude::*;
@@ -18,9 +19,12 @@ pub struct App {
     pub(crate) items: Vec<Cert>,
     pub(crate) longest_item_lens: (u16, u16, u16, u16),
     pub(crate) scroll_state: ScrollbarState,
+    pub(crate) table_area: Rect,
     pub(crate) colors: TableColors,
     pub(crate) color_index: usize,
     pub(crate) filter: String,
+    /// when data was last applied, for the status bar
+    pub(crate) refreshed: Option<DateTime<Local>>,
 }
 
 impl TuiTableState for App {
@@ -42,6 +46,14 @@ impl TuiTableState for App {
         self.scroll_state = scroll_state;
     }
 
+    fn get_table_area(&self) -> Rect {
+        self.table_area
+    }
+
+    fn set_table_area(&mut self, table_area: Rect) {
+        self.table_area = table_area;
+    }
+
     fn set_table_colors(&mut self, colors: TableColors) {
         self.colors = colors;
     }
@@ -56,7 +68,7 @@ impl TuiTableState for App {
 
     fn reset_selection_state(&mut self) {
         self.state = TableState::default().with_selected(0);
-        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * ITEM_HEIGHT);
+        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * item_height());
     }
 
     fn get_filter(&self) -> String {
@@ -80,7 +92,7 @@ impl TuiTableState for App {
     }
 
     fn get_show_filter_edit(&self) -> bool {
-        todo!()
+        false
     }
 }
 
@@ -108,6 +120,9 @@ impl AppBehavior for cert_app::app::App {
                             self.next_color();
                             app_holder = Some(Apps::Cert { app: self.clone() });
                         }
+                        Char('a' | 'A') => {
+                            cycle_column_fit();
+                        }
                         Char('f' | 'F') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                             self.page_forward();
                         }
@@ -118,24 +133,36 @@ impl AppBehavior for cert_app::app::App {
                     }
                 }
             }
+            Message::Key(Event::Mouse(mouse)) => {
+                if self.handle_mouse_event(mouse) {
+                    app_holder = Some(Apps::Cert { app: self.clone() });
+                }
+            }
             Message::Cert(data_vec) => {
-                let new_app = Self {
+                let selected = self.get_selected_key();
+                let mut new_app = Self {
                     longest_item_lens: cert_constraint_len_calculator(data_vec),
                     scroll_state: ScrollbarState::new(
-                        data_vec.len().saturating_sub(1) * ITEM_HEIGHT,
+                        data_vec.len().saturating_sub(1) * item_height(),
                     ),
                     items: data_vec.clone(),
+                    refreshed: Some(Local::now()),
                     ..self.clone()
                 };
+                new_app.select_key(selected.as_deref());
                 let new_app_holder = Apps::Cert { app: new_app };
                 app_holder = Some(new_app_holder);
             }
+            Message::Key(Event::Resize(_, _)) => {
+                self.handle_resize();
+                app_holder = Some(Apps::Cert { app: self.clone() });
+            }
             _ => {}
         }
         Ok(app_holder)
     }
     fn draw_ui<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<(), std::io::Error> {
-        terminal.draw(|f| cert_app::ui::ui(f, &mut self.clone()))?;
+        terminal.draw(|f| cert_app::ui::ui(f, self))?;
         Ok(())
     }
 
@@ -149,11 +176,13 @@ impl App {
         Self {
             state: TableState::default().with_selected(0),
             longest_item_lens: cert_constraint_len_calculator(&data_vec),
-            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * ITEM_HEIGHT),
+            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * item_height()),
             colors: TableColors::new(&PALETTES[0]),
+            table_area: Rect::default(),
             color_index: 1,
             items: data_vec,
             filter: String::new(),
+            refreshed: Some(Local::now()),
         }
     }
 }
@@ -0,0 +1,12 @@
+use crate::tui::status::StatusMessage;
+use arboard::Clipboard;
+
+/// Put `text` on the system clipboard and describe the outcome for the status line.
+/// Headless sessions (ssh without a display, in-cluster) have no clipboard, which is
+/// reported rather than treated as a failure of the view.
+pub fn copy_to_clipboard(what: &str, text: &str) -> StatusMessage {
+    match Clipboard::new().and_then(|mut clipboard| clipboard.set_text(text)) {
+        Ok(()) => StatusMessage::Info(format!("copied {} bytes of {what}", text.len())),
+        Err(e) => StatusMessage::Error(format!("clipboard unavailable, {what} not copied: {e}")),
+    }
+}
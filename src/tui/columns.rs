@@ -0,0 +1,226 @@
+//! The `|` overlay choosing which columns a table shows and in what order.
+//!
+//! The choice goes straight into the active [`config`] under the view's name, where the
+//! `columns` section of the config file also lands, and the view reads it back with
+//! [`visible_columns`] on its next draw. The first `pinned` columns, the row's name,
+//! always lead and can not be hidden.
+use crate::config;
+use crate::config_file::ConfigFile;
+use crate::tui::status::StatusMessage;
+use crate::tui::style::TableColors;
+use crate::tui::widgets::centered_rect;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+/// The columns of `names` the table of `view` shows, as indexes in the order chosen
+#[must_use]
+pub fn visible_columns(names: &[&str], view: &str, pinned: usize) -> Vec<usize> {
+    config::current().columns.get(view).map_or_else(
+        || (0..names.len()).collect(),
+        |chosen| order_columns(names, chosen, pinned),
+    )
+}
+
+/// The pinned columns followed by the `chosen` names `names` has, repeats and names
+/// the table does not have dropped
+fn order_columns(names: &[&str], chosen: &[String], pinned: usize) -> Vec<usize> {
+    let mut columns: Vec<usize> = (0..pinned.min(names.len())).collect();
+    for name in chosen {
+        if let Some(index) = names
+            .iter()
+            .position(|column| column.eq_ignore_ascii_case(name))
+        {
+            if !columns.contains(&index) {
+                columns.push(index);
+            }
+        }
+    }
+    columns
+}
+
+/// The `values` of a row or header at `columns`, in their order
+#[must_use]
+pub fn pick<T: Clone>(values: &[T], columns: &[usize]) -> Vec<T> {
+    columns
+        .iter()
+        .filter_map(|column| values.get(*column).cloned())
+        .collect()
+}
+
+/// What a key did to the overlay
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PickerOutcome {
+    Open,
+    /// Esc or `|` closed the overlay
+    Closed,
+    /// `w` wrote the columns to the config file, or failed to
+    Saved(StatusMessage),
+}
+
+#[derive(Clone, Debug)]
+pub struct ColumnPicker {
+    /// the name the choice is kept under, e.g. `pods`
+    view: &'static str,
+    names: &'static [&'static str],
+    pinned: usize,
+    /// every column, the `shown` ones first in the order drawn
+    order: Vec<usize>,
+    shown: usize,
+    selected: usize,
+}
+
+impl ColumnPicker {
+    /// An overlay for the table of `view` with the columns `names`, starting from the
+    /// columns it shows now
+    pub fn new(view: &'static str, names: &'static [&'static str], pinned: usize) -> Self {
+        let mut order = visible_columns(names, view, pinned);
+        let shown = order.len();
+        let hidden: Vec<usize> = (0..names.len())
+            .filter(|column| !order.contains(column))
+            .collect();
+        order.extend(hidden);
+        Self {
+            view,
+            names,
+            pinned,
+            order,
+            shown,
+            selected: 0,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: &KeyEvent) -> PickerOutcome {
+        if key.kind != KeyEventKind::Press {
+            return PickerOutcome::Open;
+        }
+        let rows = self.order.len().max(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('|' | 'q') => return PickerOutcome::Closed,
+            KeyCode::Char('j') | KeyCode::Down => self.selected = (self.selected + 1) % rows,
+            KeyCode::Char('k') | KeyCode::Up => self.selected = (self.selected + rows - 1) % rows,
+            KeyCode::Char(' ') | KeyCode::Enter => self.toggle(),
+            KeyCode::Char('J') => self.move_selected(true),
+            KeyCode::Char('K') => self.move_selected(false),
+            KeyCode::Char('w') => return PickerOutcome::Saved(self.save()),
+            _ => {}
+        }
+        PickerOutcome::Open
+    }
+
+    /// Show the selected column after the shown ones, or hide it
+    fn toggle(&mut self) {
+        let at = self.selected;
+        if at < self.pinned || at >= self.order.len() {
+            return;
+        }
+        let column = self.order.remove(at);
+        // the hidden columns start right after the shown ones
+        let to = if at < self.shown {
+            self.shown -= 1;
+            self.shown
+        } else {
+            self.shown += 1;
+            self.shown - 1
+        };
+        self.order.insert(to, column);
+        self.selected = to;
+        self.apply();
+    }
+
+    /// Swap a shown column with its neighbour below or above among the shown ones
+    fn move_selected(&mut self, down: bool) {
+        let at = self.selected;
+        let to = if down { at + 1 } else { at.wrapping_sub(1) };
+        let movable = self.pinned..self.shown;
+        if movable.contains(&at) && movable.contains(&to) {
+            self.order.swap(at, to);
+            self.selected = to;
+            self.apply();
+        }
+    }
+
+    fn shown_names(&self) -> Vec<String> {
+        self.order[..self.shown]
+            .iter()
+            .map(|column| self.names[*column].to_string())
+            .collect()
+    }
+
+    fn apply(&self) {
+        let names = self.shown_names();
+        config::update(|c| {
+            c.columns.insert(self.view.to_string(), names);
+        });
+    }
+
+    fn save(&self) -> StatusMessage {
+        let mut saved = ConfigFile::load();
+        saved
+            .columns
+            .insert(self.view.to_string(), self.shown_names());
+        match saved.save() {
+            Ok(()) => StatusMessage::Info(format!(
+                "saved the {} columns to the config file",
+                self.view
+            )),
+            Err(e) => StatusMessage::Error(format!("could not save the columns: {e}")),
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, colors: &TableColors) {
+        let style = Style::default().fg(colors.header_fg).bg(colors.header_bg);
+        let mut lines: Vec<Line> = self
+            .order
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                let mark = if i < self.pinned {
+                    "[=]"
+                } else if i < self.shown {
+                    "[x]"
+                } else {
+                    "[ ]"
+                };
+                let text = format!(" {mark} {}", self.names[*column]);
+                if i == self.selected {
+                    Line::from(text).style(Style::new().add_modifier(Modifier::REVERSED))
+                } else {
+                    Line::from(text)
+                }
+            })
+            .collect();
+        lines.push(Line::from(""));
+        lines.push(Line::from(
+            " j/k select  space show/hide  J/K move  w save  Esc close",
+        ));
+
+        let area = centered_rect(50, 50, f.area());
+        let block = Paragraph::new(lines)
+            .style(style)
+            .block(Block::default().borders(Borders::ALL).title("Columns"));
+
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{order_columns, pick};
+
+    #[test]
+    fn test_order_columns_keeps_the_name_first() {
+        let names = ["name", "status", "age", "node"];
+        let chosen = vec![
+            "node".to_string(),
+            "Status".to_string(),
+            "name".to_string(),
+            "bogus".to_string(),
+            "node".to_string(),
+        ];
+        let columns = order_columns(&names, &chosen, 1);
+        assert_eq!(columns, vec![0, 3, 1]);
+        assert_eq!(pick(&names, &columns), vec!["name", "node", "status"]);
+    }
+}
@@ -0,0 +1,414 @@
+use crate::config::{self, PollView};
+use crate::k8s::configmaps::{list_configmaps, list_secrets};
+use crate::k8s::errors::{list_error_message, with_retry, ApiErrorKind};
+use crate::tui::configmap_app;
+use crate::tui::data::{config_constraint_len_calculator, ConfigData};
+use crate::tui::status::StatusMessage;
+use crate::tui::stream::{request_refresh, spawn_task, wait_for_poll, Message};
+use crate::tui::style::{item_height, TableColors, PALETTES};
+use crate::tui::table_ui::{cycle_column_fit, TuiTableState};
+use crate::tui::ui_loop::{AppBehavior, Apps};
+use chrono::{DateTime, Local};
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+use futures::Stream;
+use ratatui::prelude::*;
+use ratatui::widgets::{ScrollbarState, TableState};
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tracing::debug;
+
+pub(crate) const MASK: &str = "••••";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConfigKind {
+    ConfigMap,
+    Secret,
+}
+
+impl ConfigKind {
+    pub(crate) const fn title(self) -> &'static str {
+        match self {
+            Self::ConfigMap => "ConfigMap",
+            Self::Secret => "Secret",
+        }
+    }
+
+    pub(crate) const fn resource(self) -> &'static str {
+        match self {
+            Self::ConfigMap => "configmaps",
+            Self::Secret => "secrets",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) state: TableState,
+    pub(crate) items: Vec<ConfigData>,
+    pub(crate) longest_item_lens: (u16, u16, u16, u16),
+    pub(crate) scroll_state: ScrollbarState,
+    pub(crate) table_area: Rect,
+    pub(crate) colors: TableColors,
+    color_index: usize,
+    pub(crate) kind: ConfigKind,
+    pub(crate) show_details: bool,
+    pub(crate) revealed: bool,
+    pub(crate) filter: String,
+    pub(crate) show_filter_edit: bool,
+    pub(crate) edit_filter_cursor_position: usize,
+    pub(crate) loaded: bool,
+    pub(crate) status: Option<StatusMessage>,
+    /// when data was last applied, for the status bar
+    pub(crate) refreshed: Option<DateTime<Local>>,
+}
+
+impl TuiTableState for App {
+    type Item = ConfigData;
+
+    fn get_items(&self) -> &[Self::Item] {
+        &self.items
+    }
+
+    fn get_state(&mut self) -> &mut TableState {
+        &mut self.state
+    }
+
+    fn get_scroll_state(&self) -> &ScrollbarState {
+        &self.scroll_state
+    }
+
+    fn set_scroll_state(&mut self, scroll_state: ScrollbarState) {
+        self.scroll_state = scroll_state;
+    }
+
+    fn get_table_area(&self) -> Rect {
+        self.table_area
+    }
+
+    fn set_table_area(&mut self, table_area: Rect) {
+        self.table_area = table_area;
+    }
+
+    fn set_table_colors(&mut self, colors: TableColors) {
+        self.colors = colors;
+    }
+
+    fn get_color_index(&self) -> usize {
+        self.color_index
+    }
+
+    fn set_color_index(&mut self, color_index: usize) {
+        self.color_index = color_index;
+    }
+
+    fn reset_selection_state(&mut self) {
+        self.state = TableState::default().with_selected(0);
+        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * item_height());
+    }
+
+    fn get_filter(&self) -> String {
+        self.filter.clone()
+    }
+
+    fn set_filter(&mut self, filter: String) {
+        self.filter = filter;
+    }
+
+    fn set_cursor_pos(&mut self, cursor_pos: usize) {
+        self.edit_filter_cursor_position = cursor_pos;
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    fn get_cursor_pos(&self) -> usize {
+        self.edit_filter_cursor_position
+    }
+
+    fn set_show_filter_edit(&mut self, show_filter_edit: bool) {
+        self.show_filter_edit = show_filter_edit;
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    fn get_show_filter_edit(&self) -> bool {
+        self.show_filter_edit
+    }
+}
+
+impl AppBehavior for configmap_app::app::App {
+    async fn handle_event(&mut self, event: &Message) -> Result<Option<Apps>, io::Error> {
+        if self.get_show_filter_edit() {
+            Ok(self.handle_filter_edit_event(event))
+        } else {
+            Ok(self.handle_table_event(event))
+        }
+    }
+
+    fn draw_ui<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<(), std::io::Error> {
+        terminal.draw(|f| configmap_app::ui::ui(f, self))?;
+        Ok(())
+    }
+
+    fn stream(&self, should_stop: Arc<AtomicBool>) -> impl Stream<Item = Message> {
+        let (tx, rx) = mpsc::channel(100);
+
+        let initial_items = self.get_items().to_vec();
+        let kind = self.kind;
+        let mut loaded = self.loaded;
+
+        spawn_task(async move {
+            while !should_stop.load(Ordering::Relaxed) {
+                // read each time so a change in the settings overlay applies right away
+                let poll_interval = config::current().poll_interval_for(PollView::Configmaps);
+                let list = move || async move {
+                    match kind {
+                        ConfigKind::ConfigMap => list_configmaps().await,
+                        ConfigKind::Secret => list_secrets().await,
+                    }
+                };
+                match with_retry(kind.resource(), list).await {
+                    Ok(d) => {
+                        if !loaded || d != initial_items {
+                            loaded = true;
+                            let sevent = Message::Config(d);
+                            if tx.send(sevent).await.is_err() {
+                                break;
+                            }
+                        }
+                        // a manual refresh is reported even when nothing changed
+                        if wait_for_poll(poll_interval).await {
+                            loaded = false;
+                        }
+                    }
+                    Err(e) => {
+                        _ = tx
+                            .send(Message::Error(list_error_message(&e, kind.resource())))
+                            .await;
+                        // retries are used up, but a flaky cluster may still come back
+                        if !ApiErrorKind::is_transient(&e) {
+                            break;
+                        }
+                        wait_for_poll(poll_interval).await;
+                    }
+                }
+            }
+        });
+
+        ReceiverStream::new(rx)
+    }
+}
+
+impl App {
+    pub fn new(kind: ConfigKind) -> Self {
+        let data_vec = vec![];
+        Self {
+            state: TableState::default().with_selected(0),
+            longest_item_lens: config_constraint_len_calculator(&data_vec),
+            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * item_height()),
+            colors: TableColors::new(&PALETTES[0]),
+            table_area: Rect::default(),
+            color_index: 2,
+            items: data_vec,
+            kind,
+            show_details: false,
+            revealed: false,
+            filter: String::new(),
+            refreshed: None,
+            show_filter_edit: false,
+            edit_filter_cursor_position: 0,
+            loaded: false,
+            status: None,
+        }
+    }
+
+    fn handle_table_event(&mut self, event: &Message) -> Option<Apps> {
+        if matches!(event, Message::Key(Event::Key(_))) {
+            self.status = None;
+        }
+        let mut app_holder = Some(Apps::Config { app: self.clone() });
+        match event {
+            Message::Key(Event::Key(key)) => {
+                if key.kind == KeyEventKind::Press {
+                    use KeyCode::{Char, Down, Enter, Esc, Up};
+                    match key.code {
+                        Char('q') | Esc => {
+                            app_holder = None;
+                        }
+                        Char('j') | Down => {
+                            self.next();
+                            self.revealed = false;
+                            app_holder = Some(Apps::Config { app: self.clone() });
+                        }
+                        Char('k') | Up => {
+                            self.previous();
+                            self.revealed = false;
+                            app_holder = Some(Apps::Config { app: self.clone() });
+                        }
+                        Char('c' | 'C') => {
+                            self.next_color();
+                            app_holder = Some(Apps::Config { app: self.clone() });
+                        }
+                        Char('x' | 'X') if self.kind == ConfigKind::Secret => {
+                            self.revealed = !self.revealed;
+                            app_holder = Some(Apps::Config { app: self.clone() });
+                        }
+                        Char('r' | 'R') => {
+                            request_refresh();
+                            self.status = Some(StatusMessage::Info("refreshing…".to_string()));
+                            app_holder = Some(Apps::Config { app: self.clone() });
+                        }
+                        Char('a' | 'A') => {
+                            let fit = cycle_column_fit();
+                            self.status = Some(StatusMessage::Info(format!(
+                                "column widths: {}",
+                                fit.label()
+                            )));
+                            app_holder = Some(Apps::Config { app: self.clone() });
+                        }
+                        Char('f' | 'F') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            self.page_forward();
+                        }
+                        Char('b' | 'B') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            self.page_backward();
+                        }
+                        Enter => {
+                            self.show_details = !self.show_details;
+                            app_holder = Some(Apps::Config { app: self.clone() });
+                        }
+                        Char('/') => {
+                            self.set_show_filter_edit(true);
+                            app_holder = Some(Apps::Config { app: self.clone() });
+                        }
+                        _k => {}
+                    }
+                }
+            }
+            Message::Key(Event::Mouse(mouse)) => {
+                if self.handle_mouse_event(mouse) {
+                    app_holder = Some(Apps::Config { app: self.clone() });
+                }
+            }
+            Message::Config(data_vec) => {
+                debug!("updating {} app data...", self.kind.title());
+                let selected = self.get_selected_key();
+                let mut new_app = Self {
+                    longest_item_lens: config_constraint_len_calculator(data_vec),
+                    scroll_state: ScrollbarState::new(
+                        data_vec.len().saturating_sub(1) * item_height(),
+                    ),
+                    items: data_vec.clone(),
+                    loaded: true,
+                    refreshed: Some(Local::now()),
+                    ..self.clone()
+                };
+                new_app.select_key(selected.as_deref());
+                let new_app_holder = Apps::Config { app: new_app };
+                app_holder = Some(new_app_holder);
+            }
+            Message::Error(message) => {
+                self.status = Some(StatusMessage::Error(message.clone()));
+                app_holder = Some(Apps::Config { app: self.clone() });
+            }
+            Message::Key(Event::Resize(_, _)) => {
+                self.handle_resize();
+                app_holder = Some(Apps::Config { app: self.clone() });
+            }
+            _ => {}
+        }
+        app_holder
+    }
+
+    fn handle_filter_edit_event(&mut self, event: &Message) -> Option<Apps> {
+        let mut app_holder = Some(Apps::Config { app: self.clone() });
+        match event {
+            Message::Key(Event::Key(key)) => {
+                if key.kind == KeyEventKind::Press {
+                    use KeyCode::{Backspace, Char, Enter, Esc, Left, Right, Tab};
+
+                    match key.code {
+                        Char(to_insert) => {
+                            self.enter_char(to_insert);
+                            app_holder = Some(Apps::Config { app: self.clone() });
+                        }
+                        Backspace => {
+                            self.delete_char();
+                            app_holder = Some(Apps::Config { app: self.clone() });
+                        }
+                        Left => {
+                            self.move_cursor_left();
+                            app_holder = Some(Apps::Config { app: self.clone() });
+                        }
+                        Right => {
+                            self.move_cursor_right();
+                            app_holder = Some(Apps::Config { app: self.clone() });
+                        }
+                        Esc | Enter => {
+                            self.set_show_filter_edit(false);
+                            app_holder = Some(Apps::Config { app: self.clone() });
+                        }
+                        Tab => {
+                            self.toggle_filter_mode();
+                            app_holder = Some(Apps::Config { app: self.clone() });
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Message::Config(data_vec) => {
+                let selected = self.get_selected_key();
+                let mut new_app = Self {
+                    longest_item_lens: config_constraint_len_calculator(data_vec),
+                    scroll_state: ScrollbarState::new(
+                        data_vec.len().saturating_sub(1) * item_height(),
+                    ),
+                    items: data_vec.clone(),
+                    loaded: true,
+                    refreshed: Some(Local::now()),
+                    ..self.clone()
+                };
+                new_app.select_key(selected.as_deref());
+                let new_app_holder = Apps::Config { app: new_app };
+                app_holder = Some(new_app_holder);
+            }
+            Message::Error(message) => {
+                self.status = Some(StatusMessage::Error(message.clone()));
+                app_holder = Some(Apps::Config { app: self.clone() });
+            }
+            Message::Key(Event::Resize(_, _)) => {
+                self.handle_resize();
+                app_holder = Some(Apps::Config { app: self.clone() });
+            }
+            _ => {}
+        }
+        app_holder
+    }
+
+    pub(crate) fn placeholder(&self) -> Option<String> {
+        if !self.loaded {
+            Some(format!("Loading {}s...", self.kind.title()))
+        } else if self.items.is_empty() {
+            Some(format!("No {}s found", self.kind.title()))
+        } else {
+            None
+        }
+    }
+
+    /// Keys and values of the selected item, with secret values masked until revealed
+    pub fn get_data_details(&mut self) -> Vec<(String, String, Option<String>)> {
+        let revealed = self.revealed;
+        self.get_selected_item().map_or_else(Vec::new, |item| {
+            item.entries
+                .iter()
+                .map(|entry| {
+                    let value = if entry.secret && !revealed {
+                        MASK.to_string()
+                    } else {
+                        entry.value.clone()
+                    };
+                    (entry.name.clone(), value, None)
+                })
+                .collect()
+        })
+    }
+}
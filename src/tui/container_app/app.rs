@@ -1,17 +1,28 @@
+use crate::k8s::reasons::explain_reason;
+use crate::tui::configmap_app::app::MASK;
 use crate::tui::container_app;
 use crate::tui::data::{container_constraint_len_calculator, Container};
+use crate::tui::export::export_items;
 use crate::tui::log_app;
-use crate::tui::stream::Message;
-use crate::tui::style::{TableColors, ITEM_HEIGHT, PALETTES};
-use crate::tui::table_ui::TuiTableState;
-use crate::tui::ui_loop::{AppBehavior, Apps};
+use crate::tui::status::StatusMessage;
+use crate::tui::stream::{spawn_task, Message};
+use crate::tui::style::{item_height, TableColors, PALETTES};
+use crate::tui::table_ui::{cycle_column_fit, DetailFocus, TuiTableState};
+use crate::tui::ui_loop::{create_container_data_vec, AppBehavior, Apps};
+use chrono::{DateTime, Local};
 use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
-use futures::{stream, Stream};
+use futures::Stream;
 use ratatui::prelude::*;
 use ratatui::widgets::{ScrollbarState, TableState};
+use std::collections::BTreeMap;
 use std::io;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+
+/// Mounts, probes, restarts and env vars, in the order Tab focuses them
+const DETAIL_SECTIONS: usize = 4;
 
 #[derive(Clone, Debug)]
 pub struct App {
@@ -19,9 +30,21 @@ pub struct App {
     pub(crate) items: Vec<Container>,
     pub(crate) longest_item_lens: (u16, u16, u16, u16, u16),
     pub(crate) scroll_state: ScrollbarState,
+    pub(crate) table_area: Rect,
     pub(crate) colors: TableColors,
     color_index: usize,
     pub(crate) filter: String,
+    /// the pod the containers are listed for and the labels it is found by
+    pub(crate) selectors: BTreeMap<String, String>,
+    pub(crate) pod_name: String,
+    pub(crate) loaded: bool,
+    pub(crate) status: Option<StatusMessage>,
+    /// when data was last applied, for the status bar
+    pub(crate) refreshed: Option<DateTime<Local>>,
+    /// env vars read from Secrets show their values, toggled with `x`
+    pub(crate) revealed: bool,
+    /// the detail section Tab gave the keys to and how far each is scrolled
+    pub(crate) detail_focus: DetailFocus,
 }
 
 impl TuiTableState for App {
@@ -43,6 +66,14 @@ impl TuiTableState for App {
         self.scroll_state = scroll_state;
     }
 
+    fn get_table_area(&self) -> Rect {
+        self.table_area
+    }
+
+    fn set_table_area(&mut self, table_area: Rect) {
+        self.table_area = table_area;
+    }
+
     fn set_table_colors(&mut self, colors: TableColors) {
         self.colors = colors;
     }
@@ -57,7 +88,7 @@ impl TuiTableState for App {
 
     fn reset_selection_state(&mut self) {
         self.state = TableState::default().with_selected(0);
-        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * ITEM_HEIGHT);
+        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * item_height());
     }
 
     fn get_filter(&self) -> String {
@@ -81,12 +112,21 @@ impl TuiTableState for App {
     }
 
     fn get_show_filter_edit(&self) -> bool {
-        todo!()
+        false
     }
 }
 
 impl AppBehavior for container_app::app::App {
     async fn handle_event(&mut self, event: &Message) -> Result<Option<Apps>, io::Error> {
+        if matches!(event, Message::Key(Event::Key(_))) {
+            self.status = None;
+        }
+        if let Message::Key(Event::Key(key)) = event {
+            if key.kind == KeyEventKind::Press && self.detail_focus.handle_key(key, DETAIL_SECTIONS)
+            {
+                return Ok(Some(Apps::Container { app: self.clone() }));
+            }
+        }
         let mut app_holder = Some(Apps::Container { app: self.clone() });
         match event {
             Message::Key(Event::Key(key)) => {
@@ -98,17 +138,35 @@ impl AppBehavior for container_app::app::App {
                         }
                         Char('j') | Down => {
                             self.next();
+                            self.revealed = false;
                             //todo: stop all this cloning
                             app_holder = Some(Apps::Container { app: self.clone() });
                         }
                         Char('k') | Up => {
                             self.previous();
+                            self.revealed = false;
                             app_holder = Some(Apps::Container { app: self.clone() });
                         }
                         Char('c' | 'C') => {
                             self.next_color();
                             app_holder = Some(Apps::Container { app: self.clone() });
                         }
+                        Char('x' | 'X') => {
+                            self.revealed = !self.revealed;
+                            app_holder = Some(Apps::Container { app: self.clone() });
+                        }
+                        Char('w' | 'W') => {
+                            self.status = Some(export_items("containers", self.get_items()));
+                            app_holder = Some(Apps::Container { app: self.clone() });
+                        }
+                        Char('a' | 'A') => {
+                            let fit = cycle_column_fit();
+                            self.status = Some(StatusMessage::Info(format!(
+                                "column widths: {}",
+                                fit.label()
+                            )));
+                            app_holder = Some(Apps::Container { app: self.clone() });
+                        }
                         Char('f' | 'F') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                             self.page_forward();
                         }
@@ -134,42 +192,91 @@ impl AppBehavior for container_app::app::App {
                     }
                 }
             }
+            Message::Key(Event::Mouse(mouse)) => {
+                if self.handle_mouse_event(mouse) {
+                    app_holder = Some(Apps::Container { app: self.clone() });
+                }
+            }
             Message::Container(data_vec) => {
-                let new_app = Self {
+                let selected = self.get_selected_key();
+                let mut new_app = Self {
                     longest_item_lens: container_constraint_len_calculator(data_vec),
                     scroll_state: ScrollbarState::new(
-                        data_vec.len().saturating_sub(1) * ITEM_HEIGHT,
+                        data_vec.len().saturating_sub(1) * item_height(),
                     ),
                     items: data_vec.clone(),
+                    loaded: true,
+                    refreshed: Some(Local::now()),
                     ..self.clone()
                 };
+                new_app.select_key(selected.as_deref());
                 let new_app_holder = Apps::Container { app: new_app };
                 app_holder = Some(new_app_holder);
             }
+            Message::Error(message) => {
+                self.status = Some(StatusMessage::Error(message.clone()));
+                app_holder = Some(Apps::Container { app: self.clone() });
+            }
+            Message::Key(Event::Resize(_, _)) => {
+                self.handle_resize();
+                app_holder = Some(Apps::Container { app: self.clone() });
+            }
             _ => {}
         }
         Ok(app_holder)
     }
     fn draw_ui<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<(), std::io::Error> {
-        terminal.draw(|f| container_app::ui::ui(f, &mut self.clone()))?;
+        terminal.draw(|f| container_app::ui::ui(f, self))?;
         Ok(())
     }
 
+    /// Containers are fetched once each time the view opens, they are not polled
     fn stream(&self, _should_stop: Arc<AtomicBool>) -> impl Stream<Item = Message> {
-        stream::empty()
+        let (tx, rx) = mpsc::channel(1);
+        let selectors = self.selectors.clone();
+        let pod_name = self.pod_name.clone();
+
+        spawn_task(async move {
+            let message = match create_container_data_vec(selectors, pod_name).await {
+                Ok(d) => Message::Container(d),
+                Err(e) => Message::Error(e.to_string()),
+            };
+            _ = tx.send(message).await;
+        });
+
+        ReceiverStream::new(rx)
     }
 }
 
 impl App {
-    pub fn new(data_vec: Vec<Container>) -> Self {
+    pub fn new(selectors: BTreeMap<String, String>, pod_name: String) -> Self {
+        let data_vec = vec![];
         Self {
             state: TableState::default().with_selected(0),
             longest_item_lens: container_constraint_len_calculator(&data_vec),
-            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * ITEM_HEIGHT),
+            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * item_height()),
             colors: TableColors::new(&PALETTES[0]),
+            table_area: Rect::default(),
             color_index: 2,
             items: data_vec,
             filter: String::new(),
+            selectors,
+            pod_name,
+            refreshed: None,
+            loaded: false,
+            status: None,
+            revealed: false,
+            detail_focus: DetailFocus::default(),
+        }
+    }
+
+    pub(crate) fn placeholder(&self) -> Option<&'static str> {
+        if !self.loaded {
+            Some("Loading Containers...")
+        } else if self.items.is_empty() {
+            Some("No Containers found")
+        } else {
+            None
         }
     }
 
@@ -187,12 +294,66 @@ impl App {
         })
     }
 
+    pub fn get_probe_details(&mut self) -> Vec<(String, String, Option<String>)> {
+        self.get_selected_item().map_or_else(Vec::new, |container| {
+            container
+                .probes
+                .iter()
+                .map(|probe| (probe.name.clone(), probe.value.clone(), None))
+                .collect()
+        })
+    }
+
+    /// The restart history, led by the memory limit an OOM kill hit and what the current
+    /// waiting or terminated reason means
+    pub fn get_restart_details(&mut self) -> Vec<(String, String, Option<String>)> {
+        self.get_selected_item().map_or_else(Vec::new, |container| {
+            let oom = container.oom_killed.then(|| {
+                (
+                    "OOMKilled".to_string(),
+                    format!("memory limit {}", container.memory_limit),
+                    None,
+                )
+            });
+            let hint = (!container.state_reason.is_empty()).then(|| {
+                (
+                    container.state_reason.clone(),
+                    explain_reason(&container.state_reason),
+                    None,
+                )
+            });
+            oom.into_iter()
+                .chain(hint)
+                .chain(
+                    container
+                        .restart_history
+                        .iter()
+                        .map(|restart| (restart.age.clone(), restart.reason.clone(), None)),
+                )
+                .collect()
+        })
+    }
+
+    /// The env vars of the selected container with where referenced ones come from,
+    /// values read from Secrets masked until revealed
     pub fn get_right_details(&mut self) -> Vec<(String, String, Option<String>)> {
+        let revealed = self.revealed;
         self.get_selected_item().map_or_else(Vec::new, |container| {
             container
                 .envvars
                 .iter()
-                .map(|label| (label.name.clone(), label.value.clone(), None))
+                .map(|var| {
+                    let name = var.source.as_ref().map_or_else(
+                        || var.name.clone(),
+                        |source| format!("{} (from {source})", var.name),
+                    );
+                    let value = match &var.secret_value {
+                        Some(secret) if revealed => secret.clone(),
+                        Some(_) => MASK.to_string(),
+                        None => var.value.clone(),
+                    };
+                    (name, value, None)
+                })
                 .collect()
         })
     }
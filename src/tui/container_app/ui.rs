@@ -1,28 +1,75 @@
+use crate::config;
 use crate::tui::container_app::app::App;
-use crate::tui::table_ui::{render_detail_section, TuiTableState};
+use crate::tui::data::{container_summary, ContainerReadiness};
+use crate::tui::status::{render_status_bar, render_status_line};
+use crate::tui::style::{RowLayout, RowStatus, OOM_MARK};
+use crate::tui::table_ui::{
+    column_constraints, fit_cell_text, render_breadcrumb, render_table_footer,
+    render_table_placeholder, render_too_small, TuiTableState,
+};
 use ratatui::{
     prelude::*,
-    widgets::{Cell, HighlightSpacing, Row, Scrollbar, ScrollbarOrientation, Table},
+    widgets::{
+        Block, Borders, Cell, HighlightSpacing, Paragraph, Row, Scrollbar, ScrollbarOrientation,
+        Table, Wrap,
+    },
 };
 
+const RESTARTS_COLUMN: usize = 2;
+
+/// Lines of the command pane, a longer command line is cut
+const COMMAND_LINES: u16 = 2;
+
 pub fn ui(f: &mut Frame, app: &mut App) {
-    let rects = Layout::vertical([Constraint::Min(8), Constraint::Percentage(40)]).split(f.area());
+    if render_too_small(f) {
+        return;
+    }
+    let [crumb_area, view_area, bar_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(f.area());
+
+    let rects = Layout::vertical([
+        Constraint::Min(8),
+        Constraint::Length(1),
+        Constraint::Percentage(40),
+    ])
+    .split(view_area);
 
     app.set_colors();
+    let breadcrumb = app.breadcrumb("Container");
+    render_breadcrumb(f, &app.colors, crumb_area, &breadcrumb);
 
     let table_area = rects[0];
-    let details_area = rects[1];
+    let footer_area = rects[1];
+    let details_area = rects[2];
 
+    render_table_footer(f, &app.colors, footer_area, &container_summary(&app.items));
+    let bar = app.status_bar("container", app.refreshed);
+    render_status_bar(f, &app.colors, bar_area, &bar);
     render_ui_sections(f, app, table_area, details_area);
 }
 
 fn render_ui_sections(f: &mut Frame, app: &mut App, table_area: Rect, details_area: Rect) {
     render_table(f, app, table_area);
     render_scrollbar(f, app, table_area);
+    if let Some(message) = app.placeholder() {
+        render_table_placeholder(f, &app.colors, table_area, message);
+    }
     render_details(f, app, details_area);
+    if let Some(status) = &app.status {
+        render_status_line(f, &app.colors, status);
+    }
 }
 
 fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let config = config::current();
+    let fit = config.column_fit;
+    let layout = RowLayout::new(&config);
+    app.set_table_area(area);
+
     let header_style = Style::default()
         .fg(app.colors.header_fg)
         .bg(app.colors.header_bg);
@@ -30,7 +77,7 @@ fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
         .add_modifier(Modifier::REVERSED)
         .fg(app.colors.selected_style_fg);
 
-    let header = ["Container", "Description", "Restarts", "Image", "Ports"]
+    let header = ["", "Container", "Description", "Restarts", "Image", "Ports"]
         .iter()
         .copied()
         .map(Cell::from)
@@ -46,31 +93,53 @@ fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
                 0 => app.colors.normal_row_color,
                 _ => app.colors.alt_row_color,
             };
+            let readiness_fg = match data.readiness {
+                ContainerReadiness::Ready => app.colors.ok_fg,
+                ContainerReadiness::Started => app.colors.pending_fg,
+                ContainerReadiness::Waiting => app.colors.error_fg,
+            };
+            let readiness = Cell::from(layout.text(data.readiness.symbol().to_string()))
+                .style(Style::new().fg(readiness_fg));
             let item = data.ref_array();
-            item.iter()
-                .copied()
-                .map(|content| Cell::from(Text::from(format!("\n{content}\n"))))
+            std::iter::once(readiness)
+                .chain(item.iter().copied().enumerate().map(|(col, content)| {
+                    if col == RESTARTS_COLUMN && data.oom_killed {
+                        let content = format!("{content}{OOM_MARK}");
+                        Cell::from(
+                            layout.text(fit_cell_text(fit, &content, area.width).into_owned()),
+                        )
+                        .style(app.colors.row_style(RowStatus::Error))
+                    } else {
+                        Cell::from(
+                            layout.text(fit_cell_text(fit, content, area.width).into_owned()),
+                        )
+                    }
+                }))
                 .collect::<Row>()
                 .style(Style::new().fg(app.colors.row_fg).bg(color))
-                .height(3) //height
+                .height(layout.height())
         });
-    let bar = " █ ";
-    let t = Table::new(
-        rows,
-        [
+    // the readiness dot keeps a fixed gutter whatever the column fit
+    let mut constraints = vec![Constraint::Length(1)];
+    constraints.extend(column_constraints(
+        fit,
+        &[
             // + 1 is for padding.
-            Constraint::Min(app.longest_item_lens.0 + 2),
-            Constraint::Min(app.longest_item_lens.1 + 2),
-            Constraint::Min(app.longest_item_lens.2 + 2),
-            Constraint::Min(app.longest_item_lens.3 + 2),
-            Constraint::Min(app.longest_item_lens.4),
+            app.longest_item_lens.0 + 2,
+            app.longest_item_lens.1 + 2,
+            app.longest_item_lens.2 + 2,
+            app.longest_item_lens.3 + 2,
+            app.longest_item_lens.4,
         ],
-    )
-    .header(header)
-    .row_highlight_style(selected_style)
-    .highlight_symbol(Text::from(vec!["".into(), bar.into(), "".into()]))
-    .bg(app.colors.buffer_bg)
-    .highlight_spacing(HighlightSpacing::Always);
+        area.width,
+    ));
+    let bar = layout.selection_symbol();
+    let t = Table::new(rows, constraints)
+        .header(header)
+        .row_highlight_style(selected_style)
+        .highlight_symbol(layout.text(bar.to_string()))
+        .bg(app.colors.buffer_bg)
+        .highlight_spacing(HighlightSpacing::Always);
     f.render_stateful_widget(t, area, &mut app.state);
 }
 
@@ -88,30 +157,66 @@ fn render_scrollbar(f: &mut Frame, app: &mut App, area: Rect) {
     );
 }
 
+/// The command line of the selected container, wrapped over the pane
+fn render_command(f: &mut Frame, app: &mut App, area: Rect) {
+    let command = app
+        .get_selected_item()
+        .map(|container| container.command.clone())
+        .unwrap_or_default();
+    let (foreground_color, background_color) = get_colors(app);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .style(Style::default().fg(foreground_color).bg(background_color))
+        .title(Span::styled(
+            "Command",
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+    f.render_widget(
+        Paragraph::new(command)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        area,
+    );
+}
+
 fn render_details(f: &mut Frame, app: &mut App, area: Rect) {
-    let detail_rects =
-        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).split(area);
+    let [command_area, area] =
+        Layout::vertical([Constraint::Length(COMMAND_LINES + 2), Constraint::Min(0)]).areas(area);
+    render_command(f, app, command_area);
+
+    let detail_rects = Layout::horizontal([
+        Constraint::Percentage(30),
+        Constraint::Percentage(35),
+        Constraint::Percentage(35),
+    ])
+    .split(area);
+    let middle_rects = Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(detail_rects[1]);
 
     let mount_details = app.get_left_details();
+    let probe_details = app.get_probe_details();
+    let restart_details = app.get_restart_details();
     let env_var_details = app.get_right_details();
 
-    let (foreground_color, background_color) = get_colors(app);
-    render_detail_section(
-        f,
-        foreground_color,
-        background_color,
-        detail_rects[0],
-        "Mounts",
-        &mount_details,
-    );
-    render_detail_section(
-        f,
-        foreground_color,
-        background_color,
-        detail_rects[1],
-        "Environment Variables",
-        &env_var_details,
-    );
+    let colors = get_colors(app);
+    let selected = app
+        .get_selected_item()
+        .map(|container| container.name.clone());
+    app.detail_focus
+        .follow(selected.as_deref().unwrap_or_default());
+    app.detail_focus
+        .render_section(f, colors, detail_rects[0], 0, "Mounts", &mount_details);
+    app.detail_focus
+        .render_section(f, colors, middle_rects[0], 1, "Probes", &probe_details);
+    app.detail_focus
+        .render_section(f, colors, middle_rects[1], 2, "Restarts", &restart_details);
+    let env_title = if app.revealed {
+        "Environment Variables"
+    } else {
+        "Environment Variables - x to reveal secrets"
+    };
+    app.detail_focus
+        .render_section(f, colors, detail_rects[2], 3, env_title, &env_var_details);
 }
 
 const fn get_colors(app: &App) -> (Color, Color) {
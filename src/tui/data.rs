@@ -1,31 +1,57 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
 use std::collections::BTreeMap;
+use std::time::Duration;
 use unicode_width::UnicodeWidthStr;
 
 pub trait Filterable {
+    /// Lowercase names of the columns a `column:query` filter can pick, in the order of
+    /// [`Self::column_text`]
+    const COLUMNS: &'static [&'static str] = &[];
+
     fn filter_by(&self) -> &str;
+
+    /// Identifies a row across refreshes so the selection can follow it.
+    /// The filter text is the resource name for most rows.
+    fn key(&self) -> &str {
+        self.filter_by()
+    }
+
+    /// The text of column `index` of [`Self::COLUMNS`]
+    fn column_text(&self, _index: usize) -> Option<&str> {
+        None
+    }
 }
 
-#[derive(Eq, PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
 pub struct ResourceEvent {
     pub resource_name: String,
     pub object: String,
     pub message: String,
     pub reason: String,
     pub type_: String,
+    /// how often the event was seen, the api folds repeats into one event
+    pub count: String,
     pub age: String,
 }
 
 impl ResourceEvent {
-    pub(crate) const fn ref_array(&self) -> [&String; 5] {
+    pub(crate) const fn ref_array(&self) -> [&String; 6] {
         [
             &self.object,
             &self.message,
             &self.reason,
             &self.type_,
+            &self.count,
             &self.age,
         ]
     }
 
+    /// [`Self::count`] as a number for sorting, an event without one was seen once
+    pub(crate) fn times_seen(&self) -> u64 {
+        self.count.parse().unwrap_or(1)
+    }
+
     pub(crate) fn object(&self) -> &str {
         &self.object
     }
@@ -42,6 +68,10 @@ impl ResourceEvent {
         &self.type_
     }
 
+    pub(crate) fn count(&self) -> &str {
+        &self.count
+    }
+
     pub(crate) fn age(&self) -> &str {
         &self.age
     }
@@ -59,16 +89,60 @@ pub struct RsLabel {
     pub value: String,
 }
 
-#[derive(Eq, PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
 pub struct ContainerMount {
     pub name: String,
     pub value: String,
 }
 
-#[derive(Eq, PartialEq, Clone, Debug)]
+/// Shown for a secret value by the hand written `Debug` impls of the types holding one,
+/// secret values must never end up in a log line
+const REDACTED: &str = "<redacted>";
+
+#[derive(Eq, PartialEq, Clone, Serialize)]
 pub struct ContainerEnvVar {
     pub name: String,
     pub value: String,
+    /// where a referenced value is read from, e.g. `configmap/app-config`
+    pub source: Option<String>,
+    /// a value read from a Secret, masked until revealed and never exported
+    #[serde(skip)]
+    pub secret_value: Option<String>,
+}
+
+impl std::fmt::Debug for ContainerEnvVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContainerEnvVar")
+            .field("name", &self.name)
+            .field("value", &self.value)
+            .field("source", &self.source)
+            .field(
+                "secret_value",
+                &self.secret_value.as_ref().map(|_| REDACTED),
+            )
+            .finish()
+    }
+}
+
+/// One point in a container's restart history
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
+pub struct ContainerRestart {
+    pub age: String,
+    pub reason: String,
+}
+
+/// A liveness or readiness probe, "no probe configured" when the spec has none
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
+pub struct ContainerProbe {
+    pub name: String,
+    pub value: String,
+}
+
+/// A node selector, affinity rule or toleration that decides where a pod may run
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
+pub struct PodScheduling {
+    pub name: String,
+    pub value: String,
 }
 
 #[derive(Eq, PartialEq, Clone, Debug)]
@@ -80,9 +154,15 @@ pub struct Cert {
 }
 
 impl Filterable for Cert {
+    const COLUMNS: &'static [&'static str] = &["host", "valid", "expires", "issued-by"];
+
     fn filter_by(&self) -> &str {
         self.host.as_str()
     }
+
+    fn column_text(&self, index: usize) -> Option<&str> {
+        self.ref_array().get(index).copied().map(String::as_str)
+    }
 }
 
 pub trait Detail {
@@ -145,9 +225,16 @@ impl Detail for ResourceEvent {
 }
 
 impl Filterable for ResourceEvent {
+    const COLUMNS: &'static [&'static str] =
+        &["resource", "message", "reason", "type", "count", "age"];
+
     fn filter_by(&self) -> &str {
         self.message.as_str()
     }
+
+    fn column_text(&self, index: usize) -> Option<&str> {
+        self.ref_array().get(index).copied().map(String::as_str)
+    }
 }
 
 impl Cert {
@@ -172,23 +259,63 @@ impl Cert {
     }
 }
 
-#[derive(Clone, Debug)]
+/// Where a container is in its lifecycle, from the pod's container statuses
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Serialize)]
+pub enum ContainerReadiness {
+    /// started and passing its readiness checks, or an init container that completed
+    Ready,
+    /// running but not ready yet
+    Started,
+    /// not started: waiting on an image, crash looping or never scheduled
+    Waiting,
+}
+
+impl ContainerReadiness {
+    /// Distinct shapes as well as colors so the state reads without color
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Ready => "●",
+            Self::Started => "◐",
+            Self::Waiting => "○",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
 pub struct Container {
     pub name: String,
     pub description: String,
     pub restarts: String,
+    pub readiness: ContainerReadiness,
     pub image: String,
     pub ports: String,
     pub envvars: Vec<ContainerEnvVar>,
     pub mounts: Vec<ContainerMount>,
+    pub probes: Vec<ContainerProbe>,
+    pub restart_history: Vec<ContainerRestart>,
+    /// why the container is waiting or terminated, e.g. `ImagePullBackOff`, empty while
+    /// it runs
+    pub state_reason: String,
+    /// killed for running out of memory, now or before its current run
+    pub oom_killed: bool,
+    /// e.g. `512Mi`, the constraint an OOM kill hit
+    pub memory_limit: String,
+    /// the command and args it runs, e.g. `nginx -g 'daemon off;'`
+    pub command: String,
     pub selectors: Option<BTreeMap<String, String>>,
     pub pod_name: String,
 }
 
 impl Filterable for Container {
+    const COLUMNS: &'static [&'static str] = &["name", "description", "restarts", "image", "ports"];
+
     fn filter_by(&self) -> &str {
         self.name.as_str()
     }
+
+    fn column_text(&self, index: usize) -> Option<&str> {
+        self.ref_array().get(index).copied().map(String::as_str)
+    }
 }
 
 impl Container {
@@ -223,34 +350,121 @@ impl Container {
     }
 }
 
-#[derive(Eq, PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
 pub struct RsPod {
     pub name: String,
     pub status: String,
     pub description: String,
     pub age: String,
     pub containers: String,
+    /// the node the pod is scheduled on, `-` until it is
+    pub node: String,
+    /// the pod's primary IP, `-` until it has one
+    pub ip: String,
+    /// `Guaranteed`, `Burstable` or `BestEffort`, the order the kubelet spares pods in
+    pub qos: String,
+    /// `spec.priority`, with the priority class it came from
+    pub priority: String,
+    /// e.g. "scheduled in 2s, ready in 14s", from the creation and condition timestamps
+    pub startup: String,
+    /// why the first container that is not ready waits, e.g. `ImagePullBackOff`
+    pub reason: String,
+    /// the containers killed for running out of memory with the limit they hit, e.g.
+    /// `api (512Mi)`, empty when none was
+    pub oom_killed: String,
+    /// `metadata.creationTimestamp`, for finding the newest pod
+    #[serde(skip)]
+    pub created: Option<DateTime<Utc>>,
+    /// carries the protection annotation, so deleting it is refused
+    #[serde(skip)]
+    pub protected: bool,
     pub selectors: Option<BTreeMap<String, String>>,
     pub events: Vec<ResourceEvent>,
+    pub scheduling: Vec<PodScheduling>,
 }
 
 impl Filterable for RsPod {
+    const COLUMNS: &'static [&'static str] = &[
+        "name",
+        "status",
+        "containers",
+        "qos",
+        "age",
+        "description",
+        "ip",
+        "node",
+    ];
+
     fn filter_by(&self) -> &str {
         self.name.as_str()
     }
+
+    fn column_text(&self, index: usize) -> Option<&str> {
+        self.ref_array().get(index).copied().map(String::as_str)
+    }
+}
+
+/// Where a pod's age falls against the `new_pod_age` and `old_pod_age` thresholds
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AgeBand {
+    /// likely part of a rollout still going on
+    New,
+    /// long lived, maybe forgotten
+    Old,
 }
 
 impl RsPod {
-    pub(crate) const fn ref_array(&self) -> [&String; 5] {
+    /// [`AgeBand::New`] when created less than `new` ago and [`AgeBand::Old`] when more
+    /// than `old` ago, `None` in between, without a threshold or a creation time
+    pub(crate) fn age_band(
+        &self,
+        now: DateTime<Utc>,
+        new: Option<Duration>,
+        old: Option<Duration>,
+    ) -> Option<AgeBand> {
+        // a node whose clock runs ahead can stamp a pod in the future
+        let age = now
+            .signed_duration_since(self.created?)
+            .to_std()
+            .unwrap_or_default();
+        if new.is_some_and(|new| age < new) {
+            Some(AgeBand::New)
+        } else if old.is_some_and(|old| age > old) {
+            Some(AgeBand::Old)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) const fn ref_array(&self) -> [&String; 8] {
         [
             &self.name,
             &self.status,
             &self.containers,
+            &self.qos,
             &self.age,
             &self.description,
+            &self.ip,
+            &self.node,
         ]
     }
 
+    /// true once the pod has run to completion or failed for good, e.g. the pods of
+    /// finished Jobs
+    pub(crate) fn is_finished(&self) -> bool {
+        self.status == "Succeeded" || self.status == "Failed"
+    }
+
+    /// Lower for the pods evicted first under node pressure, for sorting by QoS class
+    pub(crate) fn qos_rank(&self) -> u8 {
+        match self.qos.as_str() {
+            "BestEffort" => 0,
+            "Burstable" => 1,
+            "Guaranteed" => 2,
+            _ => 3,
+        }
+    }
+
     pub(crate) fn status(&self) -> &str {
         &self.status
     }
@@ -270,30 +484,113 @@ impl RsPod {
     pub(crate) fn containers(&self) -> &str {
         &self.containers
     }
+
+    pub(crate) fn node(&self) -> &str {
+        &self.node
+    }
+
+    pub(crate) fn ip(&self) -> &str {
+        &self.ip
+    }
+
+    pub(crate) fn qos(&self) -> &str {
+        &self.qos
+    }
 }
 
-#[derive(Eq, PartialEq, Clone, Debug)]
+/// Where the rollout of a Deployment stands, as `kubectl rollout status` words it
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub enum RolloutState {
+    Complete,
+    Progressing,
+    /// the Deployment gave up waiting, its `Progressing` condition says
+    /// `ProgressDeadlineExceeded`
+    Stalled,
+}
+
+impl RolloutState {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Complete => "complete",
+            Self::Progressing => "progressing",
+            Self::Stalled => "stalled",
+        }
+    }
+}
+
+/// How far the rollout of a set's Deployment has come
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
+pub struct Rollout {
+    pub desired: i32,
+    pub updated: i32,
+    pub available: i32,
+    pub state: RolloutState,
+    /// what the rollout is waiting on, empty once it is complete
+    pub message: String,
+}
+
+impl Rollout {
+    /// the part of the desired replicas that run the new template and are available
+    pub fn progress(&self) -> f64 {
+        if self.desired <= 0 {
+            return 1.0;
+        }
+        let done = self.updated.min(self.available).max(0);
+        (f64::from(done) / f64::from(self.desired)).min(1.0)
+    }
+}
+
+/// A `HorizontalPodAutoscaler` as shown alongside the workload it scales
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
+pub struct Autoscaler {
+    pub name: String,
+    pub current: i32,
+    pub desired: i32,
+    pub min: i32,
+    pub max: i32,
+    pub metrics: String,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
 pub struct Rs {
     pub name: String,
     pub owner: String,
     pub description: String,
     pub age: String,
     pub pods: String,
+    /// the owning Deployment's rollout revision, empty for sets without one
+    pub revision: String,
+    /// the newest revision of its owner that still has replicas
+    pub current: bool,
     pub selectors: Option<BTreeMap<String, String>>,
     pub events: Vec<ResourceEvent>,
+    /// the HPA scaling this set or its owner, if any
+    pub autoscaler: Option<Autoscaler>,
+    /// the owning Deployment is paused, so changes to its template are not rolled out
+    pub paused: bool,
+    /// the rollout of the owning Deployment, `None` for sets owned by something else
+    pub rollout: Option<Rollout>,
 }
 
 impl Filterable for Rs {
+    const COLUMNS: &'static [&'static str] =
+        &["name", "pods", "revision", "age", "description", "owner"];
+
     fn filter_by(&self) -> &str {
         self.name.as_str()
     }
+
+    fn column_text(&self, index: usize) -> Option<&str> {
+        self.ref_array().get(index).copied().map(String::as_str)
+    }
 }
 
 impl Rs {
-    pub(crate) const fn ref_array(&self) -> [&String; 5] {
+    pub(crate) const fn ref_array(&self) -> [&String; 6] {
         [
             &self.name,
             &self.pods,
+            &self.revision,
             &self.age,
             &self.description,
             &self.owner,
@@ -319,269 +616,1226 @@ impl Rs {
     pub(crate) fn pods(&self) -> &str {
         &self.pods
     }
+
+    pub(crate) fn revision(&self) -> &str {
+        &self.revision
+    }
+
+    /// the desired half of `pods`, zero for a set an older rollout scaled down
+    pub(crate) fn desired_replicas(&self) -> u64 {
+        self.pods
+            .split_once('/')
+            .and_then(|(_, desired)| desired.parse().ok())
+            .unwrap_or(0)
+    }
+
+    /// the ready half of `pods`, which reads "ready/desired"
+    pub(crate) fn ready_replicas(&self) -> u64 {
+        self.pods
+            .split('/')
+            .next()
+            .and_then(|ready| ready.parse().ok())
+            .unwrap_or(0)
+    }
 }
 
-#[derive(Eq, PartialEq, Clone, Debug)]
-pub struct LogRec {
-    pub datetime: String,
-    pub level: String,
-    pub message: String,
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
+pub struct DaemonSet {
+    pub name: String,
+    pub desired: String,
+    pub current: String,
+    pub ready: String,
+    pub age: String,
+    pub strategy: String,
+    pub selectors: Option<BTreeMap<String, String>>,
 }
 
-impl Filterable for LogRec {
+impl Filterable for DaemonSet {
+    const COLUMNS: &'static [&'static str] =
+        &["name", "desired", "current", "ready", "age", "strategy"];
+
     fn filter_by(&self) -> &str {
-        self.message.as_str()
+        self.name.as_str()
+    }
+
+    fn column_text(&self, index: usize) -> Option<&str> {
+        self.ref_array().get(index).copied().map(String::as_str)
     }
 }
 
-impl LogRec {
-    pub(crate) const fn ref_array(&self) -> [&String; 3] {
-        [&self.datetime, &self.level, &self.message]
+impl DaemonSet {
+    pub(crate) const fn ref_array(&self) -> [&String; 6] {
+        [
+            &self.name,
+            &self.desired,
+            &self.current,
+            &self.ready,
+            &self.age,
+            &self.strategy,
+        ]
     }
 
-    pub(crate) fn datetime(&self) -> &str {
-        &self.datetime
+    /// true when fewer pods are ready than there are nodes the DaemonSet should run on
+    pub(crate) fn is_degraded(&self) -> bool {
+        self.ready.parse::<i32>().unwrap_or(0) < self.desired.parse::<i32>().unwrap_or(0)
     }
 
-    pub(crate) fn level(&self) -> &str {
-        &self.level
+    pub(crate) fn name(&self) -> &str {
+        &self.name
     }
 
-    pub(crate) fn message(&self) -> &str {
-        &self.message
+    pub(crate) fn desired(&self) -> &str {
+        &self.desired
+    }
+
+    pub(crate) fn current(&self) -> &str {
+        &self.current
+    }
+
+    pub(crate) fn ready(&self) -> &str {
+        &self.ready
+    }
+
+    pub(crate) fn age(&self) -> &str {
+        &self.age
+    }
+
+    pub(crate) fn strategy(&self) -> &str {
+        &self.strategy
     }
 }
 
-#[derive(Clone, Debug)]
-pub struct Ingress {
+/// A `PersistentVolumeClaim`
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
+pub struct Pvc {
     pub name: String,
-    pub host: String,
-    pub path: String,
-    pub backend_svc: String,
-    pub port: String,
+    pub status: String,
+    pub capacity: String,
+    pub storage_class: String,
+    pub age: String,
+    /// the `PersistentVolume` the claim is bound to, empty until it is
+    pub volume: String,
+    pub access_modes: String,
 }
 
-impl Filterable for Ingress {
+impl Filterable for Pvc {
+    const COLUMNS: &'static [&'static str] =
+        &["name", "status", "capacity", "storage-class", "age"];
+
     fn filter_by(&self) -> &str {
         self.name.as_str()
     }
+
+    fn column_text(&self, index: usize) -> Option<&str> {
+        self.ref_array().get(index).copied().map(String::as_str)
+    }
 }
 
-impl Ingress {
+impl Pvc {
     pub(crate) const fn ref_array(&self) -> [&String; 5] {
         [
             &self.name,
-            &self.host,
-            &self.path,
-            &self.backend_svc,
-            &self.port,
+            &self.status,
+            &self.capacity,
+            &self.storage_class,
+            &self.age,
         ]
     }
 
+    /// true while no volume has been provisioned or bound for the claim
+    pub(crate) fn is_pending(&self) -> bool {
+        self.status == "Pending"
+    }
+
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
 
-    pub(crate) fn host(&self) -> &str {
-        &self.host
+    pub(crate) fn status(&self) -> &str {
+        &self.status
     }
 
-    pub(crate) fn path(&self) -> &str {
-        &self.path
+    pub(crate) fn capacity(&self) -> &str {
+        &self.capacity
     }
 
-    pub(crate) fn backend_svc(&self) -> &str {
-        &self.backend_svc
+    pub(crate) fn storage_class(&self) -> &str {
+        &self.storage_class
     }
 
-    pub(crate) fn port(&self) -> &str {
-        &self.port
+    pub(crate) fn age(&self) -> &str {
+        &self.age
     }
 }
 
-#[allow(clippy::cast_possible_truncation)]
-pub fn log_constraint_len_calculator(items: &[LogRec]) -> (u16, u16, u16) {
-    let datetime_len = items
-        .iter()
-        .map(LogRec::datetime)
-        .map(UnicodeWidthStr::width)
-        .max()
-        .unwrap_or(0);
-    let level_len = items
-        .iter()
-        .map(LogRec::level)
-        .map(UnicodeWidthStr::width)
-        .max()
-        .unwrap_or(0);
-    let message_len = items
-        .iter()
-        .map(LogRec::message)
-        .map(UnicodeWidthStr::width)
-        .max()
-        .unwrap_or(0);
-    (datetime_len as u16, level_len as u16, message_len as u16)
+/// One address behind a Service, from its `EndpointSlice`s
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
+pub struct ServiceEndpoint {
+    pub address: String,
+    /// the pod serving the address, empty when the endpoint is not a pod
+    pub target: String,
+    pub ready: bool,
 }
-// pub resource_name: String,
-// pub message: String,
-// pub reason: String,
-// pub type_: String,
-// pub age: String,
 
-#[allow(clippy::cast_possible_truncation)]
-pub fn event_constraint_len_calculator(items: &[ResourceEvent]) -> (u16, u16, u16, u16, u16) {
-    let object_len = items
-        .iter()
-        .map(ResourceEvent::object)
-        .map(UnicodeWidthStr::width)
-        .max()
-        .unwrap_or(0);
-    let message_len = items
-        .iter()
-        .map(ResourceEvent::message)
-        .map(UnicodeWidthStr::width)
-        .max()
-        .unwrap_or(0);
-    let reason_len = items
-        .iter()
-        .map(ResourceEvent::reason)
-        .map(UnicodeWidthStr::width)
-        .max()
-        .unwrap_or(0);
-    let type_len = items
-        .iter()
-        .map(ResourceEvent::type_)
-        .map(UnicodeWidthStr::width)
-        .max()
-        .unwrap_or(0);
-    let age_len = items
-        .iter()
-        .map(ResourceEvent::age)
-        .map(UnicodeWidthStr::width)
-        .max()
-        .unwrap_or(0);
-    (
-        object_len as u16,
-        message_len as u16,
-        reason_len as u16,
-        type_len as u16,
-        age_len as u16,
-    )
+/// A `Service` and the health of the endpoints behind it
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
+pub struct Service {
+    pub name: String,
+    pub type_: String,
+    pub cluster_ip: String,
+    pub ports: String,
+    /// "ready/total" endpoints
+    pub endpoints: String,
+    pub age: String,
+    pub ready_endpoints: usize,
+    pub addresses: Vec<ServiceEndpoint>,
+    pub selector: Option<BTreeMap<String, String>>,
 }
 
-#[allow(clippy::cast_possible_truncation)]
-pub fn ingress_constraint_len_calculator(items: &[Ingress]) -> (u16, u16, u16, u16, u16) {
-    let name_len = items
-        .iter()
-        .map(Ingress::name)
-        .map(UnicodeWidthStr::width)
-        .max()
-        .unwrap_or(0);
-    let host_len = items
-        .iter()
-        .map(Ingress::host)
-        .map(UnicodeWidthStr::width)
-        .max()
-        .unwrap_or(0);
-    let path_len = items
-        .iter()
-        .map(Ingress::path)
-        .map(UnicodeWidthStr::width)
-        .max()
-        .unwrap_or(0);
-    let backend_svc_len = items
-        .iter()
-        .map(Ingress::backend_svc)
-        .map(UnicodeWidthStr::width)
-        .max()
-        .unwrap_or(0);
+impl Filterable for Service {
+    const COLUMNS: &'static [&'static str] =
+        &["name", "type", "cluster-ip", "ports", "endpoints", "age"];
+
+    fn filter_by(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn column_text(&self, index: usize) -> Option<&str> {
+        self.ref_array().get(index).copied().map(String::as_str)
+    }
+}
+
+impl Service {
+    pub(crate) const fn ref_array(&self) -> [&String; 6] {
+        [
+            &self.name,
+            &self.type_,
+            &self.cluster_ip,
+            &self.ports,
+            &self.endpoints,
+            &self.age,
+        ]
+    }
+
+    /// true when nothing can answer: the usual cause of "service up but nothing responding".
+    /// `ExternalName` services resolve in DNS and never have endpoints.
+    pub(crate) fn is_unserved(&self) -> bool {
+        self.ready_endpoints == 0 && self.type_ != "ExternalName"
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn type_(&self) -> &str {
+        &self.type_
+    }
+
+    pub(crate) fn cluster_ip(&self) -> &str {
+        &self.cluster_ip
+    }
+
+    pub(crate) fn ports(&self) -> &str {
+        &self.ports
+    }
+
+    pub(crate) fn endpoints(&self) -> &str {
+        &self.endpoints
+    }
+
+    pub(crate) fn age(&self) -> &str {
+        &self.age
+    }
+}
+
+/// A cluster `Node`
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
+pub struct Node {
+    pub name: String,
+    /// `Ready` or `NotReady`, plus `SchedulingDisabled` while cordoned
+    pub status: String,
+    pub roles: String,
+    pub version: String,
+    pub age: String,
+    pub unschedulable: bool,
+    pub internal_ip: String,
+    pub os_image: String,
+    pub taints: Vec<String>,
+}
+
+impl Filterable for Node {
+    const COLUMNS: &'static [&'static str] = &["name", "status", "roles", "version", "age"];
+
+    fn filter_by(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn column_text(&self, index: usize) -> Option<&str> {
+        self.ref_array().get(index).copied().map(String::as_str)
+    }
+}
+
+impl Node {
+    pub(crate) const fn ref_array(&self) -> [&String; 5] {
+        [
+            &self.name,
+            &self.status,
+            &self.roles,
+            &self.version,
+            &self.age,
+        ]
+    }
+
+    pub(crate) fn is_not_ready(&self) -> bool {
+        self.status.starts_with("NotReady")
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn status(&self) -> &str {
+        &self.status
+    }
+
+    pub(crate) fn roles(&self) -> &str {
+        &self.roles
+    }
+
+    pub(crate) fn version(&self) -> &str {
+        &self.version
+    }
+
+    pub(crate) fn age(&self) -> &str {
+        &self.age
+    }
+}
+
+/// A namespaced kind the cluster serves beyond the built in ones, usually from a CRD
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
+pub struct ApiKind {
+    /// `plural.group` as `kubectl get` takes it, e.g. `rollouts.argoproj.io`
+    pub name: String,
+    pub kind: String,
+    pub group: String,
+    pub version: String,
+    pub plural: String,
+}
+
+impl Filterable for ApiKind {
+    const COLUMNS: &'static [&'static str] = &["kind", "resource", "version"];
+
+    fn filter_by(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn column_text(&self, index: usize) -> Option<&str> {
+        self.ref_array().get(index).copied().map(String::as_str)
+    }
+}
+
+impl ApiKind {
+    pub(crate) const fn ref_array(&self) -> [&String; 3] {
+        [&self.kind, &self.name, &self.version]
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub(crate) fn version(&self) -> &str {
+        &self.version
+    }
+}
+
+/// An object of an [`ApiKind`], with the status fields most custom resources share
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
+pub struct CustomResource {
+    pub name: String,
+    /// `status.phase` or `status.state`, empty when the kind has neither
+    pub status: String,
+    /// the status of the Ready (or Available) condition
+    pub ready: String,
+    pub age: String,
+    /// every condition as type and `status: message`
+    pub conditions: Vec<(String, String)>,
+}
+
+impl Filterable for CustomResource {
+    const COLUMNS: &'static [&'static str] = &["name", "status", "ready", "age"];
+
+    fn filter_by(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn column_text(&self, index: usize) -> Option<&str> {
+        self.ref_array().get(index).copied().map(String::as_str)
+    }
+}
+
+impl CustomResource {
+    pub(crate) const fn ref_array(&self) -> [&String; 4] {
+        [&self.name, &self.status, &self.ready, &self.age]
+    }
+
+    /// true when the Ready condition says it is not
+    pub(crate) fn is_unready(&self) -> bool {
+        self.ready == "False"
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn status(&self) -> &str {
+        &self.status
+    }
+
+    pub(crate) fn ready(&self) -> &str {
+        &self.ready
+    }
+
+    pub(crate) fn age(&self) -> &str {
+        &self.age
+    }
+}
+
+/// Where the global finder goes when a result is picked
+#[derive(Clone, Debug)]
+pub enum FinderTarget {
+    /// the pods matching a ReplicaSet or Service selector
+    Pods(BTreeMap<String, String>),
+    /// one pod, shown among the pods sharing its labels
+    Pod {
+        selector: BTreeMap<String, String>,
+        name: String,
+    },
+    /// the rules of an ingress
+    Ingress(Vec<Ingress>),
+    /// the ReplicaSets view, filtered to one name
+    ReplicaSet(String),
+    /// the jobs view, filtered to one name
+    Job(String),
+    /// the services view, filtered to one name
+    Service(String),
+    /// the ConfigMaps view, filtered to one name
+    ConfigMap(String),
+    /// the Secrets view, filtered to one name
+    Secret(String),
+    /// the claims mounted by the named pod
+    Claims(String),
+    /// listed for reference, k8p has no view for it
+    NoView,
+}
+
+/// A named resource in the global finder index
+#[derive(Clone, Debug)]
+pub struct FinderEntry {
+    pub kind: String,
+    pub name: String,
+    pub target: FinderTarget,
+}
+
+/// One unhealthy thing in the namespace, for the problems view
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
+pub struct Problem {
+    /// lower is more urgent
+    pub rank: u8,
+    pub kind: String,
+    pub name: String,
+    pub problem: String,
+    pub age: String,
+    pub selectors: Option<BTreeMap<String, String>>,
+    /// the pod to open for pod and container problems
+    pub pod: Option<String>,
+}
+
+impl Filterable for Problem {
+    const COLUMNS: &'static [&'static str] = &["kind", "name", "problem", "age"];
+
+    fn filter_by(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn column_text(&self, index: usize) -> Option<&str> {
+        self.ref_array().get(index).copied().map(String::as_str)
+    }
+}
+
+impl Problem {
+    pub(crate) const fn ref_array(&self) -> [&String; 4] {
+        [&self.kind, &self.name, &self.problem, &self.age]
+    }
+
+    pub(crate) fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn problem(&self) -> &str {
+        &self.problem
+    }
+
+    pub(crate) fn age(&self) -> &str {
+        &self.age
+    }
+}
+
+/// What the pods of a namespace request of one resource, e.g. `requests.cpu`, and the
+/// tightest ResourceQuota on it
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct ResourceUsage {
+    pub name: String,
+    pub used: String,
+    /// `None` when no quota caps the resource
+    pub hard: Option<String>,
+    pub percent: Option<u64>,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
+pub struct Namespace {
+    /// "★" for a pinned namespace, otherwise empty
+    pub pin: String,
+    pub name: String,
+    pub status: String,
+    pub age: String,
+}
+
+impl Filterable for Namespace {
+    const COLUMNS: &'static [&'static str] = &["pinned", "name", "status", "age"];
+
+    fn filter_by(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn column_text(&self, index: usize) -> Option<&str> {
+        self.ref_array().get(index).copied().map(String::as_str)
+    }
+}
+
+impl Namespace {
+    pub(crate) const fn ref_array(&self) -> [&String; 4] {
+        [&self.pin, &self.name, &self.status, &self.age]
+    }
+
+    pub(crate) fn is_pinned(&self) -> bool {
+        !self.pin.is_empty()
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn status(&self) -> &str {
+        &self.status
+    }
+
+    pub(crate) fn age(&self) -> &str {
+        &self.age
+    }
+}
+
+/// Mark the pinned namespaces and list them first, each group alphabetically
+pub fn sort_namespaces(items: &mut [Namespace], pinned: &[String]) {
+    for item in items.iter_mut() {
+        item.pin = if pinned.contains(&item.name) {
+            "★".to_string()
+        } else {
+            String::new()
+        };
+    }
+    items.sort_by(|a, b| {
+        b.is_pinned()
+            .cmp(&a.is_pinned())
+            .then_with(|| a.name.cmp(&b.name))
+    });
+}
+
+/// A Job or a `CronJob`, which share one table in the jobs view
+#[derive(Eq, PartialEq, Clone, Debug, Serialize)]
+pub struct Job {
+    pub name: String,
+    pub kind: String,
+    /// succeeded/completions for a Job, the schedule for a `CronJob`
+    pub completions: String,
+    pub status: String,
+    pub age: String,
+    pub complete: bool,
+    pub failed: bool,
+    pub suspended: bool,
+    pub selectors: Option<BTreeMap<String, String>>,
+}
+
+impl Filterable for Job {
+    const COLUMNS: &'static [&'static str] = &["name", "kind", "completions", "status", "age"];
+
+    fn filter_by(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn column_text(&self, index: usize) -> Option<&str> {
+        self.ref_array().get(index).copied().map(String::as_str)
+    }
+}
+
+impl Job {
+    pub(crate) const fn ref_array(&self) -> [&String; 5] {
+        [
+            &self.name,
+            &self.kind,
+            &self.completions,
+            &self.status,
+            &self.age,
+        ]
+    }
+
+    pub(crate) fn is_cronjob(&self) -> bool {
+        self.kind == "CronJob"
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub(crate) fn completions(&self) -> &str {
+        &self.completions
+    }
+
+    pub(crate) fn status(&self) -> &str {
+        &self.status
+    }
+
+    pub(crate) fn age(&self) -> &str {
+        &self.age
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct LogRec {
+    pub datetime: String,
+    pub level: String,
+    pub message: String,
+}
+
+impl Filterable for LogRec {
+    const COLUMNS: &'static [&'static str] = &["timestamp", "level", "message"];
+
+    fn filter_by(&self) -> &str {
+        self.message.as_str()
+    }
+
+    fn column_text(&self, index: usize) -> Option<&str> {
+        self.ref_array().get(index).copied().map(String::as_str)
+    }
+}
+
+impl LogRec {
+    pub(crate) const fn ref_array(&self) -> [&String; 3] {
+        [&self.datetime, &self.level, &self.message]
+    }
+
+    pub(crate) fn datetime(&self) -> &str {
+        &self.datetime
+    }
+
+    pub(crate) fn level(&self) -> &str {
+        &self.level
+    }
+
+    pub(crate) fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Ingress {
+    pub name: String,
+    pub host: String,
+    pub path: String,
+    pub backend_svc: String,
+    pub port: String,
+}
+
+impl Filterable for Ingress {
+    const COLUMNS: &'static [&'static str] = &["name", "host", "path", "service", "port"];
+
+    fn filter_by(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn column_text(&self, index: usize) -> Option<&str> {
+        self.ref_array().get(index).copied().map(String::as_str)
+    }
+}
+
+impl Ingress {
+    pub(crate) const fn ref_array(&self) -> [&String; 5] {
+        [
+            &self.name,
+            &self.host,
+            &self.path,
+            &self.backend_svc,
+            &self.port,
+        ]
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn host(&self) -> &str {
+        &self.host
+    }
+
+    pub(crate) fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub(crate) fn backend_svc(&self) -> &str {
+        &self.backend_svc
+    }
+
+    pub(crate) fn port(&self) -> &str {
+        &self.port
+    }
+}
+
+#[derive(Eq, PartialEq, Clone)]
+pub struct ConfigEntry {
+    pub name: String,
+    pub value: String,
+    pub secret: bool,
+}
+
+impl std::fmt::Debug for ConfigEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = if self.secret {
+            REDACTED
+        } else {
+            self.value.as_str()
+        };
+        f.debug_struct("ConfigEntry")
+            .field("name", &self.name)
+            .field("value", &value)
+            .field("secret", &self.secret)
+            .finish()
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct ConfigData {
+    pub name: String,
+    pub type_: String,
+    pub keys: String,
+    pub age: String,
+    pub entries: Vec<ConfigEntry>,
+}
+
+impl Filterable for ConfigData {
+    const COLUMNS: &'static [&'static str] = &["name", "type", "keys", "age"];
+
+    fn filter_by(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn column_text(&self, index: usize) -> Option<&str> {
+        self.ref_array().get(index).copied().map(String::as_str)
+    }
+}
+
+impl ConfigData {
+    pub(crate) const fn ref_array(&self) -> [&String; 4] {
+        [&self.name, &self.type_, &self.keys, &self.age]
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn type_(&self) -> &str {
+        &self.type_
+    }
+
+    pub(crate) fn keys(&self) -> &str {
+        &self.keys
+    }
+
+    pub(crate) fn age(&self) -> &str {
+        &self.age
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn log_constraint_len_calculator(items: &[LogRec]) -> (u16, u16, u16) {
+    let datetime_len = items
+        .iter()
+        .map(LogRec::datetime)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let level_len = items
+        .iter()
+        .map(LogRec::level)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let message_len = items
+        .iter()
+        .map(LogRec::message)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    (datetime_len as u16, level_len as u16, message_len as u16)
+}
+// pub resource_name: String,
+// pub message: String,
+// pub reason: String,
+// pub type_: String,
+// pub age: String,
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn event_constraint_len_calculator(items: &[ResourceEvent]) -> (u16, u16, u16, u16, u16, u16) {
+    let object_len = items
+        .iter()
+        .map(ResourceEvent::object)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let message_len = items
+        .iter()
+        .map(ResourceEvent::message)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let reason_len = items
+        .iter()
+        .map(ResourceEvent::reason)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let type_len = items
+        .iter()
+        .map(ResourceEvent::type_)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let count_len = items
+        .iter()
+        .map(ResourceEvent::count)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let age_len = items
+        .iter()
+        .map(ResourceEvent::age)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    (
+        object_len as u16,
+        message_len as u16,
+        reason_len as u16,
+        type_len as u16,
+        count_len as u16,
+        age_len as u16,
+    )
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn ingress_constraint_len_calculator(items: &[Ingress]) -> (u16, u16, u16, u16, u16) {
+    let name_len = items
+        .iter()
+        .map(Ingress::name)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let host_len = items
+        .iter()
+        .map(Ingress::host)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let path_len = items
+        .iter()
+        .map(Ingress::path)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let backend_svc_len = items
+        .iter()
+        .map(Ingress::backend_svc)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
     let port_len = items
         .iter()
-        .map(Ingress::port)
+        .map(Ingress::port)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    (
+        name_len as u16,
+        host_len as u16,
+        path_len as u16,
+        backend_svc_len as u16,
+        port_len as u16,
+    )
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn rs_constraint_len_calculator(items: &[Rs]) -> (u16, u16, u16, u16, u16, u16) {
+    let name_len = items
+        .iter()
+        .map(Rs::name)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let pods_len = items
+        .iter()
+        .map(Rs::pods)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let revision_len = items
+        .iter()
+        .map(Rs::revision)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let age_len = items
+        .iter()
+        .map(Rs::age)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let description_len = items
+        .iter()
+        .map(Rs::description)
+        .flat_map(str::lines)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let owner_len = items
+        .iter()
+        .map(Rs::owner)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+
+    (
+        name_len as u16,
+        pods_len as u16,
+        revision_len as u16,
+        age_len as u16,
+        description_len as u16,
+        owner_len as u16,
+    )
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn pod_constraint_len_calculator(items: &[RsPod]) -> (u16, u16, u16, u16, u16, u16, u16, u16) {
+    let name_len = items
+        .iter()
+        .map(RsPod::name)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let status_len = items
+        .iter()
+        .map(RsPod::status)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let description_len = items
+        .iter()
+        .map(RsPod::description)
+        .flat_map(str::lines)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let age_len = items
+        .iter()
+        .map(RsPod::age)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let containers_len = items
+        .iter()
+        .map(RsPod::containers)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let node_len = items
+        .iter()
+        .map(RsPod::node)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let qos_len = items
+        .iter()
+        .map(RsPod::qos)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let ip_len = items
+        .iter()
+        .map(RsPod::ip)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+
+    (
+        name_len as u16,
+        status_len as u16,
+        containers_len as u16,
+        qos_len as u16,
+        age_len as u16,
+        description_len as u16,
+        ip_len as u16,
+        node_len as u16,
+    )
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn daemonset_constraint_len_calculator(items: &[DaemonSet]) -> (u16, u16, u16, u16, u16, u16) {
+    let name_len = items
+        .iter()
+        .map(DaemonSet::name)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let desired_len = items
+        .iter()
+        .map(DaemonSet::desired)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let current_len = items
+        .iter()
+        .map(DaemonSet::current)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let ready_len = items
+        .iter()
+        .map(DaemonSet::ready)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let age_len = items
+        .iter()
+        .map(DaemonSet::age)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let strategy_len = items
+        .iter()
+        .map(DaemonSet::strategy)
         .map(UnicodeWidthStr::width)
         .max()
         .unwrap_or(0);
+
     (
         name_len as u16,
-        host_len as u16,
-        path_len as u16,
-        backend_svc_len as u16,
-        port_len as u16,
+        desired_len as u16,
+        current_len as u16,
+        ready_len as u16,
+        age_len as u16,
+        strategy_len as u16,
     )
 }
 
 #[allow(clippy::cast_possible_truncation)]
-pub fn rs_constraint_len_calculator(items: &[Rs]) -> (u16, u16, u16, u16, u16) {
+pub fn pvc_constraint_len_calculator(items: &[Pvc]) -> (u16, u16, u16, u16, u16) {
     let name_len = items
         .iter()
-        .map(Rs::name)
+        .map(Pvc::name)
         .map(UnicodeWidthStr::width)
         .max()
         .unwrap_or(0);
-    let pods_len = items
+    let status_len = items
         .iter()
-        .map(Rs::pods)
+        .map(Pvc::status)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let capacity_len = items
+        .iter()
+        .map(Pvc::capacity)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let storage_class_len = items
+        .iter()
+        .map(Pvc::storage_class)
         .map(UnicodeWidthStr::width)
         .max()
         .unwrap_or(0);
     let age_len = items
         .iter()
-        .map(Rs::age)
+        .map(Pvc::age)
         .map(UnicodeWidthStr::width)
         .max()
         .unwrap_or(0);
-    let description_len = items
+
+    (
+        name_len as u16,
+        status_len as u16,
+        capacity_len as u16,
+        storage_class_len as u16,
+        age_len as u16,
+    )
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn service_constraint_len_calculator(items: &[Service]) -> (u16, u16, u16, u16, u16, u16) {
+    let name_len = items
         .iter()
-        .map(Rs::description)
-        .flat_map(str::lines)
+        .map(Service::name)
         .map(UnicodeWidthStr::width)
         .max()
         .unwrap_or(0);
-    let owner_len = items
+    let type_len = items
         .iter()
-        .map(Rs::owner)
+        .map(Service::type_)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let cluster_ip_len = items
+        .iter()
+        .map(Service::cluster_ip)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let ports_len = items
+        .iter()
+        .map(Service::ports)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let endpoints_len = items
+        .iter()
+        .map(Service::endpoints)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let age_len = items
+        .iter()
+        .map(Service::age)
         .map(UnicodeWidthStr::width)
         .max()
         .unwrap_or(0);
 
     (
         name_len as u16,
-        pods_len as u16,
+        type_len as u16,
+        cluster_ip_len as u16,
+        ports_len as u16,
+        endpoints_len as u16,
         age_len as u16,
-        description_len as u16,
-        owner_len as u16,
     )
 }
 
 #[allow(clippy::cast_possible_truncation)]
-pub fn pod_constraint_len_calculator(items: &[RsPod]) -> (u16, u16, u16, u16, u16) {
+pub fn node_constraint_len_calculator(items: &[Node]) -> (u16, u16, u16, u16, u16) {
     let name_len = items
         .iter()
-        .map(RsPod::name)
+        .map(Node::name)
         .map(UnicodeWidthStr::width)
         .max()
         .unwrap_or(0);
     let status_len = items
         .iter()
-        .map(RsPod::status)
+        .map(Node::status)
         .map(UnicodeWidthStr::width)
         .max()
         .unwrap_or(0);
-    let description_len = items
+    let roles_len = items
         .iter()
-        .map(RsPod::description)
-        .flat_map(str::lines)
+        .map(Node::roles)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let version_len = items
+        .iter()
+        .map(Node::version)
         .map(UnicodeWidthStr::width)
         .max()
         .unwrap_or(0);
     let age_len = items
         .iter()
-        .map(RsPod::age)
+        .map(Node::age)
         .map(UnicodeWidthStr::width)
         .max()
         .unwrap_or(0);
-    let containers_len = items
+
+    (
+        name_len as u16,
+        status_len as u16,
+        roles_len as u16,
+        version_len as u16,
+        age_len as u16,
+    )
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn api_kind_constraint_len_calculator(items: &[ApiKind]) -> (u16, u16, u16) {
+    let kind_len = items
         .iter()
-        .map(RsPod::containers)
+        .map(ApiKind::kind)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let name_len = items
+        .iter()
+        .map(ApiKind::name)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let version_len = items
+        .iter()
+        .map(ApiKind::version)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+
+    (kind_len as u16, name_len as u16, version_len as u16)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn custom_resource_constraint_len_calculator(items: &[CustomResource]) -> (u16, u16, u16, u16) {
+    let name_len = items
+        .iter()
+        .map(CustomResource::name)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let status_len = items
+        .iter()
+        .map(CustomResource::status)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let ready_len = items
+        .iter()
+        .map(CustomResource::ready)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let age_len = items
+        .iter()
+        .map(CustomResource::age)
         .map(UnicodeWidthStr::width)
         .max()
         .unwrap_or(0);
@@ -589,9 +1843,109 @@ pub fn pod_constraint_len_calculator(items: &[RsPod]) -> (u16, u16, u16, u16, u1
     (
         name_len as u16,
         status_len as u16,
-        containers_len as u16,
+        ready_len as u16,
+        age_len as u16,
+    )
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn problem_constraint_len_calculator(items: &[Problem]) -> (u16, u16, u16, u16) {
+    let kind_len = items
+        .iter()
+        .map(Problem::kind)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let name_len = items
+        .iter()
+        .map(Problem::name)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let problem_len = items
+        .iter()
+        .map(Problem::problem)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let age_len = items
+        .iter()
+        .map(Problem::age)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+
+    (
+        kind_len as u16,
+        name_len as u16,
+        problem_len as u16,
+        age_len as u16,
+    )
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn namespace_constraint_len_calculator(items: &[Namespace]) -> (u16, u16, u16, u16) {
+    let name_len = items
+        .iter()
+        .map(Namespace::name)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let status_len = items
+        .iter()
+        .map(Namespace::status)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let age_len = items
+        .iter()
+        .map(Namespace::age)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+
+    (1, name_len as u16, status_len as u16, age_len as u16)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn job_constraint_len_calculator(items: &[Job]) -> (u16, u16, u16, u16, u16) {
+    let name_len = items
+        .iter()
+        .map(Job::name)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let kind_len = items
+        .iter()
+        .map(Job::kind)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let completions_len = items
+        .iter()
+        .map(Job::completions)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let status_len = items
+        .iter()
+        .map(Job::status)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let age_len = items
+        .iter()
+        .map(Job::age)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+
+    (
+        name_len as u16,
+        kind_len as u16,
+        completions_len as u16,
+        status_len as u16,
         age_len as u16,
-        description_len as u16,
     )
 }
 
@@ -676,13 +2030,110 @@ pub fn container_constraint_len_calculator(items: &[Container]) -> (u16, u16, u1
     )
 }
 
+#[allow(clippy::cast_possible_truncation)]
+pub fn config_constraint_len_calculator(items: &[ConfigData]) -> (u16, u16, u16, u16) {
+    let name_len = items
+        .iter()
+        .map(ConfigData::name)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let type_len = items
+        .iter()
+        .map(ConfigData::type_)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let keys_len = items
+        .iter()
+        .map(ConfigData::keys)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    let age_len = items
+        .iter()
+        .map(ConfigData::age)
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    (
+        name_len as u16,
+        type_len as u16,
+        keys_len as u16,
+        age_len as u16,
+    )
+}
+
+/// Footer text for the ReplicaSet table: how many there are and how many of their pods are ready
+pub fn rs_summary(items: &[Rs]) -> String {
+    let (ready, desired) = items.iter().filter_map(|rs| rs.pods.split_once('/')).fold(
+        (0, 0),
+        |(ready, desired), (r, d)| {
+            (
+                ready + r.parse::<i64>().unwrap_or(0),
+                desired + d.parse::<i64>().unwrap_or(0),
+            )
+        },
+    );
+    format!("{} ReplicaSets, {ready}/{desired} pods ready", items.len())
+}
+
+/// How many of `items` are in each status, e.g. `Running` or `Pending`
+pub fn status_counts(items: &[RsPod]) -> BTreeMap<&str, usize> {
+    let mut by_status: BTreeMap<&str, usize> = BTreeMap::new();
+    for pod in items {
+        *by_status.entry(pod.status()).or_default() += 1;
+    }
+    by_status
+}
+
+/// Footer text for the pod table: the total and a count per status
+pub fn pod_summary(items: &[RsPod]) -> String {
+    let counts = status_counts(items)
+        .iter()
+        .map(|(status, count)| format!("{count} {status}"))
+        .collect::<Vec<String>>()
+        .join(", ");
+    if counts.is_empty() {
+        format!("{} Pods", items.len())
+    } else {
+        format!("{} Pods: {counts}", items.len())
+    }
+}
+
+/// The most recently created of the pods in `items` that `previous` did not list yet
+pub fn newest_arrival<'a>(previous: &[RsPod], items: &'a [RsPod]) -> Option<&'a RsPod> {
+    items
+        .iter()
+        .filter(|pod| !previous.iter().any(|old| old.name == pod.name))
+        .max_by_key(|pod| pod.created)
+}
+
+/// Footer text for the container table
+pub fn container_summary(items: &[Container]) -> String {
+    format!("{} Containers", items.len())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tui::data::{
-        container_constraint_len_calculator, pod_constraint_len_calculator,
-        rs_constraint_len_calculator, Container, Rs, RsPod,
+        container_constraint_len_calculator, newest_arrival, pod_constraint_len_calculator,
+        pod_summary, rs_constraint_len_calculator, rs_summary, sort_namespaces, AgeBand,
+        ConfigEntry, Container, ContainerReadiness, Namespace, Rs, RsPod,
     };
 
+    #[test]
+    fn test_secret_config_entry_debug_is_redacted() {
+        let entry = ConfigEntry {
+            name: "password".to_string(),
+            value: "hunter2".to_string(),
+            secret: true,
+        };
+        let debug = format!("{entry:?}");
+        assert!(!debug.contains("hunter2"));
+        assert!(debug.contains("password"));
+    }
+
     #[test]
     fn test_container_constraint_len_calculator() {
         let test_data = vec![
@@ -690,9 +2141,16 @@ mod tests {
                 name: "replica-123456-123456".to_string(),
                 description: "Deployment".to_string(),
                 restarts: "0".to_string(),
+                readiness: ContainerReadiness::Ready,
                 image: "navicore/echo-secret-py:v0.1.1".to_string(),
                 ports: "http:1234".to_string(),
                 envvars: vec![],
+                probes: vec![],
+                restart_history: vec![],
+                state_reason: String::new(),
+                oom_killed: false,
+                memory_limit: "no memory limit".to_string(),
+                command: "image default entrypoint".to_string(),
                 mounts: vec![],
                 selectors: None,
                 pod_name: "my-pod-1234".to_string(),
@@ -701,9 +2159,16 @@ mod tests {
                 name: "replica-923450-987654".to_string(),
                 description: "Deployment".to_string(),
                 restarts: "0".to_string(),
+                readiness: ContainerReadiness::Ready,
                 image: "navicore/echo-secret-py:v0.1.1".to_string(),
                 ports: "http:1234".to_string(),
                 envvars: vec![],
+                probes: vec![],
+                restart_history: vec![],
+                state_reason: String::new(),
+                oom_killed: false,
+                memory_limit: "no memory limit".to_string(),
+                command: "image default entrypoint".to_string(),
                 mounts: vec![],
                 selectors: None,
                 pod_name: "my-pod-5678".to_string(),
@@ -732,8 +2197,18 @@ mod tests {
                 description: "Deployment".to_string(),
                 age: "150d".to_string(),
                 containers: "2/2".to_string(),
+                node: "node-a".to_string(),
+                ip: "10.0.1.17".to_string(),
+                qos: "Burstable".to_string(),
+                priority: "0".to_string(),
+                startup: String::new(),
+                reason: String::new(),
+                oom_killed: String::new(),
+                created: None,
+                protected: false,
                 selectors: None,
                 events: vec![],
+                scheduling: vec![],
             },
             RsPod {
                 name: "replica-923450-987654".to_string(),
@@ -741,16 +2216,29 @@ mod tests {
                 description: "Deployment".to_string(),
                 age: "10d".to_string(),
                 containers: "2/2".to_string(),
+                node: "ip-10-0-1-23.ec2.internal".to_string(),
+                ip: "-".to_string(),
+                qos: "BestEffort".to_string(),
+                priority: "0".to_string(),
+                startup: String::new(),
+                reason: String::new(),
+                oom_killed: String::new(),
+                created: None,
+                protected: false,
                 selectors: None,
                 events: vec![],
+                scheduling: vec![],
             },
         ];
         let (
             longest_pod_name_len,
             longest_status_len,
             longest_containers_len,
+            longest_qos_len,
             longest_age_len,
             longest_description_len,
+            longest_ip_len,
+            longest_node_len,
         ) = pod_constraint_len_calculator(&test_data);
 
         assert_eq!(21, longest_pod_name_len);
@@ -758,6 +2246,9 @@ mod tests {
         assert_eq!(10, longest_description_len);
         assert_eq!(4, longest_age_len);
         assert_eq!(3, longest_containers_len);
+        assert_eq!(10, longest_qos_len);
+        assert_eq!(9, longest_ip_len);
+        assert_eq!(25, longest_node_len);
     }
     #[test]
     fn test_rs_constraint_len_calculator() {
@@ -768,8 +2259,13 @@ mod tests {
                 description: "Deployment".to_string(),
                 age: "300d".to_string(),
                 pods: "10/10".to_string(),
+                revision: "12".to_string(),
+                current: true,
                 selectors: None,
                 events: vec![],
+                autoscaler: None,
+                paused: false,
+                rollout: None,
             },
             Rs {
                 name: "my-replica-923450".to_string(),
@@ -777,13 +2273,19 @@ mod tests {
                 description: "Deployment".to_string(),
                 age: "10d".to_string(),
                 pods: "1/1".to_string(),
+                revision: "3".to_string(),
+                current: false,
                 selectors: None,
                 events: vec![],
+                autoscaler: None,
+                paused: false,
+                rollout: None,
             },
         ];
         let (
             longest_name_len,
             longest_pods_len,
+            longest_revision_len,
             longest_age_len,
             longest_description_len,
             longest_owner_len,
@@ -794,5 +2296,148 @@ mod tests {
         assert_eq!(10, longest_description_len);
         assert_eq!(4, longest_age_len);
         assert_eq!(5, longest_pods_len);
+        assert_eq!(2, longest_revision_len);
+    }
+
+    #[test]
+    fn test_rs_summary_totals_pods() {
+        let rs = |pods: &str| Rs {
+            name: "my-replica-123456".to_string(),
+            owner: "my-replica".to_string(),
+            description: "Deployment".to_string(),
+            age: "10d".to_string(),
+            pods: pods.to_string(),
+            revision: "1".to_string(),
+            current: true,
+            selectors: None,
+            events: vec![],
+            autoscaler: None,
+            paused: false,
+            rollout: None,
+        };
+        let summary = rs_summary(&[rs("2/3"), rs("1/1")]);
+        assert_eq!("2 ReplicaSets, 3/4 pods ready", summary);
+    }
+
+    #[test]
+    fn test_pod_summary_counts_by_status() {
+        let pod = |status: &str| RsPod {
+            name: "replica-123456-123456".to_string(),
+            status: status.to_string(),
+            description: "Deployment".to_string(),
+            age: "1d".to_string(),
+            containers: "1/1".to_string(),
+            node: "node-a".to_string(),
+            ip: "10.0.1.17".to_string(),
+            qos: "Burstable".to_string(),
+            priority: "0".to_string(),
+            startup: String::new(),
+            reason: String::new(),
+            oom_killed: String::new(),
+            created: None,
+            protected: false,
+            selectors: None,
+            events: vec![],
+            scheduling: vec![],
+        };
+        let summary = pod_summary(&[pod("Running"), pod("Pending"), pod("Running")]);
+        assert_eq!("3 Pods: 1 Pending, 2 Running", summary);
+    }
+
+    #[test]
+    fn test_newest_arrival_picks_the_latest_new_pod() {
+        use chrono::{Duration, Utc};
+
+        let now = Utc::now();
+        let pod = |name: &str, minutes: i64| RsPod {
+            name: name.to_string(),
+            status: "Running".to_string(),
+            description: "Deployment".to_string(),
+            age: "1m".to_string(),
+            containers: "1/1".to_string(),
+            node: "node-a".to_string(),
+            ip: "10.0.1.17".to_string(),
+            qos: "Burstable".to_string(),
+            priority: "0".to_string(),
+            startup: String::new(),
+            reason: String::new(),
+            oom_killed: String::new(),
+            created: Some(now - Duration::minutes(minutes)),
+            protected: false,
+            selectors: None,
+            events: vec![],
+            scheduling: vec![],
+        };
+        let previous = vec![pod("api-old", 1)];
+        let items = vec![pod("api-old", 1), pod("api-a", 3), pod("api-b", 2)];
+        assert_eq!(
+            newest_arrival(&previous, &items).map(|pod| pod.name.as_str()),
+            Some("api-b")
+        );
+        assert!(newest_arrival(&items, &items).is_none());
+    }
+
+    #[test]
+    fn test_age_band_uses_both_thresholds() {
+        use chrono::Utc;
+        use std::time::Duration;
+
+        let now = Utc::now();
+        let pod = |age: chrono::Duration| RsPod {
+            name: "api-a".to_string(),
+            status: "Running".to_string(),
+            description: "Deployment".to_string(),
+            age: String::new(),
+            containers: "1/1".to_string(),
+            node: "node-a".to_string(),
+            ip: "10.0.1.17".to_string(),
+            qos: "Burstable".to_string(),
+            priority: "0".to_string(),
+            startup: String::new(),
+            reason: String::new(),
+            oom_killed: String::new(),
+            created: Some(now - age),
+            protected: false,
+            selectors: None,
+            events: vec![],
+            scheduling: vec![],
+        };
+        let new = Some(Duration::from_secs(120));
+        let old = Some(Duration::from_secs(30 * 24 * 60 * 60));
+        let band = |pod: &RsPod| pod.age_band(now, new, old);
+        assert_eq!(
+            band(&pod(chrono::Duration::seconds(30))),
+            Some(AgeBand::New)
+        );
+        assert_eq!(
+            band(&pod(chrono::Duration::seconds(-30))),
+            Some(AgeBand::New)
+        );
+        assert_eq!(band(&pod(chrono::Duration::hours(5))), None);
+        assert_eq!(band(&pod(chrono::Duration::days(40))), Some(AgeBand::Old));
+        assert_eq!(
+            pod(chrono::Duration::days(40)).age_band(now, new, None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_sort_namespaces_pinned_first() {
+        let namespace = |name: &str| Namespace {
+            pin: String::new(),
+            name: name.to_string(),
+            status: "Active".to_string(),
+            age: "1d".to_string(),
+        };
+        let mut items = vec![
+            namespace("kube-system"),
+            namespace("zeta"),
+            namespace("app"),
+        ];
+        sort_namespaces(&mut items, &["zeta".to_string()]);
+        let names: Vec<&str> = items.iter().map(|ns| ns.name.as_str()).collect();
+        assert_eq!(names, vec!["zeta", "app", "kube-system"]);
+        assert_eq!(items[0].pin, "★");
+        assert!(items[1].pin.is_empty());
     }
 }
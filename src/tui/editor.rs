@@ -0,0 +1,306 @@
+use crate::config;
+use crate::error::Error;
+use crate::k8s::manifests::{apply_manifest, diff_lines, get_manifest};
+use crate::tui::status::StatusMessage;
+use crate::tui::stream::pause_key_events;
+use crate::tui::ui_loop::{enter_tui, leave_tui};
+use crate::tui::widgets::ConfirmModal;
+use k8s_openapi::NamespaceResourceScope;
+use kube::Resource;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::env;
+use std::fmt::Debug;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::warn;
+
+/// Most changed lines a dry run confirmation lists
+const MAX_PREVIEW_LINES: usize = 20;
+
+/// set after the editor had the terminal, the next draw must repaint everything
+static NEEDS_REPAINT: AtomicBool = AtomicBool::new(false);
+
+/// true once after the terminal was handed to another program
+pub fn take_repaint() -> bool {
+    NEEDS_REPAINT.swap(false, Ordering::Relaxed)
+}
+
+/// The manifest handed to the editor, removed again however the edit ends
+struct TempManifest {
+    path: PathBuf,
+}
+
+impl TempManifest {
+    /// Write `text` to a new file only the current user can read. `create_new` refuses
+    /// a file or symlink already sitting at the path instead of writing through it.
+    fn create(name: &str, text: &str) -> io::Result<Self> {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.subsec_nanos());
+        let path = env::temp_dir().join(format!(
+            "navipod-{}-{nanos}-{name}.yaml",
+            std::process::id()
+        ));
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        let mut file = options.open(&path)?;
+        // from here on the guard removes the file, even when writing it fails
+        let manifest = Self { path };
+        file.write_all(text.as_bytes())?;
+        Ok(manifest)
+    }
+}
+
+impl Drop for TempManifest {
+    fn drop(&mut self) {
+        _ = fs::remove_file(&self.path);
+    }
+}
+
+/// The terminal handed to another program, taken back when dropped
+struct SuspendedTui;
+
+impl SuspendedTui {
+    fn begin() -> io::Result<Self> {
+        pause_key_events(true);
+        // built before leaving so a failed switch still unpauses the keys
+        let suspended = Self;
+        leave_tui()?;
+        Ok(suspended)
+    }
+}
+
+impl Drop for SuspendedTui {
+    fn drop(&mut self) {
+        if let Err(e) = enter_tui() {
+            warn!("could not take the terminal back from the editor: {e}");
+        }
+        NEEDS_REPAINT.store(true, Ordering::Relaxed);
+        pause_key_events(false);
+    }
+}
+
+/// `$EDITOR` split on whitespace the way kubectl does, so `code --wait` works, and vi
+/// when it is unset or blank
+fn editor_command() -> (String, Vec<String>) {
+    let editor = env::var("EDITOR").unwrap_or_default();
+    let mut words = editor.split_whitespace().map(str::to_string);
+    let program = words.next().unwrap_or_else(|| "vi".to_string());
+    (program, words.collect())
+}
+
+/// Suspend the TUI and let `$EDITOR` (vi when unset) change `text`, returning the
+/// edited text or `None` when it was saved unchanged
+fn edit_text(name: &str, text: &str) -> io::Result<Option<String>> {
+    let manifest = TempManifest::create(name, text)?;
+    let (editor, args) = editor_command();
+
+    let status = {
+        let _suspended = SuspendedTui::begin()?;
+        // the editor owns the terminal until it exits, so block this worker rather than
+        // let the ui draw underneath it
+        tokio::task::block_in_place(|| {
+            Command::new(&editor)
+                .args(&args)
+                .arg(&manifest.path)
+                .status()
+        })?
+    };
+    if !status.success() {
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!("{editor} exited with {status}"),
+        ));
+    }
+    let edited = fs::read_to_string(&manifest.path)?;
+    Ok((edited != text).then_some(edited))
+}
+
+/// A manifest changed in `$EDITOR` and not applied yet
+#[derive(Clone, Debug)]
+pub struct EditedManifest {
+    /// e.g. `pod`
+    pub what: String,
+    pub name: String,
+    pub yaml: String,
+    /// take over the fields other managers own instead of failing with a conflict
+    pub force: bool,
+}
+
+/// Where an edit ended up
+pub enum EditOutcome {
+    Done(StatusMessage),
+    /// the dry run under `--dry-run-first`, `y` applies the edit for real
+    Preview(ConfirmModal<EditedManifest>),
+    /// the api server refused the edit over fields another manager owns, `y` applies it
+    /// again with force
+    Conflict(StatusMessage, ConfirmModal<EditedManifest>),
+}
+
+impl EditOutcome {
+    /// The status line and the confirmation to show for this outcome
+    pub fn into_parts(self) -> (Option<StatusMessage>, Option<ConfirmModal<EditedManifest>>) {
+        match self {
+            Self::Done(status) => (Some(status), None),
+            Self::Preview(modal) => (None, Some(modal)),
+            Self::Conflict(status, modal) => (Some(status), Some(modal)),
+        }
+    }
+}
+
+/// Fetch the named resource as YAML, open it in `$EDITOR` and apply the result with a
+/// server side apply patch, describing the outcome for the status line. Under
+/// `--dry-run-first` the edit is only sent as a dry run, and what the api server would
+/// change is returned for confirmation.
+pub async fn edit_resource<K>(what: &str, name: &str) -> EditOutcome
+where
+    K: Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug + Serialize,
+    K::DynamicType: Default,
+{
+    let manifest = match get_manifest::<K>(name).await {
+        Ok(manifest) => manifest,
+        Err(e) => return EditOutcome::Done(StatusMessage::Error(e.to_string())),
+    };
+    let edited = match edit_text(name, &manifest) {
+        Ok(Some(edited)) => EditedManifest {
+            what: what.to_string(),
+            name: name.to_string(),
+            yaml: edited,
+            force: false,
+        },
+        Ok(None) => {
+            return EditOutcome::Done(StatusMessage::Info(format!("{what} {name} unchanged")))
+        }
+        Err(e) => {
+            return EditOutcome::Done(StatusMessage::Error(format!(
+                "could not edit {what} {name}: {e}"
+            )))
+        }
+    };
+    if !config::current().dry_run_first {
+        return apply_edit::<K>(edited).await;
+    }
+    match apply_manifest::<K>(name, &edited.yaml, true, false).await {
+        Ok(result) => EditOutcome::Preview(preview_confirm(&manifest, &result, edited)),
+        Err(e) if is_conflict(&e) => conflict(&e, edited),
+        Err(e) => EditOutcome::Done(StatusMessage::Error(format!(
+            "{what} {name} not applied, the dry run failed: {e}"
+        ))),
+    }
+}
+
+/// Apply `edited` for real, describing the outcome for the status line
+pub async fn apply_edit<K>(edited: EditedManifest) -> EditOutcome
+where
+    K: Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug + Serialize,
+    K::DynamicType: Default,
+{
+    let EditedManifest { what, name, .. } = &edited;
+    match apply_manifest::<K>(name, &edited.yaml, false, edited.force).await {
+        Ok(_) => EditOutcome::Done(StatusMessage::Info(format!("applied {what} {name}"))),
+        Err(e) if !edited.force && is_conflict(&e) => conflict(&e, edited),
+        Err(e) => EditOutcome::Done(StatusMessage::Error(format!(
+            "{what} {name} not applied: {e}"
+        ))),
+    }
+}
+
+/// true when the api server refused an apply because another field manager owns
+/// fields it changes
+fn is_conflict(err: &Error) -> bool {
+    matches!(err, Error::Kube(kube::Error::Api(response)) if response.code == 409)
+}
+
+/// The conflict `err` for the status line, with a confirmation to apply `edited` again
+/// with force
+fn conflict(err: &Error, edited: EditedManifest) -> EditOutcome {
+    let status = StatusMessage::Error(format!(
+        "{} {} not applied, it conflicts with another manager: {err}",
+        edited.what, edited.name
+    ));
+    let title = format!("Force apply {} {}?", edited.what, edited.name);
+    let lines = vec![
+        "Other field managers own fields this edit changes.".to_string(),
+        "Forcing takes them over, like kubectl apply --force-conflicts.".to_string(),
+        String::new(),
+        "y to force apply, any other key to cancel".to_string(),
+    ];
+    let edited = EditedManifest {
+        force: true,
+        ..edited
+    };
+    EditOutcome::Conflict(status, ConfirmModal::new(edited, title, lines))
+}
+
+/// The confirmation listing what the dry run of `edited` changed from `before` into
+/// `after`
+fn preview_confirm(
+    before: &str,
+    after: &str,
+    edited: EditedManifest,
+) -> ConfirmModal<EditedManifest> {
+    let changes = diff_lines(before, after);
+    let mut lines = if changes.is_empty() {
+        vec!["Dry run accepted, the api server would change nothing".to_string()]
+    } else {
+        vec!["Dry run accepted, the api server would change:".to_string()]
+    };
+    lines.extend(changes.iter().take(MAX_PREVIEW_LINES).cloned());
+    if changes.len() > MAX_PREVIEW_LINES {
+        lines.push(format!(
+            "… {} more changed lines",
+            changes.len() - MAX_PREVIEW_LINES
+        ));
+    }
+    lines.push(String::new());
+    lines.push("y to apply, any other key to cancel".to_string());
+    let title = format!("Apply {} {}?", edited.what, edited.name);
+    ConfirmModal::new(edited, title, lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_conflict, TempManifest};
+    use crate::error::Error;
+    use kube::error::ErrorResponse;
+    use std::fs;
+
+    fn api_error(code: u16) -> Error {
+        Error::Kube(kube::Error::Api(ErrorResponse {
+            status: "Failure".to_string(),
+            message: String::new(),
+            reason: String::new(),
+            code,
+        }))
+    }
+
+    #[test]
+    fn test_is_conflict() {
+        assert!(is_conflict(&api_error(409)));
+        assert!(!is_conflict(&api_error(422)));
+    }
+
+    #[test]
+    fn test_temp_manifest_is_private_and_removed() {
+        let manifest = TempManifest::create("web", "kind: Pod\n").unwrap();
+        let path = manifest.path.clone();
+        assert_eq!("kind: Pod\n", fs::read_to_string(&path).unwrap());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(0o600, mode & 0o777);
+        }
+        drop(manifest);
+        assert!(!path.exists());
+    }
+}
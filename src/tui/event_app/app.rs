@@ -1,10 +1,14 @@
+use crate::config::{self, PollView};
+use crate::k8s::errors::list_error_message;
 use crate::k8s::events::list_all;
 use crate::tui::data::{event_constraint_len_calculator, ResourceEvent};
 use crate::tui::event_app;
-use crate::tui::stream::Message;
-use crate::tui::style::{TableColors, ITEM_HEIGHT, PALETTES};
-use crate::tui::table_ui::TuiTableState;
+use crate::tui::status::StatusMessage;
+use crate::tui::stream::{request_refresh, spawn_task, wait_for_poll, Message};
+use crate::tui::style::{item_height, TableColors, PALETTES};
+use crate::tui::table_ui::{cycle_column_fit, step_since, TuiTableState};
 use crate::tui::ui_loop::{AppBehavior, Apps};
+use chrono::{DateTime, Local};
 use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
 use futures::Stream;
 use ratatui::prelude::*;
@@ -12,25 +16,67 @@ use ratatui::widgets::{ScrollbarState, TableState};
 use std::io;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
 use tokio::sync::mpsc;
-use tokio::time::sleep;
 use tokio_stream::wrappers::ReceiverStream;
 use tracing::debug;
 
-const POLL_MS: u64 = 5000;
+/// The order of the events view, stepped with `o`
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum EventSort {
+    /// as the api lists them, most recently seen first
+    #[default]
+    Newest,
+    /// most repeated first, e.g. a `FailedScheduling` seen 200 times
+    Count,
+    /// grouped by reason, most recent first within a reason
+    Reason,
+}
+
+impl EventSort {
+    const fn next(self) -> Self {
+        match self {
+            Self::Newest => Self::Count,
+            Self::Count => Self::Reason,
+            Self::Reason => Self::Newest,
+        }
+    }
+
+    pub(crate) const fn label(self) -> &'static str {
+        match self {
+            Self::Newest => "newest first",
+            Self::Count => "most repeated first",
+            Self::Reason => "grouped by reason",
+        }
+    }
+
+    /// `items` in this order. The sorts are stable, so ties stay newest first.
+    fn apply(self, items: &[ResourceEvent]) -> Vec<ResourceEvent> {
+        let mut items = items.to_vec();
+        match self {
+            Self::Newest => {}
+            Self::Count => items.sort_by_key(|event| std::cmp::Reverse(event.times_seen())),
+            Self::Reason => items.sort_by(|a, b| a.reason.cmp(&b.reason)),
+        }
+        items
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct App {
     pub(crate) state: TableState,
     pub(crate) items: Vec<ResourceEvent>,
-    pub(crate) longest_item_lens: (u16, u16, u16, u16, u16),
+    pub(crate) longest_item_lens: (u16, u16, u16, u16, u16, u16),
     pub(crate) scroll_state: ScrollbarState,
+    pub(crate) table_area: Rect,
     pub(crate) colors: TableColors,
     color_index: usize,
     pub(crate) filter: String,
     pub(crate) show_filter_edit: bool,
     pub(crate) edit_filter_cursor_position: usize,
+    pub(crate) status: Option<StatusMessage>,
+    /// when data was last applied, for the status bar
+    pub(crate) refreshed: Option<DateTime<Local>>,
+    pub(crate) sort: EventSort,
 }
 
 impl TuiTableState for App {
@@ -52,6 +98,14 @@ impl TuiTableState for App {
         self.scroll_state = scroll_state;
     }
 
+    fn get_table_area(&self) -> Rect {
+        self.table_area
+    }
+
+    fn set_table_area(&mut self, table_area: Rect) {
+        self.table_area = table_area;
+    }
+
     fn set_table_colors(&mut self, colors: TableColors) {
         self.colors = colors;
     }
@@ -66,7 +120,7 @@ impl TuiTableState for App {
 
     fn reset_selection_state(&mut self) {
         self.state = TableState::default().with_selected(0);
-        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * ITEM_HEIGHT);
+        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * item_height());
     }
 
     fn get_filter(&self) -> String {
@@ -106,7 +160,7 @@ impl AppBehavior for event_app::app::App {
     }
 
     fn draw_ui<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<(), std::io::Error> {
-        terminal.draw(|f| event_app::ui::ui(f, &mut self.clone()))?;
+        terminal.draw(|f| event_app::ui::ui(f, self))?;
         Ok(())
     }
 
@@ -115,24 +169,30 @@ impl AppBehavior for event_app::app::App {
 
         let initial_items = self.get_items().to_vec();
 
-        tokio::spawn(async move {
+        spawn_task(async move {
+            let mut forced = false;
             while !should_stop.load(Ordering::Relaxed) {
+                // read each time so a change in the settings overlay applies right away
+                let poll_interval = config::current().poll_interval_for(PollView::Events);
                 //get Vec and send
-                match list_all().await {
+                match list_all(config::current().since).await {
                     Ok(d) => {
-                        if !d.is_empty() && d != initial_items {
+                        // a manual refresh is reported even when nothing changed
+                        if forced || (!d.is_empty() && d != initial_items) {
                             let sevent = Message::Event(d);
                             if tx.send(sevent).await.is_err() {
                                 break;
                             }
                         }
-                        sleep(Duration::from_millis(POLL_MS)).await;
+                        forced = wait_for_poll(poll_interval).await;
                     }
-                    Err(_e) => {
+                    Err(e) => {
+                        _ = tx
+                            .send(Message::Error(list_error_message(&e, "events")))
+                            .await;
                         break;
                     }
                 }
-                sleep(Duration::from_millis(POLL_MS)).await;
             }
         });
 
@@ -146,17 +206,24 @@ impl App {
         Self {
             state: TableState::default().with_selected(0),
             longest_item_lens: event_constraint_len_calculator(&data_vec),
-            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * ITEM_HEIGHT),
+            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * item_height()),
             colors: TableColors::new(&PALETTES[0]),
+            table_area: Rect::default(),
             color_index: 3,
             items: data_vec,
             filter: String::new(),
+            refreshed: None,
             show_filter_edit: false,
             edit_filter_cursor_position: 0,
+            status: None,
+            sort: EventSort::default(),
         }
     }
 
     fn handle_table_event(&mut self, event: &Message) -> Option<Apps> {
+        if matches!(event, Message::Key(Event::Key(_))) {
+            self.status = None;
+        }
         let mut app_holder = Some(Apps::Event { app: self.clone() });
         match event {
             Message::Key(Event::Key(key)) => {
@@ -179,6 +246,19 @@ impl App {
                             self.next_color();
                             app_holder = Some(Apps::Event { app: self.clone() });
                         }
+                        Char('r' | 'R') => {
+                            request_refresh();
+                            self.status = Some(StatusMessage::Info("refreshing…".to_string()));
+                            app_holder = Some(Apps::Event { app: self.clone() });
+                        }
+                        Char('a' | 'A') => {
+                            let fit = cycle_column_fit();
+                            self.status = Some(StatusMessage::Info(format!(
+                                "column widths: {}",
+                                fit.label()
+                            )));
+                            app_holder = Some(Apps::Event { app: self.clone() });
+                        }
                         Char('f' | 'F') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                             self.page_forward();
                         }
@@ -188,6 +268,24 @@ impl App {
                         Enter => {
                             // noop for now but will be pretty printed detail analysis popup
                         }
+                        Char('s' | 'S') => {
+                            self.status = Some(step_since());
+                            app_holder = Some(Apps::Event { app: self.clone() });
+                        }
+                        Char('o' | 'O') => {
+                            self.sort = self.sort.next();
+                            if self.sort == EventSort::Newest {
+                                // the next list brings back the api server's order
+                                request_refresh();
+                            } else {
+                                let selected = self.get_selected_key();
+                                self.items = self.sort.apply(&self.items);
+                                self.select_key(selected.as_deref());
+                            }
+                            self.status =
+                                Some(StatusMessage::Info(format!("events {}", self.sort.label())));
+                            app_holder = Some(Apps::Event { app: self.clone() });
+                        }
                         Char('/') => {
                             self.set_show_filter_edit(true);
                             app_holder = Some(Apps::Event { app: self.clone() });
@@ -196,18 +294,34 @@ impl App {
                     }
                 }
             }
+            Message::Key(Event::Mouse(mouse)) => {
+                if self.handle_mouse_event(mouse) {
+                    app_holder = Some(Apps::Event { app: self.clone() });
+                }
+            }
             Message::Event(data_vec) => {
-                let new_app = Self {
+                let selected = self.get_selected_key();
+                let mut new_app = Self {
                     longest_item_lens: event_constraint_len_calculator(data_vec),
                     scroll_state: ScrollbarState::new(
-                        data_vec.len().saturating_sub(1) * ITEM_HEIGHT,
+                        data_vec.len().saturating_sub(1) * item_height(),
                     ),
-                    items: data_vec.clone(),
+                    items: self.sort.apply(data_vec),
+                    refreshed: Some(Local::now()),
                     ..self.clone()
                 };
+                new_app.select_key(selected.as_deref());
                 let new_app_holder = Apps::Event { app: new_app };
                 app_holder = Some(new_app_holder);
             }
+            Message::Error(message) => {
+                self.status = Some(StatusMessage::Error(message.clone()));
+                app_holder = Some(Apps::Event { app: self.clone() });
+            }
+            Message::Key(Event::Resize(_, _)) => {
+                self.handle_resize();
+                app_holder = Some(Apps::Event { app: self.clone() });
+            }
             _ => {}
         }
         app_holder
@@ -218,7 +332,7 @@ impl App {
         match event {
             Message::Key(Event::Key(key)) => {
                 if key.kind == KeyEventKind::Press {
-                    use KeyCode::{Backspace, Char, Enter, Esc, Left, Right};
+                    use KeyCode::{Backspace, Char, Enter, Esc, Left, Right, Tab};
 
                     match key.code {
                         Char(to_insert) => {
@@ -241,23 +355,38 @@ impl App {
                             self.set_show_filter_edit(false);
                             app_holder = Some(Apps::Event { app: self.clone() });
                         }
+                        Tab => {
+                            self.toggle_filter_mode();
+                            app_holder = Some(Apps::Event { app: self.clone() });
+                        }
                         _ => {}
                     }
                 }
             }
             Message::Event(data_vec) => {
                 debug!("updating event app data...");
-                let new_app = Self {
+                let selected = self.get_selected_key();
+                let mut new_app = Self {
                     longest_item_lens: event_constraint_len_calculator(data_vec),
                     scroll_state: ScrollbarState::new(
-                        data_vec.len().saturating_sub(1) * ITEM_HEIGHT,
+                        data_vec.len().saturating_sub(1) * item_height(),
                     ),
-                    items: data_vec.clone(),
+                    items: self.sort.apply(data_vec),
+                    refreshed: Some(Local::now()),
                     ..self.clone()
                 };
+                new_app.select_key(selected.as_deref());
                 let new_app_holder = Apps::Event { app: new_app };
                 app_holder = Some(new_app_holder);
             }
+            Message::Error(message) => {
+                self.status = Some(StatusMessage::Error(message.clone()));
+                app_holder = Some(Apps::Event { app: self.clone() });
+            }
+            Message::Key(Event::Resize(_, _)) => {
+                self.handle_resize();
+                app_holder = Some(Apps::Event { app: self.clone() });
+            }
             _ => {}
         }
         app_holder
@@ -269,3 +398,55 @@ impl Default for App {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::EventSort;
+    use crate::tui::data::ResourceEvent;
+
+    fn event(reason: &str, count: &str) -> ResourceEvent {
+        ResourceEvent {
+            resource_name: String::new(),
+            object: "api-7d9f-x2k4p".to_string(),
+            message: format!("{reason} x{count}"),
+            reason: reason.to_string(),
+            type_: "Warning".to_string(),
+            count: count.to_string(),
+            age: "1m".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_event_sort_keeps_newest_first_among_ties() {
+        let items = [
+            event("Pulled", "1"),
+            event("FailedScheduling", "200"),
+            event("BackOff", "3"),
+            event("Pulled", "3"),
+        ];
+        let messages = |sort: EventSort| {
+            sort.apply(&items)
+                .into_iter()
+                .map(|event| event.message)
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(
+            messages(EventSort::Count),
+            [
+                "FailedScheduling x200",
+                "BackOff x3",
+                "Pulled x3",
+                "Pulled x1"
+            ]
+        );
+        assert_eq!(
+            messages(EventSort::Reason),
+            [
+                "BackOff x3",
+                "FailedScheduling x200",
+                "Pulled x1",
+                "Pulled x3"
+            ]
+        );
+    }
+}
@@ -1,5 +1,13 @@
+use crate::config;
+use crate::tui::data::Filterable;
 use crate::tui::event_app::app::App;
-use crate::tui::table_ui::TuiTableState;
+use crate::tui::status::{render_status_bar, render_status_line};
+use crate::tui::style::RowLayout;
+use crate::tui::table_ui::{
+    column_constraints, filter_cell, filter_edit_title, fit_cell_text, render_breadcrumb,
+    render_too_small, since_text, TuiTableState,
+};
+use crate::tui::widgets::centered_rect;
 use ratatui::{
     prelude::*,
     widgets::{
@@ -9,9 +17,21 @@ use ratatui::{
 };
 
 pub fn ui(f: &mut Frame, app: &mut App) {
-    let rects = Layout::vertical([Constraint::Min(5)]).split(f.area());
+    if render_too_small(f) {
+        return;
+    }
+    let [crumb_area, view_area, bar_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(f.area());
+
+    let rects = Layout::vertical([Constraint::Min(5)]).split(view_area);
 
     app.set_colors();
+    let breadcrumb = app.breadcrumb("Event");
+    render_breadcrumb(f, &app.colors, crumb_area, &breadcrumb);
 
     render_table(f, app, rects[0]);
 
@@ -20,6 +40,17 @@ pub fn ui(f: &mut Frame, app: &mut App) {
     if app.get_show_filter_edit() {
         render_filter_edit(f, app);
     }
+
+    let mut bar = app.status_bar("event", app.refreshed);
+    bar.push_str(&format!(" | {}", app.sort.label()));
+    if let Some(since) = config::current().since {
+        bar.push_str(&format!(" | {}", since_text(Some(since))));
+    }
+    render_status_bar(f, &app.colors, bar_area, &bar);
+
+    if let Some(status) = &app.status {
+        render_status_line(f, &app.colors, status);
+    }
 }
 
 fn render_filter_edit(f: &mut Frame, app: &App) {
@@ -35,7 +66,7 @@ fn render_filter_edit(f: &mut Frame, app: &App) {
     let block = Paragraph::new(app.filter.as_str()).style(edit_style).block(
         Block::default()
             .borders(Borders::ALL)
-            .title("Edit Filter - try: (java|api)"),
+            .title(filter_edit_title()),
     );
 
     f.render_widget(Clear, input_area); //this clears out the background
@@ -49,39 +80,32 @@ fn render_filter_edit(f: &mut Frame, app: &App) {
     f.set_cursor_position(p);
 }
 
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::vertical([
-        Constraint::Percentage((100 - percent_y) / 2),
-        Constraint::Percentage(percent_y),
-        Constraint::Percentage((100 - percent_y) / 2),
-    ])
-    .split(r);
-
-    Layout::horizontal([
-        Constraint::Percentage((100 - percent_x) / 2),
-        Constraint::Percentage(percent_x),
-        Constraint::Percentage((100 - percent_x) / 2),
-    ])
-    .split(popup_layout[1])[1]
-}
-
 fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let config = config::current();
+    let fit = config.column_fit;
+    let layout = RowLayout::new(&config);
+    app.set_table_area(area);
+
     let header_style = Style::default()
         .fg(app.colors.header_fg)
         .bg(app.colors.header_bg);
     let selected_style = Style::default()
         .add_modifier(Modifier::REVERSED)
         .fg(app.colors.selected_style_fg);
+    let highlight = Style::new()
+        .fg(app.colors.selected_style_fg)
+        .add_modifier(Modifier::BOLD);
 
-    let header = ["Resource", "Message", "Reason", "Type", "Age"]
+    let header = ["Resource", "Message", "Reason", "Type", "Count", "Age"]
         .iter()
         .copied()
         .map(Cell::from)
         .collect::<Row>()
         .style(header_style)
         .height(1);
-    let rows = app
-        .get_filtered_items()
+    let row_filter = app.row_filter();
+    let rows = row_filter
+        .filter(app.get_items())
         .into_iter()
         .enumerate()
         .map(|(i, data)| {
@@ -89,29 +113,43 @@ fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
                 0 => app.colors.normal_row_color,
                 _ => app.colors.alt_row_color,
             };
+            let matched = row_filter.match_indices(data.filter_by());
             let item = data.ref_array();
             item.iter()
                 .copied()
-                .map(|content| Cell::from(Text::from(format!("\n{content}\n"))))
+                .map(|content| {
+                    filter_cell(
+                        &fit_cell_text(fit, content, area.width),
+                        data.filter_by(),
+                        &matched,
+                        highlight,
+                        layout,
+                    )
+                })
                 .collect::<Row>()
                 .style(Style::new().fg(app.colors.row_fg).bg(color))
-                .height(3)
+                .height(layout.height())
         });
-    let bar = " █ ";
+    let bar = layout.selection_symbol();
     let t = Table::new(
         rows,
-        [
-            // + 1 is for padding.
-            Constraint::Min(app.longest_item_lens.0 + 1),
-            Constraint::Min(app.longest_item_lens.1 + 1),
-            Constraint::Min(app.longest_item_lens.2 + 1),
-            Constraint::Min(app.longest_item_lens.3 + 1),
-            Constraint::Min(app.longest_item_lens.4),
-        ],
+        column_constraints(
+            fit,
+            &[
+                // + 1 is for padding.
+                app.longest_item_lens.0 + 1,
+                app.longest_item_lens.1 + 1,
+                app.longest_item_lens.2 + 1,
+                app.longest_item_lens.3 + 1,
+                app.longest_item_lens.4 + 1,
+                app.longest_item_lens.5,
+            ],
+            area.width,
+        ),
     )
     .header(header)
     .row_highlight_style(selected_style)
-    .highlight_symbol(Text::from(vec!["".into(), bar.into(), "".into()]))
+    .highlight_symbol(layout.text(bar.to_string()))
     .bg(app.colors.buffer_bg)
     .highlight_spacing(HighlightSpacing::Always);
     f.render_stateful_widget(t, area, &mut app.state);
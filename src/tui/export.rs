@@ -0,0 +1,39 @@
+use crate::config::{self, ExportFormat};
+use crate::tui::status::StatusMessage;
+use chrono::Local;
+use k8s_openapi::serde_json;
+use serde::Serialize;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Write `items` to a timestamped file in the working directory using the
+/// configured `--export-format`.
+///
+/// # Errors
+///
+/// Will return `Err` if the items can not be serialized or the file can not be written
+pub fn write_items<T: Serialize>(view: &str, items: &[T]) -> io::Result<PathBuf> {
+    let format = config::current().export_format;
+    let contents = match format {
+        ExportFormat::Json => serde_json::to_string_pretty(items).map_err(io::Error::other)?,
+        ExportFormat::Yaml => serde_yaml::to_string(items).map_err(io::Error::other)?,
+    };
+
+    let file_name = format!(
+        "navipod-{view}-{}.{}",
+        Local::now().format("%Y%m%d-%H%M%S"),
+        format.extension()
+    );
+    let path = std::env::current_dir()?.join(file_name);
+    fs::write(&path, contents)?;
+    Ok(path)
+}
+
+/// Export `items` and describe the outcome for the status line
+pub fn export_items<T: Serialize>(view: &str, items: &[T]) -> StatusMessage {
+    match write_items(view, items) {
+        Ok(path) => StatusMessage::Info(format!("wrote {}", path.display())),
+        Err(e) => StatusMessage::Error(format!("could not export {view}: {e}")),
+    }
+}
@@ -0,0 +1,172 @@
+use crate::tui::data::FinderEntry;
+use crate::tui::style::TableColors;
+use crate::tui::widgets::centered_rect;
+use crossterm::event::{KeyCode, KeyEvent};
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+/// most results the overlay lists at once
+const MAX_RESULTS: usize = 20;
+
+/// What a key press in the finder asks the owning view to do
+pub enum FinderAction {
+    Stay,
+    Close,
+    Jump(FinderEntry),
+}
+
+/// The global finder: a query typed against the names of every indexed resource
+#[derive(Clone, Debug)]
+pub struct Finder {
+    pub(crate) query: String,
+    pub(crate) entries: Vec<FinderEntry>,
+    pub(crate) selected: usize,
+    /// what the overlay lists, shown in its border
+    pub(crate) title: String,
+}
+
+impl Finder {
+    pub fn new(entries: Vec<FinderEntry>) -> Self {
+        Self {
+            query: String::new(),
+            entries,
+            selected: 0,
+            title: "Find".to_string(),
+        }
+    }
+
+    /// The resources related to `pod`, picked the same way as finder results
+    pub fn related(pod: &str, entries: Vec<FinderEntry>) -> Self {
+        Self {
+            title: format!("Related to {pod}"),
+            ..Self::new(entries)
+        }
+    }
+
+    /// Entries whose name fuzzy matches the query, best match first
+    pub fn matches(&self) -> Vec<&FinderEntry> {
+        if self.query.is_empty() {
+            return self.entries.iter().take(MAX_RESULTS).collect();
+        }
+        let matcher = SkimMatcherV2::default();
+        let mut scored: Vec<(i64, &FinderEntry)> = self
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                matcher
+                    .fuzzy_match(&entry.name, &self.query)
+                    .map(|score| (score, entry))
+            })
+            .collect();
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        scored
+            .into_iter()
+            .take(MAX_RESULTS)
+            .map(|(_, entry)| entry)
+            .collect()
+    }
+
+    pub fn handle_key(&mut self, key: &KeyEvent) -> FinderAction {
+        match key.code {
+            KeyCode::Esc => return FinderAction::Close,
+            KeyCode::Enter => {
+                return self
+                    .matches()
+                    .get(self.selected)
+                    .map_or(FinderAction::Stay, |entry| {
+                        FinderAction::Jump((*entry).clone())
+                    });
+            }
+            KeyCode::Down => {
+                if self.selected + 1 < self.matches().len() {
+                    self.selected += 1;
+                }
+            }
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.selected = 0;
+            }
+            KeyCode::Char(c) => {
+                self.query.push(c);
+                self.selected = 0;
+            }
+            _ => {}
+        }
+        FinderAction::Stay
+    }
+}
+
+pub fn render_finder(f: &mut Frame, colors: &TableColors, finder: &Finder) {
+    let style = Style::default().fg(colors.header_fg).bg(colors.header_bg);
+    let selected_style = style.add_modifier(Modifier::REVERSED);
+
+    let matches = finder.matches();
+    let kind_width = matches
+        .iter()
+        .map(|entry| entry.kind.len())
+        .max()
+        .unwrap_or(0);
+    let mut lines: Vec<Line> = vec![format!("> {}", finder.query).into(), "".into()];
+    if matches.is_empty() {
+        lines.push("  no matches".into());
+    }
+    lines.extend(matches.iter().enumerate().map(|(i, entry)| {
+        let line = Line::from(format!("  {:kind_width$}  {}", entry.kind, entry.name));
+        if i == finder.selected {
+            line.style(selected_style)
+        } else {
+            line
+        }
+    }));
+
+    let area = centered_rect(70, 60, f.area());
+    let block = Paragraph::new(lines).style(style).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("{} (Enter to open, Esc to close)", finder.title)),
+    );
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+
+    #[allow(clippy::cast_possible_truncation)]
+    let p = Position {
+        x: area.x + finder.query.len() as u16 + 3,
+        y: area.y + 1,
+    };
+    f.set_cursor_position(p);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Finder;
+    use crate::tui::data::{FinderEntry, FinderTarget};
+    use std::collections::BTreeMap;
+
+    fn entry(kind: &str, name: &str) -> FinderEntry {
+        FinderEntry {
+            kind: kind.to_string(),
+            name: name.to_string(),
+            target: FinderTarget::Pods(BTreeMap::new()),
+        }
+    }
+
+    #[test]
+    fn test_matches_ranks_by_score() {
+        let mut finder = Finder::new(vec![
+            entry("Service", "billing"),
+            entry("Pod", "api-gateway-7d9f"),
+            entry("ReplicaSet", "api-7d9f"),
+        ]);
+        finder.query = "api".to_string();
+        let names: Vec<&str> = finder
+            .matches()
+            .iter()
+            .map(|entry| entry.name.as_str())
+            .collect();
+        assert_eq!(names.len(), 2);
+        assert!(!names.contains(&"billing"));
+    }
+}
@@ -1,11 +1,14 @@
 use crate::tui::cert_app;
 use crate::tui::data::{ingress_constraint_len_calculator, Ingress};
 use crate::tui::ingress_app;
+use crate::tui::pod_app;
+use crate::tui::status::StatusMessage;
 use crate::tui::stream::Message;
-use crate::tui::style::{TableColors, ITEM_HEIGHT, PALETTES};
-use crate::tui::table_ui::TuiTableState;
-use crate::tui::ui_loop::{create_cert_data_vec, AppBehavior, Apps};
-use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+use crate::tui::style::{item_height, TableColors, PALETTES};
+use crate::tui::table_ui::{cycle_column_fit, TuiTableState};
+use crate::tui::ui_loop::{create_backend_selector, create_cert_data_vec, AppBehavior, Apps};
+use chrono::{DateTime, Local};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use futures::{stream, Stream};
 use ratatui::prelude::*;
 use ratatui::widgets::{ScrollbarState, TableState};
@@ -20,9 +23,23 @@ pub struct App {
     pub(crate) items: Vec<Ingress>,
     pub(crate) longest_item_lens: (u16, u16, u16, u16, u16),
     pub(crate) scroll_state: ScrollbarState,
+    pub(crate) table_area: Rect,
     pub(crate) colors: TableColors,
     color_index: usize,
     pub(crate) filter: String,
+    pub(crate) status: Option<StatusMessage>,
+    pub(crate) backend_picker: Option<BackendPicker>,
+    /// when data was last applied, for the status bar
+    pub(crate) refreshed: Option<DateTime<Local>>,
+}
+
+/// Backend services of one ingress, shown when its rules route to more than
+/// one service so the user can choose which to follow.
+#[derive(Clone, Debug)]
+pub(crate) struct BackendPicker {
+    pub(crate) ingress: String,
+    pub(crate) services: Vec<String>,
+    pub(crate) selected: usize,
 }
 
 impl TuiTableState for App {
@@ -44,6 +61,14 @@ impl TuiTableState for App {
         self.scroll_state = scroll_state;
     }
 
+    fn get_table_area(&self) -> Rect {
+        self.table_area
+    }
+
+    fn set_table_area(&mut self, table_area: Rect) {
+        self.table_area = table_area;
+    }
+
     fn set_table_colors(&mut self, colors: TableColors) {
         self.colors = colors;
     }
@@ -58,7 +83,7 @@ impl TuiTableState for App {
 
     fn reset_selection_state(&mut self) {
         self.state = TableState::default().with_selected(0);
-        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * ITEM_HEIGHT);
+        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * item_height());
     }
 
     fn get_filter(&self) -> String {
@@ -82,12 +107,22 @@ impl TuiTableState for App {
     }
 
     fn get_show_filter_edit(&self) -> bool {
-        todo!()
+        false
+    }
+
+    fn typing(&self) -> bool {
+        self.backend_picker.is_some()
     }
 }
 
 impl AppBehavior for ingress_app::app::App {
     async fn handle_event(&mut self, event: &Message) -> Result<Option<Apps>, io::Error> {
+        if matches!(event, Message::Key(Event::Key(_))) {
+            self.status = None;
+        }
+        if let (Some(_), Message::Key(Event::Key(key))) = (&self.backend_picker, event) {
+            return Ok(self.handle_backend_picker_event(key).await);
+        }
         let mut app_holder = Some(Apps::Ingress { app: self.clone() });
         match event {
             Message::Key(Event::Key(key)) => {
@@ -110,13 +145,21 @@ impl AppBehavior for ingress_app::app::App {
                             self.next_color();
                             app_holder = Some(Apps::Ingress { app: self.clone() });
                         }
+                        Char('a' | 'A') => {
+                            let fit = cycle_column_fit();
+                            self.status = Some(StatusMessage::Info(format!(
+                                "column widths: {}",
+                                fit.label()
+                            )));
+                            app_holder = Some(Apps::Ingress { app: self.clone() });
+                        }
                         Char('f' | 'F') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                             self.page_forward();
                         }
                         Char('b' | 'B') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                             self.page_backward();
                         }
-                        Enter => {
+                        Char('t' | 'T') => {
                             if let Some(selection) = self.get_selected_item() {
                                 let host = &selection.host;
                                 match create_cert_data_vec(&host.clone()).await {
@@ -125,37 +168,73 @@ impl AppBehavior for ingress_app::app::App {
                                             app: cert_app::app::App::new(data_vec),
                                         };
                                         app_holder = Some(new_app_holder);
-                                        debug!("changing app from pod to cert...");
+                                        debug!("changing app from ingress to cert...");
                                     }
                                     Err(e) => {
-                                        debug!("can not read certificate: {e}");
+                                        self.status = Some(StatusMessage::Error(e.to_string()));
+                                        app_holder = Some(Apps::Ingress { app: self.clone() });
                                     }
                                 }
                             };
                         }
+                        Enter => {
+                            if let Some(selection) = self.get_selected_item().cloned() {
+                                let services = self.backends_of(&selection.name);
+                                if services.len() > 1 {
+                                    let selected = services
+                                        .iter()
+                                        .position(|svc| *svc == selection.backend_svc)
+                                        .unwrap_or(0);
+                                    self.backend_picker = Some(BackendPicker {
+                                        ingress: selection.name,
+                                        services,
+                                        selected,
+                                    });
+                                    app_holder = Some(Apps::Ingress { app: self.clone() });
+                                } else {
+                                    app_holder = self.follow_backend(&selection.backend_svc).await;
+                                }
+                            };
+                        }
 
                         _k => {}
                     }
                 }
             }
+            Message::Key(Event::Mouse(mouse)) => {
+                if self.handle_mouse_event(mouse) {
+                    app_holder = Some(Apps::Ingress { app: self.clone() });
+                }
+            }
             Message::Ingress(data_vec) => {
-                let new_app = Self {
+                let selected = self.get_selected_key();
+                let mut new_app = Self {
                     longest_item_lens: ingress_constraint_len_calculator(data_vec),
                     scroll_state: ScrollbarState::new(
-                        data_vec.len().saturating_sub(1) * ITEM_HEIGHT,
+                        data_vec.len().saturating_sub(1) * item_height(),
                     ),
                     items: data_vec.clone(),
+                    refreshed: Some(Local::now()),
                     ..self.clone()
                 };
+                new_app.select_key(selected.as_deref());
                 let new_app_holder = Apps::Ingress { app: new_app };
                 app_holder = Some(new_app_holder);
             }
+            Message::Key(Event::Resize(_, _)) => {
+                self.handle_resize();
+                app_holder = Some(Apps::Ingress { app: self.clone() });
+            }
+            Message::Error(message) => {
+                self.status = Some(StatusMessage::Error(message.clone()));
+                app_holder = Some(Apps::Ingress { app: self.clone() });
+            }
             _ => {}
         }
         Ok(app_holder)
     }
     fn draw_ui<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<(), std::io::Error> {
-        terminal.draw(|f| ingress_app::ui::ui(f, &mut self.clone()))?;
+        terminal.draw(|f| ingress_app::ui::ui(f, self))?;
         Ok(())
     }
 
@@ -169,11 +248,72 @@ impl App {
         Self {
             state: TableState::default().with_selected(0),
             longest_item_lens: ingress_constraint_len_calculator(&data_vec),
-            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * ITEM_HEIGHT),
+            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * item_height()),
             colors: TableColors::new(&PALETTES[0]),
+            table_area: Rect::default(),
             color_index: 3,
             items: data_vec,
             filter: String::new(),
+            refreshed: Some(Local::now()),
+            status: None,
+            backend_picker: None,
+        }
+    }
+
+    /// Distinct backend services across all rules and paths of an ingress,
+    /// in the order they are listed.
+    fn backends_of(&self, ingress: &str) -> Vec<String> {
+        let mut services: Vec<String> = Vec::new();
+        for item in self.items.iter().filter(|item| item.name == ingress) {
+            if !services.contains(&item.backend_svc) {
+                services.push(item.backend_svc.clone());
+            }
+        }
+        services
+    }
+
+    async fn follow_backend(&mut self, service: &str) -> Option<Apps> {
+        match create_backend_selector(service).await {
+            Ok(selector) => {
+                debug!("changing app from ingress to pods of service {service}...");
+                Some(Apps::Pod {
+                    app: pod_app::app::App::new(selector, vec![]),
+                })
+            }
+            Err(e) => {
+                self.status = Some(StatusMessage::Error(e.to_string()));
+                Some(Apps::Ingress { app: self.clone() })
+            }
+        }
+    }
+
+    async fn handle_backend_picker_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        if key.kind != KeyEventKind::Press {
+            return Some(Apps::Ingress { app: self.clone() });
+        }
+        let Some(picker) = self.backend_picker.as_mut() else {
+            return Some(Apps::Ingress { app: self.clone() });
+        };
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                picker.selected = (picker.selected + 1) % picker.services.len();
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                picker.selected = picker
+                    .selected
+                    .checked_sub(1)
+                    .unwrap_or(picker.services.len() - 1);
+            }
+            KeyCode::Enter => {
+                let service = picker.services[picker.selected].clone();
+                self.backend_picker = None;
+                return self.follow_backend(&service).await;
+            }
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.backend_picker = None;
+            }
+            _ => {}
         }
+        Some(Apps::Ingress { app: self.clone() })
     }
 }
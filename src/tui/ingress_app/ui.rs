@@ -1,21 +1,89 @@
-use crate::tui::ingress_app::app::App;
-use crate::tui::table_ui::TuiTableState;
+use crate::config;
+use crate::tui::ingress_app::app::{App, BackendPicker};
+use crate::tui::status::{render_status_bar, render_status_line};
+use crate::tui::style::RowLayout;
+use crate::tui::table_ui::{
+    column_constraints, fit_cell_text, render_breadcrumb, render_too_small, TuiTableState,
+};
+use crate::tui::widgets::centered_rect;
 use ratatui::{
     prelude::*,
-    widgets::{Cell, HighlightSpacing, Row, Scrollbar, ScrollbarOrientation, Table},
+    widgets::{
+        Block, Borders, Cell, Clear, HighlightSpacing, Paragraph, Row, Scrollbar,
+        ScrollbarOrientation, Table,
+    },
 };
 
 pub fn ui(f: &mut Frame, app: &mut App) {
-    let rects = Layout::vertical([Constraint::Min(5)]).split(f.area());
+    if render_too_small(f) {
+        return;
+    }
+    let [crumb_area, view_area, bar_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(f.area());
+
+    let rects = Layout::vertical([Constraint::Min(5)]).split(view_area);
 
     app.set_colors();
+    let breadcrumb = app.breadcrumb("Ingress");
+    render_breadcrumb(f, &app.colors, crumb_area, &breadcrumb);
 
     render_table(f, app, rects[0]);
 
     render_scrollbar(f, app, rects[0]);
+
+    if let Some(picker) = &app.backend_picker {
+        render_backend_picker(f, app, picker);
+    }
+    let bar = app.status_bar("ingress", app.refreshed);
+    render_status_bar(f, &app.colors, bar_area, &bar);
+
+    if let Some(status) = &app.status {
+        render_status_line(f, &app.colors, status);
+    }
+}
+
+fn render_backend_picker(f: &mut Frame, app: &App, picker: &BackendPicker) {
+    let style = Style::default()
+        .fg(app.colors.header_fg)
+        .bg(app.colors.header_bg);
+    let selected_style = style.add_modifier(Modifier::REVERSED);
+
+    let mut lines: Vec<Line> = picker
+        .services
+        .iter()
+        .enumerate()
+        .map(|(i, svc)| {
+            if i == picker.selected {
+                Line::styled(format!(" > {svc}"), selected_style)
+            } else {
+                Line::from(format!("   {svc}"))
+            }
+        })
+        .collect();
+    lines.push("".into());
+    lines.push("Enter to follow, Esc to cancel".into());
+
+    let area = centered_rect(60, 40, f.area());
+    let block = Paragraph::new(lines).style(style).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Backends of {}", picker.ingress)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
 }
 
 fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let config = config::current();
+    let fit = config.column_fit;
+    let layout = RowLayout::new(&config);
+    app.set_table_area(area);
+
     let header_style = Style::default()
         .fg(app.colors.header_fg)
         .bg(app.colors.header_bg);
@@ -42,26 +110,32 @@ fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
             let item = data.ref_array();
             item.iter()
                 .copied()
-                .map(|content| Cell::from(Text::from(format!("\n{content}\n"))))
+                .map(|content| {
+                    Cell::from(layout.text(fit_cell_text(fit, content, area.width).into_owned()))
+                })
                 .collect::<Row>()
                 .style(Style::new().fg(app.colors.row_fg).bg(color))
-                .height(3)
+                .height(layout.height())
         });
-    let bar = " █ ";
+    let bar = layout.selection_symbol();
     let t = Table::new(
         rows,
-        [
-            // + 1 is for padding.
-            Constraint::Min(app.longest_item_lens.0 + 1),
-            Constraint::Min(app.longest_item_lens.1 + 1),
-            Constraint::Min(app.longest_item_lens.2 + 1),
-            Constraint::Min(app.longest_item_lens.3 + 1),
-            Constraint::Min(app.longest_item_lens.4),
-        ],
+        column_constraints(
+            fit,
+            &[
+                // + 1 is for padding.
+                app.longest_item_lens.0 + 1,
+                app.longest_item_lens.1 + 1,
+                app.longest_item_lens.2 + 1,
+                app.longest_item_lens.3 + 1,
+                app.longest_item_lens.4,
+            ],
+            area.width,
+        ),
     )
     .header(header)
     .row_highlight_style(selected_style)
-    .highlight_symbol(Text::from(vec!["".into(), bar.into(), "".into()]))
+    .highlight_symbol(layout.text(bar.to_string()))
     .bg(app.colors.buffer_bg)
     .highlight_spacing(HighlightSpacing::Always);
     f.render_stateful_widget(t, area, &mut app.state);
@@ -0,0 +1,191 @@
+use crate::config;
+use crate::tui::data::Filterable;
+use crate::tui::job_app::app::App;
+use crate::tui::status::{render_status_bar, render_status_line};
+use crate::tui::style::{RowLayout, RowStatus};
+use crate::tui::table_ui::{
+    column_constraints, filter_cell, filter_edit_title, fit_cell_text, render_breadcrumb,
+    render_table_placeholder, render_too_small, TuiTableState,
+};
+use crate::tui::widgets::centered_rect;
+use ratatui::{
+    prelude::*,
+    widgets::{
+        Block, Borders, Cell, Clear, HighlightSpacing, Paragraph, Row, Scrollbar,
+        ScrollbarOrientation, Table,
+    },
+};
+
+pub fn ui(f: &mut Frame, app: &mut App) {
+    if render_too_small(f) {
+        return;
+    }
+    let [crumb_area, view_area, bar_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(f.area());
+
+    app.set_colors();
+    let breadcrumb = app.breadcrumb("Job");
+    render_breadcrumb(f, &app.colors, crumb_area, &breadcrumb);
+
+    let rects = Layout::vertical([Constraint::Min(5)]).split(view_area);
+    render_table(f, app, rects[0]);
+    render_scrollbar(f, app, rects[0]);
+    if let Some(message) = app.placeholder() {
+        render_table_placeholder(f, &app.colors, rects[0], message);
+    }
+
+    if app.get_show_filter_edit() {
+        render_filter_edit(f, app);
+    }
+
+    let bar = app.status_bar("job", app.refreshed);
+    render_status_bar(f, &app.colors, bar_area, &bar);
+
+    if let Some(status) = &app.status {
+        render_status_line(f, &app.colors, status);
+    }
+}
+
+fn render_filter_edit(f: &mut Frame, app: &App) {
+    let edit_style = Style::default()
+        .fg(app.colors.header_fg)
+        .bg(app.colors.header_bg);
+
+    let area = f.area();
+
+    let input_area = centered_rect(60, 20, area);
+
+    let block = Paragraph::new(app.filter.as_str()).style(edit_style).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(filter_edit_title()),
+    );
+
+    f.render_widget(Clear, input_area); //this clears out the background
+    f.render_widget(block, input_area);
+
+    #[allow(clippy::cast_possible_truncation)]
+    let p = Position {
+        x: input_area.x + app.edit_filter_cursor_position as u16 + 1,
+        y: input_area.y + 1,
+    };
+    f.set_cursor_position(p);
+}
+
+fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let config = config::current();
+    let fit = config.column_fit;
+    let layout = RowLayout::new(&config);
+    app.set_table_area(area);
+
+    let header_style = Style::default()
+        .fg(app.colors.header_fg)
+        .bg(app.colors.header_bg);
+    let selected_style = Style::default()
+        .add_modifier(Modifier::REVERSED)
+        .fg(app.colors.selected_style_fg);
+    let highlight = Style::new()
+        .fg(app.colors.selected_style_fg)
+        .add_modifier(Modifier::BOLD);
+
+    let title = app
+        .cronjob()
+        .map_or_else(|| "Job".to_string(), |cronjob| format!("Job of {cronjob}"));
+    let filter_header = match app.get_filter() {
+        filter if filter != String::new() => format!("{title} ({filter})"),
+        _ => title,
+    };
+
+    let header = [
+        filter_header.as_str(),
+        "Kind",
+        "Completions/Schedule",
+        "Status",
+        "Age",
+    ]
+    .iter()
+    .copied()
+    .map(Cell::from)
+    .collect::<Row>()
+    .style(header_style)
+    .height(1);
+    let row_filter = app.row_filter();
+    let rows = row_filter
+        .filter(app.get_items())
+        .into_iter()
+        .enumerate()
+        .map(|(i, data)| {
+            let color = match i % 2 {
+                0 => app.colors.normal_row_color,
+                _ => app.colors.alt_row_color,
+            };
+            let style = if data.failed {
+                app.colors.row_style(RowStatus::Error)
+            } else if data.suspended {
+                app.colors.row_style(RowStatus::Muted)
+            } else if data.complete {
+                // finished work matters less than what is running or broken
+                Style::new()
+                    .fg(app.colors.row_fg)
+                    .add_modifier(Modifier::DIM)
+            } else {
+                Style::new().fg(app.colors.row_fg)
+            };
+            let matched = row_filter.match_indices(data.filter_by());
+            let item = data.ref_array();
+            item.iter()
+                .copied()
+                .map(|content| {
+                    filter_cell(
+                        &fit_cell_text(fit, content, area.width),
+                        data.filter_by(),
+                        &matched,
+                        highlight,
+                        layout,
+                    )
+                })
+                .collect::<Row>()
+                .style(style.bg(color))
+                .height(layout.height())
+        });
+    let bar = layout.selection_symbol();
+    let t = Table::new(
+        rows,
+        column_constraints(
+            fit,
+            &[
+                // + 1 is for padding.
+                app.longest_item_lens.0 + 1,
+                app.longest_item_lens.1 + 1,
+                app.longest_item_lens.2 + 1,
+                app.longest_item_lens.3 + 1,
+                app.longest_item_lens.4,
+            ],
+            area.width,
+        ),
+    )
+    .header(header)
+    .row_highlight_style(selected_style)
+    .highlight_symbol(layout.text(bar.to_string()))
+    .bg(app.colors.buffer_bg)
+    .highlight_spacing(HighlightSpacing::Always);
+    f.render_stateful_widget(t, area, &mut app.state);
+}
+
+fn render_scrollbar(f: &mut Frame, app: &mut App, area: Rect) {
+    f.render_stateful_widget(
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None),
+        area.inner(Margin {
+            vertical: 1,
+            horizontal: 1,
+        }),
+        &mut app.scroll_state,
+    );
+}
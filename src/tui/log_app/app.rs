@@ -1,10 +1,14 @@
+use crate::config::{self, PollView};
 use crate::k8s::containers::logs;
+use crate::k8s::errors::list_error_message;
 use crate::tui::data::{log_constraint_len_calculator, LogRec};
 use crate::tui::log_app;
-use crate::tui::stream::Message;
-use crate::tui::style::{TableColors, ITEM_HEIGHT, PALETTES};
-use crate::tui::table_ui::TuiTableState;
+use crate::tui::status::StatusMessage;
+use crate::tui::stream::{request_refresh, spawn_task, wait_for_poll, Message};
+use crate::tui::style::{item_height, TableColors, PALETTES};
+use crate::tui::table_ui::{cycle_column_fit, step_since, TuiTableState};
 use crate::tui::ui_loop::{AppBehavior, Apps};
+use chrono::{DateTime, Local};
 use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
 use futures::Stream;
 use ratatui::prelude::*;
@@ -13,20 +17,17 @@ use std::collections::BTreeMap;
 use std::io;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
 use tokio::sync::mpsc;
-use tokio::time::sleep;
 use tokio_stream::wrappers::ReceiverStream;
 use tracing::debug;
 
-const POLL_MS: u64 = 5000;
-
 #[derive(Clone, Debug)]
 pub struct App {
     pub(crate) state: TableState,
     pub(crate) items: Vec<LogRec>,
     pub(crate) longest_item_lens: (u16, u16, u16),
     pub(crate) scroll_state: ScrollbarState,
+    pub(crate) table_area: Rect,
     pub(crate) colors: TableColors,
     color_index: usize,
     pub(crate) selector: BTreeMap<String, String>,
@@ -35,6 +36,12 @@ pub struct App {
     pub(crate) filter: String,
     pub(crate) show_filter_edit: bool,
     pub(crate) edit_filter_cursor_position: usize,
+    pub(crate) status: Option<StatusMessage>,
+    /// shared with the polling task so toggling it switches the logs being fetched
+    pub(crate) previous: Arc<AtomicBool>,
+    pub(crate) loaded: bool,
+    /// when data was last applied, for the status bar
+    pub(crate) refreshed: Option<DateTime<Local>>,
 }
 
 impl TuiTableState for App {
@@ -56,6 +63,14 @@ impl TuiTableState for App {
         self.scroll_state = scroll_state;
     }
 
+    fn get_table_area(&self) -> Rect {
+        self.table_area
+    }
+
+    fn set_table_area(&mut self, table_area: Rect) {
+        self.table_area = table_area;
+    }
+
     fn set_table_colors(&mut self, colors: TableColors) {
         self.colors = colors;
     }
@@ -70,7 +85,7 @@ impl TuiTableState for App {
 
     fn reset_selection_state(&mut self) {
         self.state = TableState::default().with_selected(0);
-        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * ITEM_HEIGHT);
+        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * item_height());
     }
 
     fn get_filter(&self) -> String {
@@ -110,7 +125,7 @@ impl AppBehavior for log_app::app::App {
     }
 
     fn draw_ui<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<(), std::io::Error> {
-        terminal.draw(|f| log_app::ui::ui(f, &mut self.clone()))?;
+        terminal.draw(|f| log_app::ui::ui(f, self))?;
         Ok(())
     }
 
@@ -122,25 +137,45 @@ impl AppBehavior for log_app::app::App {
         let pod_name = self.pod_name.clone();
         let container_name = self.container_name.clone();
         let selector = self.selector.clone();
+        let previous = self.previous.clone();
+        let mut last_previous = previous.load(Ordering::Relaxed);
 
-        tokio::spawn(async move {
+        spawn_task(async move {
+            let mut forced = false;
             while !should_stop.load(Ordering::Relaxed) {
+                // read each time so a change in the settings overlay applies right away
+                let poll_interval = config::current().poll_interval_for(PollView::Logs);
+                let want_previous = previous.load(Ordering::Relaxed);
                 //get Vec and send
-                match logs(selector.clone(), pod_name.clone(), container_name.clone()).await {
+                match logs(
+                    selector.clone(),
+                    pod_name.clone(),
+                    container_name.clone(),
+                    want_previous,
+                    config::current().since,
+                )
+                .await
+                {
                     Ok(d) => {
-                        if !d.is_empty() && d != initial_items {
+                        // after a toggle send even an empty result so the view can say so
+                        let toggled = want_previous != last_previous;
+                        last_previous = want_previous;
+                        // a manual refresh is reported even when nothing changed
+                        if forced || toggled || (!d.is_empty() && d != initial_items) {
                             let sevent = Message::Log(d);
                             if tx.send(sevent).await.is_err() {
                                 break;
                             }
                         }
-                        sleep(Duration::from_millis(POLL_MS)).await;
+                        forced = wait_for_poll(poll_interval).await;
                     }
-                    Err(_e) => {
+                    Err(e) => {
+                        _ = tx
+                            .send(Message::Error(list_error_message(&e, "pod logs")))
+                            .await;
                         break;
                     }
                 }
-                sleep(Duration::from_millis(POLL_MS)).await;
             }
         });
 
@@ -158,20 +193,42 @@ impl App {
         Self {
             state: TableState::default().with_selected(0),
             longest_item_lens: log_constraint_len_calculator(&data_vec),
-            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * ITEM_HEIGHT),
+            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * item_height()),
             colors: TableColors::new(&PALETTES[0]),
+            table_area: Rect::default(),
             color_index: 3,
             items: data_vec,
             selector,
             pod_name,
             container_name,
             filter: String::new(),
+            refreshed: None,
             show_filter_edit: false,
             edit_filter_cursor_position: 0,
+            status: None,
+            previous: Arc::new(AtomicBool::new(false)),
+            loaded: false,
+        }
+    }
+
+    pub(crate) fn is_previous(&self) -> bool {
+        self.previous.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn placeholder(&self) -> Option<&'static str> {
+        if !self.is_previous() || !self.items.is_empty() {
+            None
+        } else if self.loaded {
+            Some("no previous container logs available")
+        } else {
+            Some("Loading previous container logs...")
         }
     }
 
     fn handle_table_event(&mut self, event: &Message) -> Option<Apps> {
+        if matches!(event, Message::Key(Event::Key(_))) {
+            self.status = None;
+        }
         let mut app_holder = Some(Apps::Log { app: self.clone() });
         match event {
             Message::Key(Event::Key(key)) => {
@@ -194,6 +251,19 @@ impl App {
                             self.next_color();
                             app_holder = Some(Apps::Log { app: self.clone() });
                         }
+                        Char('r' | 'R') => {
+                            request_refresh();
+                            self.status = Some(StatusMessage::Info("refreshing…".to_string()));
+                            app_holder = Some(Apps::Log { app: self.clone() });
+                        }
+                        Char('a' | 'A') => {
+                            let fit = cycle_column_fit();
+                            self.status = Some(StatusMessage::Info(format!(
+                                "column widths: {}",
+                                fit.label()
+                            )));
+                            app_holder = Some(Apps::Log { app: self.clone() });
+                        }
                         Char('f' | 'F') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                             self.page_forward();
                         }
@@ -203,6 +273,18 @@ impl App {
                         Enter => {
                             // noop for now but will be pretty printed detail analysis popup
                         }
+                        Char('P') => {
+                            let previous = !self.previous.load(Ordering::Relaxed);
+                            self.previous.store(previous, Ordering::Relaxed);
+                            self.items = vec![];
+                            self.loaded = false;
+                            self.reset_selection_state();
+                            app_holder = Some(Apps::Log { app: self.clone() });
+                        }
+                        Char('s' | 'S') => {
+                            self.status = Some(step_since());
+                            app_holder = Some(Apps::Log { app: self.clone() });
+                        }
                         Char('/') => {
                             self.set_show_filter_edit(true);
                             app_holder = Some(Apps::Log { app: self.clone() });
@@ -211,18 +293,35 @@ impl App {
                     }
                 }
             }
+            Message::Key(Event::Mouse(mouse)) => {
+                if self.handle_mouse_event(mouse) {
+                    app_holder = Some(Apps::Log { app: self.clone() });
+                }
+            }
             Message::Log(data_vec) => {
-                let new_app = Self {
+                let selected = self.get_selected_key();
+                let mut new_app = Self {
                     longest_item_lens: log_constraint_len_calculator(data_vec),
                     scroll_state: ScrollbarState::new(
-                        data_vec.len().saturating_sub(1) * ITEM_HEIGHT,
+                        data_vec.len().saturating_sub(1) * item_height(),
                     ),
                     items: data_vec.clone(),
+                    loaded: true,
+                    refreshed: Some(Local::now()),
                     ..self.clone()
                 };
+                new_app.select_key(selected.as_deref());
                 let new_app_holder = Apps::Log { app: new_app };
                 app_holder = Some(new_app_holder);
             }
+            Message::Error(message) => {
+                self.status = Some(StatusMessage::Error(message.clone()));
+                app_holder = Some(Apps::Log { app: self.clone() });
+            }
+            Message::Key(Event::Resize(_, _)) => {
+                self.handle_resize();
+                app_holder = Some(Apps::Log { app: self.clone() });
+            }
             _ => {}
         }
         app_holder
@@ -233,7 +332,7 @@ impl App {
         match event {
             Message::Key(Event::Key(key)) => {
                 if key.kind == KeyEventKind::Press {
-                    use KeyCode::{Backspace, Char, Enter, Esc, Left, Right};
+                    use KeyCode::{Backspace, Char, Enter, Esc, Left, Right, Tab};
 
                     match key.code {
                         Char(to_insert) => {
@@ -256,23 +355,39 @@ impl App {
                             self.set_show_filter_edit(false);
                             app_holder = Some(Apps::Log { app: self.clone() });
                         }
+                        Tab => {
+                            self.toggle_filter_mode();
+                            app_holder = Some(Apps::Log { app: self.clone() });
+                        }
                         _ => {}
                     }
                 }
             }
             Message::Log(data_vec) => {
                 debug!("updating log app data...");
-                let new_app = Self {
+                let selected = self.get_selected_key();
+                let mut new_app = Self {
                     longest_item_lens: log_constraint_len_calculator(data_vec),
                     scroll_state: ScrollbarState::new(
-                        data_vec.len().saturating_sub(1) * ITEM_HEIGHT,
+                        data_vec.len().saturating_sub(1) * item_height(),
                     ),
                     items: data_vec.clone(),
+                    loaded: true,
+                    refreshed: Some(Local::now()),
                     ..self.clone()
                 };
+                new_app.select_key(selected.as_deref());
                 let new_app_holder = Apps::Log { app: new_app };
                 app_holder = Some(new_app_holder);
             }
+            Message::Error(message) => {
+                self.status = Some(StatusMessage::Error(message.clone()));
+                app_holder = Some(Apps::Log { app: self.clone() });
+            }
+            Message::Key(Event::Resize(_, _)) => {
+                self.handle_resize();
+                app_holder = Some(Apps::Log { app: self.clone() });
+            }
             _ => {}
         }
         app_holder
@@ -1,13 +1,36 @@
+mod bookmarks;
+mod breadcrumb;
 mod cert_app;
+mod clipboard;
+mod columns;
+mod configmap_app;
 mod container_app;
+mod custom_app;
+mod daemonset_app;
 pub mod data;
+mod editor;
 mod event_app;
+mod export;
+mod finder;
 mod ingress_app;
+mod job_app;
 mod log_app;
+mod namespace_app;
+mod node_app;
+mod operations;
+mod pinned;
 mod pod_app;
+mod problems_app;
+mod pvc_app;
+mod resource_app;
 mod rs_app;
+mod selection;
+mod service_app;
+mod settings;
+mod status;
 mod stream;
 mod style;
 mod table_ui;
 pub mod ui_loop;
 mod utils;
+mod widgets;
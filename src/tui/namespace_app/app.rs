@@ -0,0 +1,493 @@
+use crate::config::{self, PollView};
+use crate::config_file::ConfigFile;
+use crate::k8s::errors::{list_error_message, with_retry, ApiErrorKind};
+use crate::k8s::namespaces::list_namespaces;
+use crate::k8s::quota::namespace_usage;
+use crate::tui::data::{
+    namespace_constraint_len_calculator, sort_namespaces, Namespace, ResourceUsage,
+};
+use crate::tui::namespace_app;
+use crate::tui::status::StatusMessage;
+use crate::tui::stream::{
+    request_refresh, sleep_unless_stopped, spawn_task, wait_for_poll, Message,
+};
+use crate::tui::style::{item_height, TableColors, PALETTES};
+use crate::tui::table_ui::{cycle_column_fit, TuiTableState};
+use crate::tui::ui_loop::{AppBehavior, Apps};
+use chrono::{DateTime, Local};
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+use futures::Stream;
+use ratatui::prelude::*;
+use ratatui::widgets::{ScrollbarState, TableState};
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tracing::debug;
+
+/// how often the usage task looks for a newly selected namespace
+const SELECTION_CHECK: Duration = Duration::from_millis(250);
+
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) state: TableState,
+    pub(crate) items: Vec<Namespace>,
+    pub(crate) longest_item_lens: (u16, u16, u16, u16),
+    pub(crate) scroll_state: ScrollbarState,
+    pub(crate) table_area: Rect,
+    pub(crate) colors: TableColors,
+    color_index: usize,
+    pub(crate) filter: String,
+    pub(crate) show_filter_edit: bool,
+    pub(crate) edit_filter_cursor_position: usize,
+    pub(crate) loaded: bool,
+    pub(crate) status: Option<StatusMessage>,
+    /// when data was last applied, for the status bar
+    pub(crate) refreshed: Option<DateTime<Local>>,
+    /// opened with Ctrl-N: Enter in the filter switches to the best match and Esc
+    /// goes straight back to the view
+    pub(crate) quick: bool,
+    /// the selected namespace, shared with the task summing its requests and limits
+    pub(crate) selected: Arc<Mutex<Option<String>>>,
+    /// requests and limits of a namespace against its quotas, for the details pane
+    pub(crate) usage: Option<(String, Result<Vec<ResourceUsage>, String>)>,
+}
+
+impl TuiTableState for App {
+    type Item = Namespace;
+
+    fn get_items(&self) -> &[Self::Item] {
+        &self.items
+    }
+
+    fn get_state(&mut self) -> &mut TableState {
+        &mut self.state
+    }
+
+    fn get_scroll_state(&self) -> &ScrollbarState {
+        &self.scroll_state
+    }
+
+    fn set_scroll_state(&mut self, scroll_state: ScrollbarState) {
+        self.scroll_state = scroll_state;
+    }
+
+    fn get_table_area(&self) -> Rect {
+        self.table_area
+    }
+
+    fn set_table_area(&mut self, table_area: Rect) {
+        self.table_area = table_area;
+    }
+
+    fn set_table_colors(&mut self, colors: TableColors) {
+        self.colors = colors;
+    }
+
+    fn get_color_index(&self) -> usize {
+        self.color_index
+    }
+
+    fn set_color_index(&mut self, color_index: usize) {
+        self.color_index = color_index;
+    }
+
+    fn reset_selection_state(&mut self) {
+        self.state = TableState::default().with_selected(0);
+        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * item_height());
+    }
+
+    fn get_filter(&self) -> String {
+        self.filter.clone()
+    }
+
+    fn set_filter(&mut self, filter: String) {
+        self.filter = filter;
+    }
+
+    fn set_cursor_pos(&mut self, cursor_pos: usize) {
+        self.edit_filter_cursor_position = cursor_pos;
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    fn get_cursor_pos(&self) -> usize {
+        self.edit_filter_cursor_position
+    }
+
+    fn set_show_filter_edit(&mut self, show_filter_edit: bool) {
+        self.show_filter_edit = show_filter_edit;
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    fn get_show_filter_edit(&self) -> bool {
+        self.show_filter_edit
+    }
+}
+
+impl AppBehavior for namespace_app::app::App {
+    async fn handle_event(&mut self, event: &Message) -> Result<Option<Apps>, io::Error> {
+        if self.get_show_filter_edit() {
+            Ok(self.handle_filter_edit_event(event))
+        } else {
+            Ok(self.handle_table_event(event))
+        }
+    }
+
+    fn draw_ui<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<(), std::io::Error> {
+        let selected = self.get_selected_item().map(|ns| ns.name.clone());
+        if let Ok(mut shared) = self.selected.lock() {
+            *shared = selected;
+        }
+        terminal.draw(|f| namespace_app::ui::ui(f, self))?;
+        Ok(())
+    }
+
+    fn stream(&self, should_stop: Arc<AtomicBool>) -> impl Stream<Item = Message> {
+        let (tx, rx) = mpsc::channel(100);
+
+        let initial_items = self.get_items().to_vec();
+        let mut loaded = self.loaded;
+
+        spawn_task(follow_usage(
+            Arc::clone(&self.selected),
+            should_stop.clone(),
+            tx.clone(),
+        ));
+
+        spawn_task(async move {
+            while !should_stop.load(Ordering::Relaxed) {
+                // read each time so a change in the settings overlay applies right away
+                let poll_interval = config::current().poll_interval_for(PollView::Namespaces);
+                let pinned = ConfigFile::load().pinned_namespaces;
+                match with_retry("namespaces", || list_namespaces(&pinned)).await {
+                    Ok(d) => {
+                        if !loaded || d != initial_items {
+                            loaded = true;
+                            let sevent = Message::Namespace(d);
+                            if tx.send(sevent).await.is_err() {
+                                break;
+                            }
+                        }
+                        // a manual refresh is reported even when nothing changed
+                        if wait_for_poll(poll_interval).await {
+                            loaded = false;
+                        }
+                    }
+                    Err(e) => {
+                        _ = tx
+                            .send(Message::Error(list_error_message(&e, "namespaces")))
+                            .await;
+                        // retries are used up, but a flaky cluster may still come back
+                        if !ApiErrorKind::is_transient(&e) {
+                            break;
+                        }
+                        wait_for_poll(poll_interval).await;
+                    }
+                }
+            }
+        });
+
+        ReceiverStream::new(rx)
+    }
+}
+
+impl App {
+    pub fn new() -> Self {
+        let data_vec = vec![];
+        Self {
+            state: TableState::default().with_selected(0),
+            longest_item_lens: namespace_constraint_len_calculator(&data_vec),
+            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * item_height()),
+            colors: TableColors::new(&PALETTES[0]),
+            table_area: Rect::default(),
+            color_index: 3,
+            items: data_vec,
+            filter: String::new(),
+            refreshed: None,
+            show_filter_edit: false,
+            edit_filter_cursor_position: 0,
+            loaded: false,
+            status: None,
+            quick: false,
+            selected: Arc::new(Mutex::new(None)),
+            usage: None,
+        }
+    }
+
+    /// The namespace list with the filter already open, for switching from any view
+    pub fn quick() -> Self {
+        Self {
+            show_filter_edit: true,
+            quick: true,
+            ..Self::new()
+        }
+    }
+
+    /// Rows of the usage pane for the selected namespace, empty until its sums arrive
+    pub(crate) fn usage_details(&mut self) -> Vec<(String, String, Option<String>)> {
+        let selected = self.get_selected_item().map(|ns| ns.name.clone());
+        match (&self.usage, selected) {
+            (Some((name, Ok(rows))), Some(selected)) if *name == selected => rows
+                .iter()
+                .map(|row| {
+                    let value = match (&row.hard, row.percent) {
+                        (Some(hard), Some(percent)) => {
+                            format!("{} of {hard} ({percent}%)", row.used)
+                        }
+                        (Some(hard), None) => format!("{} of {hard}", row.used),
+                        (None, _) => format!("{}, no quota", row.used),
+                    };
+                    (row.name.clone(), value, None)
+                })
+                .collect(),
+            (Some((name, Err(message))), Some(selected)) if *name == selected => {
+                vec![("error".to_string(), message.clone(), None)]
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Make the selected namespace the one every view lists, `false` when none is selected
+    fn switch_to_selected(&mut self) -> bool {
+        let Some(selection) = self.get_selected_item() else {
+            return false;
+        };
+        let namespace = selection.name.clone();
+        debug!("switching to namespace {namespace}...");
+        config::update(|c| c.namespace = Some(namespace));
+        true
+    }
+
+    fn handle_table_event(&mut self, event: &Message) -> Option<Apps> {
+        if matches!(event, Message::Key(Event::Key(_))) {
+            self.status = None;
+        }
+        let mut app_holder = Some(Apps::Namespace { app: self.clone() });
+        match event {
+            Message::Key(Event::Key(key)) => {
+                if key.kind == KeyEventKind::Press {
+                    use KeyCode::{Char, Down, Enter, Esc, Up};
+                    match key.code {
+                        Char('q') | Esc => {
+                            app_holder = None;
+                        }
+                        Char('j') | Down => {
+                            self.next();
+                            app_holder = Some(Apps::Namespace { app: self.clone() });
+                        }
+                        Char('k') | Up => {
+                            self.previous();
+                            app_holder = Some(Apps::Namespace { app: self.clone() });
+                        }
+                        Char('c' | 'C') => {
+                            self.next_color();
+                            app_holder = Some(Apps::Namespace { app: self.clone() });
+                        }
+                        Char('r' | 'R') => {
+                            request_refresh();
+                            self.status = Some(StatusMessage::Info("refreshing…".to_string()));
+                            app_holder = Some(Apps::Namespace { app: self.clone() });
+                        }
+                        Char('a' | 'A') => {
+                            let fit = cycle_column_fit();
+                            self.status = Some(StatusMessage::Info(format!(
+                                "column widths: {}",
+                                fit.label()
+                            )));
+                            app_holder = Some(Apps::Namespace { app: self.clone() });
+                        }
+                        Char('f' | 'F') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            self.page_forward();
+                        }
+                        Char('b' | 'B') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            self.page_backward();
+                        }
+                        Char('p' | 'P') => {
+                            self.toggle_pin();
+                            app_holder = Some(Apps::Namespace { app: self.clone() });
+                        }
+                        Enter => {
+                            if self.switch_to_selected() {
+                                app_holder = None;
+                            }
+                        }
+                        Char('/') => {
+                            self.set_show_filter_edit(true);
+                            app_holder = Some(Apps::Namespace { app: self.clone() });
+                        }
+                        _k => {}
+                    }
+                }
+            }
+            Message::Key(Event::Mouse(mouse)) => {
+                if self.handle_mouse_event(mouse) {
+                    app_holder = Some(Apps::Namespace { app: self.clone() });
+                }
+            }
+            Message::NamespaceUsage(name, usage) => {
+                self.usage = Some((name.clone(), usage.clone()));
+                app_holder = Some(Apps::Namespace { app: self.clone() });
+            }
+            Message::Namespace(data_vec) => {
+                debug!("updating namespace app data...");
+                app_holder = Some(Apps::Namespace {
+                    app: self.with_items(data_vec),
+                });
+            }
+            Message::Error(message) => {
+                self.status = Some(StatusMessage::Error(message.clone()));
+                app_holder = Some(Apps::Namespace { app: self.clone() });
+            }
+            Message::Key(Event::Resize(_, _)) => {
+                self.handle_resize();
+                app_holder = Some(Apps::Namespace { app: self.clone() });
+            }
+            _ => {}
+        }
+        app_holder
+    }
+
+    fn handle_filter_edit_event(&mut self, event: &Message) -> Option<Apps> {
+        let mut app_holder = Some(Apps::Namespace { app: self.clone() });
+        match event {
+            Message::Key(Event::Key(key)) => {
+                if key.kind == KeyEventKind::Press {
+                    use KeyCode::{Backspace, Char, Enter, Esc, Left, Right, Tab};
+
+                    match key.code {
+                        Char(to_insert) => {
+                            self.enter_char(to_insert);
+                            app_holder = Some(Apps::Namespace { app: self.clone() });
+                        }
+                        Backspace => {
+                            self.delete_char();
+                            app_holder = Some(Apps::Namespace { app: self.clone() });
+                        }
+                        Left => {
+                            self.move_cursor_left();
+                            app_holder = Some(Apps::Namespace { app: self.clone() });
+                        }
+                        Right => {
+                            self.move_cursor_right();
+                            app_holder = Some(Apps::Namespace { app: self.clone() });
+                        }
+                        Enter if self.quick => {
+                            if self.switch_to_selected() {
+                                app_holder = None;
+                            }
+                        }
+                        Esc if self.quick => {
+                            app_holder = None;
+                        }
+                        Esc | Enter => {
+                            self.set_show_filter_edit(false);
+                            app_holder = Some(Apps::Namespace { app: self.clone() });
+                        }
+                        Tab => {
+                            self.toggle_filter_mode();
+                            app_holder = Some(Apps::Namespace { app: self.clone() });
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Message::NamespaceUsage(name, usage) => {
+                self.usage = Some((name.clone(), usage.clone()));
+                app_holder = Some(Apps::Namespace { app: self.clone() });
+            }
+            Message::Namespace(data_vec) => {
+                app_holder = Some(Apps::Namespace {
+                    app: self.with_items(data_vec),
+                });
+            }
+            Message::Error(message) => {
+                self.status = Some(StatusMessage::Error(message.clone()));
+                app_holder = Some(Apps::Namespace { app: self.clone() });
+            }
+            Message::Key(Event::Resize(_, _)) => {
+                self.handle_resize();
+                app_holder = Some(Apps::Namespace { app: self.clone() });
+            }
+            _ => {}
+        }
+        app_holder
+    }
+
+    fn with_items(&mut self, data_vec: &[Namespace]) -> Self {
+        let selected = self.get_selected_key();
+        let mut new_app = Self {
+            longest_item_lens: namespace_constraint_len_calculator(data_vec),
+            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * item_height()),
+            items: data_vec.to_vec(),
+            loaded: true,
+            refreshed: Some(Local::now()),
+            ..self.clone()
+        };
+        new_app.select_key(selected.as_deref());
+        new_app
+    }
+
+    /// Pin or unpin the selected namespace, saving it to the config file.
+    fn toggle_pin(&mut self) {
+        let Some(namespace) = self.get_selected_item().map(|ns| ns.name.clone()) else {
+            return;
+        };
+        let mut config_file = ConfigFile::load();
+        let pinned = config_file.toggle_pin(&namespace);
+        if let Err(e) = config_file.save() {
+            self.status = Some(StatusMessage::Error(format!(
+                "could not save pinned namespaces: {e}"
+            )));
+            return;
+        }
+        sort_namespaces(&mut self.items, &config_file.pinned_namespaces);
+        self.select_key(Some(&namespace));
+        let verb = if pinned { "pinned" } else { "unpinned" };
+        self.status = Some(StatusMessage::Info(format!("{verb} {namespace}")));
+    }
+
+    pub(crate) fn placeholder(&self) -> Option<&'static str> {
+        if !self.loaded {
+            Some("Loading Namespaces...")
+        } else if self.items.is_empty() {
+            Some("No Namespaces found")
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sum the requests and limits of the selected namespace every poll, and right away when
+/// another namespace is selected
+async fn follow_usage(
+    selected: Arc<Mutex<Option<String>>>,
+    should_stop: Arc<AtomicBool>,
+    tx: mpsc::Sender<Message>,
+) {
+    let mut last: Option<(String, Instant)> = None;
+    while !should_stop.load(Ordering::Relaxed) {
+        let name = selected.lock().ok().and_then(|name| name.clone());
+        if let Some(name) = name {
+            let due = last.as_ref().map_or(true, |(namespace, at)| {
+                *namespace != name
+                    || at.elapsed() >= config::current().poll_interval_for(PollView::Namespaces)
+            });
+            if due {
+                last = Some((name.clone(), Instant::now()));
+                let usage = namespace_usage(&name).await.map_err(|e| e.to_string());
+                if tx.send(Message::NamespaceUsage(name, usage)).await.is_err() {
+                    return;
+                }
+            }
+        }
+        sleep_unless_stopped(SELECTION_CHECK).await;
+    }
+}
@@ -0,0 +1,561 @@
+use crate::config::{self, PollView};
+use crate::k8s::errors::{list_error_message, with_retry, ApiErrorKind};
+use crate::k8s::nodes::{drain_node, list_nodes, set_unschedulable, DrainProgress};
+use crate::tui::data::{node_constraint_len_calculator, Node};
+use crate::tui::node_app;
+use crate::tui::operations::{self, Operation};
+use crate::tui::status::{read_only_message, StatusMessage};
+use crate::tui::stream::{request_refresh, spawn_task, wait_for_poll, Message};
+use crate::tui::style::{item_height, TableColors, PALETTES};
+use crate::tui::table_ui::{cycle_column_fit, TuiTableState};
+use crate::tui::ui_loop::{AppBehavior, Apps};
+use crate::tui::widgets::ConfirmModal;
+use chrono::{DateTime, Local};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use futures::Stream;
+use ratatui::prelude::*;
+use ratatui::widgets::{ScrollbarState, TableState};
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tracing::debug;
+
+/// A drain running on its own task, shared with the view so it can show the progress
+#[derive(Debug)]
+pub(crate) struct Drain {
+    pub(crate) node: String,
+    pub(crate) progress: DrainProgress,
+    cancel: Arc<AtomicBool>,
+    /// set once the task is done: whether the node was emptied, or why it failed
+    outcome: Option<Result<bool, String>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) state: TableState,
+    pub(crate) items: Vec<Node>,
+    pub(crate) longest_item_lens: (u16, u16, u16, u16, u16),
+    pub(crate) scroll_state: ScrollbarState,
+    pub(crate) table_area: Rect,
+    pub(crate) colors: TableColors,
+    color_index: usize,
+    pub(crate) filter: String,
+    pub(crate) show_filter_edit: bool,
+    pub(crate) edit_filter_cursor_position: usize,
+    pub(crate) loaded: bool,
+    pub(crate) status: Option<StatusMessage>,
+    /// when data was last applied, for the status bar
+    pub(crate) refreshed: Option<DateTime<Local>>,
+    /// the node to drain while the confirmation is open
+    pub(crate) drain_confirm: Option<ConfirmModal<String>>,
+    /// operations that were still running when quit was requested
+    pub(crate) quit_confirm: Option<ConfirmModal<Vec<String>>>,
+    pub(crate) drain: Arc<Mutex<Option<Drain>>>,
+}
+
+impl TuiTableState for App {
+    type Item = Node;
+
+    fn get_items(&self) -> &[Self::Item] {
+        &self.items
+    }
+
+    fn get_state(&mut self) -> &mut TableState {
+        &mut self.state
+    }
+
+    fn get_scroll_state(&self) -> &ScrollbarState {
+        &self.scroll_state
+    }
+
+    fn set_scroll_state(&mut self, scroll_state: ScrollbarState) {
+        self.scroll_state = scroll_state;
+    }
+
+    fn get_table_area(&self) -> Rect {
+        self.table_area
+    }
+
+    fn set_table_area(&mut self, table_area: Rect) {
+        self.table_area = table_area;
+    }
+
+    fn set_table_colors(&mut self, colors: TableColors) {
+        self.colors = colors;
+    }
+
+    fn get_color_index(&self) -> usize {
+        self.color_index
+    }
+
+    fn set_color_index(&mut self, color_index: usize) {
+        self.color_index = color_index;
+    }
+
+    fn reset_selection_state(&mut self) {
+        self.state = TableState::default().with_selected(0);
+        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * item_height());
+    }
+
+    fn get_filter(&self) -> String {
+        self.filter.clone()
+    }
+
+    fn set_filter(&mut self, filter: String) {
+        self.filter = filter;
+    }
+
+    fn set_cursor_pos(&mut self, cursor_pos: usize) {
+        self.edit_filter_cursor_position = cursor_pos;
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    fn get_cursor_pos(&self) -> usize {
+        self.edit_filter_cursor_position
+    }
+
+    fn set_show_filter_edit(&mut self, show_filter_edit: bool) {
+        self.show_filter_edit = show_filter_edit;
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    fn get_show_filter_edit(&self) -> bool {
+        self.show_filter_edit
+    }
+    fn typing(&self) -> bool {
+        self.show_filter_edit || self.drain_confirm.is_some() || self.quit_confirm.is_some()
+    }
+}
+
+impl AppBehavior for node_app::app::App {
+    async fn handle_event(&mut self, event: &Message) -> Result<Option<Apps>, io::Error> {
+        if let (Some(_), Message::Key(Event::Key(key))) = (&self.quit_confirm, event) {
+            return Ok(self.handle_quit_confirm_event(key));
+        }
+        if let (Some(_), Message::Key(Event::Key(key))) = (&self.drain_confirm, event) {
+            return Ok(self.handle_drain_confirm_event(key));
+        }
+        if self.get_show_filter_edit() {
+            Ok(self.handle_filter_edit_event(event))
+        } else {
+            Ok(self.handle_table_event(event).await)
+        }
+    }
+
+    fn draw_ui<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<(), std::io::Error> {
+        terminal.draw(|f| node_app::ui::ui(f, self))?;
+        Ok(())
+    }
+
+    fn stream(&self, should_stop: Arc<AtomicBool>) -> impl Stream<Item = Message> {
+        let (tx, rx) = mpsc::channel(100);
+
+        let initial_items = self.get_items().to_vec();
+        let mut loaded = self.loaded;
+
+        spawn_task(async move {
+            while !should_stop.load(Ordering::Relaxed) {
+                // read each time so a change in the settings overlay applies right away
+                let poll_interval = config::current().poll_interval_for(PollView::Nodes);
+                match with_retry("nodes", list_nodes).await {
+                    Ok(d) => {
+                        if !loaded || d != initial_items {
+                            loaded = true;
+                            let sevent = Message::Node(d);
+                            if tx.send(sevent).await.is_err() {
+                                break;
+                            }
+                        }
+                        // a manual refresh is reported even when nothing changed
+                        if wait_for_poll(poll_interval).await {
+                            loaded = false;
+                        }
+                    }
+                    Err(e) => {
+                        _ = tx
+                            .send(Message::Error(list_error_message(&e, "nodes")))
+                            .await;
+                        // retries are used up, but a flaky cluster may still come back
+                        if !ApiErrorKind::is_transient(&e) {
+                            break;
+                        }
+                        wait_for_poll(poll_interval).await;
+                    }
+                }
+            }
+        });
+
+        ReceiverStream::new(rx)
+    }
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl App {
+    pub fn new() -> Self {
+        let data_vec = vec![];
+        Self {
+            state: TableState::default().with_selected(0),
+            longest_item_lens: node_constraint_len_calculator(&data_vec),
+            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * item_height()),
+            colors: TableColors::new(&PALETTES[0]),
+            table_area: Rect::default(),
+            color_index: 3,
+            items: data_vec,
+            filter: String::new(),
+            refreshed: None,
+            show_filter_edit: false,
+            edit_filter_cursor_position: 0,
+            loaded: false,
+            status: None,
+            drain_confirm: None,
+            quit_confirm: None,
+            drain: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    async fn handle_table_event(&mut self, event: &Message) -> Option<Apps> {
+        if matches!(event, Message::Key(Event::Key(_))) {
+            self.status = None;
+        }
+        let mut app_holder = Some(Apps::Node { app: self.clone() });
+        match event {
+            Message::Key(Event::Key(key)) => {
+                if key.kind == KeyEventKind::Press {
+                    use KeyCode::{Char, Down, Esc, Up};
+                    match key.code {
+                        Char('q') | Esc => {
+                            self.quit_confirm = operations::quit_confirm();
+                            if self.quit_confirm.is_some() {
+                                app_holder = Some(Apps::Node { app: self.clone() });
+                            } else {
+                                app_holder = None;
+                            }
+                        }
+                        Char('j') | Down => {
+                            self.next();
+                            app_holder = Some(Apps::Node { app: self.clone() });
+                        }
+                        Char('k') | Up => {
+                            self.previous();
+                            app_holder = Some(Apps::Node { app: self.clone() });
+                        }
+                        Char('c' | 'C') => {
+                            self.next_color();
+                            app_holder = Some(Apps::Node { app: self.clone() });
+                        }
+                        Char('o' | 'O') if config::current().read_only => {
+                            self.status = Some(read_only_message("cordoning"));
+                            app_holder = Some(Apps::Node { app: self.clone() });
+                        }
+                        Char('o' | 'O') => {
+                            self.toggle_cordon().await;
+                            app_holder = Some(Apps::Node { app: self.clone() });
+                        }
+                        Char('d' | 'D') if config::current().read_only => {
+                            self.status = Some(read_only_message("draining"));
+                            app_holder = Some(Apps::Node { app: self.clone() });
+                        }
+                        Char('d' | 'D') => {
+                            if self.is_draining() {
+                                self.status = Some(StatusMessage::Info(
+                                    "a drain is already running, x cancels it".to_string(),
+                                ));
+                            } else if let Some(name) =
+                                self.get_selected_item().map(|node| node.name.clone())
+                            {
+                                self.drain_confirm = Some(drain_confirm(name));
+                            }
+                            app_holder = Some(Apps::Node { app: self.clone() });
+                        }
+                        Char('x' | 'X') => {
+                            self.cancel_drain();
+                            app_holder = Some(Apps::Node { app: self.clone() });
+                        }
+                        Char('r' | 'R') => {
+                            request_refresh();
+                            self.status = Some(StatusMessage::Info("refreshing…".to_string()));
+                            app_holder = Some(Apps::Node { app: self.clone() });
+                        }
+                        Char('a' | 'A') => {
+                            let fit = cycle_column_fit();
+                            self.status = Some(StatusMessage::Info(format!(
+                                "column widths: {}",
+                                fit.label()
+                            )));
+                            app_holder = Some(Apps::Node { app: self.clone() });
+                        }
+                        Char('f' | 'F') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            self.page_forward();
+                        }
+                        Char('b' | 'B') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            self.page_backward();
+                        }
+                        Char('/') => {
+                            self.set_show_filter_edit(true);
+                            app_holder = Some(Apps::Node { app: self.clone() });
+                        }
+                        _k => {}
+                    }
+                }
+            }
+            Message::Key(Event::Mouse(mouse)) => {
+                if self.handle_mouse_event(mouse) {
+                    app_holder = Some(Apps::Node { app: self.clone() });
+                }
+            }
+            Message::Node(data_vec) => {
+                debug!("updating node app data...");
+                let mut app = self.with_items(data_vec);
+                app.take_drain_outcome();
+                app_holder = Some(Apps::Node { app });
+            }
+            Message::Error(message) => {
+                self.status = Some(StatusMessage::Error(message.clone()));
+                app_holder = Some(Apps::Node { app: self.clone() });
+            }
+            Message::Key(Event::Resize(_, _)) => {
+                self.handle_resize();
+                app_holder = Some(Apps::Node { app: self.clone() });
+            }
+            _ => {}
+        }
+        app_holder
+    }
+
+    fn handle_filter_edit_event(&mut self, event: &Message) -> Option<Apps> {
+        let mut app_holder = Some(Apps::Node { app: self.clone() });
+        match event {
+            Message::Key(Event::Key(key)) => {
+                if key.kind == KeyEventKind::Press {
+                    use KeyCode::{Backspace, Char, Enter, Esc, Left, Right, Tab};
+
+                    match key.code {
+                        Char(to_insert) => {
+                            self.enter_char(to_insert);
+                            app_holder = Some(Apps::Node { app: self.clone() });
+                        }
+                        Backspace => {
+                            self.delete_char();
+                            app_holder = Some(Apps::Node { app: self.clone() });
+                        }
+                        Left => {
+                            self.move_cursor_left();
+                            app_holder = Some(Apps::Node { app: self.clone() });
+                        }
+                        Right => {
+                            self.move_cursor_right();
+                            app_holder = Some(Apps::Node { app: self.clone() });
+                        }
+                        Esc | Enter => {
+                            self.set_show_filter_edit(false);
+                            app_holder = Some(Apps::Node { app: self.clone() });
+                        }
+                        Tab => {
+                            self.toggle_filter_mode();
+                            app_holder = Some(Apps::Node { app: self.clone() });
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Message::Node(data_vec) => {
+                let mut app = self.with_items(data_vec);
+                app.take_drain_outcome();
+                app_holder = Some(Apps::Node { app });
+            }
+            Message::Error(message) => {
+                self.status = Some(StatusMessage::Error(message.clone()));
+                app_holder = Some(Apps::Node { app: self.clone() });
+            }
+            Message::Key(Event::Resize(_, _)) => {
+                self.handle_resize();
+                app_holder = Some(Apps::Node { app: self.clone() });
+            }
+            _ => {}
+        }
+        app_holder
+    }
+
+    fn handle_quit_confirm_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        match self.quit_confirm.as_ref().and_then(|m| m.handle_key(key)) {
+            Some(true) => None,
+            Some(false) => {
+                self.quit_confirm = None;
+                Some(Apps::Node { app: self.clone() })
+            }
+            None => Some(Apps::Node { app: self.clone() }),
+        }
+    }
+
+    fn handle_drain_confirm_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        let Some(confirmed) = self.drain_confirm.as_ref().and_then(|m| m.handle_key(key)) else {
+            return Some(Apps::Node { app: self.clone() });
+        };
+        let node = self
+            .drain_confirm
+            .take()
+            .map(|m| m.subject)
+            .unwrap_or_default();
+        if confirmed {
+            self.start_drain(node);
+        }
+        Some(Apps::Node { app: self.clone() })
+    }
+
+    fn with_items(&mut self, data_vec: &[Node]) -> Self {
+        let selected = self.get_selected_key();
+        let mut new_app = Self {
+            longest_item_lens: node_constraint_len_calculator(data_vec),
+            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * item_height()),
+            items: data_vec.to_vec(),
+            loaded: true,
+            refreshed: Some(Local::now()),
+            ..self.clone()
+        };
+        new_app.select_key(selected.as_deref());
+        new_app
+    }
+
+    /// Cordon the selected node, or uncordon it when it already is
+    async fn toggle_cordon(&mut self) {
+        let Some(node) = self.get_selected_item().cloned() else {
+            return;
+        };
+        let cordon = !node.unschedulable;
+        let verb = if cordon { "cordon" } else { "uncordon" };
+        self.status = Some(match set_unschedulable(&node.name, cordon).await {
+            Ok(()) => {
+                request_refresh();
+                StatusMessage::Info(format!("{verb}ed {}", node.name))
+            }
+            Err(e) => StatusMessage::Error(format!("could not {verb} {}: {e}", node.name)),
+        });
+    }
+
+    fn is_draining(&self) -> bool {
+        self.drain.lock().is_ok_and(|drain| drain.is_some())
+    }
+
+    /// Evict the pods of `node` on a task of its own, the view keeps polling meanwhile
+    /// and is woken up with every bit of progress
+    fn start_drain(&mut self, node: String) {
+        let cancel = Arc::new(AtomicBool::new(false));
+        if let Ok(mut drain) = self.drain.lock() {
+            *drain = Some(Drain {
+                node: node.clone(),
+                progress: DrainProgress::default(),
+                cancel: Arc::clone(&cancel),
+                outcome: None,
+            });
+        }
+        self.status = Some(StatusMessage::Info(format!("draining {node}, x cancels")));
+
+        let shared = Arc::clone(&self.drain);
+        tokio::spawn(async move {
+            // quitting now would leave the node half drained
+            let _operation = Operation::begin(format!("drain node {node}"));
+            let report = |progress: &DrainProgress| {
+                if let Ok(mut drain) = shared.lock() {
+                    if let Some(drain) = drain.as_mut() {
+                        drain.progress = progress.clone();
+                    }
+                }
+                request_refresh();
+            };
+            let outcome = drain_node(&node, &cancel, report)
+                .await
+                .map_err(|e| e.to_string());
+            if let Ok(mut drain) = shared.lock() {
+                if let Some(drain) = drain.as_mut() {
+                    drain.outcome = Some(outcome);
+                }
+            }
+            request_refresh();
+        });
+    }
+
+    fn cancel_drain(&mut self) {
+        if let Ok(drain) = self.drain.lock() {
+            if let Some(drain) = drain.as_ref() {
+                drain.cancel.store(true, Ordering::Relaxed);
+                self.status = Some(StatusMessage::Info(format!(
+                    "cancelling the drain of {}…",
+                    drain.node
+                )));
+            }
+        }
+    }
+
+    /// Report a drain that has finished and clear it, so a new one can start
+    fn take_drain_outcome(&mut self) {
+        let Ok(mut drain) = self.drain.lock() else {
+            return;
+        };
+        let Some(outcome) = drain.as_ref().and_then(|drain| drain.outcome.clone()) else {
+            return;
+        };
+        let node = drain.take().map(|drain| drain.node).unwrap_or_default();
+        self.status = Some(match outcome {
+            Ok(true) => StatusMessage::Info(format!("drained {node}")),
+            Ok(false) => {
+                StatusMessage::Info(format!("drain of {node} cancelled, it stays cordoned"))
+            }
+            Err(e) => StatusMessage::Error(format!("could not drain {node}: {e}")),
+        });
+    }
+
+    /// The node being drained and how far the drain has got
+    pub(crate) fn drain_progress(&self) -> Option<(String, DrainProgress)> {
+        self.drain.lock().ok().and_then(|drain| {
+            drain
+                .as_ref()
+                .map(|drain| (drain.node.clone(), drain.progress.clone()))
+        })
+    }
+
+    /// Details of the selected node for the detail pane
+    pub(crate) fn get_node_details(&mut self) -> Vec<(String, String, Option<String>)> {
+        self.get_selected_item().map_or_else(Vec::new, |node| {
+            let taints = if node.taints.is_empty() {
+                "none".to_string()
+            } else {
+                node.taints.join(", ")
+            };
+            vec![
+                ("Internal IP".to_string(), node.internal_ip.clone(), None),
+                ("OS Image".to_string(), node.os_image.clone(), None),
+                ("Taints".to_string(), taints, None),
+            ]
+        })
+    }
+
+    pub(crate) fn placeholder(&self) -> Option<&'static str> {
+        if !self.loaded {
+            Some("Loading Nodes...")
+        } else if self.items.is_empty() {
+            Some("No Nodes found")
+        } else {
+            None
+        }
+    }
+}
+
+/// The question asked before the named node is drained
+fn drain_confirm(node: String) -> ConfirmModal<String> {
+    let lines = vec![
+        format!("Cordon {node} and evict its pods?"),
+        String::new(),
+        "Pods owned by a DaemonSet and mirror pods are left alone,".to_string(),
+        "disruption budgets are respected.".to_string(),
+        String::new(),
+        "y to drain, any other key to cancel".to_string(),
+    ];
+    ConfirmModal::new(node, "Drain node?", lines)
+}
@@ -0,0 +1,235 @@
+use crate::config;
+use crate::k8s::nodes::DrainProgress;
+use crate::tui::data::Filterable;
+use crate::tui::node_app::app::App;
+use crate::tui::status::{render_status_bar, render_status_line};
+use crate::tui::style::{RowLayout, RowStatus};
+use crate::tui::table_ui::{
+    column_constraints, filter_cell, filter_edit_title, fit_cell_text, render_breadcrumb,
+    render_detail_section, render_table_placeholder, render_too_small, TuiTableState,
+};
+use crate::tui::widgets::centered_rect;
+use ratatui::{
+    prelude::*,
+    widgets::{
+        Block, Borders, Cell, Clear, Gauge, HighlightSpacing, Paragraph, Row, Scrollbar,
+        ScrollbarOrientation, Table,
+    },
+};
+
+pub fn ui(f: &mut Frame, app: &mut App) {
+    if render_too_small(f) {
+        return;
+    }
+    let [crumb_area, view_area, bar_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(f.area());
+
+    app.set_colors();
+    let breadcrumb = app.breadcrumb("Node");
+    render_breadcrumb(f, &app.colors, crumb_area, &breadcrumb);
+
+    // the drain gauge only takes room while a drain runs
+    let drain = app.drain_progress();
+    let gauge_height = if drain.is_some() { 3 } else { 0 };
+    let rects = Layout::vertical([
+        Constraint::Min(5),
+        Constraint::Length(gauge_height),
+        Constraint::Length(7),
+    ])
+    .split(view_area);
+    render_table(f, app, rects[0]);
+    render_scrollbar(f, app, rects[0]);
+    if let Some(message) = app.placeholder() {
+        render_table_placeholder(f, &app.colors, rects[0], message);
+    }
+    if let Some((node, progress)) = drain {
+        render_drain_gauge(f, app, rects[1], &node, &progress);
+    }
+    render_details(f, app, rects[2]);
+
+    if app.get_show_filter_edit() {
+        render_filter_edit(f, app);
+    }
+    if let Some(modal) = &app.drain_confirm {
+        modal.render(f, &app.colors);
+    }
+    if let Some(modal) = &app.quit_confirm {
+        modal.render(f, &app.colors);
+    }
+
+    let bar = app.status_bar("node", app.refreshed);
+    render_status_bar(f, &app.colors, bar_area, &bar);
+
+    if let Some(status) = &app.status {
+        render_status_line(f, &app.colors, status);
+    }
+}
+
+fn render_filter_edit(f: &mut Frame, app: &App) {
+    let edit_style = Style::default()
+        .fg(app.colors.header_fg)
+        .bg(app.colors.header_bg);
+
+    let area = f.area();
+
+    let input_area = centered_rect(60, 20, area);
+
+    let block = Paragraph::new(app.filter.as_str()).style(edit_style).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(filter_edit_title()),
+    );
+
+    f.render_widget(Clear, input_area); //this clears out the background
+    f.render_widget(block, input_area);
+
+    #[allow(clippy::cast_possible_truncation)]
+    let p = Position {
+        x: input_area.x + app.edit_filter_cursor_position as u16 + 1,
+        y: input_area.y + 1,
+    };
+    f.set_cursor_position(p);
+}
+
+fn render_drain_gauge(f: &mut Frame, app: &App, area: Rect, node: &str, progress: &DrainProgress) {
+    let mut label = format!("{}/{} pods evicted", progress.evicted, progress.total);
+    if !progress.blocked.is_empty() {
+        label = format!(
+            "{label}, waiting on a disruption budget for {}",
+            progress.blocked.join(", ")
+        );
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let ratio = if progress.total == 0 {
+        0.0
+    } else {
+        progress.evicted as f64 / progress.total as f64
+    };
+    let gauge = Gauge::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Draining {node} (x to cancel)")),
+        )
+        .gauge_style(Style::default().fg(app.colors.header_bg))
+        .style(Style::default().bg(app.colors.buffer_bg))
+        .ratio(ratio.clamp(0.0, 1.0))
+        .label(label);
+    f.render_widget(gauge, area);
+}
+
+fn render_details(f: &mut Frame, app: &mut App, area: Rect) {
+    let details = app.get_node_details();
+    render_detail_section(
+        f,
+        app.colors.header_fg,
+        app.colors.buffer_bg,
+        area,
+        "Node",
+        &details,
+    );
+}
+
+fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let config = config::current();
+    let fit = config.column_fit;
+    let layout = RowLayout::new(&config);
+    app.set_table_area(area);
+
+    let header_style = Style::default()
+        .fg(app.colors.header_fg)
+        .bg(app.colors.header_bg);
+    let selected_style = Style::default()
+        .add_modifier(Modifier::REVERSED)
+        .fg(app.colors.selected_style_fg);
+    let highlight = Style::new()
+        .fg(app.colors.selected_style_fg)
+        .add_modifier(Modifier::BOLD);
+
+    let filter_header = match app.get_filter() {
+        filter if filter != String::new() => format!("Node ({filter})"),
+        _ => "Node".to_string(),
+    };
+
+    let header = [&filter_header, "Status", "Roles", "Version", "Age"]
+        .iter()
+        .copied()
+        .map(Cell::from)
+        .collect::<Row>()
+        .style(header_style)
+        .height(1);
+    let row_filter = app.row_filter();
+    let rows = row_filter
+        .filter(app.get_items())
+        .into_iter()
+        .enumerate()
+        .map(|(i, data)| {
+            let color = match i % 2 {
+                0 => app.colors.normal_row_color,
+                _ => app.colors.alt_row_color,
+            };
+            let style = app.colors.row_style(if data.is_not_ready() {
+                RowStatus::Error
+            } else if data.unschedulable {
+                RowStatus::Warn
+            } else {
+                RowStatus::Normal
+            });
+            let matched = row_filter.match_indices(data.filter_by());
+            let item = data.ref_array();
+            item.iter()
+                .copied()
+                .map(|content| {
+                    filter_cell(
+                        &fit_cell_text(fit, content, area.width),
+                        data.filter_by(),
+                        &matched,
+                        highlight,
+                        layout,
+                    )
+                })
+                .collect::<Row>()
+                .style(style.bg(color))
+                .height(layout.height())
+        });
+    let bar = layout.selection_symbol();
+    let t = Table::new(
+        rows,
+        column_constraints(
+            fit,
+            &[
+                // + 1 is for padding.
+                app.longest_item_lens.0 + 1,
+                app.longest_item_lens.1 + 1,
+                app.longest_item_lens.2 + 1,
+                app.longest_item_lens.3 + 1,
+                app.longest_item_lens.4,
+            ],
+            area.width,
+        ),
+    )
+    .header(header)
+    .row_highlight_style(selected_style)
+    .highlight_symbol(layout.text(bar.to_string()))
+    .bg(app.colors.buffer_bg)
+    .highlight_spacing(HighlightSpacing::Always);
+    f.render_stateful_widget(t, area, &mut app.state);
+}
+
+fn render_scrollbar(f: &mut Frame, app: &mut App, area: Rect) {
+    f.render_stateful_widget(
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None),
+        area.inner(Margin {
+            vertical: 1,
+            horizontal: 1,
+        }),
+        &mut app.scroll_state,
+    );
+}
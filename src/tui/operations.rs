@@ -0,0 +1,81 @@
+//! Background operations (deletes, port-forwards, ...) that are still running.
+//!
+//! Quitting while one of these is pending would sever it, so the root view
+//! asks for confirmation while the registry is not empty.
+use crate::tui::breadcrumb::at_root;
+use crate::tui::widgets::ConfirmModal;
+use std::collections::BTreeMap;
+use std::sync::{Mutex, OnceLock};
+
+static OPERATIONS: OnceLock<Mutex<Registry>> = OnceLock::new();
+
+#[derive(Default)]
+struct Registry {
+    next_id: u64,
+    pending: BTreeMap<u64, String>,
+}
+
+fn registry() -> &'static Mutex<Registry> {
+    OPERATIONS.get_or_init(|| Mutex::new(Registry::default()))
+}
+
+/// Marks an operation as in flight until it is dropped
+#[derive(Debug)]
+pub struct Operation {
+    id: u64,
+}
+
+impl Operation {
+    pub fn begin(description: impl Into<String>) -> Self {
+        let mut id = 0;
+        if let Ok(mut registry) = registry().lock() {
+            id = registry.next_id;
+            registry.next_id += 1;
+            registry.pending.insert(id, description.into());
+        }
+        Self { id }
+    }
+}
+
+impl Drop for Operation {
+    fn drop(&mut self) {
+        if let Ok(mut registry) = registry().lock() {
+            registry.pending.remove(&self.id);
+        }
+    }
+}
+
+/// Descriptions of the operations still in flight, oldest first
+pub fn pending() -> Vec<String> {
+    registry()
+        .lock()
+        .map(|registry| registry.pending.values().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// The question `q` asks in place of quitting while operations are still running, `None`
+/// when nothing is pending or the view is not the last one open and `q` only goes back
+pub fn quit_confirm() -> Option<ConfirmModal<Vec<String>>> {
+    let pending = pending();
+    if pending.is_empty() || !at_root() {
+        return None;
+    }
+    let mut lines = vec!["Still in progress:".to_string(), String::new()];
+    lines.extend(pending.iter().map(|op| format!("  {op}")));
+    lines.push(String::new());
+    lines.push("y to quit anyway, any other key to stay".to_string());
+    Some(ConfirmModal::new(pending, "Quit navipod?", lines))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tui::operations::{pending, Operation};
+
+    #[test]
+    fn test_operation_is_pending_until_dropped() {
+        let op = Operation::begin("port-forward my-pod 8080:80");
+        assert!(pending().contains(&"port-forward my-pod 8080:80".to_string()));
+        drop(op);
+        assert!(!pending().contains(&"port-forward my-pod 8080:80".to_string()));
+    }
+}
@@ -0,0 +1,165 @@
+//! The pod pinned with `m` in the pod view. A watch on it runs whichever view is open and
+//! flashes a toast when the pod turns ready or starts failing.
+use crate::k8s::pods::watch_rspod;
+use crate::tui::data::RsPod;
+use crate::tui::status::{show_toast, StatusMessage};
+use crate::tui::stream::{shutting_down, spawn_task};
+use futures::StreamExt;
+use std::pin::pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::timeout;
+
+/// How often a quiet watch looks whether the pod was unpinned
+const UNPIN_CHECK: Duration = Duration::from_millis(250);
+
+/// Reasons a container waits or stopped for that it does not get over on its own
+const FAILING_REASONS: [&str; 8] = [
+    "CrashLoopBackOff",
+    "ImagePullBackOff",
+    "ErrImagePull",
+    "InvalidImageName",
+    "CreateContainerConfigError",
+    "RunContainerError",
+    "OOMKilled",
+    "Error",
+];
+
+/// the pinned pod and the flag that stops its watch
+static PINNED: Mutex<Option<(String, Arc<AtomicBool>)>> = Mutex::new(None);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Health {
+    Ready,
+    Failing,
+    Waiting,
+}
+
+fn health(pod: &RsPod) -> Health {
+    if pod.status == "Failed" || FAILING_REASONS.contains(&pod.reason.as_str()) {
+        Health::Failing
+    } else if pod.status == "Running" {
+        Health::Ready
+    } else {
+        Health::Waiting
+    }
+}
+
+/// The toast for `pod` going from `before` to `now`. The state found when the pod is
+/// pinned is where it starts, not a change.
+fn transition(before: Option<Health>, now: Health, pod: &RsPod) -> Option<StatusMessage> {
+    if before.is_none() || before == Some(now) {
+        return None;
+    }
+    match now {
+        Health::Ready => Some(StatusMessage::Info(format!("pinned {} is ready", pod.name))),
+        Health::Failing => {
+            let why = if pod.reason.is_empty() {
+                &pod.status
+            } else {
+                &pod.reason
+            };
+            Some(StatusMessage::Error(format!(
+                "pinned {} is failing: {why}",
+                pod.name
+            )))
+        }
+        Health::Waiting => None,
+    }
+}
+
+/// The name of the pinned pod, if any
+pub fn pinned() -> Option<String> {
+    PINNED
+        .lock()
+        .ok()
+        .and_then(|pinned| pinned.as_ref().map(|(name, _)| name.clone()))
+}
+
+/// Pin `name`, replacing any pod pinned before, or unpin it when it already is
+pub fn toggle_pin(name: &str) -> StatusMessage {
+    let Ok(mut pinned) = PINNED.lock() else {
+        return StatusMessage::Error("could not pin the pod".to_string());
+    };
+    let previous = pinned.take();
+    if let Some((_, stop)) = &previous {
+        stop.store(true, Ordering::Relaxed);
+    }
+    if previous.is_some_and(|(previous, _)| previous == name) {
+        return StatusMessage::Info(format!("unpinned {name}"));
+    }
+    let stop = Arc::new(AtomicBool::new(false));
+    *pinned = Some((name.to_string(), Arc::clone(&stop)));
+    spawn_task(watch_pinned(name.to_string(), stop));
+    StatusMessage::Info(format!(
+        "pinned {name}, a toast tells when it is ready or failing"
+    ))
+}
+
+async fn watch_pinned(name: String, stop: Arc<AtomicBool>) {
+    let pods = match watch_rspod(&name).await {
+        Ok(pods) => pods,
+        Err(e) => {
+            show_toast(StatusMessage::Error(format!("could not watch {name}: {e}")));
+            return;
+        }
+    };
+    let mut pods = pin!(pods);
+    let mut before = None;
+    while !stop.load(Ordering::Relaxed) && !shutting_down() {
+        match timeout(UNPIN_CHECK, pods.next()).await {
+            Err(_) => {}
+            Ok(None) => break,
+            Ok(Some(pod)) => {
+                let now = health(&pod);
+                if let Some(toast) = transition(before, now, &pod) {
+                    show_toast(toast);
+                }
+                before = Some(now);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{health, transition, Health};
+    use crate::tui::data::RsPod;
+
+    fn pod(status: &str, reason: &str) -> RsPod {
+        RsPod {
+            name: "api-7d9f-x2k4p".to_string(),
+            status: status.to_string(),
+            description: "ReplicaSet/api-7d9f".to_string(),
+            age: "1m".to_string(),
+            containers: "0/1".to_string(),
+            node: "node-a".to_string(),
+            ip: "10.0.1.17".to_string(),
+            qos: "Burstable".to_string(),
+            priority: "0".to_string(),
+            startup: String::new(),
+            reason: reason.to_string(),
+            oom_killed: String::new(),
+            created: None,
+            protected: false,
+            selectors: None,
+            events: vec![],
+            scheduling: vec![],
+        }
+    }
+
+    #[test]
+    fn test_transition_toasts_only_changes_to_ready_or_failing() {
+        let starting = pod("Starting", "");
+        let ready = pod("Running", "");
+        let crashing = pod("Starting", "CrashLoopBackOff");
+
+        assert_eq!(health(&crashing), Health::Failing);
+        assert!(transition(None, health(&ready), &ready).is_none());
+        assert!(transition(Some(Health::Waiting), health(&starting), &starting).is_none());
+        assert!(transition(Some(Health::Waiting), health(&ready), &ready).is_some());
+        assert!(transition(Some(Health::Ready), health(&crashing), &crashing).is_some());
+        assert!(transition(Some(Health::Failing), health(&starting), &starting).is_none());
+    }
+}
@@ -1,38 +1,129 @@
-use crate::k8s::pods::list_rspods;
+use crate::config::{self, PollView};
+use crate::config_file::ConfigFile;
+use crate::error;
+use crate::k8s::bookmarks::resolve_bookmarks;
+use crate::k8s::client::current_namespace;
+use crate::k8s::containers::tail_logs;
+use crate::k8s::errors::{list_error_message, with_retry, ApiErrorKind};
+use crate::k8s::manifests::{get_manifest, patch_metadata};
+use crate::k8s::pending::explain_pending;
+use crate::k8s::pods::{delete_pods, list_rspods, watch_rspod};
+use crate::k8s::reasons::explain_reason;
+use crate::k8s::related::related_resources;
+use crate::labels::{format_label_selector, parse_metadata_edit};
+use crate::tui::bookmarks::{open_bookmark, toggle_bookmark, BookmarkAction, BookmarkList};
+use crate::tui::clipboard::copy_to_clipboard;
+use crate::tui::columns::{ColumnPicker, PickerOutcome};
 use crate::tui::container_app;
-use crate::tui::data::{pod_constraint_len_calculator, RsPod};
+use crate::tui::data::{newest_arrival, pod_constraint_len_calculator, Filterable, RsPod};
+use crate::tui::editor::{apply_edit, edit_resource, take_repaint, EditedManifest};
+use crate::tui::export::export_items;
+use crate::tui::finder::{Finder, FinderAction};
 use crate::tui::ingress_app;
+use crate::tui::operations::{self, Operation};
+use crate::tui::pinned::toggle_pin;
 use crate::tui::pod_app;
-use crate::tui::stream::Message;
-use crate::tui::style::{TableColors, ITEM_HEIGHT, PALETTES};
-use crate::tui::table_ui::TuiTableState;
-use crate::tui::ui_loop::{create_container_data_vec, create_ingress_data_vec, AppBehavior, Apps};
-use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
-use futures::Stream;
+use crate::tui::pvc_app;
+use crate::tui::settings::{Settings, SettingsOutcome};
+use crate::tui::status::{read_only_message, show_toast, StatusMessage};
+use crate::tui::stream::{
+    request_refresh, sleep_unless_stopped, spawn_task, tick_after, wait_for_poll, Message,
+};
+use crate::tui::style::{item_height, TableColors, PALETTES};
+use crate::tui::table_ui::{
+    apply_api_selector, changed_rows, cycle_column_fit, edit_goto, max_column_offset, run_goto,
+    update_flashes, TuiTableState, FLASH_DURATION,
+};
+use crate::tui::ui_loop::{create_ingress_data_vec, target_app, AppBehavior, Apps};
+use crate::tui::widgets::{ConfirmModal, InfoModal, InputOutcome, TextInput};
+use chrono::{DateTime, Local};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use futures::{Stream, StreamExt};
+use k8s_openapi::api::core::v1::Pod;
 use ratatui::prelude::*;
 use ratatui::widgets::{ScrollbarState, TableState};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::io;
+use std::pin::pin;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
-use tokio::time::sleep;
+use tokio::time::timeout;
 use tokio_stream::wrappers::ReceiverStream;
 use tracing::debug;
 
-const POLL_MS: u64 = 5000;
+/// How often the pod watch looks whether the selection moved while nothing changes
+const WATCH_CHECK: Duration = Duration::from_millis(250);
+
+/// How often the log pane fetches the selected pod's latest lines
+const LOG_PANE_POLL: Duration = Duration::from_secs(2);
+
+/// Lines fetched for the log pane, more than a pane is likely to be tall
+const LOG_PANE_LINES: i64 = 200;
 
 #[derive(Clone, Debug)]
 pub struct App {
     pub(crate) state: TableState,
     pub(crate) items: Vec<RsPod>,
-    pub(crate) longest_item_lens: (u16, u16, u16, u16, u16),
+    pub(crate) longest_item_lens: (u16, u16, u16, u16, u16, u16, u16, u16),
     pub(crate) scroll_state: ScrollbarState,
+    pub(crate) table_area: Rect,
     pub(crate) colors: TableColors,
     pub(crate) color_index: usize,
     pub(crate) selector: BTreeMap<String, String>,
     pub(crate) filter: String,
+    pub(crate) loaded: bool,
+    pub(crate) status: Option<StatusMessage>,
+    /// when data was last applied, for the status bar
+    pub(crate) refreshed: Option<DateTime<Local>>,
+    /// digits typed after `:`, while the goto prompt is open
+    pub(crate) goto: Option<String>,
+    /// label selector typed after `L`, while the prompt is open
+    pub(crate) selector_prompt: Option<TextInput>,
+    /// the pod and the label or annotation typed after `+`, while the form is open
+    pub(crate) metadata_prompt: Option<(String, TextInput)>,
+    /// the runtime options opened with `,`
+    pub(crate) settings: Option<Settings>,
+    /// the columns shown and their order, while `|` has them open
+    pub(crate) columns: Option<ColumnPicker>,
+    /// the name column shows generated suffixes collapsed, toggled with `n`
+    pub(crate) short_names: bool,
+    /// columns scrolled out of view to the left with `h` and `l`
+    pub(crate) column_offset: usize,
+    /// rows that changed in a recent poll and when, for a brief highlight
+    pub(crate) flashes: BTreeMap<String, Instant>,
+    /// names of the pods checked with space for a batch delete
+    pub(crate) checked: BTreeSet<String>,
+    /// the namespace and the pods to delete while the confirmation is open
+    pub(crate) delete_confirm: Option<ConfirmModal<(String, Vec<String>)>>,
+    /// operations that were still running when quit was requested
+    pub(crate) quit_confirm: Option<ConfirmModal<Vec<String>>>,
+    /// the dry run of an edit while its confirmation is open, under `--dry-run-first`
+    pub(crate) edit_confirm: Option<ConfirmModal<EditedManifest>>,
+    /// pod to select once the first list arrives, when opened from the finder
+    pub(crate) focus: Option<String>,
+    /// pods first evicted under node pressure are listed first, toggled with `s`
+    pub(crate) sort_by_qos: bool,
+    /// every pod the selector matches, `items` leaves out finished ones unless shown
+    pub(crate) all_items: Vec<RsPod>,
+    /// pods of Jobs that completed or failed are listed too, toggled with `t`
+    pub(crate) show_finished: bool,
+    /// the selected pod, shared with the task that watches it between polls
+    pub(crate) watched: Arc<Mutex<Option<String>>>,
+    /// the log pane beside the table is open, toggled with `p` and shared with the task
+    /// tailing the selected pod
+    pub(crate) log_pane: Arc<AtomicBool>,
+    /// the pod the log pane shows and its latest lines
+    pub(crate) pane_log: Option<(String, Vec<String>)>,
+    /// a pod that shows up in a refresh takes the selection, toggled with `f`
+    pub(crate) follow_newest: bool,
+    /// the resources related to the selected pod, while `g` has them open
+    pub(crate) related: Option<Finder>,
+    /// why the selected pod is not scheduled, while `e` has it open
+    pub(crate) pending: Option<InfoModal>,
+    /// the bookmarked resources, while `'` has them open
+    pub(crate) bookmarks: Option<BookmarkList>,
 }
 
 impl TuiTableState for App {
@@ -54,6 +145,14 @@ impl TuiTableState for App {
         self.scroll_state = scroll_state;
     }
 
+    fn get_table_area(&self) -> Rect {
+        self.table_area
+    }
+
+    fn set_table_area(&mut self, table_area: Rect) {
+        self.table_area = table_area;
+    }
+
     fn set_table_colors(&mut self, colors: TableColors) {
         self.colors = colors;
     }
@@ -68,7 +167,7 @@ impl TuiTableState for App {
 
     fn reset_selection_state(&mut self) {
         self.state = TableState::default().with_selected(0);
-        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * ITEM_HEIGHT);
+        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * item_height());
     }
 
     fn get_filter(&self) -> String {
@@ -92,20 +191,141 @@ impl TuiTableState for App {
     }
 
     fn get_show_filter_edit(&self) -> bool {
-        todo!()
+        false
+    }
+
+    fn typing(&self) -> bool {
+        self.quit_confirm.is_some()
+            || self.goto.is_some()
+            || self.selector_prompt.is_some()
+            || self.metadata_prompt.is_some()
+            || self.settings.is_some()
+            || self.columns.is_some()
+            || self.delete_confirm.is_some()
+            || self.edit_confirm.is_some()
+            || self.related.is_some()
+            || self.pending.is_some()
+            || self.bookmarks.is_some()
+    }
+    fn namespace_bound(&self) -> bool {
+        !self.checked.is_empty()
     }
 }
 
 impl AppBehavior for pod_app::app::App {
     async fn handle_event(&mut self, event: &Message) -> Result<Option<Apps>, io::Error> {
+        if let (Some(_), Message::Key(Event::Key(key))) = (&self.quit_confirm, event) {
+            return Ok(self.handle_quit_confirm_event(key));
+        }
+        if let (Some(_), Message::Key(Event::Key(key))) = (&self.delete_confirm, event) {
+            return Ok(self.handle_delete_confirm_event(key));
+        }
+        if let (Some(_), Message::Key(Event::Key(key))) = (&self.edit_confirm, event) {
+            return Ok(self.handle_edit_confirm_event(key).await);
+        }
+        if let (Some(_), Message::Key(Event::Key(key))) = (&self.goto, event) {
+            return Ok(self.handle_goto_event(key).await);
+        }
+        if let (Some(_), Message::Key(Event::Key(key))) = (&self.selector_prompt, event) {
+            return Ok(self.handle_selector_prompt_event(key));
+        }
+        if let (Some(_), Message::Key(Event::Key(key))) = (&self.metadata_prompt, event) {
+            return Ok(self.handle_metadata_prompt_event(key).await);
+        }
+        if let (Some(_), Message::Key(Event::Key(key))) = (&self.settings, event) {
+            return Ok(self.handle_settings_event(key));
+        }
+        if let (Some(_), Message::Key(Event::Key(key))) = (&self.columns, event) {
+            return Ok(self.handle_columns_event(key));
+        }
+        if let (Some(_), Message::Key(Event::Key(key))) = (&self.related, event) {
+            return Ok(self.handle_related_event(key));
+        }
+        if let (Some(_), Message::Key(Event::Key(key))) = (&self.bookmarks, event) {
+            return Ok(self.handle_bookmarks_event(key));
+        }
+        if let (Some(modal), Message::Key(Event::Key(key))) = (&self.pending, event) {
+            if modal.handle_key(key) {
+                self.pending = None;
+            }
+            return Ok(Some(Apps::Pod { app: self.clone() }));
+        }
+        if matches!(event, Message::Key(Event::Key(_))) {
+            self.status = None;
+        }
         let mut app_holder = Some(Apps::Pod { app: self.clone() });
         match event {
             Message::Key(Event::Key(key)) => {
                 if key.kind == KeyEventKind::Press {
-                    use KeyCode::{Char, Down, Enter, Esc, Up};
+                    use KeyCode::{Char, Down, Enter, Esc, Left, Right, Up};
                     match key.code {
+                        Esc if !self.checked.is_empty() => {
+                            self.checked.clear();
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
                         Char('q') | Esc => {
-                            app_holder = None;
+                            self.quit_confirm = operations::quit_confirm();
+                            if self.quit_confirm.is_some() {
+                                app_holder = Some(Apps::Pod { app: self.clone() });
+                            } else {
+                                app_holder = None;
+                            }
+                        }
+                        Char(' ') => {
+                            self.toggle_checked();
+                            self.next();
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('*') => {
+                            let names = self
+                                .get_filtered_items()
+                                .iter()
+                                .map(|pod| pod.name.clone())
+                                .collect::<Vec<String>>();
+                            self.checked.extend(names);
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('+') if config::current().read_only => {
+                            self.status = Some(read_only_message("labeling"));
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('+') => {
+                            if let Some(name) =
+                                self.get_selected_item().map(|item| item.name.clone())
+                            {
+                                let title =
+                                    format!("{name}: key=value labels, key- unlabels, @ annotates");
+                                self.metadata_prompt = Some((name, TextInput::new(title, "")));
+                            }
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('d' | 'D') if config::current().read_only => {
+                            self.status = Some(read_only_message("deleting pods"));
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('d' | 'D') => {
+                            let names = if self.checked.is_empty() {
+                                self.get_selected_item()
+                                    .map(|pod| vec![pod.name.clone()])
+                                    .unwrap_or_default()
+                            } else {
+                                self.checked.iter().cloned().collect()
+                            };
+                            let (protected, names) = self.split_protected(names);
+                            if !protected.is_empty() {
+                                self.status = Some(protected_message(&protected));
+                            }
+                            if !names.is_empty() {
+                                let namespace = current_namespace();
+                                let preview = if config::current().dry_run_first {
+                                    dry_run_delete(&namespace, &names).await
+                                } else {
+                                    Vec::new()
+                                };
+                                self.delete_confirm =
+                                    Some(delete_confirm(namespace, names, preview));
+                            }
+                            app_holder = Some(Apps::Pod { app: self.clone() });
                         }
                         Char('j') | Down => {
                             self.next();
@@ -122,32 +342,225 @@ impl AppBehavior for pod_app::app::App {
                         Char('i' | 'I') => {
                             if let Some(selection) = self.get_selected_item() {
                                 if let Some(selector) = selection.selectors.clone() {
-                                    let data_vec =
-                                        create_ingress_data_vec(selector.clone()).await?;
-                                    let new_app_holder = Apps::Ingress {
-                                        app: ingress_app::app::App::new(data_vec),
-                                    };
-                                    app_holder = Some(new_app_holder);
-                                    debug!("changing app from rs to ingress...");
+                                    match create_ingress_data_vec(selector.clone()).await {
+                                        Ok(data_vec) => {
+                                            let new_app_holder = Apps::Ingress {
+                                                app: ingress_app::app::App::new(data_vec),
+                                            };
+                                            app_holder = Some(new_app_holder);
+                                            debug!("changing app from rs to ingress...");
+                                        }
+                                        Err(e) => {
+                                            self.status = Some(StatusMessage::Error(e.to_string()));
+                                            app_holder = Some(Apps::Pod { app: self.clone() });
+                                        }
+                                    }
+                                };
+                            };
+                        }
+                        Char('v' | 'V') => {
+                            if let Some(selection) = self.get_selected_item() {
+                                let new_app_holder = Apps::Pvc {
+                                    app: pvc_app::app::App::new(Some(selection.name.clone())),
                                 };
+                                app_holder = Some(new_app_holder);
+                                debug!("changing app from pod to pvc...");
                             };
                         }
+                        Char('h') | Left => {
+                            self.column_offset = self.column_offset.saturating_sub(1);
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('l') | Right => {
+                            let max = max_column_offset(
+                                pod_app::ui::shown_columns().len(),
+                                pod_app::ui::PINNED_COLUMNS,
+                            );
+                            self.column_offset = (self.column_offset + 1).min(max);
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('n') => {
+                            self.short_names = !self.short_names;
+                            let names = if self.short_names { "short" } else { "full" };
+                            self.status = Some(StatusMessage::Info(format!("{names} names")));
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('t' | 'T') => {
+                            self.set_show_finished(!self.show_finished);
+                            let shown = if self.show_finished {
+                                "showing"
+                            } else {
+                                "hiding"
+                            };
+                            self.status = Some(StatusMessage::Info(format!(
+                                "{shown} completed and failed pods"
+                            )));
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('s' | 'S') => {
+                            self.sort_by_qos = !self.sort_by_qos;
+                            if self.sort_by_qos {
+                                let selected = self.get_selected_key();
+                                self.all_items = self.sorted(&self.all_items);
+                                self.items = self.sorted(&self.items);
+                                self.select_key(selected.as_deref());
+                                self.status = Some(StatusMessage::Info(
+                                    "sorted by QoS class, BestEffort first".to_string(),
+                                ));
+                            } else {
+                                // the next list brings back the api server's order
+                                request_refresh();
+                                self.status =
+                                    Some(StatusMessage::Info("sorted by name".to_string()));
+                            }
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('p' | 'P') => {
+                            let open = !self.log_pane.load(Ordering::Relaxed);
+                            self.log_pane.store(open, Ordering::Relaxed);
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('L') => {
+                            // start from the selector in force so it can be edited
+                            self.selector_prompt = Some(TextInput::new(
+                                "api label selector",
+                                format_label_selector(&config::current().api_selector),
+                            ));
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('|') => {
+                            self.columns = Some(ColumnPicker::new(
+                                pod_app::ui::COLUMNS_VIEW,
+                                RsPod::COLUMNS,
+                                pod_app::ui::PINNED_COLUMNS,
+                            ));
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char(',') => {
+                            // the overlay starts from what this view shows
+                            let show_finished = self.show_finished;
+                            config::update(|c| c.show_finished = show_finished);
+                            self.settings = Some(Settings::new(self.color_index));
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char(':') => {
+                            self.goto = Some(String::new());
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('m' | 'M') => {
+                            if let Some(name) =
+                                self.get_selected_item().map(|item| item.name.clone())
+                            {
+                                self.status = Some(toggle_pin(&name));
+                            }
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('g' | 'G') => {
+                            if let Some(name) =
+                                self.get_selected_item().map(|item| item.name.clone())
+                            {
+                                match related_resources(&name).await {
+                                    Ok(entries) => {
+                                        self.related = Some(Finder::related(&name, entries));
+                                    }
+                                    Err(e) => {
+                                        self.status = Some(StatusMessage::Error(format!(
+                                            "could not find what {name} relates to: {e}"
+                                        )));
+                                    }
+                                }
+                            }
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('"') => {
+                            if let Some(name) =
+                                self.get_selected_item().map(|item| item.name.clone())
+                            {
+                                self.status = Some(toggle_bookmark("Pod", &name));
+                            }
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('\'') => {
+                            match resolve_bookmarks(ConfigFile::load().bookmarks).await {
+                                Ok(rows) => self.bookmarks = Some(BookmarkList::new(rows)),
+                                Err(e) => {
+                                    self.status = Some(StatusMessage::Error(format!(
+                                        "could not look up the bookmarks: {e}"
+                                    )));
+                                }
+                            }
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('e' | 'E') => {
+                            self.explain_selected().await;
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('y' | 'Y') => {
+                            if let Some(name) =
+                                self.get_selected_item().map(|item| item.name.clone())
+                            {
+                                self.status = Some(match get_manifest::<Pod>(&name).await {
+                                    Ok(yaml) => copy_to_clipboard(&format!("{name} YAML"), &yaml),
+                                    Err(e) => {
+                                        StatusMessage::Error(format!("could not read {name}: {e}"))
+                                    }
+                                });
+                            }
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('o' | 'O') if config::current().read_only => {
+                            self.status = Some(read_only_message("editing"));
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('o' | 'O') => {
+                            if let Some(name) =
+                                self.get_selected_item().map(|item| item.name.clone())
+                            {
+                                let (status, confirm) =
+                                    edit_resource::<Pod>("pod", &name).await.into_parts();
+                                self.status = status.or(self.status.take());
+                                self.edit_confirm = confirm;
+                            }
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('w' | 'W') => {
+                            self.status = Some(export_items("pods", self.get_items()));
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('r' | 'R') => {
+                            request_refresh();
+                            self.status = Some(StatusMessage::Info("refreshing…".to_string()));
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
+                        Char('a' | 'A') => {
+                            let fit = cycle_column_fit();
+                            self.status = Some(StatusMessage::Info(format!(
+                                "column widths: {}",
+                                fit.label()
+                            )));
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
                         Char('f' | 'F') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                             self.page_forward();
                         }
+                        Char('f') => {
+                            self.follow_newest = !self.follow_newest;
+                            let state = if self.follow_newest { "on" } else { "off" };
+                            self.status =
+                                Some(StatusMessage::Info(format!("follow newest pod {state}")));
+                            app_holder = Some(Apps::Pod { app: self.clone() });
+                        }
                         Char('b' | 'B') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                             self.page_backward();
                         }
                         Enter => {
                             if let Some(selection) = self.get_selected_item() {
                                 if let Some(selectors) = selection.selectors.clone() {
-                                    let data_vec = create_container_data_vec(
-                                        selectors,
-                                        selection.name.clone(),
-                                    )
-                                    .await?;
                                     let new_app_holder = Apps::Container {
-                                        app: container_app::app::App::new(data_vec),
+                                        app: container_app::app::App::new(
+                                            selectors,
+                                            selection.name.clone(),
+                                        ),
                                     };
                                     app_holder = Some(new_app_holder);
                                 };
@@ -157,49 +570,124 @@ impl AppBehavior for pod_app::app::App {
                     }
                 }
             }
+            Message::Key(Event::Mouse(mouse)) => {
+                if self.handle_mouse_event(mouse) {
+                    app_holder = Some(Apps::Pod { app: self.clone() });
+                }
+            }
             Message::Pod(data_vec) => {
                 debug!("updating pod app data...");
-                let new_app = Self {
+                let all_items = self.sorted(data_vec);
+                let data_vec = &self.visible(&all_items);
+                // the first load brings no new pods, only the ones already running
+                let arrival = newest_arrival(&self.all_items, &all_items)
+                    .filter(|_| self.follow_newest && self.loaded)
+                    .map(|pod| pod.name.clone());
+                let selected = self
+                    .focus
+                    .take()
+                    .or(arrival)
+                    .or_else(|| self.get_selected_key());
+                // forget checks on pods that are gone
+                self.checked
+                    .retain(|name| data_vec.iter().any(|pod| &pod.name == name));
+                let mut new_app = Self {
                     longest_item_lens: pod_constraint_len_calculator(data_vec),
                     items: data_vec.clone(),
+                    loaded: true,
                     scroll_state: ScrollbarState::new(
-                        data_vec.len().saturating_sub(1) * ITEM_HEIGHT,
+                        data_vec.len().saturating_sub(1) * item_height(),
                     ),
+                    refreshed: Some(Local::now()),
+                    flashes: self.flashes_after(data_vec),
+                    all_items,
                     ..self.clone()
                 };
+                new_app.select_key(selected.as_deref());
                 let new_app_holder = Apps::Pod { app: new_app };
                 app_holder = Some(new_app_holder);
             }
+            Message::WatchedPod(pod) => {
+                self.apply_watched(pod);
+                app_holder = Some(Apps::Pod { app: self.clone() });
+            }
+            Message::PodLog(pod, lines) => {
+                self.pane_log = Some((pod.clone(), lines.clone()));
+                app_holder = Some(Apps::Pod { app: self.clone() });
+            }
+            Message::Error(message) => {
+                self.status = Some(StatusMessage::Error(message.clone()));
+                app_holder = Some(Apps::Pod { app: self.clone() });
+            }
+            Message::Key(Event::Resize(_, _)) => {
+                self.handle_resize();
+                app_holder = Some(Apps::Pod { app: self.clone() });
+            }
             _ => {}
         }
         Ok(app_holder)
     }
     fn draw_ui<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<(), std::io::Error> {
-        terminal.draw(|f| pod_app::ui::ui(f, &mut self.clone()))?;
+        if take_repaint() {
+            terminal.clear()?;
+        }
+        let selected = self.get_selected_item().map(|pod| pod.name.clone());
+        if let Ok(mut watched) = self.watched.lock() {
+            *watched = selected;
+        }
+        terminal.draw(|f| pod_app::ui::ui(f, self))?;
         Ok(())
     }
 
     fn stream(&self, should_stop: Arc<AtomicBool>) -> impl Stream<Item = Message> {
         let (tx, rx) = mpsc::channel(100);
 
-        let initial_items = self.get_items().to_vec();
+        let initial_items = self.all_items.clone();
         let selector = self.selector.clone();
+        let mut loaded = self.loaded;
+
+        spawn_task(follow_selected(
+            Arc::clone(&self.watched),
+            should_stop.clone(),
+            tx.clone(),
+        ));
+        spawn_task(tail_selected(
+            Arc::clone(&self.watched),
+            Arc::clone(&self.log_pane),
+            should_stop.clone(),
+            tx.clone(),
+        ));
 
-        tokio::spawn(async move {
+        spawn_task(async move {
             while !should_stop.load(Ordering::Relaxed) {
+                // read each time so a change in the settings overlay applies right away
+                let poll_interval = config::current().poll_interval_for(PollView::Pods);
                 //get Vec and send
-                match list_rspods(selector.clone()).await {
+                match with_retry("pods", || list_rspods(selector.clone())).await {
                     Ok(d) => {
-                        if !d.is_empty() && d != initial_items {
+                        if !loaded || d != initial_items {
+                            loaded = true;
                             let sevent = Message::Pod(d);
                             if tx.send(sevent).await.is_err() {
                                 break;
                             }
+                            // redraw once any rows flashed by the change are done
+                            tick_after(&tx, FLASH_DURATION);
+                        }
+                        // a manual refresh is reported even when nothing changed
+                        if wait_for_poll(poll_interval).await {
+                            loaded = false;
                         }
-                        sleep(Duration::from_millis(POLL_MS)).await;
                     }
-                    Err(_e) => {
-                        break;
+                    Err(e) => {
+                        _ = tx
+                            .send(Message::Error(list_error_message(&e, "pods")))
+                            .await;
+                        // retries are used up, but a flaky cluster may still come back
+                        if !ApiErrorKind::is_transient(&e) {
+                            break;
+                        }
+                        wait_for_poll(poll_interval).await;
                     }
                 }
             }
@@ -214,12 +702,367 @@ impl App {
         Self {
             state: TableState::default().with_selected(0),
             longest_item_lens: pod_constraint_len_calculator(&data_vec),
-            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * ITEM_HEIGHT),
+            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * item_height()),
             colors: TableColors::new(&PALETTES[0]),
+            table_area: Rect::default(),
             color_index: 1,
-            items: data_vec,
+            items: data_vec.clone(),
             selector,
             filter: String::new(),
+            refreshed: None,
+            goto: None,
+            selector_prompt: None,
+            metadata_prompt: None,
+            settings: None,
+            columns: None,
+            short_names: false,
+            column_offset: 0,
+            flashes: BTreeMap::new(),
+            checked: BTreeSet::new(),
+            delete_confirm: None,
+            quit_confirm: None,
+            edit_confirm: None,
+            focus: None,
+            sort_by_qos: false,
+            all_items: data_vec,
+            show_finished: config::current().show_finished,
+            watched: Arc::new(Mutex::new(None)),
+            log_pane: Arc::new(AtomicBool::new(false)),
+            pane_log: None,
+            follow_newest: false,
+            related: None,
+            pending: None,
+            bookmarks: None,
+            loaded: false,
+            status: None,
+        }
+    }
+
+    /// The pods of `all_items` the view lists, leaving out finished ones unless `t` shows them
+    fn visible(&self, all_items: &[RsPod]) -> Vec<RsPod> {
+        all_items
+            .iter()
+            .filter(|pod| self.show_finished || !pod.is_finished())
+            .cloned()
+            .collect()
+    }
+
+    /// List or hide finished pods, keeping the selection on the same pod
+    fn set_show_finished(&mut self, show_finished: bool) {
+        self.show_finished = show_finished;
+        let selected = self.get_selected_key();
+        self.items = self.visible(&self.all_items);
+        self.longest_item_lens = pod_constraint_len_calculator(&self.items);
+        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * item_height());
+        self.select_key(selected.as_deref());
+    }
+
+    /// Finished pods `t` would bring back, for the footer
+    pub(crate) fn hidden_count(&self) -> usize {
+        self.all_items.len() - self.items.len()
+    }
+
+    /// `data_vec` in the order the view lists it
+    fn sorted(&self, data_vec: &[RsPod]) -> Vec<RsPod> {
+        let mut items = data_vec.to_vec();
+        if self.sort_by_qos {
+            items.sort_by_key(RsPod::qos_rank);
+        }
+        items
+    }
+
+    /// Rows to highlight once `data_vec` replaces the current items. The first load is
+    /// not a change.
+    fn flashes_after(&self, data_vec: &[RsPod]) -> BTreeMap<String, Instant> {
+        let changed = if self.loaded {
+            changed_rows(
+                &self.items,
+                data_vec,
+                |item| item.name.as_str(),
+                |pod| (pod.status.clone(), pod.containers.clone()),
+            )
+        } else {
+            vec![]
+        };
+        update_flashes(&self.flashes, changed)
+    }
+
+    /// List completed and failed pods from the start, e.g. for the pods of a Job
+    #[must_use]
+    pub const fn with_finished_shown(mut self) -> Self {
+        self.show_finished = true;
+        self
+    }
+
+    /// Select the pod named `name` as soon as it is listed
+    #[must_use]
+    pub fn with_focus(mut self, name: String) -> Self {
+        self.focus = Some(name);
+        self
+    }
+
+    /// Put a pod reported by the watch in place of its row, keeping the events the
+    /// poll found for it
+    fn apply_watched(&mut self, pod: &RsPod) {
+        for item in self.all_items.iter_mut().chain(self.items.iter_mut()) {
+            if item.name == pod.name {
+                *item = RsPod {
+                    events: item.events.clone(),
+                    ..pod.clone()
+                };
+            }
+        }
+    }
+
+    /// `names` split into the pods carrying the protection annotation and the rest
+    fn split_protected(&self, names: Vec<String>) -> (Vec<String>, Vec<String>) {
+        names.into_iter().partition(|name| {
+            self.all_items
+                .iter()
+                .any(|pod| &pod.name == name && pod.protected)
+        })
+    }
+
+    fn toggle_checked(&mut self) {
+        let Some(name) = self.get_selected_item().map(|pod| pod.name.clone()) else {
+            return;
+        };
+        if !self.checked.remove(&name) {
+            self.checked.insert(name);
+        }
+    }
+
+    fn handle_quit_confirm_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        match self.quit_confirm.as_ref().and_then(|m| m.handle_key(key)) {
+            Some(true) => None,
+            Some(false) => {
+                self.quit_confirm = None;
+                Some(Apps::Pod { app: self.clone() })
+            }
+            None => Some(Apps::Pod { app: self.clone() }),
+        }
+    }
+
+    fn handle_delete_confirm_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        let Some(confirmed) = self.delete_confirm.as_ref().and_then(|m| m.handle_key(key)) else {
+            return Some(Apps::Pod { app: self.clone() });
+        };
+        let (namespace, names) = self
+            .delete_confirm
+            .take()
+            .map(|m| m.subject)
+            .unwrap_or_default();
+        if confirmed {
+            self.checked.clear();
+            self.status = Some(StatusMessage::Info(format!(
+                "deleting {} pod(s)",
+                names.len()
+            )));
+            spawn_task(async move {
+                // registered until the api server answered, quitting sooner asks first
+                let _operation =
+                    Operation::begin(format!("delete {} pod(s) in {namespace}", names.len()));
+                let result = delete_pods(&namespace, &names, false).await;
+                show_toast(delete_outcome(&names, result));
+                request_refresh();
+            });
+        }
+        Some(Apps::Pod { app: self.clone() })
+    }
+
+    async fn handle_edit_confirm_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        let Some(confirmed) = self.edit_confirm.as_ref().and_then(|m| m.handle_key(key)) else {
+            return Some(Apps::Pod { app: self.clone() });
+        };
+        if let Some(modal) = self.edit_confirm.take().filter(|_| confirmed) {
+            let (status, confirm) = apply_edit::<Pod>(modal.subject).await.into_parts();
+            self.status = status.or(self.status.take());
+            self.edit_confirm = confirm;
+        }
+        Some(Apps::Pod { app: self.clone() })
+    }
+
+    fn handle_settings_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        if let Some(settings) = self.settings.as_mut() {
+            match settings.handle_key(key) {
+                SettingsOutcome::Open => {}
+                SettingsOutcome::Closed => self.settings = None,
+                SettingsOutcome::Saved(status) => self.status = Some(status),
+            }
+        }
+        if let Some(palette) = self.settings.as_ref().map(Settings::palette) {
+            self.color_index = palette;
+            self.set_colors();
+        }
+        let show_finished = config::current().show_finished;
+        if show_finished != self.show_finished {
+            self.set_show_finished(show_finished);
+        }
+        Some(Apps::Pod { app: self.clone() })
+    }
+
+    fn handle_columns_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        if let Some(picker) = self.columns.as_mut() {
+            match picker.handle_key(key) {
+                PickerOutcome::Open => {}
+                PickerOutcome::Closed => self.columns = None,
+                PickerOutcome::Saved(status) => self.status = Some(status),
+            }
+        }
+        // with fewer columns there is less to scroll past
+        let max = max_column_offset(
+            pod_app::ui::shown_columns().len(),
+            pod_app::ui::PINNED_COLUMNS,
+        );
+        self.column_offset = self.column_offset.min(max);
+        Some(Apps::Pod { app: self.clone() })
+    }
+
+    /// Open the diagnosis of why the selected pod has no node yet, led by the scheduler's
+    /// latest complaint
+    async fn explain_selected(&mut self) {
+        let Some(pod) = self.get_selected_item().cloned() else {
+            return;
+        };
+        if pod.node != "-" {
+            self.status = Some(StatusMessage::Info(format!(
+                "{} is on {}, e explains pods waiting for a node",
+                pod.name, pod.node
+            )));
+            return;
+        }
+        match explain_pending(&pod.name).await {
+            Ok(lines) => {
+                let scheduler = pod
+                    .events
+                    .iter()
+                    .find(|event| event.reason == "FailedScheduling")
+                    .map(|event| format!("Scheduler ({} ago): {}", event.age, event.message));
+                let lines = scheduler.into_iter().chain(lines).collect();
+                self.pending = Some(InfoModal::new(
+                    format!("Why {} is pending (any key closes)", pod.name),
+                    lines,
+                ));
+            }
+            Err(e) => {
+                self.status = Some(StatusMessage::Error(format!(
+                    "could not explain {}: {e}",
+                    pod.name
+                )));
+            }
+        }
+    }
+
+    fn handle_bookmarks_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        let Some(list) = self.bookmarks.as_mut() else {
+            return Some(Apps::Pod { app: self.clone() });
+        };
+        match list.handle_key(key) {
+            BookmarkAction::Stay => {}
+            BookmarkAction::Close => self.bookmarks = None,
+            BookmarkAction::Jump(bookmark, target) => {
+                self.bookmarks = None;
+                debug!(
+                    "changing app from pod to {} {}...",
+                    bookmark.kind, bookmark.name
+                );
+                if let Some(app) = open_bookmark(&bookmark, target) {
+                    return Some(app);
+                }
+            }
+        }
+        Some(Apps::Pod { app: self.clone() })
+    }
+
+    fn handle_related_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        if key.kind != KeyEventKind::Press {
+            return Some(Apps::Pod { app: self.clone() });
+        }
+        let Some(related) = self.related.as_mut() else {
+            return Some(Apps::Pod { app: self.clone() });
+        };
+        match related.handle_key(key) {
+            FinderAction::Stay => {}
+            FinderAction::Close => self.related = None,
+            FinderAction::Jump(entry) => {
+                self.related = None;
+                debug!("changing app from pod to {} {}...", entry.kind, entry.name);
+                if let Some(app) = target_app(entry.target) {
+                    return Some(app);
+                }
+                self.status = Some(StatusMessage::Info(format!(
+                    "k8p has no view for {} {}",
+                    entry.kind, entry.name
+                )));
+            }
+        }
+        Some(Apps::Pod { app: self.clone() })
+    }
+
+    fn handle_selector_prompt_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        if let Some(prompt) = self.selector_prompt.as_mut() {
+            match prompt.handle_key(key) {
+                InputOutcome::Editing => {}
+                InputOutcome::Submitted(text) => {
+                    self.selector_prompt = None;
+                    self.status = Some(apply_api_selector(&text));
+                }
+                InputOutcome::Cancelled => self.selector_prompt = None,
+            }
+        }
+        Some(Apps::Pod { app: self.clone() })
+    }
+
+    async fn handle_metadata_prompt_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        if let Some((name, prompt)) = self.metadata_prompt.as_mut() {
+            match prompt.handle_key(key) {
+                InputOutcome::Editing => {}
+                InputOutcome::Submitted(text) => {
+                    let name = name.clone();
+                    self.metadata_prompt = None;
+                    self.status = Some(match parse_metadata_edit(&text) {
+                        Ok(edit) => match patch_metadata::<Pod>(&name, &edit).await {
+                            Ok(()) => {
+                                // the labels pane shows the change once the pod is listed again
+                                request_refresh();
+                                let change = edit.value.as_ref().map_or_else(
+                                    || format!("removed {} {}", edit.kind(), edit.key),
+                                    |value| format!("set {} {}={value}", edit.kind(), edit.key),
+                                );
+                                StatusMessage::Info(format!("{change} on {name}"))
+                            }
+                            Err(e) => StatusMessage::Error(format!(
+                                "could not change {} of {name}: {e}",
+                                edit.kind()
+                            )),
+                        },
+                        Err(e) => StatusMessage::Error(e),
+                    });
+                }
+                InputOutcome::Cancelled => self.metadata_prompt = None,
+            }
+        }
+        Some(Apps::Pod { app: self.clone() })
+    }
+
+    async fn handle_goto_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        if key.kind == KeyEventKind::Press {
+            if let Some(command) = edit_goto(&mut self.goto, key) {
+                if let Some(status) = run_goto(self, command).await {
+                    self.status = Some(status);
+                }
+            }
+        }
+        Some(Apps::Pod { app: self.clone() })
+    }
+
+    pub(crate) fn placeholder(&self) -> Option<&'static str> {
+        if !self.loaded {
+            Some("Loading Pods...")
+        } else if self.items.is_empty() {
+            Some("No Pods found")
+        } else {
+            None
         }
     }
 
@@ -238,10 +1081,57 @@ impl App {
         })
     }
 
+    /// The QoS class and priority of the selected pod, which decide what is evicted and
+    /// preempted first, how long it took to start, where it may run, and for a Pending pod
+    /// the scheduler's latest complaint, so the constraint and the reason it fails are seen
+    /// together
+    pub fn get_scheduling_details(&mut self) -> Vec<(String, String, Option<String>)> {
+        self.get_selected_item().map_or_else(Vec::new, |pod| {
+            // repeated from the table, whose last columns a narrow terminal cuts off
+            let mut details = vec![
+                ("Node".to_string(), pod.node.clone(), None),
+                ("IP".to_string(), pod.ip.clone(), None),
+                ("QoS".to_string(), pod.qos.clone(), None),
+                ("Priority".to_string(), pod.priority.clone(), None),
+                ("Startup".to_string(), pod.startup.clone(), None),
+            ];
+            if !pod.reason.is_empty() {
+                details.push((pod.reason.clone(), explain_reason(&pod.reason), None));
+            }
+            if !pod.oom_killed.is_empty() {
+                details.push(("OOMKilled".to_string(), pod.oom_killed.clone(), None));
+            }
+            details.extend(
+                pod.scheduling
+                    .iter()
+                    .map(|rule| (rule.name.clone(), rule.value.clone(), None)),
+            );
+            if pod.status == "Pending" {
+                if let Some(event) = pod
+                    .events
+                    .iter()
+                    .find(|event| event.reason == "FailedScheduling")
+                {
+                    details.push((
+                        "Scheduler".to_string(),
+                        event.message.clone(),
+                        Some(event.age.clone()),
+                    ));
+                }
+            }
+            details
+        })
+    }
+
     pub fn get_label_details(&mut self) -> Vec<(String, String, Option<String>)> {
+        let short_names = self.short_names;
         self.get_selected_item().map_or_else(Vec::new, |pod| {
             pod.selectors.clone().map_or_else(Vec::new, |labels| {
                 let mut r = Vec::new();
+                // the table only shows the short form
+                if short_names {
+                    r.push(("name".to_string(), pod.name.clone(), None));
+                }
                 for (name, value) in &labels {
                     r.push((name.to_string(), value.to_string(), None));
                 }
@@ -250,3 +1140,137 @@ impl App {
         })
     }
 }
+
+/// Watch the selected pod while the view is open, so its containers coming up show
+/// between polls. The watch moves along with the selection.
+async fn follow_selected(
+    watched: Arc<Mutex<Option<String>>>,
+    should_stop: Arc<AtomicBool>,
+    tx: mpsc::Sender<Message>,
+) {
+    let selected = || watched.lock().ok().and_then(|name| name.clone());
+    while !should_stop.load(Ordering::Relaxed) {
+        let Some(name) = selected() else {
+            sleep_unless_stopped(WATCH_CHECK).await;
+            continue;
+        };
+        let Ok(updates) = watch_rspod(&name).await else {
+            sleep_unless_stopped(WATCH_CHECK).await;
+            continue;
+        };
+        let mut updates = pin!(updates);
+        while !should_stop.load(Ordering::Relaxed) && selected().as_ref() == Some(&name) {
+            match timeout(WATCH_CHECK, updates.next()).await {
+                Ok(Some(pod)) => {
+                    if tx.send(Message::WatchedPod(pod)).await.is_err() {
+                        return;
+                    }
+                }
+                Ok(None) => {
+                    sleep_unless_stopped(WATCH_CHECK).await;
+                    break;
+                }
+                // nothing changed, look at the selection again
+                Err(_) => {}
+            }
+        }
+    }
+}
+
+/// Tail the logs of the selected pod while the log pane is open, refetching right away
+/// when the selection moves
+async fn tail_selected(
+    watched: Arc<Mutex<Option<String>>>,
+    log_pane: Arc<AtomicBool>,
+    should_stop: Arc<AtomicBool>,
+    tx: mpsc::Sender<Message>,
+) {
+    let mut last: Option<(String, Instant)> = None;
+    while !should_stop.load(Ordering::Relaxed) {
+        let selected = watched.lock().ok().and_then(|name| name.clone());
+        match selected {
+            Some(name) if log_pane.load(Ordering::Relaxed) => {
+                let due = last.as_ref().map_or(true, |(pod, at)| {
+                    *pod != name || at.elapsed() >= LOG_PANE_POLL
+                });
+                if due {
+                    last = Some((name.clone(), Instant::now()));
+                    let lines = tail_logs(&name, LOG_PANE_LINES, config::current().since)
+                        .await
+                        .unwrap_or_else(|e| vec![list_error_message(&e, "pod logs")]);
+                    if tx.send(Message::PodLog(name, lines)).await.is_err() {
+                        return;
+                    }
+                }
+            }
+            // a pane opened again starts with fresh lines
+            _ => last = None,
+        }
+        sleep_unless_stopped(WATCH_CHECK).await;
+    }
+}
+
+/// What became of deleting `names`, for the toast once the api server answered
+fn delete_outcome(names: &[String], result: error::Result<Vec<(String, String)>>) -> StatusMessage {
+    match result {
+        Ok(failed) if failed.is_empty() => {
+            StatusMessage::Info(format!("deleted {} pod(s)", names.len()))
+        }
+        Ok(failed) => {
+            let reasons = failed
+                .iter()
+                .map(|(name, reason)| format!("{name}: {reason}"))
+                .collect::<Vec<String>>();
+            StatusMessage::Error(format!("could not delete {}", reasons.join(", ")))
+        }
+        Err(e) => StatusMessage::Error(format!("could not delete pods: {e}")),
+    }
+}
+
+/// What the api server says to deleting `names` from `namespace`, for the confirmation
+/// under `--dry-run-first`
+async fn dry_run_delete(namespace: &str, names: &[String]) -> Vec<String> {
+    match delete_pods(namespace, names, true).await {
+        Ok(failed) if failed.is_empty() => {
+            vec!["Dry run: the api server would delete them all".to_string()]
+        }
+        Ok(failed) => std::iter::once("Dry run: the api server would refuse".to_string())
+            .chain(
+                failed
+                    .iter()
+                    .map(|(name, reason)| format!("  {name}: {reason}")),
+            )
+            .collect(),
+        Err(e) => vec![format!("Dry run failed: {e}")],
+    }
+}
+
+/// The question asked before the named pods are deleted, it carries the namespace it
+/// names so a confirm deletes there whatever the views switched to meanwhile
+fn delete_confirm(
+    namespace: String,
+    names: Vec<String>,
+    preview: Vec<String>,
+) -> ConfirmModal<(String, Vec<String>)> {
+    let mut lines = vec![
+        format!("Delete {} pod(s) in namespace {namespace}:", names.len()),
+        String::new(),
+    ];
+    lines.extend(names.iter().map(|name| format!("  {name}")));
+    lines.push(String::new());
+    if !preview.is_empty() {
+        lines.extend(preview);
+        lines.push(String::new());
+    }
+    lines.push("y to delete, any other key to cancel".to_string());
+    ConfirmModal::new((namespace, names), "Delete pods?", lines)
+}
+
+/// Why the named pods are left out of a delete
+fn protected_message(names: &[String]) -> StatusMessage {
+    StatusMessage::Error(format!(
+        "not deleting {}, protected by {}=true",
+        names.join(", "),
+        config::current().protection_annotation
+    ))
+}
@@ -1,33 +1,231 @@
+use crate::config::{self, ColumnFit};
+use crate::labels::format_label_selector;
+use crate::tui::columns::{pick, visible_columns};
+use crate::tui::data::{pod_summary, status_counts, Filterable, RsPod};
+use crate::tui::finder::render_finder;
+use crate::tui::pinned::pinned;
 use crate::tui::pod_app::app::App;
-use crate::tui::table_ui::{render_detail_section, TuiTableState};
+use crate::tui::status::{render_status_bar, render_status_line};
+use crate::tui::style::{RowLayout, RowStatus, OOM_MARK};
+use crate::tui::table_ui::{
+    column_constraints, fit_cell_text, flash_style, render_breadcrumb,
+    render_column_scroll_indicator, render_detail_section, render_table_footer,
+    render_table_placeholder, render_too_small, scroll_columns, short_name, short_name_len,
+    TuiTableState,
+};
+use chrono::Utc;
 use ratatui::{
     prelude::*,
-    widgets::{Cell, HighlightSpacing, Row, Scrollbar, ScrollbarOrientation, Table},
+    widgets::{
+        Block, Borders, Cell, HighlightSpacing, Paragraph, Row, Scrollbar, ScrollbarOrientation,
+        Table,
+    },
 };
+use std::borrow::Cow;
+use std::sync::atomic::Ordering;
+
+/// the name the shown columns are kept under in the config. The first `PINNED_COLUMNS`
+/// stay put when scrolling sideways.
+pub(crate) const COLUMNS_VIEW: &str = "pods";
+pub(crate) const PINNED_COLUMNS: usize = 1;
+const NAME_COLUMN: usize = 0;
+const STATUS_COLUMN: usize = 1;
+const CONTAINERS_COLUMN: usize = 2;
+const QOS_COLUMN: usize = 3;
+
+/// The data columns the table shows in order, not counting the checkmark gutter
+pub(crate) fn shown_columns() -> Vec<usize> {
+    visible_columns(RsPod::COLUMNS, COLUMNS_VIEW, PINNED_COLUMNS)
+}
 
 pub fn ui(f: &mut Frame, app: &mut App) {
-    let rects = Layout::vertical([Constraint::Min(8), Constraint::Percentage(40)]).split(f.area());
+    if render_too_small(f) {
+        return;
+    }
+    let [crumb_area, selector_area, view_area, bar_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(f.area());
+
+    let rects = Layout::vertical([
+        Constraint::Min(8),
+        Constraint::Length(1),
+        Constraint::Percentage(40),
+    ])
+    .split(view_area);
     app.set_colors();
+    let breadcrumb = app.breadcrumb("Pod");
+    render_breadcrumb(f, &app.colors, crumb_area, &breadcrumb);
+    render_selector(f, app, selector_area);
 
     let table_area = rects[0];
-    let details_area = rects[1];
+    let footer_area = rects[1];
+    let details_area = rects[2];
 
+    let mut summary = pod_summary(&app.items);
+    let hidden = app.hidden_count();
+    if hidden > 0 {
+        summary.push_str(&format!(" ({hidden} finished hidden, t shows them)"));
+    }
+    render_table_footer(f, &app.colors, footer_area, &summary);
+    let mut bar = match &app.goto {
+        Some(goto) => format!(":{goto}"),
+        None if app.checked.is_empty() => app.status_bar("pod", app.refreshed),
+        None => format!(
+            "{} | {} checked",
+            app.status_bar("pod", app.refreshed),
+            app.checked.len()
+        ),
+    };
+    if app.follow_newest && app.goto.is_none() {
+        bar.push_str(" | following newest");
+    }
+    if let Some(pinned) = pinned().filter(|_| app.goto.is_none()) {
+        bar.push_str(&format!(" | pinned {pinned}"));
+    }
+    render_status_bar(f, &app.colors, bar_area, &bar);
     render_ui_sections(f, app, table_area, details_area);
 }
 
+/// The labels scoping the list, so an unexpectedly broad or empty selector stands out,
+/// followed by the one typed after `L`
+fn render_selector(f: &mut Frame, app: &App, area: Rect) {
+    let (mut text, fg) = if app.selector.is_empty() {
+        (
+            " selector: none, every pod in the namespace".to_string(),
+            app.colors.warn_fg,
+        )
+    } else {
+        (
+            format!(" selector: {}", format_label_selector(&app.selector)),
+            app.colors.row_fg,
+        )
+    };
+    let api_selector = format_label_selector(&config::current().api_selector);
+    if !api_selector.is_empty() {
+        text.push_str(&format!(" | api: {api_selector}"));
+    }
+    let line = Paragraph::new(text).style(Style::default().fg(fg).bg(app.colors.buffer_bg));
+    f.render_widget(line, area);
+    render_status_histogram(f, app, area);
+}
+
+/// `Running:42 Pending:2 Failed:1` at the right of the header, each count colored like
+/// its status
+fn render_status_histogram(f: &mut Frame, app: &App, area: Rect) {
+    let mut spans: Vec<Span> = Vec::new();
+    for (status, count) in status_counts(&app.items) {
+        let style = match status {
+            "Running" => Style::new().fg(app.colors.ok_fg),
+            "Pending" | "Starting" => app.colors.row_style(RowStatus::Pending),
+            "Terminating" => app.colors.row_style(RowStatus::Warn),
+            "Succeeded" => app.colors.row_style(RowStatus::Muted),
+            _ => app.colors.row_style(RowStatus::Error),
+        };
+        spans.push(Span::styled(format!("{status}:{count}"), style));
+        spans.push(Span::raw(" "));
+    }
+    let histogram = Paragraph::new(Line::from(spans)).alignment(Alignment::Right);
+    f.render_widget(histogram, area);
+}
+
 fn render_ui_sections(f: &mut Frame, app: &mut App, table_area: Rect, details_area: Rect) {
+    let table_area = if app.log_pane.load(Ordering::Relaxed) {
+        let [table_area, log_area] =
+            Layout::horizontal([Constraint::Percentage(55), Constraint::Percentage(45)])
+                .areas(table_area);
+        render_log_pane(f, app, log_area);
+        table_area
+    } else {
+        table_area
+    };
     render_table(f, app, table_area);
     render_scrollbar(f, app, table_area);
+    if let Some(message) = app.placeholder() {
+        render_table_placeholder(f, &app.colors, table_area, message);
+    }
     render_details(f, app, details_area);
+    if let Some(modal) = &app.delete_confirm {
+        modal.render(f, &app.colors);
+    }
+    if let Some(modal) = &app.edit_confirm {
+        modal.render(f, &app.colors);
+    }
+    if let Some(prompt) = &app.selector_prompt {
+        prompt.render(f, &app.colors);
+    }
+    if let Some((_, prompt)) = &app.metadata_prompt {
+        prompt.render(f, &app.colors);
+    }
+    if let Some(settings) = &app.settings {
+        settings.render(f, &app.colors);
+    }
+    if let Some(picker) = &app.columns {
+        picker.render(f, &app.colors);
+    }
+    if let Some(related) = &app.related {
+        render_finder(f, &app.colors, related);
+    }
+    if let Some(pending) = &app.pending {
+        pending.render(f, &app.colors);
+    }
+    if let Some(bookmarks) = &app.bookmarks {
+        bookmarks.render(f, &app.colors);
+    }
+    if let Some(modal) = &app.quit_confirm {
+        modal.render(f, &app.colors);
+    }
+    if let Some(status) = &app.status {
+        render_status_line(f, &app.colors, status);
+    }
 }
 
-fn render_details(f: &mut Frame, app: &mut App, area: Rect) {
-    let detail_rects =
-        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).split(area);
+/// The tail of the selected pod's logs, newest line at the bottom
+fn render_log_pane(f: &mut Frame, app: &mut App, area: Rect) {
+    let selected = app.get_selected_item().map(|pod| pod.name.clone());
+    let (title, lines) = match (&app.pane_log, selected) {
+        (Some((pod, lines)), Some(name)) if *pod == name => {
+            (format!("Logs: {pod} (p closes)"), lines.as_slice())
+        }
+        (_, Some(name)) => (format!("Logs: {name} (p closes)"), [].as_slice()),
+        (_, None) => ("Logs (p closes)".to_string(), [].as_slice()),
+    };
+    let height = usize::from(area.height.saturating_sub(2));
+    let shown: Vec<Line> = lines
+        .iter()
+        .skip(lines.len().saturating_sub(height))
+        .map(|line| Line::from(line.as_str()))
+        .collect();
+    let pane = Paragraph::new(shown)
+        .style(
+            Style::default()
+                .fg(app.colors.row_fg)
+                .bg(app.colors.buffer_bg),
+        )
+        .block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(pane, area);
+}
 
+fn render_details(f: &mut Frame, app: &mut App, area: Rect) {
     let left_details = app.get_label_details();
+    let scheduling_details = app.get_scheduling_details();
     let event_details = app.get_event_details();
 
+    // the section only takes room while a pod is selected
+    let detail_rects = if scheduling_details.is_empty() {
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).split(area)
+    } else {
+        Layout::horizontal([
+            Constraint::Percentage(30),
+            Constraint::Percentage(35),
+            Constraint::Percentage(35),
+        ])
+        .split(area)
+    };
+
     let (foreground_color, background_color) = get_colors(app);
     render_detail_section(
         f,
@@ -37,11 +235,21 @@ fn render_details(f: &mut Frame, app: &mut App, area: Rect) {
         "Label",
         &left_details,
     );
+    if !scheduling_details.is_empty() {
+        render_detail_section(
+            f,
+            foreground_color,
+            background_color,
+            detail_rects[1],
+            "Scheduling",
+            &scheduling_details,
+        );
+    }
     render_detail_section(
         f,
         foreground_color,
         background_color,
-        detail_rects[1],
+        detail_rects[detail_rects.len() - 1],
         "Event",
         &event_details,
     );
@@ -52,6 +260,11 @@ const fn get_colors(app: &App) -> (Color, Color) {
 }
 
 fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let config = config::current();
+    let fit = config.column_fit;
+    let layout = RowLayout::new(&config);
+    app.set_table_area(area);
+
     let header_style = Style::default()
         .fg(app.colors.header_fg)
         .bg(app.colors.header_bg);
@@ -64,13 +277,32 @@ fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
         _ => "Pod".to_string(),
     };
 
-    let header = [&filter_header, "Status", "C", "Age", "Description"]
-        .iter()
-        .copied()
-        .map(Cell::from)
-        .collect::<Row>()
-        .style(header_style)
-        .height(1);
+    let offset = app.column_offset;
+    let columns = shown_columns();
+    let titles = [
+        filter_header.as_str(),
+        "Status",
+        "C",
+        "QoS",
+        "Age",
+        "Description",
+        "IP",
+        "Node",
+    ];
+    let header = Row::new(
+        std::iter::once(Cell::from("")).chain(scroll_columns(
+            pick(&titles, &columns)
+                .into_iter()
+                .map(Cell::from)
+                .collect::<Vec<Cell>>(),
+            PINNED_COLUMNS,
+            offset,
+        )),
+    )
+    .style(header_style)
+    .height(1);
+    let (new_pod_age, old_pod_age) = (config.new_pod_age, config.old_pod_age);
+    let now = Utc::now();
     let rows = app
         .get_filtered_items()
         .into_iter()
@@ -80,32 +312,103 @@ fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
                 0 => app.colors.normal_row_color,
                 _ => app.colors.alt_row_color,
             };
+            let check = if app.checked.contains(&data.name) {
+                "✓"
+            } else {
+                ""
+            };
             let item = data.ref_array();
-            item.iter()
-                .copied()
-                .map(|content| Cell::from(Text::from(format!("\n{content}\n"))))
+            let cells: Vec<Cell> = columns
+                .iter()
+                .map(|&col| (col, item[col]))
+                .map(|(col, content)| {
+                    let oom = col == STATUS_COLUMN && !data.oom_killed.is_empty();
+                    let content = if col == NAME_COLUMN && app.short_names {
+                        short_name(content)
+                    } else if oom {
+                        Cow::Owned(format!("{content}{OOM_MARK}"))
+                    } else {
+                        Cow::Borrowed(content.as_str())
+                    };
+                    let cell = Cell::from(
+                        layout.text(fit_cell_text(fit, &content, area.width).into_owned()),
+                    );
+                    if oom {
+                        cell.style(app.colors.row_style(RowStatus::Error))
+                    } else if col == QOS_COLUMN {
+                        cell.style(qos_style(app, &content))
+                    } else if col == CONTAINERS_COLUMN {
+                        cell.style(app.colors.ratio_style(&content))
+                    } else {
+                        cell
+                    }
+                })
+                .collect();
+            std::iter::once(Cell::from(layout.text(check.to_string())))
+                .chain(scroll_columns(cells, PINNED_COLUMNS, offset))
                 .collect::<Row>()
-                .style(Style::new().fg(app.colors.row_fg).bg(color))
-                .height(3) //height
+                .style(flash_style(
+                    &app.colors,
+                    app.colors.age_style(
+                        Style::new().fg(app.colors.row_fg).bg(color),
+                        data.age_band(now, new_pod_age, old_pod_age),
+                    ),
+                    &app.flashes,
+                    &data.name,
+                ))
+                .height(layout.height())
         });
-    let bar = " █ ";
-    let t = Table::new(
-        rows,
-        [
-            // + 1 is for padding.
-            Constraint::Min(app.longest_item_lens.0 + 1),
-            Constraint::Min(app.longest_item_lens.1 + 1),
-            Constraint::Min(app.longest_item_lens.2 + 1),
-            Constraint::Min(app.longest_item_lens.3 + 1),
-            Constraint::Min(app.longest_item_lens.4),
-        ],
-    )
-    .header(header)
-    .row_highlight_style(selected_style)
-    .highlight_symbol(Text::from(vec!["".into(), bar.into(), "".into()]))
-    .bg(app.colors.buffer_bg)
-    .highlight_spacing(HighlightSpacing::Always);
+    let name_len = if app.short_names {
+        short_name_len(app.get_items().iter().map(|pod| pod.name.as_str()))
+    } else {
+        app.longest_item_lens.0
+    };
+    let lens = scroll_columns(
+        pick(
+            &[
+                // + 1 is for padding.
+                name_len + 1,
+                app.longest_item_lens.1 + 1,
+                app.longest_item_lens.2 + 1,
+                app.longest_item_lens.3 + 1,
+                app.longest_item_lens.4 + 1,
+                app.longest_item_lens.5 + 1,
+                app.longest_item_lens.6 + 1,
+                app.longest_item_lens.7,
+            ],
+            &columns,
+        ),
+        PINNED_COLUMNS,
+        offset,
+    );
+    // the gutter for the batch delete checkmarks stays narrow whatever the column fit
+    let mut constraints = vec![Constraint::Length(1)];
+    constraints.extend(column_constraints(fit, &lens, area.width));
+    let bar = layout.selection_symbol();
+    let t = Table::new(rows, constraints)
+        .header(header)
+        .row_highlight_style(selected_style)
+        .highlight_symbol(layout.text(bar.to_string()))
+        .bg(app.colors.buffer_bg)
+        .highlight_spacing(HighlightSpacing::Always);
     f.render_stateful_widget(t, area, &mut app.state);
+    // only content sized columns can run off the right edge, the gutter adds one
+    let overflowing: Vec<u16> = if fit == ColumnFit::Content {
+        std::iter::once(1).chain(lens).collect()
+    } else {
+        vec![]
+    };
+    render_column_scroll_indicator(f, &app.colors, area, &overflowing, offset);
+}
+
+/// BestEffort pods are the first evicted under memory pressure, Burstable ones next
+fn qos_style(app: &App, qos: &str) -> Style {
+    match qos {
+        "BestEffort" => app.colors.row_style(RowStatus::Error),
+        "Burstable" => app.colors.row_style(RowStatus::Warn),
+        "Guaranteed" => Style::new().fg(app.colors.ok_fg),
+        _ => app.colors.row_style(RowStatus::Normal),
+    }
 }
 
 fn render_scrollbar(f: &mut Frame, app: &mut App, area: Rect) {
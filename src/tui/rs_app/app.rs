@@ -1,38 +1,125 @@
+use crate::config::{self, PollView};
+use crate::config_file::{self, ConfigFile};
+use crate::k8s::bookmarks::resolve_bookmarks;
+use crate::k8s::errors::{list_error_message, with_retry, ApiErrorKind};
+use crate::k8s::finder::list_finder_entries;
+use crate::k8s::manifests::get_manifest;
 use crate::k8s::rs::list_replicas;
-use crate::tui::data::{rs_constraint_len_calculator, Rs};
+use crate::labels::format_label_selector;
+use crate::tui::bookmarks::{open_bookmark, toggle_bookmark, BookmarkAction, BookmarkList};
+use crate::tui::clipboard::copy_to_clipboard;
+use crate::tui::columns::{ColumnPicker, PickerOutcome};
+use crate::tui::configmap_app::app::ConfigKind;
+use crate::tui::data::{rs_constraint_len_calculator, Filterable, Rs};
+use crate::tui::editor::{apply_edit, edit_resource, take_repaint, EditedManifest};
+use crate::tui::export::export_items;
+use crate::tui::finder::{Finder, FinderAction};
+use crate::tui::operations;
 use crate::tui::pod_app;
 use crate::tui::rs_app::ui;
-use crate::tui::stream::Message;
-use crate::tui::style::{TableColors, ITEM_HEIGHT, PALETTES};
-use crate::tui::table_ui::TuiTableState;
-use crate::tui::ui_loop::{create_ingress_data_vec, AppBehavior, Apps};
-use crate::tui::{event_app, ingress_app};
-use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+use crate::tui::settings::{Settings, SettingsOutcome};
+use crate::tui::status::{read_only_message, StatusMessage};
+use crate::tui::stream::{request_refresh, spawn_task, tick_after, wait_for_poll, Message};
+use crate::tui::style::{item_height, TableColors, PALETTES};
+use crate::tui::table_ui::{
+    apply_api_selector, changed_rows, cycle_column_fit, edit_goto, max_column_offset, run_goto,
+    update_flashes, DetailFocus, TuiTableState, FLASH_DURATION,
+};
+use crate::tui::ui_loop::{create_ingress_data_vec, target_app, AppBehavior, Apps};
+use crate::tui::widgets::{ConfirmModal, InputOutcome, TextInput};
+use crate::tui::{
+    configmap_app, daemonset_app, event_app, ingress_app, job_app, namespace_app, node_app,
+    problems_app, pvc_app, resource_app, service_app,
+};
+use chrono::{DateTime, Local};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use futures::Stream;
+use k8s_openapi::api::apps::v1::ReplicaSet;
 use ratatui::prelude::*;
 use ratatui::widgets::{ScrollbarState, TableState};
+use std::collections::{BTreeMap, VecDeque};
 use std::io;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::Instant;
 use tokio::sync::mpsc;
-use tokio::time::sleep;
 use tokio_stream::wrappers::ReceiverStream;
 use tracing::debug;
 
-const POLL_MS: u64 = 5000;
+/// percentage points the details pane grows or shrinks per key press
+const DETAILS_STEP: i16 = 5;
+/// ready counts kept for the sparkline in the details pane
+pub(crate) const TREND_LENGTH: usize = 40;
+
+/// Ready replica counts of one set over its latest updates, oldest first
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ReadyTrend {
+    pub(crate) name: String,
+    pub(crate) samples: VecDeque<u64>,
+}
+
+impl ReadyTrend {
+    /// Append `ready` for the set `name`, starting over when it is another set than before
+    pub(crate) fn record(&mut self, name: &str, ready: u64) {
+        if self.name != name {
+            self.name = name.to_string();
+            self.samples.clear();
+        }
+        if self.samples.len() == TREND_LENGTH {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(ready);
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct App {
     pub(crate) state: TableState,
     pub(crate) items: Vec<Rs>,
-    pub(crate) longest_item_lens: (u16, u16, u16, u16, u16),
+    /// every set listed, `items` leaves out scaled down revisions unless shown
+    pub(crate) all_items: Vec<Rs>,
+    /// sets of older rollouts scaled down to zero are listed too, toggled with `z`
+    pub(crate) show_scaled_down: bool,
+    pub(crate) longest_item_lens: (u16, u16, u16, u16, u16, u16),
     pub(crate) scroll_state: ScrollbarState,
+    pub(crate) table_area: Rect,
     pub(crate) colors: TableColors,
     pub(crate) color_index: usize,
     pub(crate) filter: String,
     pub(crate) show_filter_edit: bool,
     pub(crate) edit_filter_cursor_position: usize,
+    pub(crate) loaded: bool,
+    pub(crate) status: Option<StatusMessage>,
+    /// operations that were still running when quit was requested
+    pub(crate) quit_confirm: Option<ConfirmModal<Vec<String>>>,
+    /// when data was last applied, for the status bar
+    pub(crate) refreshed: Option<DateTime<Local>>,
+    /// digits typed after `:`, while the goto prompt is open
+    pub(crate) goto: Option<String>,
+    /// label selector typed after `L`, while the prompt is open
+    pub(crate) selector_prompt: Option<TextInput>,
+    /// the runtime options opened with `,`
+    pub(crate) settings: Option<Settings>,
+    /// the columns shown and their order, while `|` has them open
+    pub(crate) columns: Option<ColumnPicker>,
+    /// the name column shows generated suffixes collapsed, toggled with `n`
+    pub(crate) short_names: bool,
+    /// columns scrolled out of view to the left with `h` and `l`
+    pub(crate) column_offset: usize,
+    /// rows that changed in a recent poll and when, for a brief highlight
+    pub(crate) flashes: BTreeMap<String, Instant>,
+    /// height of the details pane, adjusted with `<` and `>`
+    pub(crate) details_percent: u16,
+    /// the global finder, while it is open
+    pub(crate) finder: Option<Finder>,
+    /// the dry run of an edit while its confirmation is open, under `--dry-run-first`
+    pub(crate) edit_confirm: Option<ConfirmModal<EditedManifest>>,
+    /// the detail section Tab gave the keys to and how far each is scrolled
+    pub(crate) detail_focus: DetailFocus,
+    /// the bookmarked resources, while `'` has them open
+    pub(crate) bookmarks: Option<BookmarkList>,
+    /// ready replicas of the selected set over the latest updates, for the sparkline
+    pub(crate) ready_trend: ReadyTrend,
 }
 
 impl TuiTableState for App {
@@ -54,6 +141,14 @@ impl TuiTableState for App {
         self.scroll_state = scroll_state;
     }
 
+    fn get_table_area(&self) -> Rect {
+        self.table_area
+    }
+
+    fn set_table_area(&mut self, table_area: Rect) {
+        self.table_area = table_area;
+    }
+
     fn set_table_colors(&mut self, colors: TableColors) {
         self.colors = colors;
     }
@@ -68,7 +163,7 @@ impl TuiTableState for App {
 
     fn reset_selection_state(&mut self) {
         self.state = TableState::default().with_selected(0);
-        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * ITEM_HEIGHT);
+        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * item_height());
     }
 
     fn get_filter(&self) -> String {
@@ -96,11 +191,39 @@ impl TuiTableState for App {
     fn get_show_filter_edit(&self) -> bool {
         self.show_filter_edit
     }
+
+    fn typing(&self) -> bool {
+        self.show_filter_edit
+            || self.quit_confirm.is_some()
+            || self.goto.is_some()
+            || self.selector_prompt.is_some()
+            || self.settings.is_some()
+            || self.columns.is_some()
+            || self.finder.is_some()
+            || self.edit_confirm.is_some()
+            || self.bookmarks.is_some()
+    }
 }
 
 impl AppBehavior for App {
     async fn handle_event(&mut self, event: &Message) -> Result<Option<Apps>, io::Error> {
-        if self.get_show_filter_edit() {
+        if let (Some(_), Message::Key(Event::Key(key))) = (&self.quit_confirm, event) {
+            Ok(self.handle_quit_confirm_event(key))
+        } else if let (Some(_), Message::Key(Event::Key(key))) = (&self.edit_confirm, event) {
+            Ok(self.handle_edit_confirm_event(key).await)
+        } else if let (Some(_), Message::Key(Event::Key(key))) = (&self.finder, event) {
+            Ok(self.handle_finder_event(key))
+        } else if let (Some(_), Message::Key(Event::Key(key))) = (&self.bookmarks, event) {
+            Ok(self.handle_bookmarks_event(key))
+        } else if let (Some(_), Message::Key(Event::Key(key))) = (&self.goto, event) {
+            Ok(self.handle_goto_event(key).await)
+        } else if let (Some(_), Message::Key(Event::Key(key))) = (&self.selector_prompt, event) {
+            Ok(self.handle_selector_prompt_event(key))
+        } else if let (Some(_), Message::Key(Event::Key(key))) = (&self.settings, event) {
+            Ok(self.handle_settings_event(key))
+        } else if let (Some(_), Message::Key(Event::Key(key))) = (&self.columns, event) {
+            Ok(self.handle_columns_event(key))
+        } else if self.get_show_filter_edit() {
             Ok(self.handle_filter_edit_event(event))
         } else {
             self.handle_table_event(event).await
@@ -108,28 +231,48 @@ impl AppBehavior for App {
     }
 
     fn draw_ui<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<(), std::io::Error> {
+        if take_repaint() {
+            terminal.clear()?;
+        }
         terminal.draw(|f| ui::ui(f, self))?; // Pass self directly if mutable access is not required
         Ok(())
     }
 
     fn stream(&self, should_stop: Arc<AtomicBool>) -> impl Stream<Item = Message> {
         let (tx, rx) = mpsc::channel(1);
-        let initial_items = self.get_items().to_vec(); // Clone or get owned data from self
+        let initial_items = self.all_items.clone();
+        let mut loaded = self.loaded;
 
-        tokio::spawn(async move {
+        spawn_task(async move {
             while !should_stop.load(Ordering::Relaxed) {
-                match list_replicas().await {
+                // read each time so a change in the settings overlay applies right away
+                let poll_interval = config::current().poll_interval_for(PollView::Replicasets);
+                match with_retry("replicasets", list_replicas).await {
                     Ok(new_items) => {
-                        if !new_items.is_empty() && new_items != initial_items {
+                        // always report the first fetch so an empty list stops looking like loading
+                        if !loaded || new_items != initial_items {
+                            loaded = true;
                             let sevent = Message::Rs(new_items);
                             if tx.send(sevent).await.is_err() {
                                 break;
                             }
+                            // redraw once any rows flashed by the change are done
+                            tick_after(&tx, FLASH_DURATION);
+                        }
+                        // a manual refresh is reported even when nothing changed
+                        if wait_for_poll(poll_interval).await {
+                            loaded = false;
                         }
-                        sleep(Duration::from_millis(POLL_MS)).await;
                     }
-                    Err(_e) => {
-                        break;
+                    Err(e) => {
+                        _ = tx
+                            .send(Message::Error(list_error_message(&e, "replicasets")))
+                            .await;
+                        // retries are used up, but a flaky cluster may still come back
+                        if !ApiErrorKind::is_transient(&e) {
+                            break;
+                        }
+                        wait_for_poll(poll_interval).await;
                     }
                 };
             }
@@ -144,13 +287,114 @@ impl App {
         Self {
             state: TableState::default().with_selected(0),
             longest_item_lens: rs_constraint_len_calculator(&data_vec),
-            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * ITEM_HEIGHT),
+            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * item_height()),
             colors: TableColors::new(&PALETTES[0]),
+            table_area: Rect::default(),
             color_index: 0,
-            items: data_vec,
+            items: data_vec.clone(),
+            all_items: data_vec,
+            show_scaled_down: false,
             filter: String::new(),
+            refreshed: None,
+            goto: None,
+            selector_prompt: None,
+            settings: None,
+            columns: None,
+            short_names: false,
+            column_offset: 0,
+            flashes: BTreeMap::new(),
+            details_percent: ConfigFile::load().details_percent,
+            finder: None,
+            bookmarks: None,
+            detail_focus: DetailFocus::default(),
+            edit_confirm: None,
+            ready_trend: ReadyTrend::default(),
             show_filter_edit: false,
             edit_filter_cursor_position: 0,
+            loaded: false,
+            status: None,
+            quit_confirm: None,
+        }
+    }
+
+    /// The sets of `all_items` the view lists, leaving out scaled down revisions unless `z`
+    /// shows them
+    fn visible(&self, all_items: &[Rs]) -> Vec<Rs> {
+        all_items
+            .iter()
+            .filter(|rs| self.show_scaled_down || rs.desired_replicas() > 0)
+            .cloned()
+            .collect()
+    }
+
+    /// List or hide scaled down revisions, keeping the selection on the same set
+    fn set_show_scaled_down(&mut self, show_scaled_down: bool) {
+        self.show_scaled_down = show_scaled_down;
+        let selected = self.get_selected_key();
+        self.items = self.visible(&self.all_items);
+        self.longest_item_lens = rs_constraint_len_calculator(&self.items);
+        self.scroll_state = ScrollbarState::new(self.items.len().saturating_sub(1) * item_height());
+        self.select_key(selected.as_deref());
+    }
+
+    /// Scaled down revisions `z` would bring back, for the footer
+    pub(crate) fn hidden_count(&self) -> usize {
+        self.all_items.len() - self.items.len()
+    }
+
+    /// Take in a fresh list of sets, keeping the selection on the same set
+    fn apply_items(&mut self, data_vec: &[Rs]) -> Self {
+        let selected = self.get_selected_key();
+        let items = self.visible(data_vec);
+        let mut new_app = Self {
+            longest_item_lens: rs_constraint_len_calculator(&items),
+            scroll_state: ScrollbarState::new(items.len().saturating_sub(1) * item_height()),
+            flashes: self.flashes_after(&items),
+            items,
+            all_items: data_vec.to_vec(),
+            loaded: true,
+            refreshed: Some(Local::now()),
+            ..self.clone()
+        };
+        new_app.select_key(selected.as_deref());
+        new_app.record_ready_trend();
+        new_app
+    }
+
+    /// Add the ready count of the selected set to its trend
+    fn record_ready_trend(&mut self) {
+        if let Some((name, ready)) = self
+            .get_selected_item()
+            .map(|rs| (rs.name.clone(), rs.ready_replicas()))
+        {
+            self.ready_trend.record(&name, ready);
+        }
+    }
+
+    /// Rows to highlight once `data_vec` replaces the current items. The first load is
+    /// not a change.
+    fn flashes_after(&self, data_vec: &[Rs]) -> BTreeMap<String, Instant> {
+        let changed = if self.loaded {
+            changed_rows(
+                &self.items,
+                data_vec,
+                |item| item.name.as_str(),
+                |rs| rs.pods.clone(),
+            )
+        } else {
+            vec![]
+        };
+        update_flashes(&self.flashes, changed)
+    }
+
+    fn handle_quit_confirm_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        match self.quit_confirm.as_ref().and_then(|m| m.handle_key(key)) {
+            Some(true) => None,
+            Some(false) => {
+                self.quit_confirm = None;
+                Some(Apps::Rs { app: self.clone() })
+            }
+            None => Some(Apps::Rs { app: self.clone() }),
         }
     }
 
@@ -159,7 +403,7 @@ impl App {
         match event {
             Message::Key(Event::Key(key)) => {
                 if key.kind == KeyEventKind::Press {
-                    use KeyCode::{Backspace, Char, Enter, Esc, Left, Right};
+                    use KeyCode::{Backspace, Char, Enter, Esc, Left, Right, Tab};
 
                     match key.code {
                         Char(to_insert) => {
@@ -182,39 +426,59 @@ impl App {
                             self.set_show_filter_edit(false);
                             app_holder = Some(Apps::Rs { app: self.clone() });
                         }
+                        Tab => {
+                            self.toggle_filter_mode();
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
                         _ => {}
                     }
                 }
             }
             Message::Rs(data_vec) => {
                 debug!("updating rs app data...");
-                let new_app = Self {
-                    longest_item_lens: rs_constraint_len_calculator(data_vec),
-                    scroll_state: ScrollbarState::new(
-                        data_vec.len().saturating_sub(1) * ITEM_HEIGHT,
-                    ),
-                    items: data_vec.clone(),
-                    ..self.clone()
+                let new_app_holder = Apps::Rs {
+                    app: self.apply_items(data_vec),
                 };
-                let new_app_holder = Apps::Rs { app: new_app };
                 app_holder = Some(new_app_holder);
             }
+            Message::Error(message) => {
+                self.status = Some(StatusMessage::Error(message.clone()));
+                app_holder = Some(Apps::Rs { app: self.clone() });
+            }
+            Message::Key(Event::Resize(_, _)) => {
+                self.handle_resize();
+                app_holder = Some(Apps::Rs { app: self.clone() });
+            }
             _ => {}
         }
         app_holder
     }
 
     async fn handle_table_event(&mut self, event: &Message) -> Result<Option<Apps>, io::Error> {
+        if matches!(event, Message::Key(Event::Key(_))) {
+            self.status = None;
+        }
+        if let Message::Key(Event::Key(key)) = event {
+            let sections = self.detail_sections();
+            if key.kind == KeyEventKind::Press && self.detail_focus.handle_key(key, sections) {
+                return Ok(Some(Apps::Rs { app: self.clone() }));
+            }
+        }
         let mut app_holder = Some(Apps::Rs { app: self.clone() });
         match event {
             Message::Key(Event::Key(key)) => {
                 if key.kind == KeyEventKind::Press {
-                    use KeyCode::{Char, Down, Enter, Up};
+                    use KeyCode::{Char, Down, Enter, Left, Right, Up};
 
                     match key.code {
                         Char('q') => {
-                            app_holder = None;
-                            debug!("quitting...");
+                            self.quit_confirm = operations::quit_confirm();
+                            if self.quit_confirm.is_some() {
+                                app_holder = Some(Apps::Rs { app: self.clone() });
+                            } else {
+                                app_holder = None;
+                                debug!("quitting...");
+                            }
                         }
                         Char('j') | Down => {
                             self.next();
@@ -235,25 +499,216 @@ impl App {
                             app_holder = Some(new_app_holder);
                             debug!("changing app from rs to event...");
                         }
+                        Char('d' | 'D') => {
+                            let new_app_holder = Apps::DaemonSet {
+                                app: daemonset_app::app::App::new(),
+                            };
+                            app_holder = Some(new_app_holder);
+                            debug!("changing app from rs to daemonset...");
+                        }
+                        Char('m' | 'M') => {
+                            let new_app_holder = Apps::Config {
+                                app: configmap_app::app::App::new(ConfigKind::ConfigMap),
+                            };
+                            app_holder = Some(new_app_holder);
+                            debug!("changing app from rs to configmap...");
+                        }
+                        Char('s' | 'S') => {
+                            let new_app_holder = Apps::Config {
+                                app: configmap_app::app::App::new(ConfigKind::Secret),
+                            };
+                            app_holder = Some(new_app_holder);
+                            debug!("changing app from rs to secret...");
+                        }
                         Char('i' | 'I') => {
                             if let Some(selection) = self.get_selected_item() {
                                 if let Some(selector) = selection.selectors.clone() {
-                                    let data_vec =
-                                        create_ingress_data_vec(selector.clone()).await?;
-                                    let new_app_holder = Apps::Ingress {
-                                        app: ingress_app::app::App::new(data_vec),
-                                    };
-                                    app_holder = Some(new_app_holder);
-                                    debug!("changing app from rs to ingress...");
+                                    match create_ingress_data_vec(selector.clone()).await {
+                                        Ok(data_vec) => {
+                                            let new_app_holder = Apps::Ingress {
+                                                app: ingress_app::app::App::new(data_vec),
+                                            };
+                                            app_holder = Some(new_app_holder);
+                                            debug!("changing app from rs to ingress...");
+                                        }
+                                        Err(e) => {
+                                            self.status = Some(StatusMessage::Error(e.to_string()));
+                                            app_holder = Some(Apps::Rs { app: self.clone() });
+                                        }
+                                    }
                                 };
                             };
                         }
+                        Char('h') | Left => {
+                            self.column_offset = self.column_offset.saturating_sub(1);
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
+                        Char('l') | Right => {
+                            let max =
+                                max_column_offset(ui::shown_columns().len(), ui::PINNED_COLUMNS);
+                            self.column_offset = (self.column_offset + 1).min(max);
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
+                        Char('n') => {
+                            self.short_names = !self.short_names;
+                            let names = if self.short_names { "short" } else { "full" };
+                            self.status = Some(StatusMessage::Info(format!("{names} names")));
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
+                        Char('z' | 'Z') => {
+                            self.set_show_scaled_down(!self.show_scaled_down);
+                            let shown = if self.show_scaled_down {
+                                "showing"
+                            } else {
+                                "hiding"
+                            };
+                            self.status = Some(StatusMessage::Info(format!(
+                                "{shown} scaled down revisions"
+                            )));
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
+                        Char('L') => {
+                            // start from the selector in force so it can be edited
+                            self.selector_prompt = Some(TextInput::new(
+                                "api label selector",
+                                format_label_selector(&config::current().api_selector),
+                            ));
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
+                        Char(':') => {
+                            self.goto = Some(String::new());
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
+                        Char('y' | 'Y') => {
+                            if let Some(name) =
+                                self.get_selected_item().map(|item| item.name.clone())
+                            {
+                                self.status = Some(match get_manifest::<ReplicaSet>(&name).await {
+                                    Ok(yaml) => copy_to_clipboard(&format!("{name} YAML"), &yaml),
+                                    Err(e) => {
+                                        StatusMessage::Error(format!("could not read {name}: {e}"))
+                                    }
+                                });
+                            }
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
+                        Char('o' | 'O') if config::current().read_only => {
+                            self.status = Some(read_only_message("editing"));
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
+                        Char('o' | 'O') => {
+                            if let Some(name) =
+                                self.get_selected_item().map(|item| item.name.clone())
+                            {
+                                let (status, confirm) =
+                                    edit_resource::<ReplicaSet>("replicaset", &name)
+                                        .await
+                                        .into_parts();
+                                self.status = status.or(self.status.take());
+                                self.edit_confirm = confirm;
+                            }
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
+                        Char('w' | 'W') => {
+                            self.status = Some(export_items("replicasets", self.get_items()));
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
+                        Char('r' | 'R') => {
+                            request_refresh();
+                            self.status = Some(StatusMessage::Info("refreshing…".to_string()));
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
+                        Char('a' | 'A') => {
+                            let fit = cycle_column_fit();
+                            self.status = Some(StatusMessage::Info(format!(
+                                "column widths: {}",
+                                fit.label()
+                            )));
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
+                        Char('p' | 'P') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            match list_finder_entries(&self.items).await {
+                                Ok(entries) => self.finder = Some(Finder::new(entries)),
+                                Err(e) => {
+                                    self.status = Some(StatusMessage::Error(e.to_string()));
+                                }
+                            }
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
+                        Char('"') => {
+                            if let Some(name) =
+                                self.get_selected_item().map(|item| item.name.clone())
+                            {
+                                self.status = Some(toggle_bookmark("ReplicaSet", &name));
+                            }
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
+                        Char('\'') => {
+                            match resolve_bookmarks(ConfigFile::load().bookmarks).await {
+                                Ok(rows) => self.bookmarks = Some(BookmarkList::new(rows)),
+                                Err(e) => {
+                                    self.status = Some(StatusMessage::Error(format!(
+                                        "could not look up the bookmarks: {e}"
+                                    )));
+                                }
+                            }
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
                         Char('f' | 'F') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                             self.page_forward();
                         }
                         Char('b' | 'B') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                             self.page_backward();
                         }
+                        Char('N') => {
+                            let new_app_holder = Apps::Namespace {
+                                app: namespace_app::app::App::new(),
+                            };
+                            app_holder = Some(new_app_holder);
+                            debug!("changing app from rs to namespace...");
+                        }
+                        Char('!') => {
+                            let new_app_holder = Apps::Problem {
+                                app: problems_app::app::App::new(),
+                            };
+                            app_holder = Some(new_app_holder);
+                            debug!("changing app from rs to problems...");
+                        }
+                        Char('u' | 'U') => {
+                            let new_app_holder = Apps::Service {
+                                app: service_app::app::App::new(),
+                            };
+                            app_holder = Some(new_app_holder);
+                            debug!("changing app from rs to service...");
+                        }
+                        Char('K') => {
+                            let new_app_holder = Apps::ApiResource {
+                                app: resource_app::app::App::new(),
+                            };
+                            app_holder = Some(new_app_holder);
+                            debug!("changing app from rs to api resources...");
+                        }
+                        Char('x' | 'X') => {
+                            let new_app_holder = Apps::Node {
+                                app: node_app::app::App::new(),
+                            };
+                            app_holder = Some(new_app_holder);
+                            debug!("changing app from rs to node...");
+                        }
+                        Char('v' | 'V') => {
+                            let new_app_holder = Apps::Pvc {
+                                app: pvc_app::app::App::new(None),
+                            };
+                            app_holder = Some(new_app_holder);
+                            debug!("changing app from rs to pvc...");
+                        }
+                        Char('b' | 'B') => {
+                            let new_app_holder = Apps::Job {
+                                app: job_app::app::App::new(),
+                            };
+                            app_holder = Some(new_app_holder);
+                            debug!("changing app from rs to job...");
+                        }
                         Enter => {
                             if let Some(selection) = self.get_selected_item() {
                                 if let Some(selectors) = selection.selectors.clone() {
@@ -266,27 +721,54 @@ impl App {
                                 };
                             };
                         }
+                        Char('<') => {
+                            self.resize_details(-DETAILS_STEP);
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
+                        Char('>') => {
+                            self.resize_details(DETAILS_STEP);
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
                         Char('/') => {
                             self.set_show_filter_edit(true);
                             app_holder = Some(Apps::Rs { app: self.clone() });
                         }
+                        Char(',') => {
+                            self.settings = Some(Settings::new(self.color_index));
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
+                        Char('|') => {
+                            self.columns = Some(ColumnPicker::new(
+                                ui::COLUMNS_VIEW,
+                                Rs::COLUMNS,
+                                ui::PINNED_COLUMNS,
+                            ));
+                            app_holder = Some(Apps::Rs { app: self.clone() });
+                        }
                         _k => {}
                     }
                 }
             }
+            Message::Key(Event::Mouse(mouse)) => {
+                if self.handle_mouse_event(mouse) {
+                    app_holder = Some(Apps::Rs { app: self.clone() });
+                }
+            }
             Message::Rs(data_vec) => {
                 debug!("updating rs app data...");
-                let new_app = Self {
-                    longest_item_lens: rs_constraint_len_calculator(data_vec),
-                    scroll_state: ScrollbarState::new(
-                        data_vec.len().saturating_sub(1) * ITEM_HEIGHT,
-                    ),
-                    items: data_vec.clone(),
-                    ..self.clone()
+                let new_app_holder = Apps::Rs {
+                    app: self.apply_items(data_vec),
                 };
-                let new_app_holder = Apps::Rs { app: new_app };
                 app_holder = Some(new_app_holder);
             }
+            Message::Error(message) => {
+                self.status = Some(StatusMessage::Error(message.clone()));
+                app_holder = Some(Apps::Rs { app: self.clone() });
+            }
+            Message::Key(Event::Resize(_, _)) => {
+                self.handle_resize();
+                app_holder = Some(Apps::Rs { app: self.clone() });
+            }
             _ => {}
         }
         Ok(app_holder)
@@ -308,6 +790,147 @@ impl App {
         self.show_filter_edit
     }
 
+    /// Change the details pane height and remember it for the next run.
+    fn resize_details(&mut self, step: i16) {
+        self.details_percent = config_file::resize_details(self.details_percent, step);
+        let mut saved = ConfigFile::load();
+        saved.details_percent = self.details_percent;
+        if let Err(e) = saved.save() {
+            self.status = Some(StatusMessage::Error(format!(
+                "could not save the details size: {e}"
+            )));
+        }
+    }
+
+    fn handle_finder_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        if key.kind != KeyEventKind::Press {
+            return Some(Apps::Rs { app: self.clone() });
+        }
+        let Some(finder) = self.finder.as_mut() else {
+            return Some(Apps::Rs { app: self.clone() });
+        };
+        match finder.handle_key(key) {
+            FinderAction::Stay => Some(Apps::Rs { app: self.clone() }),
+            FinderAction::Close => {
+                self.finder = None;
+                Some(Apps::Rs { app: self.clone() })
+            }
+            FinderAction::Jump(entry) => {
+                self.finder = None;
+                debug!("changing app from rs to {} {}...", entry.kind, entry.name);
+                // the rs view stays underneath, so Esc backs out as if the user had drilled in
+                target_app(entry.target).or_else(|| Some(Apps::Rs { app: self.clone() }))
+            }
+        }
+    }
+
+    async fn handle_edit_confirm_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        let Some(confirmed) = self.edit_confirm.as_ref().and_then(|m| m.handle_key(key)) else {
+            return Some(Apps::Rs { app: self.clone() });
+        };
+        if let Some(modal) = self.edit_confirm.take().filter(|_| confirmed) {
+            let (status, confirm) = apply_edit::<ReplicaSet>(modal.subject).await.into_parts();
+            self.status = status.or(self.status.take());
+            self.edit_confirm = confirm;
+        }
+        Some(Apps::Rs { app: self.clone() })
+    }
+
+    fn handle_bookmarks_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        let Some(list) = self.bookmarks.as_mut() else {
+            return Some(Apps::Rs { app: self.clone() });
+        };
+        match list.handle_key(key) {
+            BookmarkAction::Stay => {}
+            BookmarkAction::Close => self.bookmarks = None,
+            BookmarkAction::Jump(bookmark, target) => {
+                self.bookmarks = None;
+                debug!(
+                    "changing app from rs to {} {}...",
+                    bookmark.kind, bookmark.name
+                );
+                // the rs view stays underneath, so Esc backs out as if the user had drilled in
+                if let Some(app) = open_bookmark(&bookmark, target) {
+                    return Some(app);
+                }
+            }
+        }
+        Some(Apps::Rs { app: self.clone() })
+    }
+
+    fn handle_selector_prompt_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        if let Some(prompt) = self.selector_prompt.as_mut() {
+            match prompt.handle_key(key) {
+                InputOutcome::Editing => {}
+                InputOutcome::Submitted(text) => {
+                    self.selector_prompt = None;
+                    self.status = Some(apply_api_selector(&text));
+                }
+                InputOutcome::Cancelled => self.selector_prompt = None,
+            }
+        }
+        Some(Apps::Rs { app: self.clone() })
+    }
+
+    fn handle_settings_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        if let Some(settings) = self.settings.as_mut() {
+            match settings.handle_key(key) {
+                SettingsOutcome::Open => {}
+                SettingsOutcome::Closed => self.settings = None,
+                SettingsOutcome::Saved(status) => self.status = Some(status),
+            }
+        }
+        if let Some(palette) = self.settings.as_ref().map(Settings::palette) {
+            self.color_index = palette;
+            self.set_colors();
+        }
+        Some(Apps::Rs { app: self.clone() })
+    }
+
+    fn handle_columns_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        if let Some(picker) = self.columns.as_mut() {
+            match picker.handle_key(key) {
+                PickerOutcome::Open => {}
+                PickerOutcome::Closed => self.columns = None,
+                PickerOutcome::Saved(status) => self.status = Some(status),
+            }
+        }
+        // with fewer columns there is less to scroll past
+        let max = max_column_offset(ui::shown_columns().len(), ui::PINNED_COLUMNS);
+        self.column_offset = self.column_offset.min(max);
+        Some(Apps::Rs { app: self.clone() })
+    }
+
+    async fn handle_goto_event(&mut self, key: &KeyEvent) -> Option<Apps> {
+        if key.kind == KeyEventKind::Press {
+            if let Some(command) = edit_goto(&mut self.goto, key) {
+                if let Some(status) = run_goto(self, command).await {
+                    self.status = Some(status);
+                }
+            }
+        }
+        Some(Apps::Rs { app: self.clone() })
+    }
+
+    pub(crate) fn placeholder(&self) -> Option<&'static str> {
+        if !self.loaded {
+            Some("Loading ReplicaSets...")
+        } else if self.items.is_empty() {
+            Some("No ReplicaSets found")
+        } else {
+            None
+        }
+    }
+
+    /// The labels and events, and the autoscaler between them when the set has one
+    pub(crate) fn detail_sections(&mut self) -> usize {
+        if self.get_autoscaler_details().is_empty() {
+            2
+        } else {
+            3
+        }
+    }
+
     pub fn get_event_details(&mut self) -> Vec<(String, String, Option<String>)> {
         self.get_selected_item().map_or_else(Vec::new, |pod| {
             pod.events
@@ -323,10 +946,46 @@ impl App {
         })
     }
 
+    pub fn get_autoscaler_details(&mut self) -> Vec<(String, String, Option<String>)> {
+        self.get_selected_item()
+            .and_then(|rs| rs.autoscaler.as_ref())
+            .map_or_else(Vec::new, |hpa| {
+                vec![
+                    ("name".to_string(), hpa.name.clone(), None),
+                    (
+                        "replicas".to_string(),
+                        format!("{} current, {} desired", hpa.current, hpa.desired),
+                        None,
+                    ),
+                    (
+                        "range".to_string(),
+                        format!("{} to {}", hpa.min, hpa.max),
+                        None,
+                    ),
+                    ("metrics".to_string(), hpa.metrics.clone(), None),
+                ]
+            })
+    }
+
     pub fn get_left_details(&mut self) -> Vec<(String, String, Option<String>)> {
+        let short_names = self.short_names;
         self.get_selected_item().map_or_else(Vec::new, |pod| {
             pod.selectors.clone().map_or_else(Vec::new, |labels| {
                 let mut r = Vec::new();
+                // the table only shows the short form
+                if short_names {
+                    r.push(("name".to_string(), pod.name.clone(), None));
+                }
+                if pod.paused {
+                    r.push((
+                        "rollout".to_string(),
+                        format!(
+                            "paused, resume with kubectl rollout resume deployment/{}",
+                            pod.owner
+                        ),
+                        None,
+                    ));
+                }
                 for (name, value) in &labels {
                     r.push((name.to_string(), value.to_string(), None));
                 }
@@ -335,3 +994,20 @@ impl App {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ReadyTrend, TREND_LENGTH};
+
+    #[test]
+    fn test_ready_trend_is_capped_and_restarts_for_another_set() {
+        let mut trend = ReadyTrend::default();
+        for ready in 0..=TREND_LENGTH as u64 {
+            trend.record("web-abc", ready);
+        }
+        assert_eq!(trend.samples.len(), TREND_LENGTH);
+        assert_eq!(trend.samples.front(), Some(&1));
+        trend.record("web-def", 3);
+        assert_eq!(trend.samples, [3]);
+    }
+}
@@ -1,28 +1,114 @@
+use crate::config::{self, ColumnFit};
+use crate::labels::format_label_selector;
+use crate::tui::columns::{pick, visible_columns};
+use crate::tui::data::{rs_summary, Filterable, Rollout, RolloutState, Rs};
+use crate::tui::finder::render_finder;
 use crate::tui::rs_app::app::App;
-use crate::tui::table_ui::render_detail_section;
+use crate::tui::status::{render_status_bar, render_status_line};
+use crate::tui::style::{RowLayout, RowStatus};
 use crate::tui::table_ui::TuiTableState;
+use crate::tui::table_ui::{
+    column_constraints, filter_cell, filter_edit_title, fit_cell_text, flash_style,
+    render_column_scroll_indicator, render_table_footer, render_table_placeholder,
+    render_too_small, scroll_columns, short_name, short_name_len,
+};
+use crate::tui::widgets::centered_rect;
 use ratatui::widgets::{Cell, HighlightSpacing, Row, Scrollbar, ScrollbarOrientation, Table};
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, Clear, Paragraph},
+    widgets::{Block, Borders, Clear, Gauge, Paragraph, Sparkline},
 };
+/// the name the shown columns are kept under in the config. The first `PINNED_COLUMNS`
+/// stay put when scrolling sideways.
+pub(crate) const COLUMNS_VIEW: &str = "replicasets";
+pub(crate) const PINNED_COLUMNS: usize = 1;
+const NAME_COLUMN: usize = 0;
+const PODS_COLUMN: usize = 1;
+const REVISION_COLUMN: usize = 2;
+const OWNER_COLUMN: usize = 5;
+/// marks a set whose replica count an HPA manages
+const AUTOSCALED_MARK: &str = " ⇅";
+/// marks the revision an owner's rollout currently serves from
+const CURRENT_MARK: &str = " ●";
+/// marks the owner of a set whose Deployment is paused
+const PAUSED_MARK: &str = " ⏸";
+/// rows of the ready sparkline under the labels, borders included
+const TREND_HEIGHT: u16 = 5;
+/// rows of the rollout progress bar, borders included
+const ROLLOUT_HEIGHT: u16 = 3;
+
+/// The columns the table shows in order
+pub(crate) fn shown_columns() -> Vec<usize> {
+    visible_columns(Rs::COLUMNS, COLUMNS_VIEW, PINNED_COLUMNS)
+}
+
 pub fn ui(f: &mut Frame, app: &mut App) {
-    let rects = Layout::vertical([Constraint::Min(8), Constraint::Percentage(40)]).split(f.area());
+    if render_too_small(f) {
+        return;
+    }
+    let [view_area, bar_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(f.area());
+
+    let rects = Layout::vertical([
+        Constraint::Min(8),
+        Constraint::Length(1),
+        Constraint::Percentage(app.details_percent),
+    ])
+    .split(view_area);
     let table_area = rects[0];
-    let details_area = rects[1];
+    let footer_area = rects[1];
+    let details_area = rects[2];
 
     app.set_colors();
 
+    let mut summary = rs_summary(&app.items);
+    let hidden = app.hidden_count();
+    if hidden > 0 {
+        summary.push_str(&format!(" ({hidden} scaled down hidden, z shows them)"));
+    }
+    render_table_footer(f, &app.colors, footer_area, &summary);
+    let bar = match &app.goto {
+        Some(goto) => format!(":{goto}"),
+        None => app.status_bar("replicaset", app.refreshed),
+    };
+    render_status_bar(f, &app.colors, bar_area, &bar);
     render_ui_sections(f, app, table_area, details_area);
 }
 
 fn render_ui_sections(f: &mut Frame, app: &mut App, table_area: Rect, details_area: Rect) {
     render_table(f, app, table_area);
     render_scrollbar(f, app, table_area);
+    if let Some(message) = app.placeholder() {
+        render_table_placeholder(f, &app.colors, table_area, message);
+    }
     render_details(f, app, details_area);
     if app.get_show_filter_edit() {
         render_filter_edit(f, app);
     }
+    if let Some(status) = &app.status {
+        render_status_line(f, &app.colors, status);
+    }
+    if let Some(finder) = &app.finder {
+        render_finder(f, &app.colors, finder);
+    }
+    if let Some(prompt) = &app.selector_prompt {
+        prompt.render(f, &app.colors);
+    }
+    if let Some(settings) = &app.settings {
+        settings.render(f, &app.colors);
+    }
+    if let Some(picker) = &app.columns {
+        picker.render(f, &app.colors);
+    }
+    if let Some(bookmarks) = &app.bookmarks {
+        bookmarks.render(f, &app.colors);
+    }
+    if let Some(modal) = &app.edit_confirm {
+        modal.render(f, &app.colors);
+    }
+    if let Some(modal) = &app.quit_confirm {
+        modal.render(f, &app.colors);
+    }
 }
 
 fn render_filter_edit(f: &mut Frame, app: &App) {
@@ -38,7 +124,7 @@ fn render_filter_edit(f: &mut Frame, app: &App) {
     let block = Paragraph::new(app.filter.as_str()).style(edit_style).block(
         Block::default()
             .borders(Borders::ALL)
-            .title("Edit Filter - try: (java|api)"),
+            .title(filter_edit_title()),
     );
 
     f.render_widget(Clear, input_area); //this clears out the background
@@ -51,107 +137,280 @@ fn render_filter_edit(f: &mut Frame, app: &App) {
     };
     f.set_cursor_position(p);
 }
-
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::vertical([
-        Constraint::Percentage((100 - percent_y) / 2),
-        Constraint::Percentage(percent_y),
-        Constraint::Percentage((100 - percent_y) / 2),
-    ])
-    .split(r);
-
-    Layout::horizontal([
-        Constraint::Percentage((100 - percent_x) / 2),
-        Constraint::Percentage(percent_x),
-        Constraint::Percentage((100 - percent_x) / 2),
-    ])
-    .split(popup_layout[1])[1]
-}
 fn render_details(f: &mut Frame, app: &mut App, area: Rect) {
     let detail_rects =
         Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).split(area);
 
     let left_details = app.get_left_details();
+    let autoscaler_details = app.get_autoscaler_details();
     let event_details = app.get_event_details();
 
-    let (foreground_color, background_color) = get_colors(app);
-    render_detail_section(
-        f,
-        foreground_color,
-        background_color,
-        detail_rects[0],
-        "Label",
-        &left_details,
-    );
-    render_detail_section(
+    let colors = get_colors(app);
+    let selected = app.get_selected_item().map(|rs| rs.name.clone());
+    app.detail_focus
+        .follow(selected.as_deref().unwrap_or_default());
+    let rollout = app.get_selected_item().and_then(|rs| rs.rollout.clone());
+    let rollout_height = if rollout.is_some() { ROLLOUT_HEIGHT } else { 0 };
+    let [left_area, rollout_area, trend_area] = Layout::vertical([
+        Constraint::Min(0),
+        Constraint::Length(rollout_height),
+        Constraint::Length(TREND_HEIGHT),
+    ])
+    .areas(detail_rects[0]);
+    if let Some(rollout) = &rollout {
+        render_rollout(f, app, rollout, rollout_area);
+    }
+    render_ready_trend(f, app, trend_area);
+    // Tab goes through the sections as they are laid out, the events last
+    let event_section = if autoscaler_details.is_empty() { 1 } else { 2 };
+    let label_area = if autoscaler_details.is_empty() {
+        left_area
+    } else {
+        let left_rects = Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(left_area);
+        app.detail_focus.render_section(
+            f,
+            colors,
+            left_rects[1],
+            1,
+            "Autoscaler",
+            &autoscaler_details,
+        );
+        left_rects[0]
+    };
+    app.detail_focus
+        .render_section(f, colors, label_area, 0, "Label", &left_details);
+    app.detail_focus.render_section(
         f,
-        foreground_color,
-        background_color,
+        colors,
         detail_rects[1],
+        event_section,
         "Event",
         &event_details,
     );
 }
 
+/// A progress bar of the owning Deployment's rollout, like `kubectl rollout status`
+fn render_rollout(f: &mut Frame, app: &App, rollout: &Rollout, area: Rect) {
+    let color = match rollout.state {
+        RolloutState::Complete => app.colors.ok_fg,
+        RolloutState::Progressing => app.colors.warn_fg,
+        RolloutState::Stalled => app.colors.error_fg,
+    };
+    let mut label = format!(
+        "{}/{} updated, {} available",
+        rollout.updated, rollout.desired, rollout.available
+    );
+    if !rollout.message.is_empty() {
+        label.push_str(&format!(", {}", rollout.message));
+    }
+    let gauge = Gauge::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Rollout {}", rollout.state.label()))
+                .fg(app.colors.header_fg),
+        )
+        .gauge_style(Style::default().fg(color).bg(app.colors.buffer_bg))
+        .ratio(rollout.progress())
+        .label(label);
+    f.render_widget(gauge, area);
+}
+
+/// A sparkline of the selected set's ready replicas over its latest updates, so a
+/// rollout progressing or a set flapping shows at a glance
+fn render_ready_trend(f: &mut Frame, app: &mut App, area: Rect) {
+    let selected = app.get_selected_item().map(|rs| rs.name.clone());
+    // the trend belongs to the set selected when it was recorded
+    let samples: Vec<u64> = if selected.as_deref() == Some(app.ready_trend.name.as_str()) {
+        app.ready_trend.samples.iter().copied().collect()
+    } else {
+        vec![]
+    };
+    let title = samples.last().map_or_else(
+        || "Ready".to_string(),
+        |ready| format!("Ready ({ready} now, last {} updates)", samples.len()),
+    );
+    let sparkline = Sparkline::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .fg(app.colors.header_fg),
+        )
+        .data(&samples)
+        .style(
+            Style::default()
+                .fg(app.colors.ok_fg)
+                .bg(app.colors.buffer_bg),
+        );
+    f.render_widget(sparkline, area);
+}
+
 const fn get_colors(app: &App) -> (Color, Color) {
     (app.colors.header_fg, app.colors.buffer_bg)
 }
 
-fn render_table(f: &mut Frame, app: &App, area: Rect) {
+fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let config = config::current();
+    let fit = config.column_fit;
+    let layout = RowLayout::new(&config);
+    app.set_table_area(area);
+
     let header_style = Style::default()
         .fg(app.colors.header_fg)
         .bg(app.colors.header_bg);
     let selected_style = Style::default()
         .add_modifier(Modifier::REVERSED)
         .fg(app.colors.selected_style_fg);
+    let highlight = Style::new()
+        .fg(app.colors.selected_style_fg)
+        .add_modifier(Modifier::BOLD);
 
+    // the selector the api filters by, unlike the name filter, decides what is fetched
+    let api_selector = format_label_selector(&config.api_selector);
+    let title = if api_selector.is_empty() {
+        "ReplicaSet".to_string()
+    } else {
+        format!("ReplicaSet [{api_selector}]")
+    };
     let filter_header = match app.get_filter() {
-        filter if filter != String::new() => format!("ReplicaSet ({filter})"),
-        _ => "ReplicaSet".to_string(),
+        filter if filter != String::new() => format!("{title} ({filter})"),
+        _ => title,
     };
 
-    let header = [&filter_header, "P", "Age", "Description", "Owner"]
-        .iter()
-        .copied()
-        .map(Cell::from)
-        .collect::<Row>()
-        .style(header_style)
-        .height(1);
-    let rows = app
-        .get_filtered_items()
-        .into_iter()
-        .enumerate()
-        .map(|(i, data)| {
-            let color = match i % 2 {
-                0 => app.colors.normal_row_color,
-                _ => app.colors.alt_row_color,
-            };
-            let item = data.ref_array();
-            item.iter()
-                .copied()
-                .map(|content| Cell::from(Text::from(format!("\n{content}\n"))))
-                .collect::<Row>()
-                .style(Style::new().fg(app.colors.row_fg).bg(color))
-                .height(3) // height
-        });
-    let bar = " █ ";
-    let t = Table::new(
-        rows,
-        [
-            // + 1 is for padding.
-            Constraint::Min(app.longest_item_lens.0 + 1),
-            Constraint::Min(app.longest_item_lens.1 + 1),
-            Constraint::Min(app.longest_item_lens.2 + 1),
-            Constraint::Min(app.longest_item_lens.3 + 1),
-            Constraint::Min(app.longest_item_lens.4),
-        ],
-    )
-    .header(header)
-    .row_highlight_style(selected_style)
-    .highlight_symbol(Text::from(vec!["".into(), bar.into(), "".into()]))
-    .bg(app.colors.buffer_bg)
-    .highlight_spacing(HighlightSpacing::Always);
-    f.render_stateful_widget(t, area, &mut app.state.clone());
+    let offset = app.column_offset;
+    let columns = shown_columns();
+    let titles = [
+        filter_header.as_str(),
+        "P",
+        "Rev",
+        "Age",
+        "Description",
+        "Owner",
+    ];
+    let header = Row::new(scroll_columns(
+        pick(&titles, &columns)
+            .into_iter()
+            .map(Cell::from)
+            .collect::<Vec<Cell>>(),
+        PINNED_COLUMNS,
+        offset,
+    ))
+    .style(header_style)
+    .height(1);
+    // rows arrive grouped by owner: shade by group rather than by row and only name the
+    // owner on the first row of each group so the grouping is visible
+    let mut group = 0;
+    let mut previous_owner: Option<&str> = None;
+    let row_filter = app.row_filter();
+    let rows = row_filter.filter(app.get_items()).into_iter().map(|data| {
+        let first_in_group = previous_owner != Some(data.owner.as_str());
+        if first_in_group && previous_owner.is_some() {
+            group += 1;
+        }
+        previous_owner = Some(data.owner.as_str());
+
+        let color = match group % 2 {
+            0 => app.colors.normal_row_color,
+            _ => app.colors.alt_row_color,
+        };
+        let matched = row_filter.match_indices(data.filter_by());
+        let item = data.ref_array();
+        let cells: Vec<Cell> = columns
+            .iter()
+            .map(|&col| (col, item[col]))
+            .map(|(col, content)| {
+                if col == NAME_COLUMN && app.short_names {
+                    Cell::from(
+                        layout.text(
+                            fit_cell_text(fit, &short_name(content), area.width).into_owned(),
+                        ),
+                    )
+                } else if col == OWNER_COLUMN && !first_in_group {
+                    Cell::from(layout.text("  ⋮".to_string()))
+                } else if col == OWNER_COLUMN && data.paused {
+                    Cell::from(layout.text(format!(
+                        "{}{PAUSED_MARK}",
+                        fit_cell_text(fit, content, area.width)
+                    )))
+                } else if col == REVISION_COLUMN && data.current {
+                    Cell::from(layout.text(format!("{content}{CURRENT_MARK}")))
+                } else if col == PODS_COLUMN {
+                    let mark = if data.autoscaler.is_some() {
+                        AUTOSCALED_MARK
+                    } else {
+                        ""
+                    };
+                    Cell::from(
+                        layout.text(format!("{}{mark}", fit_cell_text(fit, content, area.width))),
+                    )
+                    .style(app.colors.ratio_style(content))
+                } else {
+                    filter_cell(
+                        &fit_cell_text(fit, content, area.width),
+                        data.filter_by(),
+                        &matched,
+                        highlight,
+                        layout,
+                    )
+                }
+            })
+            .collect();
+        // a paused rollout reads like a warning, it waits on someone to resume it, while
+        // revisions scaled down by a finished rollout are only history
+        let status = if data.paused {
+            RowStatus::Warn
+        } else if data.desired_replicas() == 0 {
+            RowStatus::Muted
+        } else {
+            RowStatus::Normal
+        };
+        Row::new(scroll_columns(cells, PINNED_COLUMNS, offset))
+            .style(flash_style(
+                &app.colors,
+                app.colors.row_style(status).bg(color),
+                &app.flashes,
+                &data.name,
+            ))
+            .height(layout.height())
+    });
+    let name_len = if app.short_names {
+        short_name_len(app.get_items().iter().map(|rs| rs.name.as_str()))
+    } else {
+        app.longest_item_lens.0
+    };
+    let lens = scroll_columns(
+        pick(
+            &[
+                // + 1 is for padding.
+                name_len + 1,
+                app.longest_item_lens.1 + 3,
+                app.longest_item_lens.2 + 3,
+                app.longest_item_lens.3 + 1,
+                app.longest_item_lens.4 + 1,
+                app.longest_item_lens.5,
+            ],
+            &columns,
+        ),
+        PINNED_COLUMNS,
+        offset,
+    );
+    let bar = layout.selection_symbol();
+    let t = Table::new(rows, column_constraints(fit, &lens, area.width))
+        .header(header)
+        .row_highlight_style(selected_style)
+        .highlight_symbol(layout.text(bar.to_string()))
+        .bg(app.colors.buffer_bg)
+        .highlight_spacing(HighlightSpacing::Always);
+    f.render_stateful_widget(t, area, &mut app.state);
+    // only content sized columns can run off the right edge
+    let overflowing: &[u16] = if fit == ColumnFit::Content {
+        &lens
+    } else {
+        &[]
+    };
+    render_column_scroll_indicator(f, &app.colors, area, overflowing, offset);
 }
 
 fn render_scrollbar(f: &mut Frame, app: &App, area: Rect) {
@@ -0,0 +1,31 @@
+//! The pods checked with space in the most recent pod view, kept outside the view so
+//! `--print-selection` can write them out after the TUI has closed.
+use std::collections::BTreeSet;
+use std::sync::{OnceLock, RwLock};
+
+static SELECTION: OnceLock<RwLock<BTreeSet<String>>> = OnceLock::new();
+
+fn cell() -> &'static RwLock<BTreeSet<String>> {
+    SELECTION.get_or_init(|| RwLock::new(BTreeSet::new()))
+}
+
+/// Remember `checked` as the selection, called after every event in a pod view
+pub fn record(checked: &BTreeSet<String>) {
+    if let Ok(mut active) = cell().write() {
+        active.clone_from(checked);
+    }
+}
+
+/// The selected names in order, one per line, empty when nothing is checked
+#[must_use]
+pub fn lines() -> String {
+    cell()
+        .read()
+        .map(|active| {
+            active
+                .iter()
+                .flat_map(|name| [name.as_str(), "\n"])
+                .collect()
+        })
+        .unwrap_or_default()
+}
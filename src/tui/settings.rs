@@ -0,0 +1,205 @@
+//! The `,` overlay listing the runtime options, so they can be changed without a restart.
+//!
+//! Changes go straight into the active [`config`] and take effect at once. The palette
+//! belongs to the view that opened the overlay, so the view reads it back with
+//! [`Settings::palette`].
+use crate::config::{self, AppConfig};
+use crate::config_file::ConfigFile;
+use crate::tui::status::StatusMessage;
+use crate::tui::style::{TableColors, PALETTES};
+use crate::tui::widgets::centered_rect;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use std::time::Duration;
+
+const MIN_POLL_SECS: u64 = 1;
+const MAX_POLL_SECS: u64 = 300;
+
+/// One row of the overlay
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Setting {
+    PollInterval,
+    Palette,
+    WrapNavigation,
+    ShowFinished,
+    ReadOnly,
+}
+
+const SETTINGS: [Setting; 5] = [
+    Setting::PollInterval,
+    Setting::Palette,
+    Setting::WrapNavigation,
+    Setting::ShowFinished,
+    Setting::ReadOnly,
+];
+
+impl Setting {
+    const fn label(self) -> &'static str {
+        match self {
+            Self::PollInterval => "poll interval",
+            Self::Palette => "color palette",
+            Self::WrapNavigation => "wrap navigation",
+            Self::ShowFinished => "show completed pods",
+            Self::ReadOnly => "read-only",
+        }
+    }
+}
+
+/// What a key did to the overlay
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SettingsOutcome {
+    Open,
+    /// Esc or `,` closed the overlay
+    Closed,
+    /// `w` wrote the settings to the config file, or failed to
+    Saved(StatusMessage),
+}
+
+#[derive(Clone, Debug)]
+pub struct Settings {
+    selected: usize,
+    palette: usize,
+}
+
+impl Settings {
+    /// An overlay for a view currently drawn with palette `palette`
+    pub const fn new(palette: usize) -> Self {
+        Self {
+            selected: 0,
+            palette,
+        }
+    }
+
+    /// The palette the view should be drawn with
+    pub const fn palette(&self) -> usize {
+        self.palette
+    }
+
+    pub fn handle_key(&mut self, key: &KeyEvent) -> SettingsOutcome {
+        if key.kind != KeyEventKind::Press {
+            return SettingsOutcome::Open;
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char(',' | 'q') => return SettingsOutcome::Closed,
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.selected = (self.selected + 1) % SETTINGS.len();
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.selected = (self.selected + SETTINGS.len() - 1) % SETTINGS.len();
+            }
+            KeyCode::Char('l' | ' ') | KeyCode::Right | KeyCode::Enter => self.change(true),
+            KeyCode::Char('h') | KeyCode::Left => self.change(false),
+            KeyCode::Char('w') => return SettingsOutcome::Saved(save()),
+            _ => {}
+        }
+        SettingsOutcome::Open
+    }
+
+    /// Step the selected setting forward or back, toggles flip either way. Read-only only
+    /// turns on, so neither the overlay nor a slip of the key lifts `--read-only`.
+    fn change(&mut self, forward: bool) {
+        match SETTINGS[self.selected] {
+            Setting::PollInterval => {
+                config::update(|c| c.poll_interval = step_poll(c.poll_interval, forward));
+            }
+            Setting::Palette => {
+                if !config::current().monochrome {
+                    let step = if forward { 1 } else { PALETTES.len() - 1 };
+                    self.palette = (self.palette + step) % PALETTES.len();
+                }
+            }
+            Setting::WrapNavigation => config::update(|c| c.wrap_navigation = !c.wrap_navigation),
+            Setting::ShowFinished => config::update(|c| c.show_finished = !c.show_finished),
+            Setting::ReadOnly => config::update(|c| c.read_only = true),
+        }
+    }
+
+    fn value(&self, setting: Setting, active: &AppConfig) -> String {
+        let toggle = |on: bool| if on { "on" } else { "off" }.to_string();
+        match setting {
+            Setting::PollInterval => format!("{}s", active.poll_interval.as_secs()),
+            Setting::Palette if active.monochrome => "none (--no-color)".to_string(),
+            Setting::Palette => format!("{} of {}", self.palette + 1, PALETTES.len()),
+            Setting::WrapNavigation => toggle(active.wrap_navigation),
+            Setting::ShowFinished => toggle(active.show_finished),
+            Setting::ReadOnly if active.read_only => "on until restart".to_string(),
+            Setting::ReadOnly => toggle(active.read_only),
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, colors: &TableColors) {
+        let style = Style::default().fg(colors.header_fg).bg(colors.header_bg);
+        let active = config::current();
+        let mut lines: Vec<Line> = SETTINGS
+            .iter()
+            .enumerate()
+            .map(|(i, setting)| {
+                let text = format!(" {:<20} {}", setting.label(), self.value(*setting, &active));
+                if i == self.selected {
+                    Line::from(text).style(Style::new().add_modifier(Modifier::REVERSED))
+                } else {
+                    Line::from(text)
+                }
+            })
+            .collect();
+        lines.push(Line::from(""));
+        lines.push(Line::from(" j/k select  h/l change  w save  Esc close"));
+
+        let area = centered_rect(50, 40, f.area());
+        let block = Paragraph::new(lines)
+            .style(style)
+            .block(Block::default().borders(Borders::ALL).title("Settings"));
+
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+    }
+}
+
+/// `current` one second longer or shorter, kept within a sane range
+fn step_poll(current: Duration, forward: bool) -> Duration {
+    let secs = current.as_secs();
+    let secs = if forward {
+        secs.saturating_add(1)
+    } else {
+        secs.saturating_sub(1)
+    };
+    Duration::from_secs(secs.clamp(MIN_POLL_SECS, MAX_POLL_SECS))
+}
+
+/// Keep the settings that make sense across runs. The poll interval and read-only mode
+/// have flags of their own and the palette belongs to a single view, so they are left out.
+fn save() -> StatusMessage {
+    let active = config::current();
+    let mut saved = ConfigFile::load();
+    saved.wrap_navigation = active.wrap_navigation;
+    saved.show_finished = active.show_finished;
+    match saved.save() {
+        Ok(()) => StatusMessage::Info(
+            "saved wrap navigation and completed pods to the config file".to_string(),
+        ),
+        Err(e) => StatusMessage::Error(format!("could not save the settings: {e}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::step_poll;
+    use std::time::Duration;
+
+    #[test]
+    fn test_step_poll_stays_in_range() {
+        assert_eq!(
+            Duration::from_secs(6),
+            step_poll(Duration::from_secs(5), true)
+        );
+        assert_eq!(
+            Duration::from_secs(1),
+            step_poll(Duration::from_secs(1), false)
+        );
+        assert_eq!(
+            Duration::from_secs(300),
+            step_poll(Duration::from_secs(300), true)
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use crate::config;
+use crate::k8s::client;
+use crate::tui::stream::{request_redraw, sleep_unless_quitting, spawn_task};
+use crate::tui::style::TableColors;
+use chrono::{DateTime, Local};
+use ratatui::prelude::*;
+use ratatui::widgets::{Clear, Paragraph};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use unicode_width::UnicodeWidthStr;
+
+/// How long a toast stays in the status bar
+const TOAST_DURATION: Duration = Duration::from_secs(5);
+
+/// the latest toast and when it was shown, drawn by whichever view is open
+static TOAST: Mutex<Option<(StatusMessage, Instant)>> = Mutex::new(None);
+
+/// A one line message drawn along the bottom of a view until the next key press
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StatusMessage {
+    Info(String),
+    Error(String),
+}
+
+/// Flashed instead of acting on a key that would change the cluster under `--read-only`
+pub fn read_only_message(action: &str) -> StatusMessage {
+    StatusMessage::Error(format!("read-only mode, {action} is disabled"))
+}
+
+/// Flash `message` in the status bar of whichever view is open, for news from a
+/// background task rather than an answer to a key
+pub fn show_toast(message: StatusMessage) {
+    if let Ok(mut toast) = TOAST.lock() {
+        *toast = Some((message, Instant::now()));
+    }
+    request_redraw();
+    // and once more when it is due to go
+    spawn_task(async {
+        sleep_unless_quitting(TOAST_DURATION).await;
+        request_redraw();
+    });
+}
+
+fn current_toast() -> Option<StatusMessage> {
+    TOAST
+        .lock()
+        .ok()?
+        .as_ref()
+        .filter(|(_, shown)| shown.elapsed() < TOAST_DURATION)
+        .map(|(message, _)| message.clone())
+}
+
+pub fn render_status_line(f: &mut Frame, colors: &TableColors, message: &StatusMessage) {
+    let area = f.area();
+    if area.height == 0 {
+        return;
+    }
+    let line_area = Rect::new(area.x, area.bottom() - 1, area.width, 1);
+    let (text, style) = message_line(colors, message);
+
+    f.render_widget(Clear, line_area);
+    f.render_widget(Paragraph::new(text).style(style), line_area);
+}
+
+fn message_line(colors: &TableColors, message: &StatusMessage) -> (String, Style) {
+    match message {
+        StatusMessage::Info(text) => (
+            format!(" {text}"),
+            Style::default().fg(colors.header_fg).bg(colors.header_bg),
+        ),
+        StatusMessage::Error(text) if config::current().monochrome => (
+            format!(" ! Error: {text}"),
+            Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD),
+        ),
+        StatusMessage::Error(text) => (
+            format!(" Error: {text}"),
+            Style::default()
+                .fg(Color::White)
+                .bg(Color::Red)
+                .add_modifier(Modifier::BOLD),
+        ),
+    }
+}
+
+/// Text of the persistent status bar, e.g. "pod 12/47 | filter: api | refreshed 14:03:22"
+pub fn status_bar_text(
+    kind: &str,
+    selected: Option<usize>,
+    total: usize,
+    filter: &str,
+    refreshed: Option<DateTime<Local>>,
+) -> String {
+    let position = if total == 0 {
+        0
+    } else {
+        selected.map_or(0, |i| i.min(total - 1) + 1)
+    };
+    let mut text = format!("{kind} {position}/{total}");
+    if !filter.is_empty() {
+        text.push_str(&format!(" | filter: {filter}"));
+    }
+    if let Some(refreshed) = refreshed {
+        text.push_str(&format!(" | refreshed {}", refreshed.format("%H:%M:%S")));
+    }
+    text
+}
+
+/// Where the views look, e.g. "prod | payments | kubeconfig | v1.29.3"
+pub fn cluster_info() -> String {
+    let mut parts: Vec<String> = client::context_name().into_iter().collect();
+    parts.push(client::current_namespace());
+    parts.extend(client::connection_mode().map(|mode| mode.label().to_string()));
+    parts.push(client::server_version());
+    parts.join(" | ")
+}
+
+/// The single line reserved at the bottom of every view, a recent toast takes its place.
+/// The cluster info sits at the right end while it fits beside `text`.
+pub fn render_status_bar(f: &mut Frame, colors: &TableColors, area: Rect, text: &str) {
+    if let Some(toast) = current_toast() {
+        let (text, style) = message_line(colors, &toast);
+        f.render_widget(Paragraph::new(text).style(style), area);
+        return;
+    }
+    let style = Style::default().fg(colors.header_fg).bg(colors.header_bg);
+    f.render_widget(Paragraph::new(format!(" {text}")).style(style), area);
+    let info = format!("{} ", cluster_info());
+    if text.width() + info.width() + 3 <= usize::from(area.width) {
+        let info = Paragraph::new(info)
+            .style(style)
+            .alignment(Alignment::Right);
+        f.render_widget(info, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::status_bar_text;
+
+    #[test]
+    fn test_status_bar_text_counts_from_one() {
+        assert_eq!(status_bar_text("pod", Some(11), 47, "", None), "pod 12/47");
+    }
+
+    #[test]
+    fn test_status_bar_text_shows_filter_and_clamps() {
+        assert_eq!(
+            status_bar_text("pod", Some(9), 3, "api", None),
+            "pod 3/3 | filter: api"
+        );
+        assert_eq!(status_bar_text("pod", None, 0, "", None), "pod 0/0");
+    }
+}
@@ -2,16 +2,23 @@ use crate::tui::data;
 use crossterm::event::Event;
 use crossterm::event::{poll, read};
 use futures::stream::Stream;
+use std::future::Future;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::Duration;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, Notify};
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, timeout};
 use tokio_stream::wrappers::ReceiverStream;
-use tracing::error;
+use tracing::{debug, error};
 
 pub enum Message {
     Key(Event),
     Pod(Vec<data::RsPod>),
+    /// the selected pod as the api's watch reported it, ahead of the next poll
+    WatchedPod(data::RsPod),
+    /// the latest lines logged by the named pod, for the log pane beside the pod table
+    PodLog(String, Vec<String>),
     Rs(Vec<data::Rs>),
     #[allow(dead_code)]
     Ingress(Vec<data::Ingress>),
@@ -23,13 +30,62 @@ pub enum Message {
     Log(Vec<data::LogRec>),
     #[allow(dead_code)]
     Event(Vec<data::ResourceEvent>),
+    Config(Vec<data::ConfigData>),
+    DaemonSet(Vec<data::DaemonSet>),
+    Job(Vec<data::Job>),
+    Namespace(Vec<data::Namespace>),
+    /// requests and limits of the named namespace against its quotas, or why they could
+    /// not be read
+    NamespaceUsage(String, Result<Vec<data::ResourceUsage>, String>),
+    Node(Vec<data::Node>),
+    Pvc(Vec<data::Pvc>),
+    Problem(Vec<data::Problem>),
+    Service(Vec<data::Service>),
+    ApiKind(Vec<data::ApiKind>),
+    Custom(Vec<data::CustomResource>),
+    /// nothing new, but time passed and the view should be drawn again, e.g. to end a
+    /// row highlight
+    Tick,
+    /// a background list call failed, already phrased for the error banner
+    Error(String),
+}
+
+/// How long quitting waits for the background tasks to wind down before dropping them
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(2);
+
+/// the background tasks of the views, awaited on quit
+static TASKS: Mutex<Vec<JoinHandle<()>>> = Mutex::new(vec![]);
+
+/// set once k8p is quitting, for tasks that outlive the view that started them
+static SHUTTING_DOWN: AtomicBool = AtomicBool::new(false);
+
+/// set while another program owns the terminal, e.g. `$EDITOR`, so its keys are left alone
+static KEYS_PAUSED: AtomicBool = AtomicBool::new(false);
+
+/// set when something outside the open view needs it drawn again, e.g. a toast
+static REDRAW: AtomicBool = AtomicBool::new(false);
+
+/// Have the open view drawn again within a key poll, whatever view it is
+pub fn request_redraw() {
+    REDRAW.store(true, Ordering::Relaxed);
+}
+
+pub fn pause_key_events(paused: bool) {
+    KEYS_PAUSED.store(paused, Ordering::Relaxed);
 }
 
 pub fn async_key_events(should_stop: Arc<AtomicBool>) -> impl Stream<Item = Message> {
     let (tx, rx) = mpsc::channel(100);
 
-    tokio::spawn(async move {
+    spawn_task(async move {
         while !should_stop.load(Ordering::Relaxed) {
+            if KEYS_PAUSED.load(Ordering::Relaxed) {
+                sleep_unless_stopped(Duration::from_millis(100)).await;
+                continue;
+            }
+            if REDRAW.swap(false, Ordering::Relaxed) && tx.send(Message::Tick).await.is_err() {
+                break;
+            }
             match poll(Duration::from_millis(100)) {
                 Ok(true) => {
                     if let Ok(event) = read() {
@@ -50,3 +106,101 @@ pub fn async_key_events(should_stop: Arc<AtomicBool>) -> impl Stream<Item = Mess
 
     ReceiverStream::new(rx)
 }
+
+fn refresh_signal() -> &'static Notify {
+    static REFRESH: OnceLock<Notify> = OnceLock::new();
+    REFRESH.get_or_init(Notify::new)
+}
+
+/// Wake the data streams waiting out their poll interval so the current view refetches
+/// now, for the `r` key
+pub fn request_refresh() {
+    refresh_signal().notify_waiters();
+}
+
+fn stop_signal() -> &'static Notify {
+    static STOP: OnceLock<Notify> = OnceLock::new();
+    STOP.get_or_init(Notify::new)
+}
+
+fn quit_signal() -> &'static Notify {
+    static QUIT: OnceLock<Notify> = OnceLock::new();
+    QUIT.get_or_init(Notify::new)
+}
+
+/// Wake the background tasks sleeping between polls so the ones whose view closed see
+/// their stop flag now rather than after the sleep
+pub fn stop_tasks() {
+    stop_signal().notify_waiters();
+}
+
+/// Whether k8p is quitting, for a task that has no view stop flag to look at
+pub fn shutting_down() -> bool {
+    SHUTTING_DOWN.load(Ordering::Relaxed)
+}
+
+/// Spawn a background task of a view, remembered so quitting can wait for it
+pub fn spawn_task<F>(task: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let handle = tokio::spawn(task);
+    if let Ok(mut tasks) = TASKS.lock() {
+        tasks.retain(|task| !task.is_finished());
+        tasks.push(handle);
+    }
+}
+
+/// Stop every background task and wait for them to leave their loops, so no list call
+/// or watch is cut off mid request. A task still busy after [`SHUTDOWN_GRACE`] is
+/// dropped.
+pub async fn shutdown() {
+    SHUTTING_DOWN.store(true, Ordering::Relaxed);
+    stop_tasks();
+    quit_signal().notify_waiters();
+    let tasks = TASKS
+        .lock()
+        .map(|mut tasks| std::mem::take(&mut *tasks))
+        .unwrap_or_default();
+    let all = futures::future::join_all(tasks);
+    if timeout(SHUTDOWN_GRACE, all).await.is_err() {
+        debug!("background tasks still busy after {SHUTDOWN_GRACE:?}, dropping them");
+    }
+}
+
+/// Sleep for `duration`, waking early when the tasks are told to stop
+pub async fn sleep_unless_stopped(duration: Duration) {
+    tokio::select! {
+        () = sleep(duration) => {}
+        () = stop_signal().notified() => {}
+    }
+}
+
+/// Sleep for `duration`, waking early only when k8p quits, for a task that outlives the
+/// view that started it
+pub async fn sleep_unless_quitting(duration: Duration) {
+    tokio::select! {
+        () = sleep(duration) => {}
+        () = quit_signal().notified() => {}
+    }
+}
+
+/// Wait out the poll interval between fetches, returning `true` when it was cut short by
+/// `request_refresh`. Stopping the tasks cuts it short too.
+pub async fn wait_for_poll(interval: Duration) -> bool {
+    tokio::select! {
+        () = sleep(interval) => false,
+        () = refresh_signal().notified() => true,
+        () = stop_signal().notified() => false,
+    }
+}
+
+/// Send a `Message::Tick` after `delay`, so a view redraws once something time based
+/// such as a row highlight has run out
+pub fn tick_after(tx: &mpsc::Sender<Message>, delay: Duration) {
+    let tx = tx.clone();
+    spawn_task(async move {
+        sleep_unless_stopped(delay).await;
+        _ = tx.send(Message::Tick).await;
+    });
+}
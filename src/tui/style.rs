@@ -1,14 +1,121 @@
+use crate::config::{self, AppConfig};
+use crate::tui::data::AgeBand;
 use ratatui::prelude::*;
 use style::palette::tailwind;
 
-pub const PALETTES: [tailwind::Palette; 4] = [
-    tailwind::RED,
-    tailwind::BLUE,
-    tailwind::EMERALD,
-    tailwind::INDIGO,
+/// The color schemes `c` cycles through. Indexes 4 and 5 are built from the Okabe-Ito
+/// colors, which stay distinguishable with the common kinds of color blindness.
+pub const PALETTES: [Palette; 6] = [
+    Palette::tailwind(&tailwind::RED),
+    Palette::tailwind(&tailwind::BLUE),
+    Palette::tailwind(&tailwind::EMERALD),
+    Palette::tailwind(&tailwind::INDIGO),
+    Palette::okabe_ito(OKABE_ITO_BLUE, OKABE_ITO_SKY_BLUE),
+    Palette::okabe_ito(OKABE_ITO_VERMILLION, OKABE_ITO_ORANGE),
 ];
 
-pub const ITEM_HEIGHT: usize = 3;
+const OKABE_ITO_ORANGE: Color = Color::Rgb(230, 159, 0);
+const OKABE_ITO_SKY_BLUE: Color = Color::Rgb(86, 180, 233);
+const OKABE_ITO_BLUISH_GREEN: Color = Color::Rgb(0, 158, 115);
+const OKABE_ITO_YELLOW: Color = Color::Rgb(240, 228, 66);
+const OKABE_ITO_BLUE: Color = Color::Rgb(0, 114, 178);
+const OKABE_ITO_VERMILLION: Color = Color::Rgb(213, 94, 0);
+
+/// The header and selection colors of a scheme and the colors rows take for their status
+#[derive(Clone, Copy, Debug)]
+pub struct Palette {
+    header_bg: Color,
+    selected_fg: Color,
+    ok_fg: Color,
+    error_fg: Color,
+    warn_fg: Color,
+    pending_fg: Color,
+}
+
+impl Palette {
+    const fn tailwind(color: &tailwind::Palette) -> Self {
+        Self {
+            header_bg: color.c900,
+            selected_fg: color.c400,
+            ok_fg: tailwind::GREEN.c400,
+            error_fg: tailwind::RED.c400,
+            warn_fg: tailwind::AMBER.c400,
+            pending_fg: tailwind::YELLOW.c400,
+        }
+    }
+
+    /// red against green is the pair most color blind users can not tell apart, so
+    /// health is bluish green and trouble is vermillion, which also differ in lightness
+    const fn okabe_ito(header_bg: Color, selected_fg: Color) -> Self {
+        Self {
+            header_bg,
+            selected_fg,
+            ok_fg: OKABE_ITO_BLUISH_GREEN,
+            error_fg: OKABE_ITO_VERMILLION,
+            warn_fg: OKABE_ITO_ORANGE,
+            pending_fg: OKABE_ITO_YELLOW,
+        }
+    }
+}
+
+/// How the rows of a table are drawn, taken from the config once per draw and handed to
+/// every row and cell
+#[derive(Clone, Copy, Debug)]
+pub struct RowLayout {
+    compact: bool,
+    monochrome: bool,
+}
+
+impl RowLayout {
+    pub const fn new(config: &AppConfig) -> Self {
+        Self {
+            compact: config.compact_rows,
+            monochrome: config.monochrome,
+        }
+    }
+
+    /// Lines a table row takes, one in compact mode and otherwise the text with a blank
+    /// line above and below
+    pub const fn height(self) -> u16 {
+        if self.compact {
+            1
+        } else {
+            3
+        }
+    }
+
+    /// `content` laid out for a row of [`Self::height`] lines
+    pub fn text(self, content: String) -> Text<'static> {
+        if self.compact {
+            Text::from(content)
+        } else {
+            Text::from(format!("\n{content}\n"))
+        }
+    }
+
+    /// `line` laid out for a row of [`Self::height`] lines
+    pub fn line(self, line: Line<'static>) -> Text<'static> {
+        if self.compact {
+            Text::from(line)
+        } else {
+            Text::from(vec![Line::default(), line, Line::default()])
+        }
+    }
+
+    /// The marker drawn beside the selected row
+    pub const fn selection_symbol(self) -> &'static str {
+        if self.monochrome {
+            " > "
+        } else {
+            " █ "
+        }
+    }
+}
+
+/// [`RowLayout::height`] in the units the scrollbars count in
+pub fn item_height() -> usize {
+    usize::from(RowLayout::new(&config::current()).height())
+}
 
 #[derive(Clone, Debug)]
 pub struct TableColors {
@@ -19,18 +126,114 @@ pub struct TableColors {
     pub(crate) selected_style_fg: Color,
     pub(crate) normal_row_color: Color,
     pub(crate) alt_row_color: Color,
+    /// things that are healthy, e.g. ready containers
+    pub(crate) ok_fg: Color,
+    /// rows that are broken, e.g. failed jobs
+    pub(crate) error_fg: Color,
+    /// rows that are degraded but working
+    pub(crate) warn_fg: Color,
+    /// rows waiting on something, e.g. pending claims
+    pub(crate) pending_fg: Color,
+    /// rows that are deliberately idle, e.g. suspended jobs
+    pub(crate) muted_fg: Color,
+    /// the tint of pod rows younger than `new_pod_age`
+    pub(crate) new_bg: Color,
+    /// the tint of pod rows older than `old_pod_age`
+    pub(crate) old_bg: Color,
 }
 
 impl TableColors {
-    pub const fn new(color: &tailwind::Palette) -> Self {
+    pub const fn new(palette: &Palette) -> Self {
         Self {
             buffer_bg: tailwind::SLATE.c950,
-            header_bg: color.c900,
+            header_bg: palette.header_bg,
             header_fg: tailwind::SLATE.c200,
             row_fg: tailwind::SLATE.c200,
-            selected_style_fg: color.c400,
+            selected_style_fg: palette.selected_fg,
             normal_row_color: tailwind::SLATE.c950,
             alt_row_color: tailwind::SLATE.c900,
+            ok_fg: palette.ok_fg,
+            error_fg: palette.error_fg,
+            warn_fg: palette.warn_fg,
+            pending_fg: palette.pending_fg,
+            muted_fg: tailwind::SLATE.c500,
+            new_bg: tailwind::SKY.c950,
+            old_bg: tailwind::STONE.c800,
+        }
+    }
+
+    /// The terminal's own colors everywhere, for `--no-color` and `NO_COLOR`. Selection
+    /// and errors are marked with symbols instead.
+    pub const fn monochrome() -> Self {
+        Self {
+            buffer_bg: Color::Reset,
+            header_bg: Color::Reset,
+            header_fg: Color::Reset,
+            row_fg: Color::Reset,
+            selected_style_fg: Color::Reset,
+            normal_row_color: Color::Reset,
+            alt_row_color: Color::Reset,
+            ok_fg: Color::Reset,
+            error_fg: Color::Reset,
+            warn_fg: Color::Reset,
+            pending_fg: Color::Reset,
+            muted_fg: Color::Reset,
+            new_bg: Color::Reset,
+            old_bg: Color::Reset,
+        }
+    }
+}
+
+/// How a row is doing, for views that color whole rows by status
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RowStatus {
+    Normal,
+    Error,
+    Warn,
+    Pending,
+    Muted,
+}
+
+impl TableColors {
+    /// The row's color along with a text modifier, so the status still reads for color
+    /// blind users and under `--no-color`
+    pub fn row_style(&self, status: RowStatus) -> Style {
+        match status {
+            RowStatus::Normal => Style::new().fg(self.row_fg),
+            RowStatus::Error => Style::new().fg(self.error_fg).add_modifier(Modifier::BOLD),
+            RowStatus::Warn => Style::new().fg(self.warn_fg).add_modifier(Modifier::ITALIC),
+            RowStatus::Pending => Style::new()
+                .fg(self.pending_fg)
+                .add_modifier(Modifier::ITALIC),
+            RowStatus::Muted => Style::new().fg(self.muted_fg).add_modifier(Modifier::DIM),
+        }
+    }
+
+    /// `style` tinted for a pod of `band`, with a modifier so it still reads under
+    /// `--no-color`
+    pub fn age_style(&self, style: Style, band: Option<AgeBand>) -> Style {
+        match band {
+            Some(AgeBand::New) => style.bg(self.new_bg).add_modifier(Modifier::BOLD),
+            Some(AgeBand::Old) => style.bg(self.old_bg).add_modifier(Modifier::DIM),
+            None => style,
+        }
+    }
+
+    /// The style of a `ready/desired` cell: ok when all are ready, a warning when some
+    /// are and an error when none are. Nothing desired, or text that is no ratio, keeps
+    /// the row's style.
+    pub fn ratio_style(&self, ratio: &str) -> Style {
+        let counts = ratio.split_once('/').and_then(|(ready, desired)| {
+            Some((ready.parse::<u32>().ok()?, desired.parse::<u32>().ok()?))
+        });
+        match counts {
+            Some((_, 0)) | None => Style::new(),
+            Some((ready, desired)) if ready >= desired => Style::new().fg(self.ok_fg),
+            Some((0, _)) => self.row_style(RowStatus::Error),
+            Some(_) => self.row_style(RowStatus::Warn),
         }
     }
 }
+
+/// Appended to the cell of a pod or container that was killed for running out of memory
+pub const OOM_MARK: &str = " OOM";
@@ -1,10 +1,25 @@
+use crate::config::{self, next_since, since_label, ColumnFit, FilterMode, COLUMN_CAP_PERCENT};
+use crate::config_file::ConfigFile;
+use crate::k8s::namespaces::namespace_exists;
+use crate::labels::{format_label_selector, parse_label_selector};
+use crate::tui::breadcrumb::breadcrumb_text;
 use crate::tui::data::Filterable;
-use crate::tui::style::{TableColors, ITEM_HEIGHT, PALETTES};
-use ratatui::widgets::{Block, Borders, ScrollbarState, TableState};
+use crate::tui::status::{status_bar_text, StatusMessage};
+use crate::tui::stream::request_refresh;
+use crate::tui::style::{item_height, RowLayout, TableColors, PALETTES};
+use chrono::{DateTime, Local};
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use ratatui::widgets::{Block, Borders, Cell, ScrollbarState, TableState, Wrap};
 use ratatui::{prelude::*, widgets::Paragraph};
 use regex::Regex;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::rc::Rc;
-use tracing::debug;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 pub fn draw_timeseries_name_value_paragraphs(
     f: &mut Frame,
@@ -90,26 +105,112 @@ where
     fn next(&mut self) {
         let pos = self.get_state().selected().unwrap_or(0);
         let len = self.get_filtered_items().len();
-        if len > 0 && pos < len - 1 {
-            // don't wrap
-            let new_pos = pos + 1;
-            self.get_state().select(Some(new_pos));
-            let new_scroll_state = self.get_scroll_state().position(new_pos * ITEM_HEIGHT);
-
-            self.set_scroll_state(new_scroll_state);
+        if let Some(new_pos) = step_row(pos, len, true, config::current().wrap_navigation) {
+            self.select_row(new_pos);
         }
     }
 
     fn previous(&mut self) {
         let pos = self.get_state().selected().unwrap_or(0);
-        if pos > 0 {
-            // don't wrap
-            let new_pos = pos - 1;
-            self.get_state().select(Some(new_pos));
-            let new_scroll_state = self.get_scroll_state().position((new_pos) * ITEM_HEIGHT);
+        let len = self.get_filtered_items().len();
+        if let Some(new_pos) = step_row(pos, len, false, config::current().wrap_navigation) {
+            self.select_row(new_pos);
+        }
+    }
+
+    /// Select `row` of the filtered items, returning false if there is no such row
+    fn select_row(&mut self, row: usize) -> bool {
+        if row >= self.get_filtered_items().len() {
+            return false;
+        }
+        self.get_state().select(Some(row));
+        let new_scroll_state = self.get_scroll_state().position(row * item_height());
+        self.set_scroll_state(new_scroll_state);
+        true
+    }
+
+    /// Jump to 1-based `line` of the filtered items, past the end clamps to the last row
+    fn goto_line(&mut self, line: usize) {
+        let len = self.get_filtered_items().len();
+        if len > 0 {
+            self.select_row(line.saturating_sub(1).min(len - 1));
+        }
+    }
+
+    /// A left click selects the row under the pointer (or jumps proportionally when the
+    /// scrollbar column is clicked) and the wheel moves the selection.
+    /// Returns true if the selection changed.
+    fn handle_mouse_event(&mut self, mouse: &MouseEvent) -> bool {
+        match mouse.kind {
+            MouseEventKind::ScrollDown => {
+                self.next();
+                true
+            }
+            MouseEventKind::ScrollUp => {
+                self.previous();
+                true
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                let area = self.get_table_area();
+                let body_top = area.y.saturating_add(1); // header row
+                if !area.contains(Position::new(mouse.column, mouse.row)) || mouse.row < body_top {
+                    return false;
+                }
+                let offset_in_body = usize::from(mouse.row - body_top);
+
+                if mouse.column >= area.right().saturating_sub(2) {
+                    let len = self.get_filtered_items().len();
+                    let body_height = usize::from(area.height.saturating_sub(1).max(1));
+                    let row = (offset_in_body * len / body_height).min(len.saturating_sub(1));
+                    return self.select_row(row);
+                }
+
+                let row = self.get_state().offset() + offset_in_body / item_height();
+                self.select_row(row)
+            }
+            _ => false,
+        }
+    }
+
+    /// On `Event::Resize` the stored table area is stale until the next draw, so drop it
+    /// rather than map clicks against it, and re-sync the scrollbar with the selection.
+    /// The table render keeps the selected row in view as the offset is recomputed.
+    fn handle_resize(&mut self) {
+        self.set_table_area(Rect::default());
+        let len = self.get_filtered_items().len();
+        let pos = self
+            .get_state()
+            .selected()
+            .unwrap_or(0)
+            .min(len.saturating_sub(1));
+        self.get_state().select(Some(pos));
+        let new_scroll_state = self
+            .get_scroll_state()
+            .content_length(len.saturating_sub(1) * item_height())
+            .position(pos * item_height());
+        self.set_scroll_state(new_scroll_state);
+    }
 
-            self.set_scroll_state(new_scroll_state);
+    /// Status bar text for this table, positions count among the filtered rows
+    fn status_bar(&mut self, kind: &str, refreshed: Option<DateTime<Local>>) -> String {
+        let total = self.get_filtered_items().len();
+        let selected = self.get_state().selected();
+        let mut text = status_bar_text(kind, selected, total, &self.get_filter(), refreshed);
+        if let Some(hint) = unknown_column_hint::<Self::Item>(&self.get_filter()) {
+            text.push_str(&format!(" | {hint}"));
         }
+        text
+    }
+
+    /// `name (Kind)` for the selected row, the crumb this view adds to the breadcrumb
+    fn selection_crumb(&mut self, kind: &str) -> Option<String> {
+        self.get_selected_item()
+            .map(|item| format!("{} ({kind})", item.key()))
+    }
+
+    /// Header text showing how the user got here, ending with the selected row
+    fn breadcrumb(&mut self, kind: &str) -> String {
+        breadcrumb_text(self.selection_crumb(kind))
     }
 
     fn page_forward(&mut self) {}
@@ -117,12 +218,19 @@ where
     fn page_backward(&mut self) {}
 
     fn next_color(&mut self) {
+        if config::current().monochrome {
+            return;
+        }
         let new_color_index = (self.get_color_index() + 1) % PALETTES.len();
         self.set_color_index(new_color_index);
     }
 
     fn set_colors(&mut self) {
-        let new_colors = TableColors::new(&PALETTES[self.get_color_index()]);
+        let new_colors = if config::current().monochrome {
+            TableColors::monochrome()
+        } else {
+            TableColors::new(&PALETTES[self.get_color_index()])
+        };
         self.set_table_colors(new_colors);
     }
 
@@ -139,12 +247,45 @@ where
         }
     }
 
+    /// Key of the selected row, captured before new data replaces the items.
+    fn get_selected_key(&mut self) -> Option<String> {
+        let selected = self.get_state().selected()?;
+        self.get_filtered_items()
+            .get(selected)
+            .map(|item| item.key().to_string())
+    }
+
+    /// Re-resolve a previously selected row against refreshed items. If the row is gone the
+    /// selection stays at the same position, clamped to the new length.
+    fn select_key(&mut self, key: Option<&str>) {
+        let (found, len) = {
+            let items = self.get_filtered_items();
+            (
+                key.and_then(|key| items.iter().position(|item| item.key() == key)),
+                items.len(),
+            )
+        };
+        let pos = found.unwrap_or_else(|| {
+            self.get_state()
+                .selected()
+                .unwrap_or(0)
+                .min(len.saturating_sub(1))
+        });
+        self.get_state().select(Some(pos));
+        let new_scroll_state = self.get_scroll_state().position(pos * item_height());
+        self.set_scroll_state(new_scroll_state);
+    }
+
     fn get_items(&self) -> &[Self::Item];
     fn get_state(&mut self) -> &mut TableState;
     //fn set_state(&mut self, state: TableState);
 
     fn get_scroll_state(&self) -> &ScrollbarState;
     fn set_scroll_state(&mut self, scroll_state: ScrollbarState);
+
+    /// Where the table (header included) was last drawn, for mapping mouse positions to rows
+    fn get_table_area(&self) -> Rect;
+    fn set_table_area(&mut self, table_area: Rect);
     //fn get_table_colors(&self) -> &TableColors;
 
     fn set_table_colors(&mut self, colors: TableColors);
@@ -158,18 +299,23 @@ where
     fn get_filter(&self) -> String;
     fn set_filter(&mut self, filter: String);
     fn get_filtered_items(&self) -> Vec<&Self::Item> {
-        let filter_pattern = self.get_filter();
-        match Regex::new(&filter_pattern) {
-            Ok(regex) => self
-                .get_items()
-                .iter()
-                .filter(|item| regex.is_match(item.filter_by()))
-                .collect(),
-            Err(e) => {
-                debug!("Invalid regex pattern: {}", e); // Log the error
-                self.get_items().iter().collect() // Return the unfiltered list
-            }
-        }
+        self.row_filter().filter(self.get_items())
+    }
+
+    /// The view's filter compiled for the active [`FilterMode`]. A render builds it once
+    /// and uses it for every row.
+    fn row_filter(&self) -> RowFilter {
+        RowFilter::new::<Self::Item>(&self.get_filter(), config::filter_mode())
+    }
+
+    fn toggle_filter_mode(&mut self) {
+        config::update(|config| {
+            config.filter_mode = match config.filter_mode {
+                FilterMode::Fuzzy => FilterMode::Regex,
+                FilterMode::Regex => FilterMode::Fuzzy,
+            };
+        });
+        self.reset_selection_state();
     }
 
     fn move_cursor_left(&mut self) {
@@ -214,6 +360,551 @@ where
     fn get_cursor_pos(&self) -> usize;
     fn set_show_filter_edit(&mut self, show_filter_edit: bool);
     fn get_show_filter_edit(&self) -> bool;
+
+    /// Whether one of the view's prompts, confirmations or overlays has the keyboard, so
+    /// the keys every view shares go to it instead
+    fn typing(&self) -> bool {
+        self.get_show_filter_edit()
+    }
+    /// Whether the view holds rows picked in the current namespace, e.g. pods checked for
+    /// a delete, which a namespace switch would leave naming objects of another one
+    fn namespace_bound(&self) -> bool {
+        false
+    }
+}
+
+/// The row `j` (`forward`) or `k` moves to from `pos` in `len` rows, `None` at either end
+/// unless `wrap` carries on from the other end
+pub fn step_row(pos: usize, len: usize, forward: bool, wrap: bool) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    let last = len - 1;
+    match (forward, wrap) {
+        (true, _) if pos < last => Some(pos + 1),
+        (false, _) if pos > 0 && last > 0 => Some(pos.min(last) - 1),
+        (true, true) => Some(0),
+        (false, true) => Some(last),
+        _ => None,
+    }
+}
+
+/// The smallest frame a view is laid out in, width by height. Below it the fixed
+/// heights of the panes leave the table no rows, or none at all.
+const MIN_FRAME: (u16, u16) = (40, 12);
+
+const fn too_small(area: Rect) -> bool {
+    area.width < MIN_FRAME.0 || area.height < MIN_FRAME.1
+}
+
+/// In place of a view whose frame is too small to lay it out, a note saying how big it
+/// has to be. Returns whether the view should skip drawing itself.
+pub fn render_too_small(f: &mut Frame) -> bool {
+    let area = f.area();
+    if !too_small(area) {
+        return false;
+    }
+    let note = Paragraph::new(format!(
+        "terminal too small (need at least {}x{})",
+        MIN_FRAME.0, MIN_FRAME.1
+    ))
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true });
+    let [_, line, _] = Layout::vertical([
+        Constraint::Fill(1),
+        Constraint::Length(2),
+        Constraint::Fill(1),
+    ])
+    .areas(area);
+    f.render_widget(note, line);
+    true
+}
+
+/// Draw `message` over the body of a table whose header sits on the first row of `area`.
+/// Used when there are no rows to show, either because the first fetch is still in flight
+/// or because it came back empty.
+pub fn render_table_placeholder(f: &mut Frame, colors: &TableColors, area: Rect, message: &str) {
+    let body = Rect {
+        y: area.y.saturating_add(1),
+        height: area.height.saturating_sub(1),
+        ..area
+    };
+    let rects = Layout::vertical([
+        Constraint::Fill(1),
+        Constraint::Length(1),
+        Constraint::Fill(1),
+    ])
+    .split(body);
+
+    let placeholder = Paragraph::new(message)
+        .style(Style::default().fg(colors.header_fg).bg(colors.buffer_bg))
+        .alignment(Alignment::Center);
+    f.render_widget(placeholder, rects[1]);
+}
+
+/// Title for the filter edit popup, naming the active match mode
+pub fn filter_edit_title() -> &'static str {
+    match config::current().filter_mode {
+        FilterMode::Fuzzy => "Edit Filter (fuzzy) - Tab for regex",
+        FilterMode::Regex => "Edit Filter (regex) - try: (java|api) - Tab for fuzzy",
+    }
+}
+
+/// A table cell for `content`. When it is the text the filter applies to, the matched
+/// characters are highlighted.
+pub fn filter_cell(
+    content: &str,
+    filter_text: &str,
+    matched: &[usize],
+    highlight: Style,
+    layout: RowLayout,
+) -> Cell<'static> {
+    if matched.is_empty() || content != filter_text {
+        return Cell::from(layout.text(content.to_string()));
+    }
+    let spans = content
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if matched.contains(&i) {
+                Span::styled(c.to_string(), highlight)
+            } else {
+                Span::raw(c.to_string())
+            }
+        })
+        .collect::<Vec<Span>>();
+    Cell::from(layout.line(Line::from(spans)))
+}
+
+/// A `/` filter with its matcher built once, to filter a table and highlight its rows
+pub struct RowFilter {
+    column: Option<usize>,
+    pattern: String,
+    matcher: RowMatcher,
+}
+
+enum RowMatcher {
+    Fuzzy(SkimMatcherV2),
+    /// `None` for a pattern that does not compile, it filters nothing out
+    Regex(Option<Regex>),
+}
+
+impl RowFilter {
+    pub fn new<T: Filterable>(filter: &str, mode: FilterMode) -> Self {
+        let (column, pattern) = column_filter::<T>(filter);
+        let matcher = match mode {
+            FilterMode::Fuzzy => RowMatcher::Fuzzy(SkimMatcherV2::default()),
+            FilterMode::Regex => RowMatcher::Regex(
+                Regex::new(&pattern)
+                    .inspect_err(|e| debug!("Invalid regex pattern: {}", e))
+                    .ok(),
+            ),
+        };
+        Self {
+            column,
+            pattern,
+            matcher,
+        }
+    }
+
+    /// The `items` the filter keeps, best fuzzy matches first
+    pub fn filter<'a, T: Filterable>(&self, items: &'a [T]) -> Vec<&'a T> {
+        if self.pattern.is_empty() {
+            return items.iter().collect();
+        }
+        match &self.matcher {
+            RowMatcher::Fuzzy(matcher) => {
+                let mut scored: Vec<(i64, &T)> = items
+                    .iter()
+                    .filter_map(|item| {
+                        matcher
+                            .fuzzy_match(filter_text(item, self.column), &self.pattern)
+                            .map(|score| (score, item))
+                    })
+                    .collect();
+                // stable, so equally good matches keep their original order
+                scored.sort_by(|a, b| b.0.cmp(&a.0));
+                scored.into_iter().map(|(_, item)| item).collect()
+            }
+            RowMatcher::Regex(Some(regex)) => items
+                .iter()
+                .filter(|item| regex.is_match(filter_text(*item, self.column)))
+                .collect(),
+            RowMatcher::Regex(None) => items.iter().collect(),
+        }
+    }
+
+    /// Char positions in `text` the filter matched, for highlighting. A filter on one
+    /// column highlights nothing, the highlighted cell is another column.
+    pub fn match_indices(&self, text: &str) -> Vec<usize> {
+        if self.pattern.is_empty() || self.column.is_some() {
+            return vec![];
+        }
+        match &self.matcher {
+            RowMatcher::Fuzzy(matcher) => matcher
+                .fuzzy_indices(text, &self.pattern)
+                .map(|(_, indices)| indices)
+                .unwrap_or_default(),
+            RowMatcher::Regex(regex) => regex
+                .as_ref()
+                .and_then(|regex| regex.find(text))
+                .map(|m| {
+                    let start = text[..m.start()].chars().count();
+                    let end = text[..m.end()].chars().count();
+                    (start..end).collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// `filter` split into `column:` and the query when it starts with a word and a colon
+fn split_column_filter(filter: &str) -> Option<(&str, &str)> {
+    let (column, query) = filter.split_once(':')?;
+    let is_word = !column.is_empty() && column.chars().all(|c| c.is_ascii_alphabetic() || c == '-');
+    is_word.then_some((column, query))
+}
+
+/// The column of `T` a filter names and the query left to match in it. A filter that
+/// names no column, or one `T` does not have, is matched whole against the usual text.
+fn column_filter<T: Filterable>(filter: &str) -> (Option<usize>, String) {
+    split_column_filter(filter)
+        .and_then(|(name, query)| {
+            T::COLUMNS
+                .iter()
+                .position(|column| column.eq_ignore_ascii_case(name))
+                .map(|index| (Some(index), query.to_string()))
+        })
+        .unwrap_or_else(|| (None, filter.to_string()))
+}
+
+/// The text of `item` a filter is matched against
+fn filter_text<T: Filterable>(item: &T, column: Option<usize>) -> &str {
+    column
+        .and_then(|index| item.column_text(index))
+        .unwrap_or_else(|| item.filter_by())
+}
+
+/// Says which columns there are when `filter` names one `T` does not have
+fn unknown_column_hint<T: Filterable>(filter: &str) -> Option<String> {
+    let (name, _) = split_column_filter(filter)?;
+    if T::COLUMNS
+        .iter()
+        .any(|column| column.eq_ignore_ascii_case(name))
+    {
+        return None;
+    }
+    Some(if T::COLUMNS.is_empty() {
+        format!("no column {name}, this view filters by name only")
+    } else {
+        format!("no column {name}, try {}", T::COLUMNS.join(", "))
+    })
+}
+
+/// Step every table to the next [`ColumnFit`], returning the one now active
+pub fn cycle_column_fit() -> ColumnFit {
+    config::update(|config| config.column_fit = config.column_fit.next());
+    config::current().column_fit
+}
+
+/// Switch every table between three line and one line rows, and keep the choice for the
+/// next run
+pub fn toggle_compact_rows() {
+    config::update(|config| config.compact_rows = !config.compact_rows);
+    let mut saved = ConfigFile::load();
+    saved.compact_rows = config::current().compact_rows;
+    if let Err(e) = saved.save() {
+        warn!("could not save the row height: {e}");
+    }
+}
+
+/// Switch to the next namespace of the config file's `namespace_cycle` and refetch the
+/// open view, whatever it is. Refused while the view is `bound` to the current
+/// namespace, see [`TuiTableState::namespace_bound`].
+pub fn cycle_namespace(bound: bool) -> StatusMessage {
+    if bound {
+        return namespace_bound_message();
+    }
+    let saved = ConfigFile::load();
+    let current = config::current().namespace;
+    let Some(next) = saved.next_in_cycle(current.as_deref()) else {
+        return StatusMessage::Error(
+            "no namespace_cycle in the config file to switch through".to_string(),
+        );
+    };
+    config::update(|config| config.namespace = Some(next.to_string()));
+    request_refresh();
+    StatusMessage::Info(format!("namespace {next}"))
+}
+
+/// Why the namespace stays while the view holds checked rows
+pub fn namespace_bound_message() -> StatusMessage {
+    StatusMessage::Error("uncheck the rows with Esc before switching namespace".to_string())
+}
+
+/// Step the log and event views to the next time window and refetch, see
+/// [`config::SINCE_STEPS`]
+pub fn step_since() -> StatusMessage {
+    config::update(|config| config.since = next_since(config.since));
+    request_refresh();
+    StatusMessage::Info(since_text(config::current().since))
+}
+
+/// How far back the log and event views go, for their status bars
+pub fn since_text(since: Option<Duration>) -> String {
+    since.map_or_else(
+        || "since: everything".to_string(),
+        |window| format!("since: last {}", since_label(window)),
+    )
+}
+
+/// The widest a column may be in a table `width` columns wide under [`ColumnFit::Truncate`]
+const fn column_cap(width: u16) -> u16 {
+    width.saturating_mul(COLUMN_CAP_PERCENT) / 100
+}
+
+/// Column constraints for a table `width` columns wide. `lens` are the padded content
+/// widths from the `_constraint_len_calculator` of the view.
+pub fn column_constraints(fit: ColumnFit, lens: &[u16], width: u16) -> Vec<Constraint> {
+    #[allow(clippy::cast_possible_truncation)]
+    let columns = lens.len() as u32;
+    match fit {
+        ColumnFit::Content => lens.iter().map(|len| Constraint::Min(*len)).collect(),
+        ColumnFit::Balanced => lens.iter().map(|_| Constraint::Ratio(1, columns)).collect(),
+        ColumnFit::Truncate => {
+            let cap = column_cap(width);
+            lens.iter()
+                .map(|len| Constraint::Min((*len).min(cap)))
+                .collect()
+        }
+    }
+}
+
+/// `columns` with `offset` of them scrolled out of view to the left. The first `pinned`
+/// columns, the row's name, never scroll away.
+pub fn scroll_columns<T>(columns: Vec<T>, pinned: usize, offset: usize) -> Vec<T> {
+    columns
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| *i < pinned || *i >= pinned + offset)
+        .map(|(_, column)| column)
+        .collect()
+}
+
+/// The furthest a table of `columns` with `pinned` fixed ones may scroll, leaving one
+/// scrollable column in view
+pub const fn max_column_offset(columns: usize, pinned: usize) -> usize {
+    columns.saturating_sub(pinned + 1)
+}
+
+/// Arrows in the corners of the header row when columns are scrolled out of view to the
+/// left or do not fit on the right. `lens` are the widths of the visible columns.
+pub fn render_column_scroll_indicator(
+    f: &mut Frame,
+    colors: &TableColors,
+    area: Rect,
+    lens: &[u16],
+    offset: usize,
+) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+    let style = Style::default()
+        .fg(colors.selected_style_fg)
+        .bg(colors.header_bg)
+        .add_modifier(Modifier::BOLD);
+    // a space between each column and the selection symbol in front of the first
+    let needed: u32 = lens.iter().map(|len| u32::from(*len) + 1).sum::<u32>() + 3;
+    if needed > u32::from(area.width) {
+        let right = Rect::new(area.right() - 1, area.y, 1, 1);
+        f.render_widget(Paragraph::new("▸").style(style), right);
+    }
+    if offset > 0 {
+        let left = Rect::new(area.x, area.y, 1, 1);
+        f.render_widget(Paragraph::new("◂").style(style), left);
+    }
+}
+
+/// `content` as it should be drawn in a table `width` columns wide: cut with an ellipsis
+/// when [`ColumnFit::Truncate`] is active and it is wider than a column may be
+pub fn fit_cell_text(fit: ColumnFit, content: &str, width: u16) -> Cow<'_, str> {
+    if fit != ColumnFit::Truncate {
+        return Cow::Borrowed(content);
+    }
+    // leave a column for the gap to the next cell
+    let max = usize::from(column_cap(width).saturating_sub(1));
+    if max == 0 || content.lines().all(|line| line.width() <= max) {
+        return Cow::Borrowed(content);
+    }
+    let lines = content
+        .lines()
+        .map(|line| {
+            if line.width() <= max {
+                line.to_string()
+            } else {
+                let (head, _) = split_at_width(line, max - 1);
+                format!("{head}…")
+            }
+        })
+        .collect::<Vec<String>>();
+    Cow::Owned(lines.join("\n"))
+}
+
+/// Characters kubernetes uses for generated name suffixes, no vowels and no confusable digits
+const GENERATED_NAME_ALPHABET: &str = "bcdfghjklmnpqrstvwxz2456789";
+/// How much of the last generated suffix a short name keeps
+const SHORT_NAME_TAIL: usize = 5;
+
+fn is_generated_suffix(segment: &str) -> bool {
+    segment.len() >= SHORT_NAME_TAIL && segment.chars().all(|c| GENERATED_NAME_ALPHABET.contains(c))
+}
+
+/// `name` with its generated hash suffixes collapsed, `nginx-deployment-66b6c48dd5-x7kqz`
+/// becomes `nginx-deployment…x7kqz`. Names without a generated suffix are unchanged.
+pub fn short_name(name: &str) -> Cow<'_, str> {
+    let segments: Vec<&str> = name.split('-').collect();
+    let generated = segments
+        .iter()
+        .rev()
+        .take_while(|segment| is_generated_suffix(segment))
+        .count();
+    if generated == 0 || generated == segments.len() {
+        return Cow::Borrowed(name);
+    }
+    let base = segments[..segments.len() - generated].join("-");
+    let last = segments[segments.len() - 1];
+    let tail = &last[last.len() - SHORT_NAME_TAIL..];
+    Cow::Owned(format!("{base}…{tail}"))
+}
+
+/// Width of the widest of `names` once shortened, for sizing the name column
+pub fn short_name_len<'a>(names: impl Iterator<Item = &'a str>) -> u16 {
+    let width = names
+        .map(|name| short_name(name).width())
+        .max()
+        .unwrap_or(0);
+    u16::try_from(width).unwrap_or(u16::MAX)
+}
+
+/// What was typed at the `:` prompt
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GotoCommand {
+    /// `:42` selects the 42nd row
+    Line(usize),
+    /// `:ns staging` lists the same view in another namespace
+    Namespace(String),
+    Unknown(String),
+}
+
+impl GotoCommand {
+    fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        if text.is_empty() {
+            return None;
+        }
+        if let Ok(line) = text.parse() {
+            return Some(Self::Line(line));
+        }
+        Some(match text.strip_prefix("ns ").map(str::trim) {
+            Some(namespace) if !namespace.is_empty() => Self::Namespace(namespace.to_string()),
+            _ => Self::Unknown(text.to_string()),
+        })
+    }
+}
+
+/// Feed a key to the `:` prompt held in `goto`. Typing and Backspace edit it, Esc
+/// closes it, and Enter closes it returning the command typed, if any.
+pub fn edit_goto(goto: &mut Option<String>, key: &KeyEvent) -> Option<GotoCommand> {
+    let buffer = goto.as_mut()?;
+    match key.code {
+        KeyCode::Char(c) => {
+            buffer.push(c);
+            None
+        }
+        KeyCode::Backspace => {
+            buffer.pop();
+            None
+        }
+        KeyCode::Enter => goto.take().and_then(|text| GotoCommand::parse(&text)),
+        KeyCode::Esc => {
+            *goto = None;
+            None
+        }
+        _ => None,
+    }
+}
+
+/// Push the label selector typed after `L` to the api and refetch, an empty one clears it
+pub fn apply_api_selector(text: &str) -> StatusMessage {
+    match parse_label_selector(text) {
+        Ok(selector) => {
+            let message = if selector.is_empty() {
+                "api selector cleared".to_string()
+            } else {
+                format!("api selector: {}", format_label_selector(&selector))
+            };
+            config::update(|config| config.api_selector = selector);
+            request_refresh();
+            StatusMessage::Info(message)
+        }
+        Err(e) => StatusMessage::Error(format!("invalid selector, {e}")),
+    }
+}
+
+/// Make `namespace` the one every view lists and refetch the open view, once the cluster
+/// confirms it exists
+pub async fn apply_namespace(namespace: &str) -> StatusMessage {
+    match namespace_exists(namespace).await {
+        Ok(true) => {
+            config::update(|config| config.namespace = Some(namespace.to_string()));
+            request_refresh();
+            StatusMessage::Info(format!("namespace {namespace}"))
+        }
+        Ok(false) => StatusMessage::Error(format!("no namespace {namespace}")),
+        Err(e) => StatusMessage::Error(format!("could not check namespace {namespace}: {e}")),
+    }
+}
+
+/// Carry out a command typed at the `:` prompt, the status to flash if it has one
+pub async fn run_goto<T: TuiTableState>(
+    table: &mut T,
+    command: GotoCommand,
+) -> Option<StatusMessage> {
+    match command {
+        GotoCommand::Line(line) => {
+            table.goto_line(line);
+            None
+        }
+        GotoCommand::Namespace(namespace) => Some(apply_namespace(&namespace).await),
+        GotoCommand::Unknown(text) => Some(StatusMessage::Error(format!(
+            "unknown command :{text}, try :<line> or :ns <namespace>"
+        ))),
+    }
+}
+
+/// A single summary line drawn under a table
+pub fn render_table_footer(f: &mut Frame, colors: &TableColors, area: Rect, summary: &str) {
+    let footer = Paragraph::new(format!(" {summary}"))
+        .style(Style::default().fg(colors.header_fg).bg(colors.header_bg));
+    f.render_widget(footer, area);
+}
+
+/// The owner chain above a drilled-in view, see `TuiTableState::breadcrumb`
+pub fn render_breadcrumb(f: &mut Frame, colors: &TableColors, area: Rect, breadcrumb: &str) {
+    let header = Paragraph::new(format!(" {breadcrumb}"))
+        .style(Style::default().fg(colors.header_fg).bg(colors.header_bg));
+    f.render_widget(header, area);
+    if config::current().read_only {
+        let mode = Paragraph::new("read-only mode ")
+            .style(
+                Style::default()
+                    .fg(colors.header_fg)
+                    .bg(colors.header_bg)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .alignment(Alignment::Right);
+        f.render_widget(mode, area);
+    }
 }
 
 pub fn render_detail_section(
@@ -236,9 +927,97 @@ pub fn render_detail_section(
     );
 }
 
-fn get_chunks_from_area(area: Rect, sz: usize) -> Rc<[Rect]> {
-    let constraints = std::iter::repeat(Constraint::Length(1))
-        .take(sz)
+/// Which detail section Tab has handed the keys to, and how far each section of the
+/// selected row is scrolled. Without a focused section `j` and `k` move through the table.
+#[derive(Clone, Debug, Default)]
+pub struct DetailFocus {
+    focused: Option<usize>,
+    /// first detail shown, by section
+    offsets: Vec<usize>,
+    /// the row the offsets belong to
+    item: String,
+}
+
+impl DetailFocus {
+    /// Tab focuses the next of `sections` and after the last one gives the keys back to
+    /// the table, `j` and `k` scroll the focused section and Esc leaves it. Returns
+    /// whether the key was used.
+    pub fn handle_key(&mut self, key: &KeyEvent, sections: usize) -> bool {
+        match (key.code, self.focused) {
+            (KeyCode::Tab, focused) => {
+                self.focused = match focused {
+                    None if sections > 0 => Some(0),
+                    Some(section) if section + 1 < sections => Some(section + 1),
+                    _ => None,
+                };
+            }
+            (KeyCode::Char('j') | KeyCode::Down, Some(section)) => {
+                self.set_offset(section, self.offset(section) + 1);
+            }
+            (KeyCode::Char('k') | KeyCode::Up, Some(section)) => {
+                self.set_offset(section, self.offset(section).saturating_sub(1));
+            }
+            (KeyCode::Esc, Some(_)) => self.focused = None,
+            _ => return false,
+        }
+        true
+    }
+
+    fn offset(&self, section: usize) -> usize {
+        self.offsets.get(section).copied().unwrap_or(0)
+    }
+
+    fn set_offset(&mut self, section: usize, offset: usize) {
+        if self.offsets.len() <= section {
+            self.offsets.resize(section + 1, 0);
+        }
+        self.offsets[section] = offset;
+    }
+
+    /// Start every section from its top when another row than `item` was scrolled
+    pub fn follow(&mut self, item: &str) {
+        if self.item != item {
+            item.clone_into(&mut self.item);
+            self.offsets.clear();
+        }
+    }
+
+    /// [`render_detail_section`] for `section`, starting from its scrolled to detail and
+    /// marked while it has the keys
+    pub fn render_section(
+        &mut self,
+        f: &mut Frame,
+        (foreground_color, background_color): (Color, Color),
+        area: Rect,
+        section: usize,
+        title: &str,
+        details: &[(String, String, Option<String>)],
+    ) {
+        let offset = self.offset(section).min(details.len().saturating_sub(1));
+        self.set_offset(section, offset);
+        let mut block_title = if offset > 0 {
+            format!("{title} ({}, from {})", details.len(), offset + 1)
+        } else {
+            format!("{title} ({})", details.len())
+        };
+        if self.focused == Some(section) {
+            block_title = format!("» {block_title} - j/k scroll, Tab next, Esc back");
+        }
+        render_block_with_title_and_details(
+            f,
+            foreground_color,
+            background_color,
+            area,
+            &block_title,
+            &details[offset..],
+        );
+    }
+}
+
+fn get_chunks_from_area(area: Rect, heights: &[u16]) -> Rc<[Rect]> {
+    let constraints = heights
+        .iter()
+        .map(|height| Constraint::Length(*height))
         .collect::<Vec<Constraint>>();
 
     Layout::default()
@@ -248,6 +1027,89 @@ fn get_chunks_from_area(area: Rect, sz: usize) -> Rc<[Rect]> {
         .split(area)
 }
 
+/// Width of the value column of a detail row, using the same constraints as
+/// `draw_name_value_paragraphs`
+fn detail_value_width(row: Rect, timeseries: bool) -> usize {
+    let row = Rect { height: 1, ..row };
+    let width = if timeseries {
+        Layout::horizontal([
+            Constraint::Min(4),
+            Constraint::Min(11),
+            Constraint::Percentage(90),
+        ])
+        .split(row)[2]
+            .width
+    } else {
+        Layout::horizontal([Constraint::Min(30), Constraint::Percentage(90)]).split(row)[1].width
+    };
+    usize::from(width)
+}
+
+fn split_at_width(word: &str, width: usize) -> (&str, &str) {
+    let mut used = 0;
+    for (i, c) in word.char_indices() {
+        let w = c.width().unwrap_or(0);
+        if i > 0 && used + w > width {
+            return word.split_at(i);
+        }
+        used += w;
+    }
+    (word, "")
+}
+
+/// Greedy word wrap to `width` columns; words wider than a line are broken
+pub(crate) fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    if width == 0 {
+        return vec![text.to_string()];
+    }
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let mut word = word;
+            while !word.is_empty() {
+                let sep = usize::from(!line.is_empty());
+                if line.width() + sep + word.width() <= width {
+                    if sep == 1 {
+                        line.push(' ');
+                    }
+                    line.push_str(word);
+                    break;
+                }
+                if line.is_empty() {
+                    let (head, tail) = split_at_width(word, width);
+                    lines.push(head.to_string());
+                    word = tail;
+                } else {
+                    lines.push(std::mem::take(&mut line));
+                }
+            }
+        }
+        if !line.is_empty() {
+            lines.push(line);
+        }
+    }
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines
+}
+
+/// Keep the first `max` lines, marking the cut with an ellipsis
+fn truncate_lines(mut lines: Vec<String>, max: usize, width: usize) -> Vec<String> {
+    if lines.len() <= max {
+        return lines;
+    }
+    lines.truncate(max);
+    if let Some(last) = lines.last_mut() {
+        while !last.is_empty() && last.width() + 1 > width {
+            last.pop();
+        }
+        last.push('…');
+    }
+    lines
+}
+
 fn create_block(title: String, foreground_color: &Color) -> Block<'_> {
     Block::default()
         .borders(Borders::ALL)
@@ -270,9 +1132,35 @@ fn render_block_with_title_and_details(
         .style(Style::default().fg(foreground_color).bg(background_color));
     f.render_widget(details_block, area);
 
-    let chunks = get_chunks_from_area(area, details.len());
+    // long values wrap onto extra lines, so show as many whole rows as fit and only cut
+    // a value short when it alone is taller than the pane
+    let inner = area.inner(Margin {
+        vertical: 1,
+        horizontal: 1,
+    });
+    let mut available = usize::from(inner.height);
+    let mut rows = Vec::new();
+    for (name, value, age) in details {
+        let width = detail_value_width(inner, age.is_some());
+        let lines = wrap_text(value, width);
+        if lines.len() > available {
+            if rows.is_empty() && available > 0 {
+                rows.push((name, truncate_lines(lines, available, width), age));
+            }
+            break;
+        }
+        available -= lines.len();
+        rows.push((name, lines, age));
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    let heights = rows
+        .iter()
+        .map(|(_, lines, _)| lines.len() as u16)
+        .collect::<Vec<u16>>();
+    let chunks = get_chunks_from_area(area, &heights);
 
-    for (i, (name, value, age)) in details.iter().enumerate() {
+    for (i, (name, lines, age)) in rows.into_iter().enumerate() {
         let formatted_name = format!("{}: ", &name);
         if let Some(chunk) = chunks.get(i) {
             draw_name_value_paragraphs(
@@ -281,7 +1169,7 @@ fn render_block_with_title_and_details(
                 foreground_color,
                 *chunk,
                 formatted_name.to_string(),
-                value.to_string(),
+                lines.join("\n"),
                 age.clone(),
             );
         }
@@ -291,3 +1179,226 @@ fn render_block_with_title_and_details(
         .style(Style::default().fg(foreground_color).bg(background_color));
     f.render_widget(details_block, area);
 }
+
+/// How long a row stays highlighted after it changed between polls
+pub const FLASH_DURATION: Duration = Duration::from_secs(2);
+
+/// Names of rows that are new or whose `key_fields` differ from the previous snapshot.
+/// Only the fields that matter are compared, an age ticking over is not a change.
+pub fn changed_rows<T, K: PartialEq>(
+    old: &[T],
+    new: &[T],
+    name: impl Fn(&T) -> &str,
+    key_fields: impl Fn(&T) -> K,
+) -> Vec<String> {
+    new.iter()
+        .filter(|item| {
+            old.iter()
+                .find(|previous| name(previous) == name(item))
+                .map_or(true, |previous| key_fields(previous) != key_fields(item))
+        })
+        .map(|item| name(item).to_string())
+        .collect()
+}
+
+/// The flashes still running plus a new one for each changed row
+pub fn update_flashes(
+    flashes: &BTreeMap<String, Instant>,
+    changed: Vec<String>,
+) -> BTreeMap<String, Instant> {
+    let now = Instant::now();
+    flashes
+        .iter()
+        .filter(|(_, started)| now.duration_since(**started) < FLASH_DURATION)
+        .map(|(name, started)| (name.clone(), *started))
+        .chain(changed.into_iter().map(|name| (name, now)))
+        .collect()
+}
+
+/// `style` for a row, highlighted while the row is still flashing from a change
+pub fn flash_style(
+    colors: &TableColors,
+    style: Style,
+    flashes: &BTreeMap<String, Instant>,
+    name: &str,
+) -> Style {
+    if flashes
+        .get(name)
+        .is_some_and(|started| started.elapsed() < FLASH_DURATION)
+    {
+        style.bg(colors.header_bg).add_modifier(Modifier::BOLD)
+    } else {
+        style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::ColumnFit;
+    use crate::tui::table_ui::{
+        changed_rows, column_constraints, edit_goto, fit_cell_text, max_column_offset,
+        scroll_columns, short_name, split_column_filter, step_row, too_small, truncate_lines,
+        wrap_text, DetailFocus, GotoCommand,
+    };
+    use crossterm::event::{KeyCode, KeyEvent};
+    use ratatui::layout::Constraint;
+
+    #[test]
+    fn test_detail_focus_cycles_and_scrolls() {
+        let key = |code| KeyEvent::from(code);
+        let mut focus = DetailFocus::default();
+        assert!(!focus.handle_key(&key(KeyCode::Char('j')), 2));
+        assert!(focus.handle_key(&key(KeyCode::Tab), 2));
+        assert!(focus.handle_key(&key(KeyCode::Char('j')), 2));
+        assert!(focus.handle_key(&key(KeyCode::Char('j')), 2));
+        assert_eq!(focus.offset(0), 2);
+        focus.handle_key(&key(KeyCode::Tab), 2);
+        focus.handle_key(&key(KeyCode::Char('k')), 2);
+        assert_eq!((focus.offset(0), focus.offset(1)), (2, 0));
+        focus.handle_key(&key(KeyCode::Tab), 2);
+        assert_eq!(focus.focused, None);
+        focus.follow("api-7d9f");
+        assert_eq!(focus.offset(0), 0);
+    }
+
+    #[test]
+    fn test_too_small_needs_both_dimensions() {
+        use ratatui::layout::Rect;
+
+        assert!(too_small(Rect::new(0, 0, 120, 8)));
+        assert!(too_small(Rect::new(0, 0, 30, 40)));
+        assert!(!too_small(Rect::new(0, 0, 80, 24)));
+    }
+
+    #[test]
+    fn test_split_column_filter_needs_a_word_before_the_colon() {
+        assert_eq!(split_column_filter("owner:nginx"), Some(("owner", "nginx")));
+        assert_eq!(
+            split_column_filter("storage-class:gp3"),
+            Some(("storage-class", "gp3"))
+        );
+        assert_eq!(split_column_filter("nginx"), None);
+        assert_eq!(split_column_filter(":nginx"), None);
+        assert_eq!(split_column_filter("10.0.1.17:8080"), None);
+    }
+
+    #[test]
+    fn test_wrap_text_breaks_on_words() {
+        let lines = wrap_text("Back-off restarting failed container", 16);
+        assert_eq!(vec!["Back-off", "restarting", "failed container"], lines);
+    }
+
+    #[test]
+    fn test_wrap_text_splits_long_words() {
+        let lines = wrap_text("abcdefghij", 4);
+        assert_eq!(vec!["abcd", "efgh", "ij"], lines);
+    }
+
+    #[test]
+    fn test_truncate_lines_adds_ellipsis() {
+        let lines = truncate_lines(wrap_text("one two three four", 5), 2, 5);
+        assert_eq!(vec!["one", "two…"], lines);
+    }
+
+    #[test]
+    fn test_edit_goto_collects_text_until_enter() {
+        let key = |code| KeyEvent::from(code);
+        let mut goto = Some(String::new());
+        assert_eq!(edit_goto(&mut goto, &key(KeyCode::Char('4'))), None);
+        assert_eq!(edit_goto(&mut goto, &key(KeyCode::Char('x'))), None);
+        assert_eq!(edit_goto(&mut goto, &key(KeyCode::Backspace)), None);
+        assert_eq!(edit_goto(&mut goto, &key(KeyCode::Char('2'))), None);
+        assert_eq!(goto.as_deref(), Some("42"));
+        assert_eq!(
+            edit_goto(&mut goto, &key(KeyCode::Enter)),
+            Some(GotoCommand::Line(42))
+        );
+        assert_eq!(goto, None);
+
+        let mut goto = Some("ns staging".to_string());
+        assert_eq!(
+            edit_goto(&mut goto, &key(KeyCode::Enter)),
+            Some(GotoCommand::Namespace("staging".to_string()))
+        );
+        let mut goto = Some("ns".to_string());
+        assert_eq!(
+            edit_goto(&mut goto, &key(KeyCode::Enter)),
+            Some(GotoCommand::Unknown("ns".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_column_constraints_cap_long_columns() {
+        let lens = [64, 8, 4];
+        assert_eq!(
+            column_constraints(ColumnFit::Content, &lens, 100),
+            vec![Constraint::Min(64), Constraint::Min(8), Constraint::Min(4)]
+        );
+        assert_eq!(
+            column_constraints(ColumnFit::Balanced, &lens, 100),
+            vec![Constraint::Ratio(1, 3); 3]
+        );
+        assert_eq!(
+            column_constraints(ColumnFit::Truncate, &lens, 100),
+            vec![Constraint::Min(40), Constraint::Min(8), Constraint::Min(4)]
+        );
+    }
+
+    #[test]
+    fn test_fit_cell_text_truncates_with_ellipsis() {
+        let name = "a".repeat(63);
+        assert_eq!(fit_cell_text(ColumnFit::Content, &name, 50), name);
+        assert_eq!(
+            fit_cell_text(ColumnFit::Truncate, &name, 50),
+            "aaaaaaaaaaaaaaaaaa…"
+        );
+        assert_eq!(fit_cell_text(ColumnFit::Truncate, "short", 50), "short");
+    }
+
+    #[test]
+    fn test_short_name() {
+        assert_eq!(
+            short_name("nginx-deployment-66b6c48dd5-x7kqz"),
+            "nginx-deployment…x7kqz"
+        );
+        assert_eq!(
+            short_name("nginx-deployment-7c5f9d8b4"),
+            "nginx-deployment…9d8b4"
+        );
+        assert_eq!(short_name("web-0"), "web-0");
+        assert_eq!(short_name("kube-dns"), "kube-dns");
+    }
+
+    #[test]
+    fn test_scroll_columns_keeps_pinned() {
+        let columns = vec!["name", "pods", "age", "description", "owner"];
+        assert_eq!(scroll_columns(columns.clone(), 1, 0), columns);
+        assert_eq!(
+            scroll_columns(columns, 1, 2),
+            vec!["name", "description", "owner"]
+        );
+        assert_eq!(max_column_offset(5, 1), 3);
+    }
+
+    #[test]
+    fn test_changed_rows() {
+        let old = [("web-0", "Running", "5m"), ("web-1", "Pending", "5m")];
+        let new = [
+            ("web-0", "Running", "6m"),
+            ("web-1", "Running", "6m"),
+            ("web-2", "Pending", "1s"),
+        ];
+        let changed = changed_rows(&old, &new, |pod| pod.0, |pod| pod.1);
+        assert_eq!(changed, vec!["web-1".to_string(), "web-2".to_string()]);
+    }
+
+    #[test]
+    fn test_step_row_wraps_only_when_asked() {
+        assert_eq!(step_row(1, 3, true, false), Some(2));
+        assert_eq!(step_row(2, 3, true, false), None);
+        assert_eq!(step_row(2, 3, true, true), Some(0));
+        assert_eq!(step_row(0, 3, false, false), None);
+        assert_eq!(step_row(0, 3, false, true), Some(2));
+        assert_eq!(step_row(0, 0, true, true), None);
+    }
+}
@@ -1,21 +1,50 @@
+use crate::config::{self, AppConfig, StartView};
+use crate::config_file::ConfigFile;
+use crate::k8s::client;
 use crate::k8s::containers::list as list_containers;
+use crate::k8s::custom::parse_kind;
+use crate::k8s::errors::list_error_message;
+use crate::k8s::namespaces::namespace_exists;
 use crate::k8s::rs::get_replicaset;
 use crate::k8s::rs_ingress::list_ingresses;
+use crate::k8s::services::get_service_selector;
 use crate::net::analyze_tls_certificate;
+use crate::tui::breadcrumb::{self, Crumb};
 use crate::tui::cert_app;
+use crate::tui::configmap_app;
+use crate::tui::configmap_app::app::ConfigKind;
 use crate::tui::container_app;
-use crate::tui::data;
+use crate::tui::custom_app;
+use crate::tui::daemonset_app;
+use crate::tui::data::{self, FinderTarget};
 use crate::tui::event_app;
 use crate::tui::ingress_app;
+use crate::tui::job_app;
 use crate::tui::log_app;
+use crate::tui::namespace_app;
+use crate::tui::node_app;
 use crate::tui::pod_app;
+use crate::tui::problems_app;
+use crate::tui::pvc_app;
+use crate::tui::resource_app;
 use crate::tui::rs_app;
-use crate::tui::stream::{async_key_events, Message};
+use crate::tui::selection;
+use crate::tui::service_app;
+use crate::tui::status::show_toast;
+use crate::tui::stream::{
+    async_key_events, request_redraw, shutdown, spawn_task, stop_tasks, Message,
+};
+use crate::tui::table_ui::{
+    cycle_namespace, namespace_bound_message, toggle_compact_rows, TuiTableState,
+};
 use crate::tui::utils::time::asn1time_to_future_days_string;
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    cursor,
+    event::{DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers},
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    terminal::{
+        disable_raw_mode, enable_raw_mode, size, EnterAlternateScreen, LeaveAlternateScreen,
+    },
 };
 use futures::stream::Stream;
 use futures::stream::StreamExt;
@@ -24,7 +53,7 @@ use std::collections::BTreeMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::{error::Error, io};
-use tracing::error;
+use tracing::{debug, error, warn};
 
 pub(crate) trait AppBehavior {
     async fn handle_event(&mut self, event: &Message) -> Result<Option<Apps>, io::Error>;
@@ -36,33 +65,104 @@ pub(crate) trait AppBehavior {
 
 /// # Errors
 ///
-/// Will return `Err` if function cannot access a terminal or render a ui
-pub async fn run() -> Result<(), Box<dyn Error>> {
-    // setup terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
+/// Will return `Err` if the config is invalid or function cannot access a terminal or render a ui
+pub async fn run(app_config: AppConfig) -> Result<(), Box<dyn Error>> {
+    // reject bad options while the terminal is still usable for the error message
+    app_config.validate()?;
+    let requested_namespace = app_config.namespace.clone();
+    let saved = ConfigFile::load();
+    let app_config = saved.apply_preferences(app_config)?;
+    let app_config = if app_config.fresh {
+        app_config
+    } else {
+        saved.restore_session(app_config)
+    };
+    config::install(app_config);
+    // one client for every poll instead of a new connection per list call, built once the
+    // session is restored and before raw mode, so a missing kubeconfig or service account
+    // is reported on a sane terminal
+    client::shared().await?;
+    if requested_namespace.is_none() {
+        forget_missing_namespace().await;
+    }
+    // the status bar shows `unknown` until the server answers, startup does not wait
+    spawn_task(async {
+        client::fetch_server_version().await;
+        request_redraw();
+    });
+
+    enter_tui()?;
+    let backend = CrosstermBackend::new(io::stdout());
     let mut terminal = Terminal::new(backend)?;
 
     let res = run_root_ui_loop(&mut terminal).await;
 
-    // restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    // the tasks may still be mid request, let them finish before the terminal is restored
+    shutdown().await;
+    leave_tui()?;
 
     if let Err(err) = res {
         error!("{err:?}");
     }
 
+    // the terminal is back in its normal mode, so the names land cleanly in a pipe
+    if config::current().print_selection {
+        print!("{}", selection::lines());
+    }
+
+    let mut saved = ConfigFile::load();
+    saved.record_session(&config::current());
+    if let Err(e) = saved.save() {
+        error!("could not save the session: {e}");
+    }
+
     Ok(())
 }
 
+/// Put the terminal in the raw, alternate screen mode the TUI draws in
+///
+/// # Errors
+///
+/// Will return `Err` if the terminal can not be switched
+pub(crate) fn enter_tui() -> io::Result<()> {
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)
+}
+
+/// Hand the terminal back the way the TUI found it
+///
+/// # Errors
+///
+/// Will return `Err` if the terminal can not be switched
+pub(crate) fn leave_tui() -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        cursor::Show
+    )
+}
+
+/// Fall back to the context's default namespace when the one restored from the last
+/// session has been deleted since
+async fn forget_missing_namespace() {
+    let Some(namespace) = config::current().namespace else {
+        return;
+    };
+    match namespace_exists(&namespace).await {
+        Ok(true) => {}
+        Ok(false) => {
+            warn!(
+                "namespace {namespace} from the last session no longer exists, using the default"
+            );
+            config::update(|config| config.namespace = None);
+        }
+        // e.g. not allowed to read namespaces, the views will report any real problem
+        Err(e) => debug!("could not check namespace {namespace}: {e}"),
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum Apps {
     Rs { app: rs_app::app::App },
@@ -72,6 +172,16 @@ pub enum Apps {
     Cert { app: cert_app::app::App },
     Log { app: log_app::app::App },
     Event { app: event_app::app::App },
+    Config { app: configmap_app::app::App },
+    DaemonSet { app: daemonset_app::app::App },
+    Job { app: job_app::app::App },
+    Namespace { app: namespace_app::app::App },
+    Node { app: node_app::app::App },
+    Pvc { app: pvc_app::app::App },
+    Problem { app: problems_app::app::App },
+    Service { app: service_app::app::App },
+    ApiResource { app: resource_app::app::App },
+    Custom { app: custom_app::app::App },
 }
 
 /// # Errors
@@ -83,7 +193,10 @@ pub async fn create_container_data_vec(
 ) -> Result<Vec<data::Container>, io::Error> {
     match list_containers(selectors, pod_name).await {
         Ok(cntrs) => Ok(cntrs),
-        Err(e) => Err(io::Error::new(io::ErrorKind::Other, e.to_string())),
+        Err(e) => Err(io::Error::new(
+            io::ErrorKind::Other,
+            list_error_message(&e, "containers"),
+        )),
     }
 }
 
@@ -97,11 +210,34 @@ pub async fn create_ingress_data_vec(
         Ok(rso) => match rso {
             Some(rs) => match list_ingresses(&rs, "").await {
                 Ok(ingress) => Ok(ingress),
-                Err(e) => Err(io::Error::new(io::ErrorKind::Other, e.to_string())),
+                Err(e) => Err(io::Error::new(
+                    io::ErrorKind::Other,
+                    list_error_message(&e, "ingresses"),
+                )),
             },
             _ => Ok(vec![]),
         },
-        Err(e) => Err(io::Error::new(io::ErrorKind::Other, e.to_string())),
+        Err(e) => Err(io::Error::new(
+            io::ErrorKind::Other,
+            list_error_message(&e, "replicasets"),
+        )),
+    }
+}
+
+/// # Errors
+///
+/// Will return `Err` if the service can not be read or does not select any pods
+pub async fn create_backend_selector(service: &str) -> Result<BTreeMap<String, String>, io::Error> {
+    match get_service_selector(service).await {
+        Ok(Some(selector)) => Ok(selector),
+        Ok(None) => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("service {service} not found or has no pod selector"),
+        )),
+        Err(e) => Err(io::Error::new(
+            io::ErrorKind::Other,
+            list_error_message(&e, "services"),
+        )),
     }
 }
 
@@ -130,6 +266,7 @@ pub async fn create_cert_data_vec(host: &str) -> Result<Vec<data::Cert>, io::Err
 async fn run_app<B>(
     terminal: &mut Terminal<B>,
     apps_app: &mut Apps,
+    mut restore: Option<Crumb>,
 ) -> Result<(Option<Apps>, Option<Apps>), io::Error>
 where
     B: Backend + Send,
@@ -150,7 +287,11 @@ where
             loop {
                 _ = current_app.draw_ui(terminal);
                 if let Some(event) = events.next().await {
-                    let app_holder = current_app.handle_event(&event).await?;
+                    let (event, switch) = global_key(event, &current_app);
+                    let app_holder = match switch {
+                        Some(switch) => Some(switch),
+                        None => handle_and_restore(&mut current_app, &event, &mut restore).await?,
+                    };
                     if let Some(Apps::Rs { app }) = &app_holder {
                         current_app = app.clone();
                         old_app_holder = app_holder;
@@ -169,7 +310,12 @@ where
             loop {
                 _ = current_app.draw_ui(terminal);
                 if let Some(event) = events.next().await {
-                    let app_holder = current_app.handle_event(&event).await?;
+                    let (event, switch) = global_key(event, &current_app);
+                    let app_holder = match switch {
+                        Some(switch) => Some(switch),
+                        None => handle_and_restore(&mut current_app, &event, &mut restore).await?,
+                    };
+                    selection::record(&current_app.checked);
                     if let Some(Apps::Pod { app }) = &app_holder {
                         current_app = app.clone();
                         old_app_holder = app_holder;
@@ -188,7 +334,11 @@ where
             loop {
                 _ = current_app.draw_ui(terminal);
                 if let Some(event) = events.next().await {
-                    let app_holder = current_app.handle_event(&event).await?;
+                    let (event, switch) = global_key(event, &current_app);
+                    let app_holder = match switch {
+                        Some(switch) => Some(switch),
+                        None => handle_and_restore(&mut current_app, &event, &mut restore).await?,
+                    };
                     if let Some(Apps::Container { app }) = &app_holder {
                         current_app = app.clone();
                         old_app_holder = app_holder;
@@ -207,7 +357,11 @@ where
             loop {
                 _ = current_app.draw_ui(terminal);
                 if let Some(event) = events.next().await {
-                    let app_holder = current_app.handle_event(&event).await?;
+                    let (event, switch) = global_key(event, &current_app);
+                    let app_holder = match switch {
+                        Some(switch) => Some(switch),
+                        None => handle_and_restore(&mut current_app, &event, &mut restore).await?,
+                    };
                     if let Some(Apps::Cert { app }) = &app_holder {
                         current_app = app.clone();
                         old_app_holder = app_holder;
@@ -226,7 +380,11 @@ where
             loop {
                 _ = current_app.draw_ui(terminal);
                 if let Some(event) = events.next().await {
-                    let app_holder = current_app.handle_event(&event).await?;
+                    let (event, switch) = global_key(event, &current_app);
+                    let app_holder = match switch {
+                        Some(switch) => Some(switch),
+                        None => handle_and_restore(&mut current_app, &event, &mut restore).await?,
+                    };
                     if let Some(Apps::Ingress { app }) = &app_holder {
                         current_app = app.clone();
                         old_app_holder = app_holder;
@@ -246,7 +404,11 @@ where
             loop {
                 _ = current_app.draw_ui(terminal);
                 if let Some(event) = events.next().await {
-                    let app_holder = current_app.handle_event(&event).await?;
+                    let (event, switch) = global_key(event, &current_app);
+                    let app_holder = match switch {
+                        Some(switch) => Some(switch),
+                        None => handle_and_restore(&mut current_app, &event, &mut restore).await?,
+                    };
                     if let Some(Apps::Log { app }) = &app_holder {
                         current_app = app.clone();
                         old_app_holder = app_holder;
@@ -266,7 +428,11 @@ where
             loop {
                 _ = current_app.draw_ui(terminal);
                 if let Some(event) = events.next().await {
-                    let app_holder = current_app.handle_event(&event).await?;
+                    let (event, switch) = global_key(event, &current_app);
+                    let app_holder = match switch {
+                        Some(switch) => Some(switch),
+                        None => handle_and_restore(&mut current_app, &event, &mut restore).await?,
+                    };
                     if let Some(Apps::Event { app }) = &app_holder {
                         current_app = app.clone();
                         old_app_holder = app_holder;
@@ -277,34 +443,408 @@ where
                 };
             }
         }
+
+        Apps::Config { app } => {
+            let data_init_clone = app.clone();
+            let data_events = data_init_clone.stream(should_stop.clone());
+            let mut events = futures::stream::select(data_events, key_events);
+            let mut current_app = app.clone();
+            loop {
+                _ = current_app.draw_ui(terminal);
+                if let Some(event) = events.next().await {
+                    let (event, switch) = global_key(event, &current_app);
+                    let app_holder = match switch {
+                        Some(switch) => Some(switch),
+                        None => handle_and_restore(&mut current_app, &event, &mut restore).await?,
+                    };
+                    if let Some(Apps::Config { app }) = &app_holder {
+                        current_app = app.clone();
+                        old_app_holder = app_holder;
+                    } else {
+                        new_app_holder = app_holder;
+                        break;
+                    };
+                };
+            }
+        }
+
+        Apps::DaemonSet { app } => {
+            let data_init_clone = app.clone();
+            let data_events = data_init_clone.stream(should_stop.clone());
+            let mut events = futures::stream::select(data_events, key_events);
+            let mut current_app = app.clone();
+            loop {
+                _ = current_app.draw_ui(terminal);
+                if let Some(event) = events.next().await {
+                    let (event, switch) = global_key(event, &current_app);
+                    let app_holder = match switch {
+                        Some(switch) => Some(switch),
+                        None => handle_and_restore(&mut current_app, &event, &mut restore).await?,
+                    };
+                    if let Some(Apps::DaemonSet { app }) = &app_holder {
+                        current_app = app.clone();
+                        old_app_holder = app_holder;
+                    } else {
+                        new_app_holder = app_holder;
+                        break;
+                    };
+                };
+            }
+        }
+
+        Apps::Job { app } => {
+            let data_init_clone = app.clone();
+            let data_events = data_init_clone.stream(should_stop.clone());
+            let mut events = futures::stream::select(data_events, key_events);
+            let mut current_app = app.clone();
+            loop {
+                _ = current_app.draw_ui(terminal);
+                if let Some(event) = events.next().await {
+                    let (event, switch) = global_key(event, &current_app);
+                    let app_holder = match switch {
+                        Some(switch) => Some(switch),
+                        None => handle_and_restore(&mut current_app, &event, &mut restore).await?,
+                    };
+                    if let Some(Apps::Job { app }) = &app_holder {
+                        current_app = app.clone();
+                        old_app_holder = app_holder;
+                    } else {
+                        new_app_holder = app_holder;
+                        break;
+                    };
+                };
+            }
+        }
+
+        Apps::Namespace { app } => {
+            let data_init_clone = app.clone();
+            let data_events = data_init_clone.stream(should_stop.clone());
+            let mut events = futures::stream::select(data_events, key_events);
+            let mut current_app = app.clone();
+            loop {
+                _ = current_app.draw_ui(terminal);
+                if let Some(event) = events.next().await {
+                    let (event, _) = global_key(event, &current_app);
+                    let app_holder =
+                        handle_and_restore(&mut current_app, &event, &mut restore).await?;
+                    if let Some(Apps::Namespace { app }) = &app_holder {
+                        current_app = app.clone();
+                        old_app_holder = app_holder;
+                    } else {
+                        new_app_holder = app_holder;
+                        break;
+                    };
+                };
+            }
+        }
+
+        Apps::Pvc { app } => {
+            let data_init_clone = app.clone();
+            let data_events = data_init_clone.stream(should_stop.clone());
+            let mut events = futures::stream::select(data_events, key_events);
+            let mut current_app = app.clone();
+            loop {
+                _ = current_app.draw_ui(terminal);
+                if let Some(event) = events.next().await {
+                    let (event, switch) = global_key(event, &current_app);
+                    let app_holder = match switch {
+                        Some(switch) => Some(switch),
+                        None => handle_and_restore(&mut current_app, &event, &mut restore).await?,
+                    };
+                    if let Some(Apps::Pvc { app }) = &app_holder {
+                        current_app = app.clone();
+                        old_app_holder = app_holder;
+                    } else {
+                        new_app_holder = app_holder;
+                        break;
+                    };
+                };
+            }
+        }
+
+        Apps::Node { app } => {
+            let data_init_clone = app.clone();
+            let data_events = data_init_clone.stream(should_stop.clone());
+            let mut events = futures::stream::select(data_events, key_events);
+            let mut current_app = app.clone();
+            loop {
+                _ = current_app.draw_ui(terminal);
+                if let Some(event) = events.next().await {
+                    let (event, switch) = global_key(event, &current_app);
+                    let app_holder = match switch {
+                        Some(switch) => Some(switch),
+                        None => handle_and_restore(&mut current_app, &event, &mut restore).await?,
+                    };
+                    if let Some(Apps::Node { app }) = &app_holder {
+                        current_app = app.clone();
+                        old_app_holder = app_holder;
+                    } else {
+                        new_app_holder = app_holder;
+                        break;
+                    };
+                };
+            }
+        }
+
+        Apps::Problem { app } => {
+            let data_init_clone = app.clone();
+            let data_events = data_init_clone.stream(should_stop.clone());
+            let mut events = futures::stream::select(data_events, key_events);
+            let mut current_app = app.clone();
+            loop {
+                _ = current_app.draw_ui(terminal);
+                if let Some(event) = events.next().await {
+                    let (event, switch) = global_key(event, &current_app);
+                    let app_holder = match switch {
+                        Some(switch) => Some(switch),
+                        None => handle_and_restore(&mut current_app, &event, &mut restore).await?,
+                    };
+                    if let Some(Apps::Problem { app }) = &app_holder {
+                        current_app = app.clone();
+                        old_app_holder = app_holder;
+                    } else {
+                        new_app_holder = app_holder;
+                        break;
+                    };
+                };
+            }
+        }
+
+        Apps::Service { app } => {
+            let data_init_clone = app.clone();
+            let data_events = data_init_clone.stream(should_stop.clone());
+            let mut events = futures::stream::select(data_events, key_events);
+            let mut current_app = app.clone();
+            loop {
+                _ = current_app.draw_ui(terminal);
+                if let Some(event) = events.next().await {
+                    let (event, switch) = global_key(event, &current_app);
+                    let app_holder = match switch {
+                        Some(switch) => Some(switch),
+                        None => handle_and_restore(&mut current_app, &event, &mut restore).await?,
+                    };
+                    if let Some(Apps::Service { app }) = &app_holder {
+                        current_app = app.clone();
+                        old_app_holder = app_holder;
+                    } else {
+                        new_app_holder = app_holder;
+                        break;
+                    };
+                };
+            }
+        }
+
+        Apps::ApiResource { app } => {
+            let data_init_clone = app.clone();
+            let data_events = data_init_clone.stream(should_stop.clone());
+            let mut events = futures::stream::select(data_events, key_events);
+            let mut current_app = app.clone();
+            loop {
+                _ = current_app.draw_ui(terminal);
+                if let Some(event) = events.next().await {
+                    let (event, switch) = global_key(event, &current_app);
+                    let app_holder = match switch {
+                        Some(switch) => Some(switch),
+                        None => handle_and_restore(&mut current_app, &event, &mut restore).await?,
+                    };
+                    if let Some(Apps::ApiResource { app }) = &app_holder {
+                        current_app = app.clone();
+                        old_app_holder = app_holder;
+                    } else {
+                        new_app_holder = app_holder;
+                        break;
+                    };
+                };
+            }
+        }
+
+        Apps::Custom { app } => {
+            let data_init_clone = app.clone();
+            let data_events = data_init_clone.stream(should_stop.clone());
+            let mut events = futures::stream::select(data_events, key_events);
+            let mut current_app = app.clone();
+            loop {
+                _ = current_app.draw_ui(terminal);
+                if let Some(event) = events.next().await {
+                    let (event, switch) = global_key(event, &current_app);
+                    let app_holder = match switch {
+                        Some(switch) => Some(switch),
+                        None => handle_and_restore(&mut current_app, &event, &mut restore).await?,
+                    };
+                    if let Some(Apps::Custom { app }) = &app_holder {
+                        current_app = app.clone();
+                        old_app_holder = app_holder;
+                    } else {
+                        new_app_holder = app_holder;
+                        break;
+                    };
+                };
+            }
+        }
     }
 
     should_stop.store(true, Ordering::Relaxed);
+    stop_tasks();
     Ok((old_app_holder, new_app_holder))
 }
 
+/// Keys every view shares, handled before the view sees them unless one of its prompts
+/// or confirmations has the keyboard. Ctrl-N opens the namespace finder, picking a namespace there returns
+/// to the view, which then lists the same kind of objects in the new namespace. Ctrl-T
+/// toggles compact rows and Ctrl-O steps to the next namespace of the cycle, the view
+/// gets a resize in place of the key, which re-syncs its scrollbar to the new row height
+/// or list. Neither namespace key switches while the view has rows checked.
+fn global_key<T: TuiTableState>(event: Message, app: &T) -> (Message, Option<Apps>) {
+    let key = match &event {
+        Message::Key(Event::Key(key)) => *key,
+        _ => return (event, None),
+    };
+    if app.typing()
+        || key.kind != KeyEventKind::Press
+        || !key.modifiers.contains(KeyModifiers::CONTROL)
+    {
+        return (event, None);
+    }
+    match key.code {
+        KeyCode::Char('n') if app.namespace_bound() => show_toast(namespace_bound_message()),
+        KeyCode::Char('n') => {
+            let finder = Apps::Namespace {
+                app: namespace_app::app::App::quick(),
+            };
+            return (event, Some(finder));
+        }
+        KeyCode::Char('t') => toggle_compact_rows(),
+        KeyCode::Char('o') => show_toast(cycle_namespace(app.namespace_bound())),
+        _ => return (event, None),
+    }
+    let resize = size().map_or(event, |(columns, rows)| {
+        Message::Key(Event::Resize(columns, rows))
+    });
+    (resize, None)
+}
+
+/// The app at the bottom of the navigation stack, Esc from it quits
+fn root_app(view: StartView) -> Apps {
+    match view {
+        StartView::Replicasets => Apps::Rs {
+            app: rs_app::app::App::new(vec![]),
+        },
+        StartView::Pods => Apps::Pod {
+            app: pod_app::app::App::new(BTreeMap::new(), vec![]),
+        },
+        StartView::Nodes => Apps::Node {
+            app: node_app::app::App::new(),
+        },
+        StartView::Problems => Apps::Problem {
+            app: problems_app::app::App::new(),
+        },
+    }
+}
+
+/// The view a finder or related resources pick opens, `None` when k8p has no view for it
+pub(crate) fn target_app(target: FinderTarget) -> Option<Apps> {
+    Some(match target {
+        FinderTarget::Pods(selector) => Apps::Pod {
+            app: pod_app::app::App::new(selector, vec![]),
+        },
+        FinderTarget::Pod { selector, name } => Apps::Pod {
+            app: pod_app::app::App::new(selector, vec![]).with_focus(name),
+        },
+        FinderTarget::Ingress(data_vec) => Apps::Ingress {
+            app: ingress_app::app::App::new(data_vec),
+        },
+        FinderTarget::ReplicaSet(name) => {
+            let mut app = rs_app::app::App::new(vec![]);
+            app.set_filter(name);
+            Apps::Rs { app }
+        }
+        FinderTarget::Job(name) => {
+            let mut app = job_app::app::App::new();
+            app.set_filter(name);
+            Apps::Job { app }
+        }
+        FinderTarget::Service(name) => {
+            let mut app = service_app::app::App::new();
+            app.set_filter(name);
+            Apps::Service { app }
+        }
+        FinderTarget::ConfigMap(name) => {
+            let mut app = configmap_app::app::App::new(ConfigKind::ConfigMap);
+            app.set_filter(name);
+            Apps::Config { app }
+        }
+        FinderTarget::Secret(name) => {
+            let mut app = configmap_app::app::App::new(ConfigKind::Secret);
+            app.set_filter(name);
+            Apps::Config { app }
+        }
+        FinderTarget::Claims(pod) => Apps::Pvc {
+            app: pvc_app::app::App::new(Some(pod)),
+        },
+        FinderTarget::NoView => return None,
+    })
+}
+
+/// The view to go back to and where it stood when the user drilled out of it
+pub(crate) fn pop_view(history: &mut Vec<(Arc<Apps>, Crumb)>) -> Option<(Apps, Crumb)> {
+    history
+        .pop()
+        .map(|(previous_app, crumb)| ((*previous_app).clone(), crumb))
+}
+
+/// Let `app` handle `event`. The first items it receives after going back to it are
+/// fresh from the cluster, so the row `restore` names is selected again among those.
+pub(crate) async fn handle_and_restore<T: AppBehavior>(
+    app: &mut T,
+    event: &Message,
+    restore: &mut Option<Crumb>,
+) -> Result<Option<Apps>, io::Error> {
+    let mut app_holder = app.handle_event(event).await?;
+    if matches!(event, Message::Key(Event::Key(_) | Event::Mouse(_))) {
+        // the user moved on before the items arrived
+        *restore = None;
+    } else if breadcrumb::lists_items(event) {
+        if let (Some(crumb), Some(holder)) = (restore.take(), app_holder.as_mut()) {
+            breadcrumb::restore(holder, &crumb);
+        }
+    }
+    Ok(app_holder)
+}
+
 /// runs a stack of apps where navigation is "<Enter>" into and "<Esc>" out of
 async fn run_root_ui_loop<B: Backend + Send>(terminal: &mut Terminal<B>) -> io::Result<()> {
-    let data_vec = vec![];
-    let mut app_holder = Apps::Rs {
-        app: rs_app::app::App::new(data_vec),
-    };
+    let mut app_holder = root_app(config::current().view.unwrap_or_default());
 
-    let mut history: Vec<Arc<Apps>> = Vec::new();
+    let mut history: Vec<(Arc<Apps>, Crumb)> = Vec::new();
+    // where the view popped back to stood, until its first items arrive
+    let mut restore = None;
+    // --resource was checked by validate, Esc still leads back to the start view
+    if let Some(Ok(kind)) = config::current().resource.as_deref().map(parse_kind) {
+        let crumb = Crumb::of(&app_holder);
+        history.push((Arc::new(app_holder), crumb));
+        app_holder = Apps::Custom {
+            app: custom_app::app::App::new(kind),
+        };
+        breadcrumb::set_trail(&history);
+    }
     loop {
-        match run_app(terminal, &mut app_holder).await? {
+        match run_app(terminal, &mut app_holder, restore.take()).await? {
             (Some(old_app_holder), Some(new_app_holder)) => {
-                history.push(Arc::new(old_app_holder)); // this is an app switch
+                // this is an app switch
+                let crumb = Crumb::of(&old_app_holder);
+                history.push((Arc::new(old_app_holder), crumb));
                 app_holder = new_app_holder;
             }
             (_, _) => {
-                if let Some(previous_app) = history.pop() {
-                    app_holder = (*previous_app).clone();
+                if let Some((previous_app, crumb)) = pop_view(&mut history) {
+                    app_holder = previous_app;
+                    restore = Some(crumb);
                 } else {
                     break; //quit
                 }
             }
         }
+        breadcrumb::set_trail(&history);
     }
     Ok(())
 }
@@ -0,0 +1,220 @@
+//! Modals shared by the views: a yes/no confirmation, a one line text input and a
+//! panel of text read until the next key.
+//!
+//! Each handles its own keys and draws itself centered over the current frame, so a
+//! view only keeps the widget while it is open and acts on what it reports back.
+use crate::tui::style::TableColors;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+/// A question about `subject`, e.g. the pods to delete. `y` confirms, any other key
+/// cancels.
+#[derive(Clone, Debug)]
+pub struct ConfirmModal<T> {
+    pub subject: T,
+    title: String,
+    lines: Vec<String>,
+}
+
+impl<T> ConfirmModal<T> {
+    pub fn new(subject: T, title: impl Into<String>, lines: Vec<String>) -> Self {
+        Self {
+            subject,
+            title: title.into(),
+            lines,
+        }
+    }
+
+    /// `Some(true)` once confirmed, `Some(false)` once cancelled, `None` for a key that
+    /// is not a press
+    pub fn handle_key(&self, key: &KeyEvent) -> Option<bool> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        Some(matches!(key.code, KeyCode::Char('y' | 'Y')))
+    }
+
+    pub fn render(&self, f: &mut Frame, colors: &TableColors) {
+        let style = Style::default().fg(colors.header_fg).bg(colors.header_bg);
+        let lines: Vec<Line> = self
+            .lines
+            .iter()
+            .map(|line| Line::from(line.as_str()))
+            .collect();
+
+        let area = centered_rect(60, 40, f.area());
+        let block = Paragraph::new(lines)
+            .style(style)
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.title.as_str()),
+            );
+
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+    }
+}
+
+/// Lines to read, e.g. a diagnosis, closed by any key
+#[derive(Clone, Debug)]
+pub struct InfoModal {
+    title: String,
+    lines: Vec<String>,
+}
+
+impl InfoModal {
+    pub fn new(title: impl Into<String>, lines: Vec<String>) -> Self {
+        Self {
+            title: title.into(),
+            lines,
+        }
+    }
+
+    /// Whether `key` closes the panel, any press does
+    pub fn handle_key(&self, key: &KeyEvent) -> bool {
+        key.kind == KeyEventKind::Press
+    }
+
+    pub fn render(&self, f: &mut Frame, colors: &TableColors) {
+        let style = Style::default().fg(colors.header_fg).bg(colors.header_bg);
+        let lines: Vec<Line> = self
+            .lines
+            .iter()
+            .map(|line| Line::from(line.as_str()))
+            .collect();
+
+        let area = centered_rect(70, 50, f.area());
+        let block = Paragraph::new(lines)
+            .style(style)
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.title.as_str()),
+            );
+
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+    }
+}
+
+/// What a key did to a `TextInput`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputOutcome {
+    Editing,
+    /// Enter was pressed on this text
+    Submitted(String),
+    /// Esc closed the input
+    Cancelled,
+}
+
+/// A single line of text typed into a titled box, with a cursor at the end
+#[derive(Clone, Debug)]
+pub struct TextInput {
+    title: String,
+    value: String,
+}
+
+impl TextInput {
+    /// An input titled `title` that starts out holding `value`, so it can be edited
+    pub fn new(title: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn handle_key(&mut self, key: &KeyEvent) -> InputOutcome {
+        if key.kind != KeyEventKind::Press {
+            return InputOutcome::Editing;
+        }
+        match key.code {
+            KeyCode::Char(c) => {
+                self.value.push(c);
+                InputOutcome::Editing
+            }
+            KeyCode::Backspace => {
+                self.value.pop();
+                InputOutcome::Editing
+            }
+            KeyCode::Enter => InputOutcome::Submitted(self.value.clone()),
+            KeyCode::Esc => InputOutcome::Cancelled,
+            _ => InputOutcome::Editing,
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, colors: &TableColors) {
+        let style = Style::default().fg(colors.header_fg).bg(colors.header_bg);
+        let area = centered_rect(60, 20, f.area());
+        let block = Paragraph::new(self.value.as_str()).style(style).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(self.title.as_str()),
+        );
+
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        #[allow(clippy::cast_possible_truncation)]
+        let x = (area.x + 1 + self.value.chars().count() as u16).min(area.right() - 1);
+        f.set_cursor_position(Position { x, y: area.y + 1 });
+    }
+}
+
+/// A rectangle `percent_x` by `percent_y` of `r`, in its middle
+pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::vertical([
+        Constraint::Percentage((100 - percent_y) / 2),
+        Constraint::Percentage(percent_y),
+        Constraint::Percentage((100 - percent_y) / 2),
+    ])
+    .split(r);
+
+    Layout::horizontal([
+        Constraint::Percentage((100 - percent_x) / 2),
+        Constraint::Percentage(percent_x),
+        Constraint::Percentage((100 - percent_x) / 2),
+    ])
+    .split(popup_layout[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConfirmModal, InputOutcome, TextInput};
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    #[test]
+    fn test_text_input_edits_until_enter() {
+        let mut input = TextInput::new("selector", "app=");
+        assert_eq!(
+            input.handle_key(&KeyEvent::from(KeyCode::Char('x'))),
+            InputOutcome::Editing
+        );
+        input.handle_key(&KeyEvent::from(KeyCode::Char('y')));
+        input.handle_key(&KeyEvent::from(KeyCode::Backspace));
+        assert_eq!(
+            input.handle_key(&KeyEvent::from(KeyCode::Enter)),
+            InputOutcome::Submitted("app=x".to_string())
+        );
+        assert_eq!(
+            input.handle_key(&KeyEvent::from(KeyCode::Esc)),
+            InputOutcome::Cancelled
+        );
+    }
+
+    #[test]
+    fn test_confirm_modal_only_confirms_on_y() {
+        let modal = ConfirmModal::new(vec!["web-0"], "Delete pods?", vec![]);
+        assert_eq!(
+            modal.handle_key(&KeyEvent::from(KeyCode::Char('y'))),
+            Some(true)
+        );
+        assert_eq!(
+            modal.handle_key(&KeyEvent::from(KeyCode::Char('n'))),
+            Some(false)
+        );
+    }
+}